@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use subtle::ConstantTimeEq;
+use tokio::{net::TcpListener, sync::Notify};
+
+use crate::{world::WorldHandle, SETTINGS};
+
+mod maps;
+mod players;
+mod server;
+
+#[derive(Clone)]
+struct ApiState {
+    world: WorldHandle,
+    shutdown: Arc<Notify>,
+    token: Arc<String>,
+}
+
+/// Body shared by the moderation endpoints. `admin` is the name shown in
+/// announcements and recorded against bans.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ActionRequest {
+    admin: Option<String>,
+    silent: bool,
+    /// Also ban the player's last address and hardware id
+    ban_ip_and_hdid: bool,
+    duration: Option<String>,
+    message: String,
+}
+
+impl ActionRequest {
+    fn admin_name(&self) -> String {
+        self.admin.clone().unwrap_or_else(|| "Server".to_string())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ApiError {
+    error: String,
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(ApiError {
+            error: message.to_string(),
+        }),
+    )
+        .into_response()
+}
+
+pub async fn serve(world: WorldHandle, shutdown: Arc<Notify>) {
+    if SETTINGS.admin_api.token.is_empty() {
+        error!("Admin API is enabled but no token is configured, not starting it");
+        return;
+    }
+
+    let addr = format!("{}:{}", SETTINGS.admin_api.host, SETTINGS.admin_api.port);
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind admin API to {}: {}", addr, e);
+            return;
+        }
+    };
+
+    info!("admin API listening at {}", addr);
+
+    serve_on(listener, world, shutdown, &SETTINGS.admin_api.token).await;
+}
+
+pub async fn serve_on(
+    listener: TcpListener,
+    world: WorldHandle,
+    shutdown: Arc<Notify>,
+    token: &str,
+) {
+    let state = ApiState {
+        world,
+        shutdown,
+        token: Arc::new(token.to_owned()),
+    };
+
+    let app = Router::new()
+        .route("/players", get(players::list_players))
+        .route("/players/:name/kick", post(players::kick_player))
+        .route("/players/:name/ban", post(players::ban_player))
+        .route("/players/:name/mute", post(players::mute_player))
+        .route("/players/:name/jail", post(players::jail_player))
+        .route("/maps", get(maps::list_maps))
+        .route("/maps/:id", get(maps::get_map))
+        .route("/maps/:id/reload", post(maps::reload_map))
+        .route("/announce", post(server::announce))
+        .route("/save", post(server::save))
+        .route("/reload/:kind", post(server::reload))
+        .route("/shutdown", post(server::shutdown))
+        .layer(middleware::from_fn_with_state(state.clone(), authenticate))
+        .with_state(state);
+
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("Admin API stopped: {}", e);
+    }
+}
+
+async fn authenticate(State(state): State<ApiState>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        // Compared in constant time so response times don't give the token away
+        .is_some_and(|token| token.as_bytes().ct_eq(state.token.as_bytes()).into());
+
+    if !authorized {
+        return error(StatusCode::UNAUTHORIZED, "Invalid or missing token");
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::map::MapHandle;
+
+use super::{error, ApiState};
+
+#[derive(Debug, Serialize)]
+pub struct MapSummary {
+    map_id: i32,
+    players: usize,
+    npcs: usize,
+    items: usize,
+}
+
+async fn summarize(map_id: i32, map: &MapHandle) -> MapSummary {
+    let stats = map.get_stats().await;
+    MapSummary {
+        map_id,
+        players: stats.players,
+        npcs: stats.npcs,
+        items: stats.items,
+    }
+}
+
+pub async fn list_maps(State(state): State<ApiState>) -> Json<Vec<MapSummary>> {
+    let mut maps = Vec::new();
+    for (map_id, map) in state.world.get_maps().await {
+        maps.push(summarize(map_id, &map).await);
+    }
+
+    maps.sort_by_key(|map| map.map_id);
+
+    Json(maps)
+}
+
+pub async fn get_map(State(state): State<ApiState>, Path(map_id): Path<i32>) -> Response {
+    match state.world.get_maps().await.get(&map_id) {
+        Some(map) => Json(summarize(map_id, map).await).into_response(),
+        None => error(StatusCode::NOT_FOUND, "Map not found"),
+    }
+}
+
+pub async fn reload_map(State(state): State<ApiState>, Path(map_id): Path<i32>) -> Response {
+    if !state.world.get_maps().await.contains_key(&map_id) {
+        return error(StatusCode::NOT_FOUND, "Map not found");
+    }
+
+    state.world.reload_map(map_id);
+    StatusCode::ACCEPTED.into_response()
+}
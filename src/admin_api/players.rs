@@ -0,0 +1,114 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use super::{error, ActionRequest, ApiState};
+
+#[derive(Debug, Serialize)]
+pub struct PlayerSummary {
+    player_id: i32,
+    name: String,
+    level: i32,
+    admin_level: i32,
+    map_id: i32,
+    x: i32,
+    y: i32,
+}
+
+pub async fn list_players(State(state): State<ApiState>) -> Json<Vec<PlayerSummary>> {
+    let mut players = Vec::new();
+
+    for player in state.world.get_online_players().await {
+        let character = match player.get_character().await {
+            Ok(character) => character,
+            Err(_) => continue,
+        };
+
+        players.push(PlayerSummary {
+            player_id: character.player_id.unwrap_or_default(),
+            name: character.name.clone(),
+            level: character.level,
+            admin_level: i32::from(character.admin_level),
+            map_id: character.map_id,
+            x: character.coords.x,
+            y: character.coords.y,
+        });
+    }
+
+    players.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Json(players)
+}
+
+pub async fn kick_player(
+    State(state): State<ApiState>,
+    Path(name): Path<String>,
+    body: Option<Json<ActionRequest>>,
+) -> Response {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+    let name = name.to_lowercase();
+
+    if state.world.get_character_by_name(&name).await.is_err() {
+        return error(StatusCode::NOT_FOUND, "Player is not online");
+    }
+
+    state
+        .world
+        .kick_player(name, body.admin_name(), body.silent);
+    StatusCode::NO_CONTENT.into_response()
+}
+
+pub async fn ban_player(
+    State(state): State<ApiState>,
+    Path(name): Path<String>,
+    body: Option<Json<ActionRequest>>,
+) -> Response {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+
+    state.world.ban_player(
+        name.to_lowercase(),
+        body.duration.clone().unwrap_or_default(),
+        body.admin_name(),
+        body.silent,
+        body.ban_ip_and_hdid,
+    );
+    StatusCode::NO_CONTENT.into_response()
+}
+
+pub async fn mute_player(
+    State(state): State<ApiState>,
+    Path(name): Path<String>,
+    body: Option<Json<ActionRequest>>,
+) -> Response {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+
+    if let Some(duration) = body.duration.as_deref() {
+        if !duration.is_empty() && duration_str::parse(duration).is_err() {
+            return error(StatusCode::BAD_REQUEST, "Invalid duration");
+        }
+    }
+
+    state.world.mute_player(
+        name.to_lowercase(),
+        body.admin_name(),
+        body.duration.clone().unwrap_or_default(),
+        false,
+    );
+    StatusCode::NO_CONTENT.into_response()
+}
+
+pub async fn jail_player(
+    State(state): State<ApiState>,
+    Path(name): Path<String>,
+    body: Option<Json<ActionRequest>>,
+) -> Response {
+    let body = body.map(|Json(body)| body).unwrap_or_default();
+
+    state
+        .world
+        .jail_player(name.to_lowercase(), body.admin_name());
+    StatusCode::NO_CONTENT.into_response()
+}
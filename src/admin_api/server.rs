@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    response::Response,
+    Json,
+};
+
+use crate::world::ReloadKind;
+
+use super::{error, ActionRequest, ApiState};
+
+pub async fn announce(State(state): State<ApiState>, Json(body): Json<ActionRequest>) -> Response {
+    if body.message.is_empty() {
+        return error(StatusCode::BAD_REQUEST, "Message is required");
+    }
+
+    state
+        .world
+        .broadcast_announcement(body.admin_name(), body.message.clone());
+    StatusCode::NO_CONTENT.into_response()
+}
+
+pub async fn save(State(state): State<ApiState>) -> StatusCode {
+    state.world.save();
+    StatusCode::ACCEPTED
+}
+
+pub async fn reload(State(state): State<ApiState>, Path(kind): Path<String>) -> Response {
+    match ReloadKind::parse(&kind) {
+        Some(kind) => {
+            state.world.reload(kind);
+            StatusCode::ACCEPTED.into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "Unknown reload type"),
+    }
+}
+
+pub async fn shutdown(State(state): State<ApiState>) -> StatusCode {
+    state.shutdown.notify_one();
+    StatusCode::ACCEPTED
+}
@@ -0,0 +1,5 @@
+mod audit_entry;
+pub use audit_entry::{AuditEntry, AuditEvent, AuditItem};
+mod audit_handle;
+pub use audit_handle::AuditHandle;
+mod audit_log;
@@ -0,0 +1,224 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::character::Character;
+
+/// An item and how many of it moved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditItem {
+    pub id: i32,
+    pub amount: i32,
+}
+
+/// Something that created, destroyed or moved items or gold
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AuditEvent {
+    Trade {
+        partner_id: i32,
+        partner_name: String,
+        gave: Vec<AuditItem>,
+        received: Vec<AuditItem>,
+    },
+    DropItem {
+        item_id: i32,
+        amount: i32,
+    },
+    PickupItem {
+        item_id: i32,
+        amount: i32,
+    },
+    ShopBuy {
+        npc_id: i32,
+        item_id: i32,
+        amount: i32,
+        price: i32,
+    },
+    ShopSell {
+        npc_id: i32,
+        item_id: i32,
+        amount: i32,
+        price: i32,
+    },
+    ShopCraft {
+        npc_id: i32,
+        item_id: i32,
+        ingredients: Vec<AuditItem>,
+    },
+    BankDeposit {
+        amount: i32,
+    },
+    BankWithdraw {
+        amount: i32,
+    },
+    LockerDeposit {
+        item_id: i32,
+        amount: i32,
+    },
+    LockerWithdraw {
+        item_id: i32,
+        amount: i32,
+    },
+    ChestDeposit {
+        item_id: i32,
+        amount: i32,
+    },
+    ChestTake {
+        item_id: i32,
+        amount: i32,
+    },
+    GuildBankDeposit {
+        guild_tag: String,
+        amount: i32,
+    },
+    AdminSpawnItem {
+        item_id: i32,
+        amount: i32,
+    },
+    AdminSpawnNpc {
+        npc_id: i32,
+        amount: i32,
+    },
+    QuestGiveItem {
+        item_id: i32,
+        amount: i32,
+    },
+    Death,
+}
+
+impl fmt::Display for AuditEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trade {
+                partner_name,
+                gave,
+                received,
+                ..
+            } => write!(
+                f,
+                "traded {} for {} with {}",
+                items(gave),
+                items(received),
+                partner_name
+            ),
+            Self::DropItem { item_id, amount } => {
+                write!(f, "dropped {}x item {}", amount, item_id)
+            }
+            Self::PickupItem { item_id, amount } => {
+                write!(f, "picked up {}x item {}", amount, item_id)
+            }
+            Self::ShopBuy {
+                npc_id,
+                item_id,
+                amount,
+                price,
+            } => write!(
+                f,
+                "bought {}x item {} for {} gold from npc {}",
+                amount, item_id, price, npc_id
+            ),
+            Self::ShopSell {
+                npc_id,
+                item_id,
+                amount,
+                price,
+            } => write!(
+                f,
+                "sold {}x item {} for {} gold to npc {}",
+                amount, item_id, price, npc_id
+            ),
+            Self::ShopCraft {
+                npc_id,
+                item_id,
+                ingredients,
+            } => write!(
+                f,
+                "crafted item {} from {} at npc {}",
+                item_id,
+                items(ingredients),
+                npc_id
+            ),
+            Self::BankDeposit { amount } => write!(f, "deposited {} gold", amount),
+            Self::BankWithdraw { amount } => write!(f, "withdrew {} gold", amount),
+            Self::LockerDeposit { item_id, amount } => {
+                write!(f, "put {}x item {} in locker", amount, item_id)
+            }
+            Self::LockerWithdraw { item_id, amount } => {
+                write!(f, "took {}x item {} from locker", amount, item_id)
+            }
+            Self::ChestDeposit { item_id, amount } => {
+                write!(f, "put {}x item {} in chest", amount, item_id)
+            }
+            Self::ChestTake { item_id, amount } => {
+                write!(f, "took {}x item {} from chest", amount, item_id)
+            }
+            Self::GuildBankDeposit { guild_tag, amount } => {
+                write!(f, "deposited {} gold to guild {}", amount, guild_tag)
+            }
+            Self::AdminSpawnItem { item_id, amount } => {
+                write!(f, "spawned {}x item {}", amount, item_id)
+            }
+            Self::AdminSpawnNpc { npc_id, amount } => {
+                write!(f, "spawned {}x npc {}", amount, npc_id)
+            }
+            Self::QuestGiveItem { item_id, amount } => {
+                write!(f, "got {}x item {} from a quest", amount, item_id)
+            }
+            Self::Death => write!(f, "died"),
+        }
+    }
+}
+
+fn items(items: &[AuditItem]) -> String {
+    if items.is_empty() {
+        return "nothing".to_owned();
+    }
+
+    items
+        .iter()
+        .map(|item| format!("{}x item {}", item.amount, item.id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A single line of the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Utc>,
+    pub character_id: i32,
+    pub character_name: String,
+    pub map_id: i32,
+    pub x: i32,
+    pub y: i32,
+    #[serde(flatten)]
+    pub event: AuditEvent,
+}
+
+impl AuditEntry {
+    pub fn new(character: &Character, event: AuditEvent) -> Self {
+        Self {
+            at: Utc::now(),
+            character_id: character.id,
+            character_name: character.name.clone(),
+            map_id: character.map_id,
+            x: character.coords.x,
+            y: character.coords.y,
+            event,
+        }
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} map {} ({}, {}) {}",
+            self.at.format("%m/%d %H:%M"),
+            self.map_id,
+            self.x,
+            self.y,
+            self.event
+        )
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{character::Character, db::Db, SETTINGS};
+
+use super::{
+    audit_log::{AuditLog, Command},
+    AuditEntry, AuditEvent,
+};
+
+#[derive(Debug, Clone)]
+pub struct AuditHandle {
+    tx: mpsc::UnboundedSender<Command>,
+}
+
+impl AuditHandle {
+    /// Events are written to `db` and/or daily JSONL files in `dir`.
+    /// With neither they're discarded
+    pub fn new(db: Option<Db>, dir: Option<PathBuf>) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let log = AuditLog::new(rx, db, dir);
+        tokio::spawn(run_audit_log(log));
+
+        Self { tx }
+    }
+
+    /// Uses the sinks configured in the `[audit]` section
+    pub fn from_settings(db: &Db) -> Self {
+        if !SETTINGS.audit.enabled {
+            return Self::new(None, None);
+        }
+
+        Self::new(
+            if SETTINGS.audit.database {
+                Some(db.clone())
+            } else {
+                None
+            },
+            if SETTINGS.audit.directory.is_empty() {
+                None
+            } else {
+                Some(PathBuf::from(&SETTINGS.audit.directory))
+            },
+        )
+    }
+
+    pub fn record(&self, character: &Character, event: AuditEvent) {
+        let _ = self
+            .tx
+            .send(Command::Record(AuditEntry::new(character, event)));
+    }
+
+    /// Most recent entries for the named character, newest first
+    pub async fn get_entries(&self, character_name: &str, limit: usize) -> Vec<AuditEntry> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetEntries {
+            character_name: character_name.to_lowercase(),
+            limit,
+            respond_to: tx,
+        });
+        rx.await.unwrap_or_default()
+    }
+}
+
+async fn run_audit_log(mut log: AuditLog) {
+    while let Some(command) = log.rx.recv().await {
+        // Batch whatever else is already queued into a single write
+        let mut commands = vec![command];
+        while commands.len() < 256 {
+            match log.rx.try_recv() {
+                Ok(command) => commands.push(command),
+                Err(_) => break,
+            }
+        }
+
+        let mut entries = Vec::new();
+        for command in commands {
+            match command {
+                Command::Record(entry) => entries.push(entry),
+                Command::GetEntries {
+                    character_name,
+                    limit,
+                    respond_to,
+                } => {
+                    // Flush first so queries see everything recorded before them
+                    log.write(&mut entries).await;
+                    let _ = respond_to.send(log.get_entries(&character_name, limit).await);
+                }
+            }
+        }
+
+        log.write(&mut entries).await;
+    }
+}
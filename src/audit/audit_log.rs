@@ -0,0 +1,183 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::PathBuf,
+};
+
+use chrono::{Duration, NaiveDate, Utc};
+use tokio::sync::{mpsc::UnboundedReceiver, oneshot};
+
+use crate::{db::Db, SETTINGS};
+
+use super::AuditEntry;
+
+#[derive(Debug)]
+pub enum Command {
+    Record(AuditEntry),
+    GetEntries {
+        character_name: String,
+        limit: usize,
+        respond_to: oneshot::Sender<Vec<AuditEntry>>,
+    },
+}
+
+pub struct AuditLog {
+    pub rx: UnboundedReceiver<Command>,
+    db: Option<Db>,
+    dir: Option<PathBuf>,
+    file: Option<(NaiveDate, File)>,
+}
+
+impl AuditLog {
+    pub fn new(rx: UnboundedReceiver<Command>, db: Option<Db>, dir: Option<PathBuf>) -> Self {
+        Self {
+            rx,
+            db,
+            dir,
+            file: None,
+        }
+    }
+
+    /// Appends `entries` to every sink and clears it
+    pub async fn write(&mut self, entries: &mut Vec<AuditEntry>) {
+        if entries.is_empty() {
+            return;
+        }
+
+        if self.dir.is_some() {
+            if let Err(e) = self.write_file(entries) {
+                error!("Failed to write audit log: {}", e);
+            }
+        }
+
+        if let Some(db) = self.db.as_ref() {
+            if let Err(e) = db.create_audit_entries(entries).await {
+                error!("Failed to save audit log: {}", e);
+            }
+        }
+
+        entries.clear();
+    }
+
+    pub async fn get_entries(&self, character_name: &str, limit: usize) -> Vec<AuditEntry> {
+        if let Some(db) = self.db.as_ref() {
+            return match db.get_audit_entries(character_name, limit as i32).await {
+                Ok(entries) => entries,
+                Err(e) => {
+                    error!("Failed to load audit log: {}", e);
+                    Vec::new()
+                }
+            };
+        }
+
+        match self.read_files(character_name, limit) {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Failed to read audit log: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    fn write_file(&mut self, entries: &[AuditEntry]) -> io::Result<()> {
+        let dir = match self.dir.as_ref() {
+            Some(dir) => dir,
+            None => return Ok(()),
+        };
+
+        let today = Utc::now().date_naive();
+        if !matches!(self.file, Some((date, _)) if date == today) {
+            fs::create_dir_all(dir)?;
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join(file_name(today)))?;
+            self.file = Some((today, file));
+            self.prune_files(today);
+        }
+
+        let (_, file) = self.file.as_mut().unwrap();
+
+        let mut buf = Vec::new();
+        for entry in entries {
+            serde_json::to_writer(&mut buf, entry)?;
+            buf.push(b'\n');
+        }
+
+        file.write_all(&buf)
+    }
+
+    /// Deletes files older than `retention_days`
+    fn prune_files(&self, today: NaiveDate) {
+        let retention_days = SETTINGS.audit.retention_days;
+        if retention_days <= 0 {
+            return;
+        }
+
+        let oldest = file_name(today - Duration::days(retention_days as i64 - 1));
+        for path in self.list_files() {
+            let expired = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name < oldest.as_str());
+
+            if expired {
+                if let Err(e) = fs::remove_file(&path) {
+                    error!("Failed to remove {}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+
+    /// Audit files sorted oldest first
+    fn list_files(&self) -> Vec<PathBuf> {
+        let dir = match self.dir.as_ref() {
+            Some(dir) => dir,
+            None => return Vec::new(),
+        };
+
+        let mut files: Vec<PathBuf> = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with("audit-") && name.ends_with(".jsonl"))
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        files.sort();
+        files
+    }
+
+    fn read_files(&self, character_name: &str, limit: usize) -> io::Result<Vec<AuditEntry>> {
+        let mut entries = Vec::new();
+
+        for path in self.list_files().iter().rev() {
+            let mut file_entries = Vec::new();
+            for line in BufReader::new(File::open(path)?).lines() {
+                let line = line?;
+                match serde_json::from_str::<AuditEntry>(&line) {
+                    Ok(entry) if entry.character_name == character_name => file_entries.push(entry),
+                    Ok(_) => {}
+                    Err(e) => warn!("Skipping bad line in {}: {}", path.display(), e),
+                }
+            }
+
+            entries.extend(file_entries.into_iter().rev());
+            if entries.len() >= limit {
+                break;
+            }
+        }
+
+        entries.truncate(limit);
+        Ok(entries)
+    }
+}
+
+fn file_name(date: NaiveDate) -> String {
+    format!("audit-{}.jsonl", date.format("%Y-%m-%d"))
+}
@@ -9,36 +9,41 @@ use eolib::protocol::{
 };
 use eoplus::Arg;
 use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
-use mysql_async::Conn;
 use rand::Rng;
 use std::cmp;
 
-use crate::{player::PlayerHandle, EXP_TABLE, FORMULAS, QUEST_DB, SETTINGS};
+use crate::{
+    db::Db, player::PlayerHandle, status_effects::ActiveStatusEffects, EXP_TABLE, FORMULAS,
+    QUEST_DB, SETTINGS,
+};
 
 mod add_bank_item;
 mod add_item;
 mod calculate_stats;
 mod can_hold;
-mod create;
-mod delete;
+mod check_quest_rules;
+mod clear_status_effects;
 mod destroy_equipment;
 mod equip;
 mod equipment_slot;
 mod get_equipment;
+mod get_instrument;
+mod get_quest_stat;
 mod get_spawn_coords;
 mod get_spawn_map;
 mod get_stats;
-mod load;
 pub use equipment_slot::EquipmentSlot;
+mod played_notes;
+pub use played_notes::PlayedNotes;
 mod remove_item;
 mod reset;
+mod set_quest_stat;
 mod spell_target;
 pub use spell_target::SpellTarget;
 mod equip_result;
 pub use equip_result::EquipResult;
 mod to_map_info;
 mod unequip;
-mod update;
 
 #[derive(Debug, Clone, Default)]
 pub struct Character {
@@ -109,7 +114,11 @@ pub struct Character {
     pub captcha_open: bool,
     pub warp_suck_ticks: i32,
     pub ghost_ticks: i32,
+    /// Last time the player was refreshed for being out of sync on a walk
+    pub rubber_banded_at: Option<DateTime<Utc>>,
     pub auto_pickup_items: Vec<i32>,
+    pub status_effects: ActiveStatusEffects,
+    pub played_notes: PlayedNotes,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -120,6 +129,8 @@ pub struct QuestProgress {
     pub player_kills: i32,
     pub done_at: Option<DateTime<Utc>>,
     pub completions: i32,
+    /// Result of the last `Roll` action, only kept for the current session
+    pub rolled: i32,
 }
 
 impl QuestProgress {
@@ -184,7 +195,7 @@ impl Character {
             }
         };
 
-        let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+        let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
             Ok(hit_rate) => hit_rate,
             Err(e) => {
                 error!("Failed to calculate hit rate: {}", e);
@@ -198,7 +209,7 @@ impl Character {
         let damage = if hit_rate < rand {
             0
         } else {
-            match eval_float_with_context(&FORMULAS.damage, &context) {
+            match eval_float_with_context(&FORMULAS.load().damage, &context) {
                 Ok(amount) => amount.floor() as i32,
                 Err(e) => {
                     error!("Failed to calculate damage: {}", e);
@@ -301,14 +312,24 @@ impl Character {
         }
     }
 
-    pub async fn save(
-        &mut self,
-        conn: &mut Conn,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// Loads a character by name straight from the database, for when they
+    /// aren't online
+    pub async fn load(db: &Db, name: &str) -> anyhow::Result<Option<Self>> {
+        let id = match db.get_character_id(name).await? {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        let mut character = db.load_character(id).await?;
+        character.calculate_stats();
+        Ok(Some(character))
+    }
+
+    pub async fn save(&mut self, db: &Db) -> anyhow::Result<()> {
         if self.id > 0 {
-            self.update(conn).await
+            db.update_character(self).await
         } else {
-            self.create(conn).await
+            db.create_character(self).await
         }
     }
 
@@ -332,8 +353,9 @@ impl Character {
 
     pub fn talked_to_npc(&mut self, behavior_id: i32, quest_id: i32, action_id: Option<i32>) {
         let mut progressed = false;
+        let quest_db = QUEST_DB.load();
         if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => return,
             };
@@ -394,8 +416,9 @@ impl Character {
 
     pub fn killed_npc(&mut self, npc_id: i32) {
         let mut quests_progressed = Vec::new();
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -444,8 +467,9 @@ impl Character {
     pub fn entered_map(&mut self) {
         let mut quests_progressed = Vec::new();
         let map_id = self.map_id;
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -481,12 +505,13 @@ impl Character {
         }
     }
 
-    pub fn entered_coord(&mut self) {
+    pub fn entered_coord(&mut self, previous_coords: Coords) {
         let mut quests_progressed = Vec::new();
         let map_id = self.map_id;
         let coords = self.coords;
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -497,10 +522,15 @@ impl Character {
             };
 
             let rule = match state.rules.iter().find(|rule| {
-                rule.name == "EnterCoord"
+                (rule.name == "EnterCoord"
                     && rule.args[0] == Arg::Int(map_id)
                     && rule.args[1] == Arg::Int(coords.x)
-                    && rule.args[2] == Arg::Int(coords.y)
+                    && rule.args[2] == Arg::Int(coords.y))
+                    || (rule.name == "LeaveCoord"
+                        && coords != previous_coords
+                        && rule.args[0] == Arg::Int(map_id)
+                        && rule.args[1] == Arg::Int(previous_coords.x)
+                        && rule.args[2] == Arg::Int(previous_coords.y))
             }) {
                 Some(rule) => rule,
                 None => continue,
@@ -524,26 +554,98 @@ impl Character {
         }
     }
 
+    pub fn killed_player(&mut self) {
+        let quest_db = QUEST_DB.load();
+        for progress in self.quests.iter_mut() {
+            let quest = match quest_db.get(&progress.id) {
+                Some(quest) => quest,
+                None => continue,
+            };
+
+            let state = match quest.states.get(progress.state as usize) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            if state.rules.iter().any(|rule| rule.name == "KilledPlayers") {
+                progress.player_kills += 1;
+            }
+        }
+
+        self.check_quest_rules();
+    }
+
+    pub fn killed_arena_player(&mut self, kills: i32) {
+        let mut quests_progressed = Vec::new();
+        let quest_db = QUEST_DB.load();
+        for progress in self.quests.iter_mut() {
+            let quest = match quest_db.get(&progress.id) {
+                Some(quest) => quest,
+                None => continue,
+            };
+
+            let state = match quest.states.get(progress.state as usize) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            let rule = match state.rules.iter().find(|rule| {
+                rule.name == "ArenaKills"
+                    && matches!(rule.args.first(), Some(Arg::Int(required)) if kills >= *required)
+            }) {
+                Some(rule) => rule,
+                None => continue,
+            };
+
+            if let Some(next_state) = quest
+                .states
+                .iter()
+                .position(|state| state.name == rule.goto)
+            {
+                progress.state = next_state as i32;
+                quests_progressed.push(progress.id);
+            }
+        }
+
+        for quest_id in quests_progressed {
+            self.do_quest_actions(quest_id);
+        }
+    }
+
     fn do_quest_actions(&mut self, quest_id: i32) {
-        let state = match self.quests.iter().find(|progress| progress.id == quest_id) {
+        self.do_quest_actions_in_chain(quest_id, &mut Vec::new());
+    }
+
+    /// `chain` holds every (quest, state) entered since the event that
+    /// started this, so quests that lead back into a state they've already
+    /// been through stop instead of recursing forever
+    fn do_quest_actions_in_chain(&mut self, quest_id: i32, chain: &mut Vec<(i32, i32)>) {
+        let state_index = match self.quests.iter().find(|progress| progress.id == quest_id) {
             Some(progress) => progress.state,
             None => return,
         };
 
-        let quest = match QUEST_DB.get(&quest_id) {
+        let quest_db = QUEST_DB.load();
+        let quest = match quest_db.get(&quest_id) {
             Some(quest) => quest,
             None => return,
         };
 
-        let state = match quest.states.get(state as usize) {
+        let state = match quest.states.get(state_index as usize) {
             Some(state) => state,
             None => return,
         };
 
-        let player = match self.player {
-            Some(ref player) => player,
-            None => return,
-        };
+        if chain.contains(&(quest_id, state_index)) {
+            warn!(
+                "Quest {} looped back into state \"{}\" for {}, stopping",
+                quest_id, state.name, self.name
+            );
+            return;
+        }
+        chain.push((quest_id, state_index));
+
+        let mut state_changes: Vec<(i32, String)> = Vec::new();
 
         for action in state.actions.iter() {
             match action.name.as_str() {
@@ -571,27 +673,121 @@ impl Character {
                         progress.state = 0;
                     }
                 }
-                _ => player.quest_action(action.name.to_owned(), action.args.to_owned()),
+                "SetState" => {
+                    if let Some(Arg::Str(state_name)) = action.args.first() {
+                        state_changes.push((quest_id, state_name.to_owned()));
+                    }
+                }
+                "SetQuestState" | "StartQuest" => {
+                    let other_quest_id = match action.args.first() {
+                        Some(Arg::Int(quest_id)) => *quest_id,
+                        _ => continue,
+                    };
+
+                    if action.name == "StartQuest"
+                        && self.quests.iter().any(|q| q.id == other_quest_id)
+                    {
+                        continue;
+                    }
+
+                    let other_quest = match quest_db.get(&other_quest_id) {
+                        Some(quest) => quest,
+                        None => continue,
+                    };
+
+                    let state_name = match action.args.get(1) {
+                        Some(Arg::Str(state_name)) => state_name.to_owned(),
+                        _ => match other_quest.states.first() {
+                            Some(state) => state.name.to_owned(),
+                            None => continue,
+                        },
+                    };
+
+                    state_changes.push((other_quest_id, state_name));
+                }
+                "ResetQuest" => {
+                    if let Some(Arg::Int(other_quest_id)) = action.args.first() {
+                        self.quests.retain(|q| q.id != *other_quest_id);
+                    }
+                }
+                "Roll" => {
+                    let max = match action.args.first() {
+                        Some(Arg::Int(max)) if *max > 0 => *max,
+                        _ => continue,
+                    };
+
+                    if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+                        let mut rng = rand::thread_rng();
+                        progress.rolled = rng.gen_range(1..=max);
+                    }
+                }
+                // Title, fiance, partner and home have no packet of their
+                // own, clients see them the next time they open the
+                // paperdoll and they're written by the next character save
+                "SetTitle" => {
+                    if let Some(Arg::Str(title)) = action.args.first() {
+                        self.title = if title.is_empty() {
+                            None
+                        } else {
+                            Some(title.to_owned())
+                        };
+                    }
+                }
+                "SetFiance" => {
+                    if let Some(Arg::Str(fiance)) = action.args.first() {
+                        self.fiance = if fiance.is_empty() {
+                            None
+                        } else {
+                            Some(fiance.to_lowercase())
+                        };
+                    }
+                }
+                "SetPartner" => {
+                    if let Some(Arg::Str(partner)) = action.args.first() {
+                        self.partner = if partner.is_empty() {
+                            None
+                        } else {
+                            Some(partner.to_lowercase())
+                        };
+                    }
+                }
+                "SetHome" => {
+                    if let Some(Arg::Str(home)) = action.args.first() {
+                        self.home = home.to_owned();
+                    }
+                }
+                _ => {
+                    if let Some(player) = self.player.as_ref() {
+                        player.quest_action(action.name.to_owned(), action.args.to_owned());
+                    }
+                }
             }
         }
 
-        let rule = match state.rules.iter().find(|rule| rule.name == "Always") {
-            Some(rule) => rule,
-            None => return,
-        };
+        // Jumping to another state of this quest replaces the rule check below
+        let mut changed_own_state = false;
+        for (other_quest_id, state_name) in state_changes {
+            let other_quest = match quest_db.get(&other_quest_id) {
+                Some(quest) => quest,
+                None => continue,
+            };
 
-        let progress = match self.quests.iter_mut().find(|q| q.id == quest_id) {
-            Some(progress) => progress,
-            None => return,
-        };
+            let next_state = match other_quest
+                .states
+                .iter()
+                .position(|state| state.name.eq_ignore_ascii_case(&state_name))
+            {
+                Some(next_state) => next_state,
+                None => continue,
+            };
 
-        if let Some(next_state) = quest
-            .states
-            .iter()
-            .position(|state| state.name == rule.goto)
-        {
-            progress.state = next_state as i32;
-            self.do_quest_actions(quest_id);
+            self.save_quest_progress(other_quest_id, next_state as i32);
+            changed_own_state |= other_quest_id == quest_id;
+            self.do_quest_actions_in_chain(other_quest_id, chain);
+        }
+
+        if !changed_own_state {
+            self.check_quest_state_rules_in_chain(quest_id, chain);
         }
     }
 }
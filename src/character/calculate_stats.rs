@@ -2,7 +2,7 @@ use std::cmp;
 
 use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
 
-use crate::{CLASS_DB, FORMULAS, ITEM_DB};
+use crate::{CLASS_DB, FORMULAS, ITEM_DB, STATUS_EFFECTS};
 
 use super::Character;
 
@@ -11,7 +11,7 @@ impl Character {
         let original_hp = self.hp;
         let original_max_hp = self.max_hp;
 
-        let class = &CLASS_DB.classes[(self.class - 1) as usize];
+        let class = &CLASS_DB.load().classes[(self.class - 1) as usize];
 
         self.adj_strength = self.base_strength + class.str;
         self.adj_intelligence = self.base_intelligence + class.intl;
@@ -20,6 +20,14 @@ impl Character {
         self.adj_constitution = self.base_constitution + class.con;
         self.adj_charisma = self.base_charisma + class.cha;
 
+        let modifiers = self.status_effects.get_modifiers(&STATUS_EFFECTS.load());
+        self.adj_strength += modifiers.str;
+        self.adj_intelligence += modifiers.intl;
+        self.adj_wisdom += modifiers.wis;
+        self.adj_agility += modifiers.agi;
+        self.adj_constitution += modifiers.con;
+        self.adj_charisma += modifiers.cha;
+
         self.weight = 0;
         self.max_hp = 0;
         self.max_tp = 0;
@@ -30,12 +38,20 @@ impl Character {
         self.armor = 0;
         self.max_sp = 0;
 
+        self.max_hp += modifiers.max_hp;
+        self.max_tp += modifiers.max_tp;
+        self.min_damage += modifiers.min_damage;
+        self.max_damage += modifiers.max_damage;
+        self.accuracy += modifiers.accuracy;
+        self.evasion += modifiers.evade;
+        self.armor += modifiers.armor;
+
         for item in &self.items {
             if item.id == 0 {
                 continue;
             }
 
-            let record = &ITEM_DB.items[(item.id - 1) as usize];
+            let record = &ITEM_DB.load().items[(item.id - 1) as usize];
             self.weight += record.weight * item.amount;
         }
 
@@ -62,7 +78,7 @@ impl Character {
                 continue;
             }
 
-            let item = &ITEM_DB.items[(item_id - 1) as usize];
+            let item = &ITEM_DB.load().items[(item_id - 1) as usize];
             self.weight += item.weight;
             self.max_hp += item.hp;
             self.max_tp += item.tp;
@@ -101,7 +117,7 @@ impl Character {
             }
         };
 
-        self.max_hp += match eval_float_with_context(&FORMULAS.hp, &context) {
+        self.max_hp += match eval_float_with_context(&FORMULAS.load().hp, &context) {
             Ok(max_hp) => cmp::min(max_hp.floor() as i32, 64000),
             Err(e) => {
                 error!("Failed to calculate max_hp: {}", e);
@@ -109,7 +125,7 @@ impl Character {
             }
         };
 
-        self.max_tp += match eval_float_with_context(&FORMULAS.tp, &context) {
+        self.max_tp += match eval_float_with_context(&FORMULAS.load().tp, &context) {
             Ok(max_tp) => cmp::min(max_tp.floor() as i32, 64000),
             Err(e) => {
                 error!("Failed to calculate max_tp: {}", e);
@@ -117,7 +133,7 @@ impl Character {
             }
         };
 
-        self.max_sp += match eval_float_with_context(&FORMULAS.sp, &context) {
+        self.max_sp += match eval_float_with_context(&FORMULAS.load().sp, &context) {
             Ok(max_sp) => cmp::min(max_sp.floor() as i32, 64000),
             Err(e) => {
                 error!("Failed to calculate max_sp: {}", e);
@@ -125,7 +141,7 @@ impl Character {
             }
         };
 
-        self.max_weight = match eval_float_with_context(&FORMULAS.max_weight, &context) {
+        self.max_weight = match eval_float_with_context(&FORMULAS.load().max_weight, &context) {
             Ok(max_weight) => cmp::min(max_weight.floor() as i32, 250),
             Err(e) => {
                 error!("Failed to calculate max_weight: {}", e);
@@ -133,7 +149,7 @@ impl Character {
             }
         };
 
-        let class_formulas = &FORMULAS.classes[class.stat_group as usize];
+        let class_formulas = &FORMULAS.load().classes[class.stat_group as usize];
         let damage = match eval_float_with_context(&class_formulas.damage, &context) {
             Ok(damage) => damage.floor() as i32,
             Err(e) => {
@@ -169,14 +185,20 @@ impl Character {
             }
         };
 
-        if self.min_damage == 0 {
+        if self.min_damage <= 0 {
             self.min_damage = 1;
         }
 
-        if self.max_damage == 0 {
+        if self.max_damage <= 0 {
             self.max_damage = 1;
         }
 
+        self.max_hp = cmp::max(self.max_hp, 1);
+        self.max_tp = cmp::max(self.max_tp, 0);
+        self.accuracy = cmp::max(self.accuracy, 0);
+        self.evasion = cmp::max(self.evasion, 0);
+        self.armor = cmp::max(self.armor, 0);
+
         if self.hp > self.max_hp {
             self.hp = self.max_hp;
         }
@@ -185,11 +207,11 @@ impl Character {
             self.tp = self.max_tp;
         }
 
+        // Offline characters loaded from the database have no player
         if self.hp != original_hp || self.max_hp != original_max_hp {
-            self.player
-                .as_ref()
-                .unwrap()
-                .update_party_hp(self.get_hp_percentage());
+            if let Some(player) = self.player.as_ref() {
+                player.update_party_hp(self.get_hp_percentage());
+            }
         }
     }
 }
@@ -0,0 +1,125 @@
+use eoplus::{Arg, Rule};
+
+use crate::QUEST_DB;
+
+use super::{Character, QuestProgress};
+
+impl Character {
+    /// Re-evaluates the rules of every active quest that depend on the
+    /// character's current state (stats, spells, equipment, etc.) rather
+    /// than on a specific event.
+    pub fn check_quest_rules(&mut self) {
+        let quest_ids: Vec<i32> = self.quests.iter().map(|q| q.id).collect();
+        for quest_id in quest_ids {
+            self.check_quest_state_rules(quest_id);
+        }
+    }
+
+    pub(super) fn check_quest_state_rules(&mut self, quest_id: i32) {
+        self.check_quest_state_rules_in_chain(quest_id, &mut Vec::new());
+    }
+
+    pub(super) fn check_quest_state_rules_in_chain(
+        &mut self,
+        quest_id: i32,
+        chain: &mut Vec<(i32, i32)>,
+    ) {
+        let quest_db = QUEST_DB.load();
+        let quest = match quest_db.get(&quest_id) {
+            Some(quest) => quest,
+            None => return,
+        };
+
+        let progress = match self.quests.iter().find(|q| q.id == quest_id) {
+            Some(progress) => progress,
+            None => return,
+        };
+
+        let state = match quest.states.get(progress.state as usize) {
+            Some(state) => state,
+            None => return,
+        };
+
+        let rule = match state
+            .rules
+            .iter()
+            .find(|rule| self.quest_rule_met(progress, rule) == Some(true))
+        {
+            Some(rule) => rule,
+            None => return,
+        };
+
+        let next_state = match quest
+            .states
+            .iter()
+            .position(|state| state.name == rule.goto)
+        {
+            Some(next_state) => next_state,
+            None => return,
+        };
+
+        if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+            progress.state = next_state as i32;
+        }
+
+        self.do_quest_actions_in_chain(quest_id, chain);
+    }
+
+    fn quest_rule_met(&self, progress: &QuestProgress, rule: &Rule) -> Option<bool> {
+        let int_arg = |index: usize| match rule.args.get(index) {
+            Some(Arg::Int(value)) => Some(*value),
+            _ => None,
+        };
+
+        let str_arg = |index: usize| match rule.args.get(index) {
+            Some(Arg::Str(value)) => Some(value.as_str()),
+            _ => None,
+        };
+
+        Some(match rule.name.as_str() {
+            "Always" => true,
+            "GotItems" => self.get_item_amount(int_arg(0)?) >= int_arg(1).unwrap_or(1),
+            "LostItems" => self.get_item_amount(int_arg(0)?) < int_arg(1).unwrap_or(1),
+            "GotSpell" => {
+                let spell_id = int_arg(0)?;
+                let level = int_arg(1).unwrap_or(0);
+                self.spells
+                    .iter()
+                    .any(|spell| spell.id == spell_id && spell.level >= level)
+            }
+            "LostSpell" => !self.has_spell(int_arg(0)?),
+            "IsGender" => i32::from(self.gender) == int_arg(0)?,
+            "IsClass" => self.class == int_arg(0)?,
+            "IsRace" => self.skin == int_arg(0)?,
+            "IsWearing" => {
+                let item_id = int_arg(0)?;
+                self.get_equipment_array().contains(&item_id)
+            }
+            "IsLeader" => self.is_guild_leader(),
+            "CitizenOf" => self.home.eq_ignore_ascii_case(str_arg(0)?),
+            "StatIs" => self.get_quest_stat(str_arg(0)?)? == int_arg(1)?,
+            "StatNot" => self.get_quest_stat(str_arg(0)?)? != int_arg(1)?,
+            "StatGreater" => self.get_quest_stat(str_arg(0)?)? > int_arg(1)?,
+            "StatLess" => self.get_quest_stat(str_arg(0)?)? < int_arg(1)?,
+            "StatBetween" => {
+                let value = self.get_quest_stat(str_arg(0)?)?;
+                value >= int_arg(1)? && value <= int_arg(2)?
+            }
+            "KilledPlayers" => progress.player_kills >= int_arg(0)?,
+            "Rolled" => progress.rolled == int_arg(0)?,
+            "IsQuestState" => {
+                let quest_id = int_arg(0)?;
+                let state_name = str_arg(1)?;
+                let other_progress = self.get_quest_progress(quest_id);
+                let quest_db = QUEST_DB.load();
+                let quest = quest_db.get(&quest_id)?;
+                quest
+                    .states
+                    .get(other_progress.state as usize)?
+                    .name
+                    .eq_ignore_ascii_case(state_name)
+            }
+            _ => false,
+        })
+    }
+}
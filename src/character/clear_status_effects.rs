@@ -0,0 +1,17 @@
+use crate::{settings::StatusEffectClearPolicy, status_effects::StatusEffectTick, STATUS_EFFECTS};
+
+use super::Character;
+
+impl Character {
+    /// Removes the status effects `policy` says to, recalculating stats if
+    /// any of them changed them
+    pub fn clear_status_effects(&mut self, policy: StatusEffectClearPolicy) -> StatusEffectTick {
+        let cleared = self.status_effects.clear(&STATUS_EFFECTS.load(), policy);
+
+        if cleared.stats_changed {
+            self.calculate_stats();
+        }
+
+        cleared
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{ITEM_DB, SETTINGS};
+
+use super::Character;
+
+impl Character {
+    /// Graphic id of the instrument the character has equipped
+    pub fn get_instrument(&self) -> Option<i32> {
+        if self.equipment.weapon == 0 {
+            return None;
+        }
+
+        let item_db = ITEM_DB.load();
+        let item = item_db.items.get(self.equipment.weapon as usize - 1)?;
+
+        if SETTINGS.bard.instrument_items.contains(&item.spec1) {
+            Some(item.spec1)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::Character;
+
+impl Character {
+    pub fn get_quest_stat(&self, stat: &str) -> Option<i32> {
+        Some(match stat.to_lowercase().as_str() {
+            "level" => self.level,
+            "exp" | "experience" => self.experience,
+            "str" | "strength" => self.base_strength,
+            "int" | "intelligence" => self.base_intelligence,
+            "wis" | "wisdom" => self.base_wisdom,
+            "agi" | "agility" => self.base_agility,
+            "con" | "constitution" => self.base_constitution,
+            "cha" | "charisma" => self.base_charisma,
+            "statpoints" => self.stat_points,
+            "skillpoints" => self.skill_points,
+            "karma" => self.karma,
+            "hp" => self.hp,
+            "maxhp" => self.max_hp,
+            "tp" => self.tp,
+            "maxtp" => self.max_tp,
+            "maxsp" => self.max_sp,
+            "weight" => self.weight,
+            "maxweight" => self.max_weight,
+            "mindam" => self.min_damage,
+            "maxdam" => self.max_damage,
+            "accuracy" => self.accuracy,
+            "evade" => self.evasion,
+            "armor" => self.armor,
+            "goldbank" => self.gold_bank,
+            "usage" => self.usage,
+            "class" => self.class,
+            "race" | "skin" => self.skin,
+            "gender" => i32::from(self.gender),
+            "hairstyle" => self.hair_style,
+            "haircolor" => self.hair_color,
+            "admin" => i32::from(self.admin_level),
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+
+use crate::settings::Bard;
+
+/// Notes a bard has played recently, used to recognise song melodies
+#[derive(Debug, Clone, Default)]
+pub struct PlayedNotes {
+    notes: Vec<i32>,
+    played_at: Option<DateTime<Utc>>,
+}
+
+impl PlayedNotes {
+    /// Records a note and returns the spell whose melody it completes, if
+    /// the bard knows it
+    pub fn play(
+        &mut self,
+        note_id: i32,
+        now: DateTime<Utc>,
+        settings: &Bard,
+        knows_spell: impl Fn(i32) -> bool,
+    ) -> Option<i32> {
+        if self
+            .played_at
+            .is_some_and(|played_at| (now - played_at).num_seconds() >= settings.note_timeout)
+        {
+            self.notes.clear();
+        }
+
+        self.played_at = Some(now);
+        self.notes.push(note_id);
+
+        let longest = settings
+            .songs
+            .iter()
+            .map(|song| song.notes.len())
+            .max()
+            .unwrap_or_default();
+        if self.notes.len() > longest {
+            self.notes.drain(..self.notes.len() - longest);
+        }
+
+        let song = settings.songs.iter().find(|song| {
+            !song.notes.is_empty()
+                && self.notes.ends_with(&song.notes)
+                && knows_spell(song.spell_id)
+        })?;
+
+        self.notes.clear();
+        Some(song.spell_id)
+    }
+}
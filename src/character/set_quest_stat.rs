@@ -0,0 +1,58 @@
+use eolib::protocol::Gender;
+
+use crate::{CLASS_DB, EXP_TABLE, SETTINGS};
+
+use super::Character;
+
+impl Character {
+    pub fn set_quest_stat(&mut self, stat: &str, value: i32) -> bool {
+        match stat.to_lowercase().as_str() {
+            "level" => self.level = value.clamp(0, EXP_TABLE.len() as i32 - 2),
+            "exp" | "experience" => self.experience = value.max(0),
+            "str" | "strength" => self.base_strength = value.max(0),
+            "int" | "intelligence" => self.base_intelligence = value.max(0),
+            "wis" | "wisdom" => self.base_wisdom = value.max(0),
+            "agi" | "agility" => self.base_agility = value.max(0),
+            "con" | "constitution" => self.base_constitution = value.max(0),
+            "cha" | "charisma" => self.base_charisma = value.max(0),
+            "statpoints" => self.stat_points = value.max(0),
+            "skillpoints" => self.skill_points = value.max(0),
+            "karma" => self.karma = value.clamp(0, 2000),
+            "hp" => self.hp = value.clamp(0, self.max_hp),
+            "tp" => self.tp = value.clamp(0, self.max_tp),
+            "goldbank" => self.gold_bank = value.max(0),
+            "class" => {
+                if value < 1 || value as usize > CLASS_DB.load().classes.len() {
+                    return false;
+                }
+                self.class = value;
+            }
+            "race" | "skin" => {
+                if value < 0 || value > SETTINGS.character.max_skin {
+                    return false;
+                }
+                self.skin = value;
+            }
+            "gender" => match Gender::from(value) {
+                Gender::Unrecognized(_) => return false,
+                gender => self.gender = gender,
+            },
+            "hairstyle" => {
+                if value < 0 || value > SETTINGS.character.max_hair_style {
+                    return false;
+                }
+                self.hair_style = value;
+            }
+            "haircolor" => {
+                if value < 0 || value > SETTINGS.character.max_hair_color {
+                    return false;
+                }
+                self.hair_color = value;
+            }
+            _ => return false,
+        }
+
+        self.calculate_stats();
+        true
+    }
+}
@@ -10,7 +10,8 @@ impl Character {
             return false;
         }
 
-        let item_record = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_record = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => return false,
         };
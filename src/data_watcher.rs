@@ -0,0 +1,85 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+
+use crate::{
+    world::{ReloadKind, WorldHandle},
+    DATA_DIR, SETTINGS,
+};
+
+/// Watches pub files, quests and config files and reloads them when they
+/// change. Watching stops when the returned debouncer is dropped.
+pub fn watch_data(
+    world: WorldHandle,
+) -> Result<Debouncer<RecommendedWatcher>, Box<dyn std::error::Error>> {
+    let pub_dir = fs::canonicalize(format!("{}/pub", DATA_DIR))?;
+    let quest_dir = fs::canonicalize(format!("{}/quests", DATA_DIR))?;
+    let config_dir = fs::canonicalize("config")?;
+
+    let dirs = (pub_dir.clone(), quest_dir.clone(), config_dir.clone());
+    let mut debouncer = new_debouncer(
+        Duration::from_secs(1),
+        move |result: DebounceEventResult| {
+            let events = match result {
+                Ok(events) => events,
+                Err(e) => {
+                    error!("Data watcher error: {}", e);
+                    return;
+                }
+            };
+
+            let mut kinds = Vec::new();
+            for event in events {
+                if let Some(kind) = get_reload_kind(&event.path, &dirs) {
+                    if !kinds.contains(&kind) {
+                        kinds.push(kind);
+                    }
+                }
+            }
+
+            for kind in kinds {
+                info!("Detected changes to {:?} files, reloading", kind);
+                world.reload(kind);
+            }
+        },
+    )?;
+
+    let watcher = debouncer.watcher();
+    watcher.watch(&pub_dir, RecursiveMode::Recursive)?;
+    watcher.watch(&quest_dir, RecursiveMode::NonRecursive)?;
+    watcher.watch(&config_dir, RecursiveMode::NonRecursive)?;
+
+    Ok(debouncer)
+}
+
+fn get_reload_kind(
+    path: &Path,
+    (pub_dir, quest_dir, config_dir): &(PathBuf, PathBuf, PathBuf),
+) -> Option<ReloadKind> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+
+    if path.starts_with(pub_dir) {
+        // Generating pub files from JSON writes the binary files back into
+        // this directory, so only react to the files a person would edit
+        let is_json = extension == "json";
+        return (is_json == SETTINGS.server.generate_pub).then_some(ReloadKind::Pub);
+    }
+
+    if path.starts_with(quest_dir) && extension == "eqf" {
+        return Some(ReloadKind::Quests);
+    }
+
+    if path.starts_with(config_dir) && extension == "ron" {
+        return Some(ReloadKind::Config);
+    }
+
+    None
+}
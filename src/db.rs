@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{audit::AuditEntry, settings::DatabaseDriver, SETTINGS};
+
+mod account_storage;
+pub use account_storage::{AccountCredentials, AccountStorage, NewAccount};
+mod audit_storage;
+pub use audit_storage::AuditStorage;
+mod ban_storage;
+pub use ban_storage::{find_ban, parse_ip_range, Ban, BanStorage, BanTarget};
+mod board_storage;
+pub use board_storage::{BoardPost, BoardStorage};
+mod character_storage;
+pub use character_storage::{CharacterAccount, CharacterStorage};
+mod email_storage;
+pub use email_storage::{EmailStorage, QueuedEmail};
+mod guild_storage;
+pub use guild_storage::{GuildDetails, GuildStorage};
+mod login_lock_storage;
+pub use login_lock_storage::{add_login_failure, LoginLock, LoginLockKind, LoginLockStorage};
+mod migrate;
+pub use migrate::{migrate, Migration};
+mod mysql;
+pub use mysql::MySqlDb;
+mod sanction_storage;
+pub use sanction_storage::{Sanction, SanctionKind, SanctionStorage};
+mod sqlite;
+pub use sqlite::SqliteDb;
+
+/// Everything the server persists, regardless of which database backs it
+#[async_trait]
+pub trait Storage:
+    AccountStorage
+    + AuditStorage
+    + BanStorage
+    + BoardStorage
+    + CharacterStorage
+    + EmailStorage
+    + GuildStorage
+    + LoginLockStorage
+    + SanctionStorage
+    + std::fmt::Debug
+    + Send
+    + Sync
+{
+    /// Migrations for this backend in ascending version order
+    fn migrations(&self) -> &'static [Migration];
+
+    /// Highest migration applied to the database, 0 if it's empty
+    async fn get_schema_version(&self) -> anyhow::Result<i32>;
+
+    /// Runs a single migration and records its version
+    async fn apply_migration(&self, migration: &Migration) -> anyhow::Result<()>;
+
+    async fn get_server_stats(&self) -> anyhow::Result<ServerStats>;
+}
+
+pub type Db = Arc<dyn Storage>;
+
+#[derive(Debug, Default)]
+pub struct ServerStats {
+    pub accounts: i64,
+    pub characters: i64,
+    pub admins: i64,
+    pub guilds: i64,
+}
+
+/// Connects to the database configured in the `[database]` section
+pub async fn connect() -> anyhow::Result<Db> {
+    let db: Db = match SETTINGS.database.driver {
+        DatabaseDriver::MySql => Arc::new(MySqlDb::new(&format!(
+            "mysql://{}:{}@{}:{}/{}",
+            SETTINGS.database.username,
+            SETTINGS.database.password,
+            SETTINGS.database.host,
+            SETTINGS.database.port,
+            SETTINGS.database.name
+        ))?),
+        DatabaseDriver::Sqlite => Arc::new(SqliteDb::open(&SETTINGS.database.path)?),
+    };
+
+    Ok(db)
+}
+
+// Quest npc kills are stored as a JSON object of npc id => kills
+fn npc_kills_from_json(json: &str) -> Vec<(i32, i32)> {
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(value) => match value.as_object() {
+            Some(object) => object
+                .iter()
+                .filter_map(|(id, amount)| Some((id.parse::<i32>().ok()?, amount.as_i64()? as i32)))
+                .collect(),
+            None => Vec::new(),
+        },
+        Err(_) => Vec::new(),
+    }
+}
+
+fn npc_kills_to_json(npc_kills: &[(i32, i32)]) -> String {
+    let object: serde_json::Map<String, serde_json::Value> = npc_kills
+        .iter()
+        .map(|(npc_id, kills)| (npc_id.to_string(), serde_json::Value::from(*kills)))
+        .collect();
+    serde_json::Value::Object(object).to_string()
+}
+
+// Audit entries are stored whole as JSON, the other columns are for searching.
+// Returns the event name and the JSON
+fn audit_entry_to_json(entry: &AuditEntry) -> anyhow::Result<(String, String)> {
+    let value = serde_json::to_value(entry)?;
+    let event = value["event"].as_str().unwrap_or_default().to_owned();
+    Ok((event, value.to_string()))
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+
+pub struct NewAccount {
+    pub name: String,
+    pub password_hash: String,
+    pub real_name: String,
+    pub location: String,
+    pub email: String,
+    pub computer: String,
+    pub hdid: String,
+    pub register_ip: String,
+}
+
+pub struct AccountCredentials {
+    pub id: i32,
+    pub name: String,
+    pub password_hash: String,
+    pub queue_priority: bool,
+}
+
+#[async_trait]
+pub trait AccountStorage {
+    async fn account_exists(&self, name: &str) -> anyhow::Result<bool>;
+
+    async fn create_account(&self, account: &NewAccount) -> anyhow::Result<()>;
+
+    async fn get_account_credentials(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<Option<AccountCredentials>>;
+
+    /// Returns the account id and email address
+    async fn get_account_email(&self, name: &str) -> anyhow::Result<Option<(i32, String)>>;
+
+    async fn update_password_hash(
+        &self,
+        account_id: i32,
+        password_hash: &str,
+    ) -> anyhow::Result<()>;
+
+    async fn update_last_login_ip(&self, account_id: i32, ip: &str) -> anyhow::Result<()>;
+}
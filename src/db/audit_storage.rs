@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::audit::AuditEntry;
+
+#[async_trait]
+pub trait AuditStorage {
+    async fn create_audit_entries(&self, entries: &[AuditEntry]) -> anyhow::Result<()>;
+
+    /// Most recent entries for the named character, newest first
+    async fn get_audit_entries(
+        &self,
+        character_name: &str,
+        limit: i32,
+    ) -> anyhow::Result<Vec<AuditEntry>>;
+}
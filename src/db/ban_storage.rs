@@ -0,0 +1,160 @@
+use std::{fmt, net::IpAddr, str::FromStr, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use ipnet::IpNet;
+
+use super::Db;
+
+#[derive(Debug, Clone)]
+pub struct Ban {
+    pub id: i32,
+    pub account_id: Option<i32>,
+    pub account_name: Option<String>,
+    /// Exact address or CIDR range
+    pub ip: Option<String>,
+    pub hdid: Option<String>,
+    /// Minutes, 0 = permanent
+    pub duration: i32,
+    pub created_by: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Who is connecting or logging in. Anything unknown is left as `None`
+#[derive(Debug, Default)]
+pub struct BanTarget<'a> {
+    pub account_id: Option<i32>,
+    pub ip: Option<&'a str>,
+    pub hdid: Option<&'a str>,
+}
+
+impl Ban {
+    /// Minutes until the ban runs out, 0 = permanent and `None` once it has
+    pub fn minutes_remaining(&self) -> Option<i32> {
+        if self.duration == 0 {
+            return Some(0);
+        }
+
+        let expires_at = self.created_at + Duration::from_secs(self.duration as u64 * 60);
+        let remaining = (expires_at - Utc::now().naive_utc()).num_minutes();
+        if remaining <= 0 {
+            return None;
+        }
+
+        Some(remaining as i32)
+    }
+
+    pub fn matches(&self, target: &BanTarget) -> bool {
+        if self.account_id.is_some() && self.account_id == target.account_id {
+            return true;
+        }
+
+        if let (Some(hdid), Some(target_hdid)) = (&self.hdid, target.hdid) {
+            if hdid == target_hdid {
+                return true;
+            }
+        }
+
+        match (&self.ip, target.ip.and_then(parse_ip)) {
+            (Some(ip), Some(target_ip)) => match parse_ip_range(ip) {
+                Some(range) => range.contains(&target_ip),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Ban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.id)?;
+
+        if let Some(account_name) = &self.account_name {
+            write!(f, " account {}", account_name)?;
+        }
+
+        if let Some(ip) = &self.ip {
+            write!(f, " ip {}", ip)?;
+        }
+
+        if let Some(hdid) = &self.hdid {
+            write!(f, " hdid {}", hdid)?;
+        }
+
+        match self.minutes_remaining() {
+            Some(0) => write!(f, " permanent")?,
+            Some(minutes) => write!(f, " {}m left", minutes)?,
+            None => write!(f, " expired")?,
+        }
+
+        write!(f, " {}", self.created_at.format("%m/%d %H:%M"))?;
+
+        if let Some(created_by) = &self.created_by {
+            write!(f, " -{}", created_by)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses an address, treating IPv4 addresses mapped into IPv6 as IPv4
+pub fn parse_ip(ip: &str) -> Option<IpAddr> {
+    let ip = IpAddr::from_str(ip).ok()?;
+    Some(match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+        IpAddr::V4(_) => ip,
+    })
+}
+
+/// Parses a CIDR range, a single address is treated as a range of one
+pub fn parse_ip_range(range: &str) -> Option<IpNet> {
+    match IpNet::from_str(range) {
+        Ok(range) => Some(range),
+        Err(_) => parse_ip(range).map(IpNet::from),
+    }
+}
+
+/// Most recent active ban matching any part of the target
+pub async fn find_ban(db: &Db, target: &BanTarget<'_>) -> anyhow::Result<Option<Ban>> {
+    let ip = target.ip.and_then(parse_ip).map(|ip| ip.to_string());
+    Ok(db
+        .get_active_bans(
+            target.account_id,
+            ip.as_deref(),
+            target.hdid,
+            Utc::now().naive_utc(),
+        )
+        .await?
+        .into_iter()
+        .find(|ban| ban.matches(target)))
+}
+
+#[async_trait]
+pub trait BanStorage {
+    /// Every ban that hasn't been lifted, newest first. Expired bans are
+    /// included
+    async fn get_bans(&self) -> anyhow::Result<Vec<Ban>>;
+
+    /// Bans still in effect at `now` on the account, hardware id or exact
+    /// address, plus every active range ban. Range bans still need to be
+    /// checked with [`Ban::matches`]
+    async fn get_active_bans(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        now: NaiveDateTime,
+    ) -> anyhow::Result<Vec<Ban>>;
+
+    /// `duration` is in minutes, `None` = permanent
+    async fn create_ban(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        duration: Option<i32>,
+        admin_name: &str,
+    ) -> anyhow::Result<()>;
+
+    async fn lift_ban(&self, id: i32) -> anyhow::Result<()>;
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+pub struct BoardPost {
+    pub id: i32,
+    pub author: String,
+    pub subject: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[async_trait]
+pub trait BoardStorage {
+    /// Newest posts first
+    async fn get_board_posts(&self, board_id: i32, limit: i32) -> anyhow::Result<Vec<BoardPost>>;
+
+    /// Returns the post body
+    async fn get_board_post(&self, board_id: i32, post_id: i32) -> anyhow::Result<Option<String>>;
+
+    async fn create_board_post(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        subject: &str,
+        body: &str,
+    ) -> anyhow::Result<()>;
+
+    async fn delete_board_post(&self, post_id: i32) -> anyhow::Result<()>;
+
+    /// Posts by the character in the last `minutes`
+    async fn get_recent_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        minutes: i32,
+    ) -> anyhow::Result<i32>;
+
+    /// Posts by the character among the newest `limit` posts on the board
+    async fn get_total_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        limit: i32,
+    ) -> anyhow::Result<i32>;
+}
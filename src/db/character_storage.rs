@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use eolib::protocol::net::server::CharacterSelectionListEntry;
+
+use crate::character::Character;
+
+pub struct CharacterAccount {
+    pub account_id: i32,
+    pub last_login_ip: Option<String>,
+    pub hdid: String,
+}
+
+#[async_trait]
+pub trait CharacterStorage {
+    async fn character_exists(&self, name: &str) -> anyhow::Result<bool>;
+
+    /// Equipment in the returned entries holds item ids rather than graphics
+    async fn get_character_list(
+        &self,
+        account_id: i32,
+    ) -> anyhow::Result<Vec<CharacterSelectionListEntry>>;
+
+    async fn get_num_of_characters(&self, account_id: i32) -> anyhow::Result<usize>;
+
+    async fn get_character_account(&self, name: &str) -> anyhow::Result<Option<CharacterAccount>>;
+
+    async fn get_character_id(&self, name: &str) -> anyhow::Result<Option<i32>>;
+
+    async fn load_character(&self, id: i32) -> anyhow::Result<Character>;
+
+    /// Inserts a new character and sets its id
+    async fn create_character(&self, character: &mut Character) -> anyhow::Result<()>;
+
+    async fn update_character(&self, character: &Character) -> anyhow::Result<()>;
+
+    /// Updates every character in one transaction so either all of them are
+    /// saved or none are
+    async fn update_characters(&self, characters: &[Character]) -> anyhow::Result<()>;
+
+    async fn delete_character(&self, id: i32) -> anyhow::Result<()>;
+
+    /// Moves an offline character
+    async fn set_character_position(
+        &self,
+        name: &str,
+        map: i32,
+        x: i32,
+        y: i32,
+    ) -> anyhow::Result<()>;
+
+    async fn divorce_character(&self, name: &str) -> anyhow::Result<()>;
+}
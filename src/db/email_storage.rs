@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::email::OutgoingEmail;
+
+/// An email in the outbox that hasn't been sent yet
+#[derive(Debug, Clone)]
+pub struct QueuedEmail {
+    pub id: i32,
+    pub email: OutgoingEmail,
+    /// Failed attempts so far
+    pub attempts: i32,
+}
+
+#[async_trait]
+pub trait EmailStorage {
+    /// Adds an email to the outbox, due to be sent right away
+    async fn queue_email(&self, email: &OutgoingEmail, now: NaiveDateTime) -> anyhow::Result<i32>;
+
+    /// Unsent emails whose next attempt is due, oldest first
+    async fn get_due_emails(
+        &self,
+        now: NaiveDateTime,
+        limit: usize,
+    ) -> anyhow::Result<Vec<QueuedEmail>>;
+
+    /// Records a successful send and clears the bodies, which can hold
+    /// account codes
+    async fn mark_email_sent(&self, id: i32, now: NaiveDateTime) -> anyhow::Result<()>;
+
+    /// Records a failed attempt. Without a `next_attempt_at` the email is
+    /// given up on, its bodies are cleared and the row is left as a record
+    async fn mark_email_failed(
+        &self,
+        id: i32,
+        attempts: i32,
+        next_attempt_at: Option<NaiveDateTime>,
+        error: &str,
+    ) -> anyhow::Result<()>;
+}
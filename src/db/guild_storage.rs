@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use eolib::protocol::net::server::{GuildMember, GuildStaff};
+
+pub struct GuildDetails {
+    pub tag: String,
+    pub name: String,
+    pub description: String,
+    pub created_at: NaiveDateTime,
+    pub bank: i32,
+    pub ranks: Vec<String>,
+    pub staff: Vec<GuildStaff>,
+}
+
+#[async_trait]
+pub trait GuildStorage {
+    async fn guild_exists(&self, tag: &str, name: &str) -> anyhow::Result<bool>;
+
+    /// Creates the guild along with its default ranks
+    async fn create_guild(&self, tag: &str, name: &str, description: &str) -> anyhow::Result<()>;
+
+    /// Deletes the guild and clears guild info from its members
+    async fn delete_guild(&self, tag: &str) -> anyhow::Result<()>;
+
+    async fn get_guild_name(&self, tag: &str) -> anyhow::Result<Option<String>>;
+
+    async fn get_guild_description(&self, tag: &str) -> anyhow::Result<Option<String>>;
+
+    async fn set_guild_description(&self, tag: &str, description: &str) -> anyhow::Result<()>;
+
+    /// All nine rank names in order
+    async fn get_guild_ranks(&self, tag: &str) -> anyhow::Result<Vec<String>>;
+
+    async fn set_guild_rank(&self, tag: &str, index: usize, rank: &str) -> anyhow::Result<()>;
+
+    async fn get_guild_bank(&self, tag: &str) -> anyhow::Result<i32>;
+
+    async fn set_guild_bank(&self, tag: &str, bank: i32) -> anyhow::Result<()>;
+
+    /// `identity` may be either the guild tag or name
+    async fn get_guild_details(&self, identity: &str) -> anyhow::Result<Option<GuildDetails>>;
+
+    /// `identity` may be either the guild tag or name
+    async fn get_guild_members(&self, identity: &str) -> anyhow::Result<Vec<GuildMember>>;
+
+    async fn get_guild_leader_count(&self, tag: &str) -> anyhow::Result<i32>;
+
+    /// Changes the rank of an offline member
+    async fn set_guild_member_rank(
+        &self,
+        name: &str,
+        rank: i32,
+        rank_str: &str,
+    ) -> anyhow::Result<()>;
+
+    /// Takes an offline member out of their guild
+    async fn remove_guild_member(&self, name: &str) -> anyhow::Result<()>;
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+
+use crate::settings::LoginLockout;
+
+use super::Db;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginLockKind {
+    /// Keyed by the username typed in, whether or not the account exists
+    Account,
+    Ip,
+}
+
+impl LoginLockKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Account => "account",
+            Self::Ip => "ip",
+        }
+    }
+}
+
+/// Failed logins for an account or address, kept across reconnects
+#[derive(Debug, Clone)]
+pub struct LoginLock {
+    /// Failures since the last lockout
+    pub failures: i32,
+    /// Lockouts in a row, each one twice as long as the last
+    pub lockouts: i32,
+    pub locked_until: Option<NaiveDateTime>,
+}
+
+impl LoginLock {
+    /// Seconds until the lock runs out, `None` if it isn't locked
+    pub fn seconds_remaining(&self, now: NaiveDateTime) -> Option<i64> {
+        let locked_until = self.locked_until?;
+        if locked_until <= now {
+            return None;
+        }
+
+        Some((locked_until - now).num_seconds().max(1))
+    }
+
+    /// Length of the next lockout, twice as long as the last one
+    pub fn lockout_duration(&self, settings: &LoginLockout) -> i64 {
+        settings
+            .lockout_duration
+            .saturating_mul(1 << self.lockouts.clamp(0, 30))
+            .min(settings.max_lockout_duration)
+    }
+}
+
+#[async_trait]
+pub trait LoginLockStorage {
+    async fn get_login_lock(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+    ) -> anyhow::Result<Option<LoginLock>>;
+
+    /// Counts a failure in a single statement so parallel connections can't
+    /// overwrite each other's counts. Counts are started again when the last
+    /// failure was at or before `reset_before`
+    async fn increment_login_failures(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        now: NaiveDateTime,
+        reset_before: NaiveDateTime,
+    ) -> anyhow::Result<LoginLock>;
+
+    /// Locks until `locked_until` and starts counting again if there have
+    /// been `max_failures`. Returns whether this call started the lockout
+    async fn start_login_lockout(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        max_failures: i32,
+        locked_until: NaiveDateTime,
+    ) -> anyhow::Result<bool>;
+
+    async fn delete_login_lock(&self, kind: LoginLockKind, value: &str) -> anyhow::Result<()>;
+
+    /// Clears the account and address locks on `value`, returns how many
+    /// there were
+    async fn delete_login_locks(&self, value: &str) -> anyhow::Result<u64>;
+}
+
+/// Counts a failed login against `value` and locks it once there have been
+/// `max_failures`. Returns the lockout length in seconds when this failure
+/// started one
+pub async fn add_login_failure(
+    db: &Db,
+    kind: LoginLockKind,
+    value: &str,
+    now: NaiveDateTime,
+    max_failures: i32,
+    settings: &LoginLockout,
+) -> anyhow::Result<Option<i64>> {
+    let lock = db
+        .increment_login_failures(
+            kind,
+            value,
+            now,
+            now - Duration::seconds(settings.reset_after),
+        )
+        .await?;
+
+    if max_failures <= 0 || lock.failures < max_failures {
+        return Ok(None);
+    }
+
+    let duration = lock.lockout_duration(settings);
+    let started = db
+        .start_login_lockout(kind, value, max_failures, now + Duration::seconds(duration))
+        .await?;
+
+    Ok(if started { Some(duration) } else { None })
+}
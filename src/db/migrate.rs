@@ -0,0 +1,47 @@
+use anyhow::bail;
+
+use super::Db;
+
+/// A numbered schema change. Versions start at 1 and must be applied in order
+#[derive(Debug)]
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+/// Applies every migration newer than the database's schema version.
+///
+/// Refuses to continue if the database has been migrated by a newer build
+pub async fn migrate(db: &Db) -> anyhow::Result<()> {
+    let migrations = db.migrations();
+    let latest = migrations.last().map(|m| m.version).unwrap_or(0);
+    let current = db.get_schema_version().await?;
+
+    if current > latest {
+        bail!(
+            "Database schema version {} is newer than this build supports ({}). Please update the server.",
+            current,
+            latest
+        );
+    }
+
+    for migration in migrations.iter().filter(|m| m.version > current) {
+        info!(
+            "Applying migration {:04}_{}",
+            migration.version, migration.name
+        );
+        db.apply_migration(migration).await?;
+    }
+
+    if current < latest {
+        info!(
+            "Database schema migrated from version {} to {}",
+            current, latest
+        );
+    } else {
+        info!("Database schema is up to date (version {})", current);
+    }
+
+    Ok(())
+}
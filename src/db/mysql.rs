@@ -0,0 +1,162 @@
+use std::{
+    future::Future,
+    ops::{Deref, DerefMut},
+};
+
+use async_trait::async_trait;
+use mysql_async::{prelude::*, Conn, Opts, Pool, Row};
+use prometheus::HistogramTimer;
+
+use crate::metrics;
+
+use super::{Migration, ServerStats, Storage};
+
+mod account;
+mod audit;
+mod ban;
+mod board;
+mod character;
+mod email;
+mod guild;
+mod login_lock;
+mod sanction;
+
+static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial",
+        sql: include_str!("mysql/migrations/0001_initial.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "audit_log",
+        sql: include_str!("mysql/migrations/0002_audit_log.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "sanctions",
+        sql: include_str!("mysql/migrations/0003_sanctions.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "ban_targets",
+        sql: include_str!("mysql/migrations/0004_ban_targets.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "status_effects",
+        sql: include_str!("mysql/migrations/0005_status_effects.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "queue_priority",
+        sql: include_str!("mysql/migrations/0006_queue_priority.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "login_locks",
+        sql: include_str!("mysql/migrations/0007_login_locks.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "email_outbox",
+        sql: include_str!("mysql/migrations/0008_email_outbox.sql"),
+    },
+];
+
+#[derive(Debug)]
+pub struct MySqlDb {
+    pool: Pool,
+}
+
+impl MySqlDb {
+    pub fn new(url: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            pool: Pool::new(Opts::from_url(url)?),
+        })
+    }
+
+    /// Checks out a connection from the pool. The storage call is timed
+    /// until the connection is dropped
+    #[track_caller]
+    fn get_conn(&self) -> impl Future<Output = anyhow::Result<TimedConn>> + '_ {
+        let timer = metrics::start_db_timer();
+        async move {
+            Ok(TimedConn {
+                conn: self.pool.get_conn().await?,
+                _timer: timer,
+            })
+        }
+    }
+}
+
+struct TimedConn {
+    conn: Conn,
+    _timer: HistogramTimer,
+}
+
+impl Deref for TimedConn {
+    type Target = Conn;
+
+    fn deref(&self) -> &Conn {
+        &self.conn
+    }
+}
+
+impl DerefMut for TimedConn {
+    fn deref_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+}
+
+#[async_trait]
+impl Storage for MySqlDb {
+    fn migrations(&self) -> &'static [Migration] {
+        MIGRATIONS
+    }
+
+    async fn get_schema_version(&self) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        conn.query_drop(include_str!("../sql/create_schema_version_table.sql"))
+            .await?;
+        let version: Option<Option<i32>> = conn
+            .query_first(include_str!("../sql/get_schema_version.sql"))
+            .await?;
+        Ok(version.flatten().unwrap_or(0))
+    }
+
+    async fn apply_migration(&self, migration: &Migration) -> anyhow::Result<()> {
+        // The whole file is sent as one multi-statement query and the server
+        // stops at the first statement that fails. DDL commits implicitly in
+        // MySQL so there's no point wrapping this in a transaction, migrations
+        // should be written to be re-runnable (see 0004_ban_targets.sql for
+        // guarding ALTER TABLE)
+        let mut conn = self.get_conn().await?;
+        conn.query_drop(migration.sql).await?;
+        conn.exec_drop(
+            include_str!("../sql/create_schema_version.sql"),
+            params! {
+                "version" => migration.version,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_server_stats(&self) -> anyhow::Result<ServerStats> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .query_first(include_str!("../sql/get_server_stats.sql"))
+            .await?;
+
+        Ok(match row {
+            Some(row) => ServerStats {
+                accounts: row.get("accounts").unwrap_or_default(),
+                characters: row.get("characters").unwrap_or_default(),
+                guilds: row.get("guilds").unwrap_or_default(),
+                admins: row.get("admins").unwrap_or_default(),
+            },
+            None => ServerStats::default(),
+        })
+    }
+}
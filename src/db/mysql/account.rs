@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use mysql_async::{prelude::*, Row};
+
+use crate::db::{AccountCredentials, AccountStorage, NewAccount};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl AccountStorage for MySqlDb {
+    async fn account_exists(&self, name: &str) -> anyhow::Result<bool> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_account_id.sql"),
+                params! {
+                    "name" => name,
+                },
+            )
+            .await?;
+        Ok(row.is_some())
+    }
+
+    async fn create_account(&self, account: &NewAccount) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/create_account.sql"),
+            params! {
+                "name" => &account.name,
+                "password_hash" => &account.password_hash,
+                "real_name" => &account.real_name,
+                "location" => &account.location,
+                "email" => &account.email,
+                "computer" => &account.computer,
+                "hdid" => &account.hdid,
+                "register_ip" => &account.register_ip,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_account_credentials(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<Option<AccountCredentials>> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_password_hash.sql"),
+                params! {
+                    "name" => name,
+                },
+            )
+            .await?;
+
+        Ok(row.map(|mut row| AccountCredentials {
+            id: row.take("id").unwrap(),
+            name: row.take("name").unwrap(),
+            password_hash: row.take("password_hash").unwrap(),
+            queue_priority: row.take::<u32, &str>("queue_priority").unwrap() == 1,
+        }))
+    }
+
+    async fn get_account_email(&self, name: &str) -> anyhow::Result<Option<(i32, String)>> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_account_email.sql"),
+                params! {
+                    "name" => name,
+                },
+            )
+            .await?;
+
+        Ok(row.map(|mut row| (row.take("id").unwrap(), row.take("email").unwrap())))
+    }
+
+    async fn update_password_hash(
+        &self,
+        account_id: i32,
+        password_hash: &str,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_password_hash.sql"),
+            params! {
+                "id" => account_id,
+                "password_hash" => password_hash,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_last_login_ip(&self, account_id: i32, ip: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_last_login_ip.sql"),
+            params! {
+                "account_id" => account_id,
+                "ip" => ip,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
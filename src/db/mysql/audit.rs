@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use mysql_async::prelude::*;
+
+use crate::{
+    audit::AuditEntry,
+    db::{audit_entry_to_json, AuditStorage},
+};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl AuditStorage for MySqlDb {
+    async fn create_audit_entries(&self, entries: &[AuditEntry]) -> anyhow::Result<()> {
+        let mut params = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let (event, data) = audit_entry_to_json(entry)?;
+            params.push(params! {
+                "character_id" => entry.character_id,
+                "character_name" => &entry.character_name,
+                "event" => event,
+                "map_id" => entry.map_id,
+                "x" => entry.x,
+                "y" => entry.y,
+                "data" => data,
+                "created_at" => entry.at.naive_utc(),
+            });
+        }
+
+        let mut conn = self.get_conn().await?;
+        conn.exec_batch(include_str!("../../sql/create_audit_entry.sql"), params)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_audit_entries(
+        &self,
+        character_name: &str,
+        limit: i32,
+    ) -> anyhow::Result<Vec<AuditEntry>> {
+        let mut conn = self.get_conn().await?;
+        let rows: Vec<String> = conn
+            .exec(
+                include_str!("../../sql/get_audit_entries.sql"),
+                params! {
+                    "character_name" => character_name,
+                    "limit" => limit,
+                },
+            )
+            .await?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|data| serde_json::from_str(data).ok())
+            .collect())
+    }
+}
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use mysql_async::{prelude::*, Row};
+
+use crate::db::{Ban, BanStorage};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl BanStorage for MySqlDb {
+    async fn get_bans(&self) -> anyhow::Result<Vec<Ban>> {
+        let mut conn = self.get_conn().await?;
+        let rows: Vec<Row> = conn.query(include_str!("../../sql/get_bans.sql")).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|mut row| Ban {
+                id: row.take("id").unwrap(),
+                account_id: row.take("account_id").unwrap(),
+                account_name: row.take("account_name").unwrap(),
+                ip: row.take("ip").unwrap(),
+                hdid: row.take("hdid").unwrap(),
+                duration: row.take("duration").unwrap(),
+                created_by: row.take("created_by").unwrap(),
+                created_at: row.take("created_at").unwrap(),
+            })
+            .collect())
+    }
+
+    async fn get_active_bans(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        now: NaiveDateTime,
+    ) -> anyhow::Result<Vec<Ban>> {
+        let mut conn = self.get_conn().await?;
+        let rows: Vec<Row> = conn
+            .exec(
+                include_str!("../../sql/get_active_bans.sql"),
+                params! {
+                    "account_id" => account_id,
+                    "ip" => ip,
+                    "hdid" => hdid,
+                    "now" => now,
+                },
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|mut row| Ban {
+                id: row.take("id").unwrap(),
+                account_id: row.take("account_id").unwrap(),
+                account_name: row.take("account_name").unwrap(),
+                ip: row.take("ip").unwrap(),
+                hdid: row.take("hdid").unwrap(),
+                duration: row.take("duration").unwrap(),
+                created_by: row.take("created_by").unwrap(),
+                created_at: row.take("created_at").unwrap(),
+            })
+            .collect())
+    }
+
+    async fn create_ban(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        duration: Option<i32>,
+        admin_name: &str,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/create_ban.sql"),
+            params! {
+                "account_id" => account_id,
+                "admin_name" => admin_name,
+                "ip" => ip,
+                "hdid" => hdid,
+                "duration" => duration,
+                "created_at" => Utc::now().naive_utc(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn lift_ban(&self, id: i32) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/lift_ban.sql"),
+            params! {
+                "id" => id,
+                "lifted_at" => Utc::now().naive_utc(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use mysql_async::{prelude::*, Row};
+
+use crate::db::{BoardPost, BoardStorage};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl BoardStorage for MySqlDb {
+    async fn get_board_posts(&self, board_id: i32, limit: i32) -> anyhow::Result<Vec<BoardPost>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec_map(
+                include_str!("../../sql/get_board_posts.sql"),
+                params! {
+                    "board_id" => board_id,
+                    "limit" => limit,
+                },
+                |mut row: Row| BoardPost {
+                    id: row.take("id").unwrap(),
+                    author: row.take("author").unwrap(),
+                    subject: row.take("subject").unwrap(),
+                    created_at: row.take("created_at").unwrap(),
+                },
+            )
+            .await?)
+    }
+
+    async fn get_board_post(&self, board_id: i32, post_id: i32) -> anyhow::Result<Option<String>> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_board_post.sql"),
+                params! {
+                    "board_id" => board_id,
+                    "post_id" => post_id,
+                },
+            )
+            .await?;
+
+        Ok(row.map(|mut row| row.take("body").unwrap()))
+    }
+
+    async fn create_board_post(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        subject: &str,
+        body: &str,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/create_board_post.sql"),
+            params! {
+                "board_id" => board_id,
+                "character_id" => character_id,
+                "subject" => subject,
+                "body" => body,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_board_post(&self, post_id: i32) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/delete_board_post.sql"),
+            params! {
+                "id" => post_id,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_recent_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        minutes: i32,
+    ) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_recent_post_count.sql"),
+                params! {
+                    "board_id" => board_id,
+                    "character_id" => character_id,
+                    "post_time" => minutes,
+                },
+            )
+            .await?;
+
+        Ok(row
+            .and_then(|mut row| row.take("recent_posts"))
+            .unwrap_or(0))
+    }
+
+    async fn get_total_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        limit: i32,
+    ) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_total_post_count.sql"),
+                params! {
+                    "board_id" => board_id,
+                    "character_id" => character_id,
+                    "limit" => limit,
+                },
+            )
+            .await?;
+
+        Ok(row.and_then(|mut row| row.take("total_posts")).unwrap_or(0))
+    }
+}
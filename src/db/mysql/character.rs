@@ -0,0 +1,807 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use eolib::protocol::{
+    net::{
+        server::{CharacterSelectionListEntry, EquipmentCharacterSelect, SitState},
+        Item, Spell,
+    },
+    AdminLevel, Direction, Gender,
+};
+use mysql_async::{prelude::*, Params, Row, Transaction, TxOpts};
+
+use crate::{
+    character::{Character, QuestProgress},
+    db::{npc_kills_from_json, npc_kills_to_json, CharacterAccount, CharacterStorage},
+    SETTINGS, STATUS_EFFECTS,
+};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl CharacterStorage for MySqlDb {
+    async fn character_exists(&self, name: &str) -> anyhow::Result<bool> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_character_id.sql"),
+                params! {
+                    "name" => name,
+                },
+            )
+            .await?;
+        Ok(row.is_some())
+    }
+
+    async fn get_character_list(
+        &self,
+        account_id: i32,
+    ) -> anyhow::Result<Vec<CharacterSelectionListEntry>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec_map(
+                include_str!("../../sql/get_character_list.sql"),
+                params! {
+                    "account_id" => account_id,
+                },
+                |row: Row| CharacterSelectionListEntry {
+                    id: row.get(0).unwrap(),
+                    name: row.get(1).unwrap(),
+                    level: row.get(2).unwrap(),
+                    gender: Gender::from(row.get::<i32, usize>(3).unwrap()),
+                    hair_style: row.get(4).unwrap(),
+                    hair_color: row.get(5).unwrap(),
+                    skin: row.get(6).unwrap(),
+                    admin: AdminLevel::from(row.get::<i32, usize>(7).unwrap()),
+                    equipment: EquipmentCharacterSelect {
+                        boots: row.get(8).unwrap(),
+                        armor: row.get(9).unwrap(),
+                        hat: row.get(10).unwrap(),
+                        shield: row.get(11).unwrap(),
+                        weapon: row.get(12).unwrap(),
+                    },
+                },
+            )
+            .await?)
+    }
+
+    async fn get_num_of_characters(&self, account_id: i32) -> anyhow::Result<usize> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_num_of_characters.sql"),
+                params! {
+                    "account_id" => account_id,
+                },
+            )
+            .await?;
+        Ok(row.map(|row| row.get(0).unwrap()).unwrap_or(0))
+    }
+
+    async fn get_character_account(&self, name: &str) -> anyhow::Result<Option<CharacterAccount>> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_character_account.sql"),
+                params! {
+                    "character_name" => name,
+                },
+            )
+            .await?;
+
+        Ok(row.map(|mut row| CharacterAccount {
+            account_id: row.take("id").unwrap(),
+            last_login_ip: row.take("last_login_ip").unwrap(),
+            hdid: row.take("hdid").unwrap(),
+        }))
+    }
+
+    async fn get_character_id(&self, name: &str) -> anyhow::Result<Option<i32>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec_first(
+                include_str!("../../sql/get_character_id.sql"),
+                params! {
+                    "name" => name,
+                },
+            )
+            .await?)
+    }
+
+    async fn load_character(&self, id: i32) -> anyhow::Result<Character> {
+        let mut conn = self.get_conn().await?;
+        let mut character = Character::default();
+        let mut row = match conn
+            .exec_first::<Row, &str, Params>(
+                include_str!("../../sql/get_character.sql"),
+                params! {
+                    "character_id" => id,
+                },
+            )
+            .await?
+        {
+            Some(row) => row,
+            None => return Err(anyhow!("Character {} not found", id)),
+        };
+
+        character.id = id;
+        character.account_id = row.take("account_id").unwrap();
+        character.name = row.take("name").unwrap();
+        character.title = row.take("title").unwrap();
+        character.home = row.take("home").unwrap();
+        character.fiance = row.take("fiance").unwrap();
+        character.partner = row.take("partner").unwrap();
+        character.admin_level = AdminLevel::from(row.take::<i32, &str>("admin_level").unwrap());
+        character.class = row.take("class").unwrap();
+        character.gender = Gender::from(row.take::<i32, &str>("gender").unwrap());
+        character.skin = row.take("race").unwrap();
+        character.hair_style = row.take("hair_style").unwrap();
+        character.hair_color = row.take("hair_color").unwrap();
+        character.bank_level = row.take("bank_level").unwrap();
+        character.gold_bank = row.take("gold_bank").unwrap();
+        character.guild_rank = row.take("guild_rank").unwrap();
+        character.guild_rank_string = row.take("guild_rank_string").unwrap();
+        character.equipment.boots = row.take("boots").unwrap();
+        character.equipment.accessory = row.take("accessory").unwrap();
+        character.equipment.gloves = row.take("gloves").unwrap();
+        character.equipment.belt = row.take("belt").unwrap();
+        character.equipment.armor = row.take("armor").unwrap();
+        character.equipment.hat = row.take("hat").unwrap();
+        character.equipment.shield = row.take("shield").unwrap();
+        character.equipment.weapon = row.take("weapon").unwrap();
+        character.equipment.ring[0] = row.take("ring").unwrap();
+        character.equipment.ring[1] = row.take("ring2").unwrap();
+        character.equipment.armlet[0] = row.take("armlet").unwrap();
+        character.equipment.armlet[1] = row.take("armlet2").unwrap();
+        character.equipment.bracer[0] = row.take("bracer").unwrap();
+        character.equipment.bracer[1] = row.take("bracer2").unwrap();
+        character.equipment.necklace = row.take("necklace").unwrap();
+        character.level = row.take("level").unwrap();
+        character.experience = row.take("experience").unwrap();
+        character.hp = row.take("hp").unwrap();
+        character.tp = row.take("tp").unwrap();
+        character.base_strength = row.take("strength").unwrap();
+        character.base_intelligence = row.take("intelligence").unwrap();
+        character.base_wisdom = row.take("wisdom").unwrap();
+        character.base_agility = row.take("agility").unwrap();
+        character.base_constitution = row.take("constitution").unwrap();
+        character.base_charisma = row.take("charisma").unwrap();
+        character.stat_points = row.take("stat_points").unwrap();
+        character.skill_points = row.take("skill_points").unwrap();
+        character.karma = row.take("karma").unwrap();
+        character.usage = row.take("usage").unwrap();
+        character.map_id = row.take("map").unwrap();
+        character.coords.x = row.take("x").unwrap();
+        character.coords.y = row.take("y").unwrap();
+        character.direction = Direction::from(row.take::<i32, &str>("direction").unwrap());
+        character.sit_state = SitState::from(row.take::<i32, &str>("sitting").unwrap());
+        character.hidden = row.take::<u32, &str>("hidden").unwrap() == 1;
+        character.guild_name = row.take("guild_name").unwrap();
+        character.guild_tag = row.take("tag").unwrap();
+
+        character.items = conn
+            .exec_map(
+                include_str!("../../sql/get_character_inventory.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| Item {
+                    id: row.take(0).unwrap(),
+                    amount: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        character.bank = conn
+            .exec_map(
+                include_str!("../../sql/get_character_bank.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| Item {
+                    id: row.take(0).unwrap(),
+                    amount: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        character.spells = conn
+            .exec_map(
+                include_str!("../../sql/get_character_spells.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| Spell {
+                    id: row.take(0).unwrap(),
+                    level: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        character.quests = conn
+            .exec_map(
+                include_str!("../../sql/get_character_quest_progress.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| QuestProgress {
+                    id: row.take(0).unwrap(),
+                    state: row.take(1).unwrap(),
+                    npc_kills: npc_kills_from_json(&row.take::<String, usize>(2).unwrap()),
+                    player_kills: row.take(3).unwrap(),
+                    done_at: row
+                        .take::<Option<NaiveDateTime>, usize>(4)
+                        .map(|done_at| {
+                            done_at.map(|done_at| Utc.from_local_datetime(&done_at).unwrap())
+                        })
+                        .unwrap(),
+                    completions: row.take(5).unwrap(),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        character.auto_pickup_items = conn
+            .exec_map(
+                include_str!("../../sql/get_character_auto_pickup.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| row.take::<i32, usize>(0).unwrap(),
+            )
+            .await?;
+
+        let status_effects = conn
+            .exec_map(
+                include_str!("../../sql/get_character_status_effects.sql"),
+                params! {
+                    "character_id" => id,
+                },
+                |mut row: Row| {
+                    (
+                        row.take::<i32, usize>(0).unwrap(),
+                        row.take::<i32, usize>(1).unwrap(),
+                    )
+                },
+            )
+            .await?;
+
+        for (effect_id, remaining) in status_effects {
+            character
+                .status_effects
+                .resume(&STATUS_EFFECTS.load(), effect_id, remaining);
+        }
+
+        character.warp_suck_ticks = SETTINGS.world.warp_suck_rate;
+        character.ghost_ticks = SETTINGS.world.ghost_rate;
+
+        Ok(character)
+    }
+
+    async fn create_character(&self, character: &mut Character) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/create_character.sql"),
+            params! {
+                "account_id" => &character.account_id,
+                "name" => &character.name,
+                "home" => &SETTINGS.new_character.home,
+                "gender" => &(i32::from(character.gender)),
+                "race" => &character.skin,
+                "hair_style" => &(character.hair_style as u32),
+                "hair_color" => &(character.hair_color as u32),
+            },
+        )
+        .await?;
+
+        character.id = tx.last_insert_id().unwrap() as i32;
+
+        tx.exec_drop(
+            include_str!("../../sql/create_paperdoll.sql"),
+            params! {
+                "character_id" => &character.id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/create_position.sql"),
+            params! {
+                "character_id" => &character.id,
+                "map" => &SETTINGS.new_character.spawn_map,
+                "x" => &SETTINGS.new_character.spawn_x,
+                "y" => &SETTINGS.new_character.spawn_y,
+                "direction" => &SETTINGS.new_character.spawn_direction,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/create_stats.sql"),
+            params! {
+                "character_id" => &character.id,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn update_character(&self, character: &Character) -> anyhow::Result<()> {
+        self.update_characters(std::slice::from_ref(character))
+            .await
+    }
+
+    async fn update_characters(&self, characters: &[Character]) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+
+        for character in characters {
+            update_character_rows(&mut tx, character).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_character(&self, id: i32) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_stats.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_spells.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_position.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_paperdoll.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_inventory.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character_bank.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/delete_character.sql"),
+            params! {
+                "character_id" => id,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn set_character_position(
+        &self,
+        name: &str,
+        map: i32,
+        x: i32,
+        y: i32,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/offline_jail.sql"),
+            params! {
+                "map" => map,
+                "x" => x,
+                "y" => y,
+                "name" => name,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn divorce_character(&self, name: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/divorce_character.sql"),
+            params! {
+                "name" => name,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Writes one character as part of a larger transaction
+async fn update_character_rows(
+    tx: &mut Transaction<'_>,
+    character: &Character,
+) -> anyhow::Result<()> {
+    let old_items = tx
+        .exec_map(
+            include_str!("../../sql/get_character_inventory.sql"),
+            params! {
+                "character_id" => character.id,
+            },
+            |mut row: Row| Item {
+                id: row.take(0).unwrap(),
+                amount: row.take(1).unwrap(),
+            },
+        )
+        .await?;
+
+    let old_bank = tx
+        .exec_map(
+            include_str!("../../sql/get_character_bank.sql"),
+            params! {
+                "character_id" => character.id,
+            },
+            |mut row: Row| Item {
+                id: row.take(0).unwrap(),
+                amount: row.take(1).unwrap(),
+            },
+        )
+        .await?;
+
+    let old_spells = tx
+        .exec_map(
+            include_str!("../../sql/get_character_spells.sql"),
+            params! {
+                "character_id" => character.id,
+            },
+            |mut row: Row| Spell {
+                id: row.take(0).unwrap(),
+                level: row.take(1).unwrap(),
+            },
+        )
+        .await?;
+
+    let old_quests = tx
+        .exec_map(
+            include_str!("../../sql/get_character_quest_progress.sql"),
+            params! {
+                "character_id" => character.id,
+            },
+            |mut row: Row| QuestProgress {
+                id: row.take(0).unwrap(),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let old_auto_pickup = tx
+        .exec_map(
+            include_str!("../../sql/get_character_auto_pickup.sql"),
+            params! {
+                "character_id" => character.id,
+            },
+            |mut row: Row| row.take::<i32, usize>(0).unwrap(),
+        )
+        .await?;
+
+    tx.exec_drop(
+        include_str!("../../sql/update_character.sql"),
+        params! {
+            "character_id" => character.id,
+            "title" => &character.title,
+            "home" => &character.home,
+            "fiance" => &character.fiance,
+            "partner" => &character.partner,
+            "admin_level" => i32::from(character.admin_level),
+            "class" => character.class as u32,
+            "gender" => i32::from(character.gender),
+            "race" => character.skin,
+            "hair_style" => character.hair_style as u32,
+            "hair_color" => character.hair_color as u32,
+            "bank_level" => character.bank_level,
+            "gold_bank" => character.gold_bank,
+            "guild_tag" => &character.guild_tag,
+            "guild_rank" => character.guild_rank,
+            "guild_rank_string" => &character.guild_rank_string,
+        },
+    )
+    .await?;
+
+    tx.exec_drop(
+        include_str!("../../sql/update_paperdoll.sql"),
+        params! {
+            "character_id" => character.id,
+            "boots" => character.equipment.boots as u32,
+            "accessory" => character.equipment.accessory as u32,
+            "gloves" => character.equipment.gloves as u32,
+            "belt" => character.equipment.belt as u32,
+            "armor" => character.equipment.armor as u32,
+            "necklace" => character.equipment.necklace as u32,
+            "hat" => character.equipment.hat as u32,
+            "shield" => character.equipment.shield as u32,
+            "weapon" => character.equipment.weapon as u32,
+            "ring" => character.equipment.ring[0] as u32,
+            "ring2" => character.equipment.ring[1] as u32,
+            "armlet" => character.equipment.armlet[0] as u32,
+            "armlet2" => character.equipment.armlet[1] as u32,
+            "bracer" => character.equipment.bracer[0] as u32,
+            "bracer2" => character.equipment.bracer[1] as u32,
+        },
+    )
+    .await?;
+
+    tx.exec_drop(
+        include_str!("../../sql/update_position.sql"),
+        params! {
+            "character_id" => character.id,
+            "map_id" => character.map_id as u32,
+            "x" => character.coords.x as u32,
+            "y" => character.coords.y as u32,
+            "direction" => i32::from(character.direction),
+            "sitting" => i32::from(character.sit_state),
+            "hidden" => i32::from(character.hidden),
+        },
+    )
+    .await?;
+
+    tx.exec_drop(
+        include_str!("../../sql/update_stats.sql"),
+        params! {
+            "character_id" => character.id,
+            "level" => character.level as u32,
+            "experience" => character.experience,
+            "hp" => character.hp as u32,
+            "tp" => character.tp as u32,
+            "strength" => character.base_strength as u32,
+            "intelligence" => character.base_intelligence as u32,
+            "wisdom" => character.base_wisdom as u32,
+            "agility" => character.base_agility as u32,
+            "constitution" => character.base_constitution as u32,
+            "charisma" => character.base_charisma as u32,
+            "stat_points" => character.stat_points as u32,
+            "skill_points" => character.skill_points as u32,
+            "karma" => character.karma as u32,
+            "usage" => character.usage,
+        },
+    )
+    .await?;
+
+    for spell in &old_spells {
+        if !character.has_spell(spell.id) {
+            tx.exec_drop(
+                include_str!("../../sql/delete_spell.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "spell_id" => spell.id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for spell in &character.spells {
+        if !old_spells.iter().any(|s| s.id == spell.id) {
+            tx.exec_drop(
+                include_str!("../../sql/create_spell.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "spell_id" => spell.id,
+                    "level" => spell.level,
+                },
+            )
+            .await?;
+        } else {
+            tx.exec_drop(
+                include_str!("../../sql/update_spell.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "spell_id" => spell.id,
+                    "level" => spell.level,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item in &old_items {
+        if !character.items.iter().any(|i| i.id == item.id) {
+            tx.exec_drop(
+                include_str!("../../sql/delete_inventory_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item in &character.items {
+        if !old_items.iter().any(|i| i.id == item.id) {
+            tx.exec_drop(
+                include_str!("../../sql/create_inventory_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                    "quantity" => item.amount,
+                },
+            )
+            .await?;
+        } else {
+            tx.exec_drop(
+                include_str!("../../sql/update_inventory_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                    "quantity" => item.amount,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item in &old_bank {
+        if !character.bank.iter().any(|i| i.id == item.id) {
+            tx.exec_drop(
+                include_str!("../../sql/delete_bank_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item in &character.bank {
+        if !old_bank.iter().any(|i| i.id == item.id) {
+            tx.exec_drop(
+                include_str!("../../sql/create_bank_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                    "quantity" => item.amount,
+                },
+            )
+            .await?;
+        } else {
+            tx.exec_drop(
+                include_str!("../../sql/update_bank_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item.id,
+                    "quantity" => item.amount,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for quest in &old_quests {
+        if !character.quests.iter().any(|q| q.id == quest.id) {
+            tx.exec_drop(
+                include_str!("../../sql/delete_quest_progress.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "quest_id" => quest.id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for quest in &character.quests {
+        let npc_kills = npc_kills_to_json(&quest.npc_kills);
+
+        if !old_quests.iter().any(|q| q.id == quest.id) {
+            tx.exec_drop(
+                include_str!("../../sql/create_quest_progress.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "quest_id" => quest.id,
+                    "state" => quest.state,
+                    "npc_kills" => npc_kills,
+                    "player_kills" => quest.player_kills,
+                    "done_at" => quest.done_at.map(|done_at| done_at.naive_utc()),
+                    "completions" => quest.completions,
+                },
+            )
+            .await?;
+        } else {
+            tx.exec_drop(
+                include_str!("../../sql/update_quest_progress.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "quest_id" => quest.id,
+                    "state" => quest.state,
+                    "npc_kills" => npc_kills,
+                    "player_kills" => quest.player_kills,
+                    "done_at" => quest.done_at.map(|done_at| done_at.naive_utc()),
+                    "completions" => quest.completions,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item_id in &old_auto_pickup {
+        if !character.auto_pickup_items.iter().any(|i| i == item_id) {
+            tx.exec_drop(
+                include_str!("../../sql/delete_auto_pickup_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item_id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for item_id in &character.auto_pickup_items {
+        if !old_auto_pickup.iter().any(|i| i == item_id) {
+            tx.exec_drop(
+                include_str!("../../sql/create_auto_pickup_item.sql"),
+                params! {
+                    "character_id" => character.id,
+                    "item_id" => item_id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    // Every effect's remaining time changes each second so there's nothing
+    // to gain from diffing
+    tx.exec_drop(
+        include_str!("../../sql/delete_character_status_effects.sql"),
+        params! {
+            "character_id" => character.id,
+        },
+    )
+    .await?;
+
+    for effect in &character.status_effects.0 {
+        tx.exec_drop(
+            include_str!("../../sql/create_status_effect.sql"),
+            params! {
+                "character_id" => character.id,
+                "effect_id" => effect.effect_id,
+                "remaining" => effect.remaining,
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mysql_async::{prelude::*, Row};
+
+use crate::{
+    db::{EmailStorage, QueuedEmail},
+    email::OutgoingEmail,
+};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl EmailStorage for MySqlDb {
+    async fn queue_email(&self, email: &OutgoingEmail, now: NaiveDateTime) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/queue_email.sql"),
+            params! {
+                "to_address" => &email.to_address,
+                "to_name" => &email.to_name,
+                "subject" => &email.subject,
+                "text_body" => &email.text_body,
+                "html_body" => &email.html_body,
+                "now" => now,
+            },
+        )
+        .await?;
+        Ok(conn.last_insert_id().unwrap_or_default() as i32)
+    }
+
+    async fn get_due_emails(
+        &self,
+        now: NaiveDateTime,
+        limit: usize,
+    ) -> anyhow::Result<Vec<QueuedEmail>> {
+        let mut conn = self.get_conn().await?;
+        let rows: Vec<Row> = conn
+            .exec(
+                include_str!("../../sql/get_due_emails.sql"),
+                params! {
+                    "now" => now,
+                    "limit" => limit as u64,
+                },
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|mut row| {
+                Some(QueuedEmail {
+                    id: row.take("id")?,
+                    email: OutgoingEmail {
+                        to_address: row.take("to_address")?,
+                        to_name: row.take("to_name")?,
+                        subject: row.take("subject")?,
+                        text_body: row.take("text_body")?,
+                        html_body: row.take("html_body")?,
+                    },
+                    attempts: row.take("attempts")?,
+                })
+            })
+            .collect())
+    }
+
+    async fn mark_email_sent(&self, id: i32, now: NaiveDateTime) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/mark_email_sent.sql"),
+            params! {
+                "id" => id,
+                "now" => now,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_email_failed(
+        &self,
+        id: i32,
+        attempts: i32,
+        next_attempt_at: Option<NaiveDateTime>,
+        error: &str,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/mark_email_failed.sql"),
+            params! {
+                "id" => id,
+                "attempts" => attempts,
+                "next_attempt_at" => next_attempt_at,
+                "error" => error.chars().take(255).collect::<String>(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use async_trait::async_trait;
+use eolib::protocol::net::server::{GuildMember, GuildStaff};
+use mysql_async::{prelude::*, Row, TxOpts};
+
+use crate::{
+    db::{GuildDetails, GuildStorage},
+    SETTINGS,
+};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl GuildStorage for MySqlDb {
+    async fn guild_exists(&self, tag: &str, name: &str) -> anyhow::Result<bool> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_guild_id.sql"),
+                params! {
+                    "name" => name,
+                    "tag" => tag,
+                },
+            )
+            .await?;
+        Ok(row.is_some())
+    }
+
+    async fn create_guild(&self, tag: &str, name: &str, description: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+
+        tx.exec_drop(
+            include_str!("../../sql/create_guild.sql"),
+            params! {
+                "tag" => tag,
+                "name" => name,
+                "description" => description,
+            },
+        )
+        .await?;
+
+        let guild_id = tx.last_insert_id().unwrap();
+
+        tx.exec_drop(
+            include_str!("../../sql/create_guild_ranks.sql"),
+            params! {
+                "guild_id" => guild_id,
+                "leader_rank_name" => &SETTINGS.guild.default_leader_rank_name,
+                "recruiter_rank_name" => &SETTINGS.guild.default_recruiter_rank_name,
+                "new_member_rank_name" => &SETTINGS.guild.default_new_member_rank_name,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_guild(&self, tag: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/delete_guild.sql"),
+            params! {
+                "tag" => tag,
+            },
+        )
+        .await?;
+        conn.query_drop(include_str!("../../sql/cleanup_guildless_characters.sql"))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_guild_name(&self, tag: &str) -> anyhow::Result<Option<String>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec_first(
+                include_str!("../../sql/get_guild_name.sql"),
+                params! {
+                    "tag" => tag,
+                },
+            )
+            .await?)
+    }
+
+    async fn get_guild_description(&self, tag: &str) -> anyhow::Result<Option<String>> {
+        let mut conn = self.get_conn().await?;
+        let description: Option<Option<String>> = conn
+            .exec_first(
+                include_str!("../../sql/get_guild_description.sql"),
+                params! {
+                    "tag" => tag,
+                },
+            )
+            .await?;
+        Ok(description.flatten())
+    }
+
+    async fn set_guild_description(&self, tag: &str, description: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_guild_description.sql"),
+            params! {
+                "description" => description,
+                "tag" => tag,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_ranks(&self, tag: &str) -> anyhow::Result<Vec<String>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec(
+                include_str!("../../sql/get_guild_ranks.sql"),
+                params! {
+                    "tag" => tag,
+                },
+            )
+            .await?)
+    }
+
+    async fn set_guild_rank(&self, tag: &str, index: usize, rank: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_guild_rank.sql"),
+            params! {
+                "rank" => rank,
+                "tag" => tag,
+                "index" => index,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_bank(&self, tag: &str) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        let bank: Option<i32> = conn
+            .exec_first(
+                include_str!("../../sql/get_guild_bank.sql"),
+                params! {
+                    "tag" => tag,
+                },
+            )
+            .await?;
+        Ok(bank.unwrap_or(0))
+    }
+
+    async fn set_guild_bank(&self, tag: &str, bank: i32) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_guild_bank.sql"),
+            params! {
+                "bank" => bank,
+                "tag" => tag,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_details(&self, identity: &str) -> anyhow::Result<Option<GuildDetails>> {
+        let mut conn = self.get_conn().await?;
+        let mut row: Row = match conn
+            .exec_first(
+                include_str!("../../sql/get_guild_details.sql"),
+                params! {
+                    "guild_identity" => identity,
+                },
+            )
+            .await?
+        {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        let ranks: Vec<String> = conn
+            .exec(
+                include_str!("../../sql/get_guild_ranks_by_identity.sql"),
+                params! {
+                    "guild_identity" => identity,
+                },
+            )
+            .await?;
+
+        let staff = conn
+            .exec_map(
+                include_str!("../../sql/get_guild_staff.sql"),
+                params! {
+                    "guild_identity" => identity,
+                },
+                |mut row: Row| GuildStaff {
+                    rank: row.take("guild_rank").unwrap(),
+                    name: row.take("name").unwrap(),
+                },
+            )
+            .await?;
+
+        Ok(Some(GuildDetails {
+            tag: row.take("tag").unwrap(),
+            name: row.take("name").unwrap(),
+            description: row
+                .take::<Option<String>, &str>("description")
+                .unwrap()
+                .unwrap_or_default(),
+            created_at: row.take("created_at").unwrap(),
+            bank: row.take("bank").unwrap(),
+            ranks,
+            staff,
+        }))
+    }
+
+    async fn get_guild_members(&self, identity: &str) -> anyhow::Result<Vec<GuildMember>> {
+        let mut conn = self.get_conn().await?;
+        Ok(conn
+            .exec_map(
+                include_str!("../../sql/get_guild_memberlist.sql"),
+                params! {
+                    "guild_identity" => identity,
+                },
+                |mut row: Row| GuildMember {
+                    rank: row.take("guild_rank").unwrap(),
+                    name: row.take("name").unwrap(),
+                    rank_name: row.take("guild_rank_string").unwrap(),
+                },
+            )
+            .await?)
+    }
+
+    async fn get_guild_leader_count(&self, tag: &str) -> anyhow::Result<i32> {
+        let mut conn = self.get_conn().await?;
+        let count: Option<i32> = conn
+            .exec_first(
+                include_str!("../../sql/get_count_guild_leader.sql"),
+                params! {
+                    "guild_tag" => tag,
+                },
+            )
+            .await?;
+        Ok(count.unwrap_or(0))
+    }
+
+    async fn set_guild_member_rank(
+        &self,
+        name: &str,
+        rank: i32,
+        rank_str: &str,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/update_guild_member_rank.sql"),
+            params! {
+                "name" => name,
+                "guild_rank" => rank,
+                "guild_rank_string" => rank_str,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_guild_member(&self, name: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/remove_guild_member.sql"),
+            params! {
+                "name" => name,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mysql_async::{prelude::*, Row, TxOpts};
+
+use crate::db::{LoginLock, LoginLockKind, LoginLockStorage};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl LoginLockStorage for MySqlDb {
+    async fn get_login_lock(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+    ) -> anyhow::Result<Option<LoginLock>> {
+        let mut conn = self.get_conn().await?;
+        let row: Option<Row> = conn
+            .exec_first(
+                include_str!("../../sql/get_login_lock.sql"),
+                params! {
+                    "kind" => kind.as_str(),
+                    "value" => value,
+                },
+            )
+            .await?;
+
+        Ok(row.and_then(|mut row| {
+            Some(LoginLock {
+                failures: row.take("failures")?,
+                lockouts: row.take("lockouts")?,
+                locked_until: row.take("locked_until")?,
+            })
+        }))
+    }
+
+    async fn increment_login_failures(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        now: NaiveDateTime,
+        reset_before: NaiveDateTime,
+    ) -> anyhow::Result<LoginLock> {
+        // MySQL has no RETURNING, the upsert keeps the row locked until the
+        // transaction commits so the read back sees only this increment
+        let mut conn = self.get_conn().await?;
+        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+        tx.exec_drop(
+            include_str!("../../sql/increment_login_failures.sql"),
+            params! {
+                "kind" => kind.as_str(),
+                "value" => value,
+                "now" => now,
+                "reset_before" => reset_before,
+            },
+        )
+        .await?;
+        let row: Option<Row> = tx
+            .exec_first(
+                include_str!("../../sql/get_login_lock.sql"),
+                params! {
+                    "kind" => kind.as_str(),
+                    "value" => value,
+                },
+            )
+            .await?;
+        tx.commit().await?;
+
+        let mut row = row.ok_or_else(|| anyhow::anyhow!("Login lock missing after upsert"))?;
+        Ok(LoginLock {
+            failures: row.take("failures").unwrap_or_default(),
+            lockouts: row.take("lockouts").unwrap_or_default(),
+            locked_until: row.take("locked_until").unwrap_or_default(),
+        })
+    }
+
+    async fn start_login_lockout(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        max_failures: i32,
+        locked_until: NaiveDateTime,
+    ) -> anyhow::Result<bool> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/start_login_lockout.sql"),
+            params! {
+                "kind" => kind.as_str(),
+                "value" => value,
+                "max_failures" => max_failures,
+                "locked_until" => locked_until,
+            },
+        )
+        .await?;
+        Ok(conn.affected_rows() > 0)
+    }
+
+    async fn delete_login_lock(&self, kind: LoginLockKind, value: &str) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/delete_login_lock.sql"),
+            params! {
+                "kind" => kind.as_str(),
+                "value" => value,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_login_locks(&self, value: &str) -> anyhow::Result<u64> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/delete_login_locks.sql"),
+            params! {
+                "value" => value,
+            },
+        )
+        .await?;
+        Ok(conn.affected_rows())
+    }
+}
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS `AuditEvent` (
+  `id` bigint NOT NULL AUTO_INCREMENT,
+  `character_id` int NOT NULL,
+  `character_name` varchar(12) NOT NULL,
+  `event` varchar(32) NOT NULL,
+  `map_id` int NOT NULL,
+  `x` int NOT NULL,
+  `y` int NOT NULL,
+  `data` text NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `audit_event_character_name` (`character_name`, `created_at`),
+  KEY `audit_event_character_id` (`character_id`)
+);
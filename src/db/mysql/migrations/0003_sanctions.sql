@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS `Sanction` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `character_id` int NOT NULL,
+  `type` varchar(16) NOT NULL,
+  `reason` varchar(255) NOT NULL DEFAULT '',
+  `duration` int DEFAULT NULL,
+  `created_by` varchar(12) NOT NULL,
+  `created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `lifted_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  KEY `character_id_idx` (`character_id`),
+  CONSTRAINT `sanction_character_id` FOREIGN KEY (`character_id`) REFERENCES `Character` (`id`) ON DELETE CASCADE
+);
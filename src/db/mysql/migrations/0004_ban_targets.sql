@@ -0,0 +1,62 @@
+-- MySQL commits every ALTER TABLE on its own, so each step checks whether it
+-- has already been applied in case an earlier run stopped part way through
+SET @sql = IF(
+  EXISTS(
+    SELECT 1 FROM information_schema.TABLE_CONSTRAINTS
+    WHERE `CONSTRAINT_SCHEMA` = DATABASE()
+    AND `TABLE_NAME` = 'Ban'
+    AND `CONSTRAINT_NAME` = 'ban_created_by'
+  ),
+  'ALTER TABLE `Ban` DROP FOREIGN KEY `ban_created_by`',
+  'DO 0'
+);
+PREPARE stmt FROM @sql;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
+
+ALTER TABLE `Ban`
+  MODIFY `account_id` int DEFAULT NULL,
+  MODIFY `ip` varchar(43) DEFAULT NULL,
+  MODIFY `created_by` int DEFAULT NULL;
+
+SET @sql = IF(
+  EXISTS(
+    SELECT 1 FROM information_schema.COLUMNS
+    WHERE `TABLE_SCHEMA` = DATABASE()
+    AND `TABLE_NAME` = 'Ban'
+    AND `COLUMN_NAME` = 'hdid'
+  ),
+  'DO 0',
+  'ALTER TABLE `Ban` ADD COLUMN `hdid` varchar(12) DEFAULT NULL AFTER `ip`'
+);
+PREPARE stmt FROM @sql;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
+
+SET @sql = IF(
+  EXISTS(
+    SELECT 1 FROM information_schema.COLUMNS
+    WHERE `TABLE_SCHEMA` = DATABASE()
+    AND `TABLE_NAME` = 'Ban'
+    AND `COLUMN_NAME` = 'lifted_at'
+  ),
+  'DO 0',
+  'ALTER TABLE `Ban` ADD COLUMN `lifted_at` datetime DEFAULT NULL'
+);
+PREPARE stmt FROM @sql;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
+
+SET @sql = IF(
+  EXISTS(
+    SELECT 1 FROM information_schema.TABLE_CONSTRAINTS
+    WHERE `CONSTRAINT_SCHEMA` = DATABASE()
+    AND `TABLE_NAME` = 'Ban'
+    AND `CONSTRAINT_NAME` = 'ban_created_by'
+  ),
+  'DO 0',
+  'ALTER TABLE `Ban` ADD CONSTRAINT `ban_created_by` FOREIGN KEY (`created_by`) REFERENCES `Character` (`id`) ON DELETE SET NULL'
+);
+PREPARE stmt FROM @sql;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
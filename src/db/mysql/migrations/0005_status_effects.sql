@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS `StatusEffect` (
+  `character_id` int NOT NULL,
+  `effect_id` int NOT NULL,
+  `remaining` int NOT NULL,
+  PRIMARY KEY (`character_id`, `effect_id`),
+  CONSTRAINT `statuseffect_character_id` FOREIGN KEY (`character_id`) REFERENCES `Character` (`id`) ON DELETE CASCADE
+);
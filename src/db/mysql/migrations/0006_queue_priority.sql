@@ -0,0 +1,14 @@
+-- Checked first so the migration can be re-run, see 0004_ban_targets.sql
+SET @sql = IF(
+  EXISTS(
+    SELECT 1 FROM information_schema.COLUMNS
+    WHERE `TABLE_SCHEMA` = DATABASE()
+    AND `TABLE_NAME` = 'Account'
+    AND `COLUMN_NAME` = 'queue_priority'
+  ),
+  'DO 0',
+  'ALTER TABLE `Account` ADD COLUMN `queue_priority` tinyint NOT NULL DEFAULT 0 AFTER `last_login_ip`'
+);
+PREPARE stmt FROM @sql;
+EXECUTE stmt;
+DEALLOCATE PREPARE stmt;
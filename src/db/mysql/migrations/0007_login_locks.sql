@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS `LoginLock` (
+  `kind` varchar(8) NOT NULL,
+  `value` varchar(45) NOT NULL,
+  `failures` int NOT NULL DEFAULT 0,
+  `lockouts` int NOT NULL DEFAULT 0,
+  `last_failure_at` datetime NOT NULL,
+  `locked_until` datetime DEFAULT NULL,
+  PRIMARY KEY (`kind`, `value`)
+);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS `EmailOutbox` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `to_address` varchar(64) NOT NULL,
+  `to_name` varchar(64) NOT NULL,
+  `subject` varchar(255) NOT NULL,
+  `text_body` text NOT NULL,
+  `html_body` text,
+  `attempts` int NOT NULL DEFAULT 0,
+  `last_error` varchar(255) DEFAULT NULL,
+  `next_attempt_at` datetime DEFAULT NULL,
+  `created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `sent_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  KEY `next_attempt_at_idx` (`next_attempt_at`)
+);
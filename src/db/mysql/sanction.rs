@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use mysql_async::{prelude::*, Row};
+
+use crate::db::{Sanction, SanctionKind, SanctionStorage};
+
+use super::MySqlDb;
+
+#[async_trait]
+impl SanctionStorage for MySqlDb {
+    async fn create_sanction(&self, character_id: i32, sanction: &Sanction) -> anyhow::Result<()> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/create_sanction.sql"),
+            params! {
+                "character_id" => character_id,
+                "type" => sanction.kind.as_str(),
+                "reason" => &sanction.reason,
+                "duration" => if sanction.duration > 0 { Some(sanction.duration) } else { None },
+                "created_by" => &sanction.created_by,
+                "created_at" => sanction.created_at,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_sanctions(&self, character_id: i32) -> anyhow::Result<Vec<Sanction>> {
+        let mut conn = self.get_conn().await?;
+        let rows: Vec<Row> = conn
+            .exec(
+                include_str!("../../sql/get_sanctions.sql"),
+                params! {
+                    "character_id" => character_id,
+                },
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|mut row| {
+                let kind: String = row.take("type")?;
+                Some(Sanction {
+                    kind: SanctionKind::parse(&kind)?,
+                    reason: row.take("reason")?,
+                    duration: row.take("duration")?,
+                    created_by: row.take("created_by")?,
+                    created_at: row.take("created_at")?,
+                    lifted_at: row.take("lifted_at")?,
+                })
+            })
+            .collect())
+    }
+
+    async fn lift_mutes(&self, character_id: i32) -> anyhow::Result<u64> {
+        let mut conn = self.get_conn().await?;
+        conn.exec_drop(
+            include_str!("../../sql/lift_mutes.sql"),
+            params! {
+                "character_id" => character_id,
+                "now" => Utc::now().naive_utc(),
+            },
+        )
+        .await?;
+        Ok(conn.affected_rows())
+    }
+}
@@ -0,0 +1,119 @@
+use std::{fmt, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SanctionKind {
+    /// Can't talk anywhere
+    Mute,
+    /// Can't talk in global chat
+    GlobalMute,
+    Warning,
+}
+
+impl SanctionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mute => "mute",
+            Self::GlobalMute => "global_mute",
+            Self::Warning => "warning",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "mute" => Some(Self::Mute),
+            "global_mute" => Some(Self::GlobalMute),
+            "warning" => Some(Self::Warning),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sanction {
+    pub kind: SanctionKind,
+    pub reason: String,
+    /// Minutes, 0 = permanent
+    pub duration: i32,
+    pub created_by: String,
+    pub created_at: NaiveDateTime,
+    pub lifted_at: Option<NaiveDateTime>,
+}
+
+impl Sanction {
+    pub fn new(kind: SanctionKind, duration: i32, reason: &str, admin_name: &str) -> Self {
+        Self {
+            kind,
+            reason: reason.to_owned(),
+            duration,
+            created_by: admin_name.to_owned(),
+            created_at: Utc::now().naive_utc(),
+            lifted_at: None,
+        }
+    }
+
+    /// `None` for permanent sanctions
+    pub fn expires_at(&self) -> Option<NaiveDateTime> {
+        if self.duration > 0 {
+            Some(self.created_at + Duration::from_secs(self.duration as u64 * 60))
+        } else {
+            None
+        }
+    }
+
+    /// Mutes that haven't been lifted or run out yet
+    pub fn is_active_mute(&self) -> bool {
+        matches!(self.kind, SanctionKind::Mute | SanctionKind::GlobalMute)
+            && self.lifted_at.is_none()
+            && self
+                .expires_at()
+                .is_none_or(|expires_at| expires_at > Utc::now().naive_utc())
+    }
+}
+
+impl fmt::Display for Sanction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            SanctionKind::Mute => "muted",
+            SanctionKind::GlobalMute => "global muted",
+            SanctionKind::Warning => "warned",
+        };
+
+        write!(f, "{} {}", self.created_at.format("%m/%d %H:%M"), kind)?;
+
+        if self.kind != SanctionKind::Warning {
+            if self.duration > 0 {
+                write!(f, " for {}m", self.duration)?;
+            } else {
+                write!(f, " permanently")?;
+            }
+        }
+
+        write!(f, " by {}", self.created_by)?;
+
+        if !self.reason.is_empty() {
+            write!(f, ": {}", self.reason)?;
+        }
+
+        if self.lifted_at.is_some() {
+            write!(f, " (lifted)")?;
+        } else if self.kind != SanctionKind::Warning && !self.is_active_mute() {
+            write!(f, " (expired)")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+pub trait SanctionStorage {
+    async fn create_sanction(&self, character_id: i32, sanction: &Sanction) -> anyhow::Result<()>;
+
+    /// Every sanction the character has had, newest first
+    async fn get_sanctions(&self, character_id: i32) -> anyhow::Result<Vec<Sanction>>;
+
+    /// Lifts every mute still in place, returns how many there were
+    async fn lift_mutes(&self, character_id: i32) -> anyhow::Result<u64>;
+}
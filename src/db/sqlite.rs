@@ -0,0 +1,153 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use rusqlite::{named_params, Connection};
+
+use crate::metrics;
+
+use super::{Migration, ServerStats, Storage};
+
+mod account;
+mod audit;
+mod ban;
+mod board;
+mod character;
+mod email;
+mod guild;
+mod login_lock;
+mod sanction;
+
+static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial",
+        sql: include_str!("sqlite/migrations/0001_initial.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "audit_log",
+        sql: include_str!("sqlite/migrations/0002_audit_log.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "sanctions",
+        sql: include_str!("sqlite/migrations/0003_sanctions.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "ban_targets",
+        sql: include_str!("sqlite/migrations/0004_ban_targets.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "status_effects",
+        sql: include_str!("sqlite/migrations/0005_status_effects.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "queue_priority",
+        sql: include_str!("sqlite/migrations/0006_queue_priority.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "login_locks",
+        sql: include_str!("sqlite/migrations/0007_login_locks.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "email_outbox",
+        sql: include_str!("sqlite/migrations/0008_email_outbox.sql"),
+    },
+];
+
+/// rusqlite is blocking so every query runs on tokio's blocking pool
+/// against a single shared connection
+#[derive(Debug)]
+pub struct SqliteDb {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteDb {
+    /// Opens (or creates) the database file at `path`, `:memory:` for a
+    /// throwaway in-memory database
+    pub fn open(path: &str) -> anyhow::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "foreign_keys", "ON")?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    #[track_caller]
+    fn call<F, T>(&self, f: F) -> impl Future<Output = anyhow::Result<T>>
+    where
+        F: FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let timer = metrics::start_db_timer();
+        let conn = self.conn.clone();
+        async move {
+            let result = tokio::task::spawn_blocking(move || {
+                let mut conn = match conn.lock() {
+                    Ok(conn) => conn,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                f(&mut conn)
+            })
+            .await?;
+            timer.observe_duration();
+            Ok(result?)
+        }
+    }
+}
+
+#[async_trait]
+impl Storage for SqliteDb {
+    fn migrations(&self) -> &'static [Migration] {
+        MIGRATIONS
+    }
+
+    async fn get_schema_version(&self) -> anyhow::Result<i32> {
+        self.call(|conn| {
+            conn.execute(
+                include_str!("../sql/sqlite/create_schema_version_table.sql"),
+                [],
+            )?;
+            conn.query_row(include_str!("../sql/get_schema_version.sql"), [], |row| {
+                row.get(0)
+            })
+        })
+        .await
+    }
+
+    async fn apply_migration(&self, migration: &Migration) -> anyhow::Result<()> {
+        let (version, sql) = (migration.version, migration.sql);
+        self.call(move |conn| {
+            let tx = conn.transaction()?;
+            tx.execute_batch(sql)?;
+            tx.execute(
+                include_str!("../sql/create_schema_version.sql"),
+                named_params! { ":version": version },
+            )?;
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn get_server_stats(&self) -> anyhow::Result<ServerStats> {
+        self.call(|conn| {
+            conn.query_row(include_str!("../sql/get_server_stats.sql"), [], |row| {
+                Ok(ServerStats {
+                    accounts: row.get(0)?,
+                    characters: row.get(1)?,
+                    guilds: row.get(2)?,
+                    admins: row.get(3)?,
+                })
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use rusqlite::{named_params, OptionalExtension};
+
+use crate::db::{AccountCredentials, AccountStorage, NewAccount};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl AccountStorage for SqliteDb {
+    async fn account_exists(&self, name: &str) -> anyhow::Result<bool> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/account_exists.sql"),
+                named_params! { ":name": name },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn create_account(&self, account: &NewAccount) -> anyhow::Result<()> {
+        let (name, password_hash, real_name, location, email, computer, hdid, register_ip) = (
+            account.name.clone(),
+            account.password_hash.clone(),
+            account.real_name.clone(),
+            account.location.clone(),
+            account.email.clone(),
+            account.computer.clone(),
+            account.hdid.clone(),
+            account.register_ip.clone(),
+        );
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/sqlite/create_account.sql"),
+                named_params! {
+                    ":name": name,
+                    ":password_hash": password_hash,
+                    ":real_name": real_name,
+                    ":location": location,
+                    ":email": email,
+                    ":computer": computer,
+                    ":hdid": hdid,
+                    ":register_ip": register_ip,
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_account_credentials(
+        &self,
+        name: &str,
+    ) -> anyhow::Result<Option<AccountCredentials>> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_password_hash.sql"),
+                named_params! { ":name": name },
+                |row| {
+                    Ok(AccountCredentials {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                        password_hash: row.get(2)?,
+                        queue_priority: row.get(3)?,
+                    })
+                },
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn get_account_email(&self, name: &str) -> anyhow::Result<Option<(i32, String)>> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_account_email.sql"),
+                named_params! { ":name": name },
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn update_password_hash(
+        &self,
+        account_id: i32,
+        password_hash: &str,
+    ) -> anyhow::Result<()> {
+        let password_hash = password_hash.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/update_password_hash.sql"),
+                named_params! { ":id": account_id, ":password_hash": password_hash },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_last_login_ip(&self, account_id: i32, ip: &str) -> anyhow::Result<()> {
+        let ip = ip.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/sqlite/update_last_login_ip.sql"),
+                named_params! { ":account_id": account_id, ":ip": ip },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use rusqlite::named_params;
+
+use crate::{
+    audit::AuditEntry,
+    db::{audit_entry_to_json, AuditStorage},
+};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl AuditStorage for SqliteDb {
+    async fn create_audit_entries(&self, entries: &[AuditEntry]) -> anyhow::Result<()> {
+        let rows = entries
+            .iter()
+            .map(|entry| {
+                let (event, data) = audit_entry_to_json(entry)?;
+                Ok((entry.clone(), event, data))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        self.call(move |conn| {
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare(include_str!("../../sql/create_audit_entry.sql"))?;
+                for (entry, event, data) in &rows {
+                    stmt.execute(named_params! {
+                        ":character_id": entry.character_id,
+                        ":character_name": entry.character_name,
+                        ":event": event,
+                        ":map_id": entry.map_id,
+                        ":x": entry.x,
+                        ":y": entry.y,
+                        ":data": data,
+                        ":created_at": entry.at.naive_utc(),
+                    })?;
+                }
+            }
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn get_audit_entries(
+        &self,
+        character_name: &str,
+        limit: i32,
+    ) -> anyhow::Result<Vec<AuditEntry>> {
+        let character_name = character_name.to_owned();
+        let rows: Vec<String> = self
+            .call(move |conn| {
+                let mut stmt = conn.prepare(include_str!("../../sql/get_audit_entries.sql"))?;
+                let rows = stmt
+                    .query_map(
+                        named_params! { ":character_name": character_name, ":limit": limit },
+                        |row| row.get(0),
+                    )?
+                    .collect();
+                rows
+            })
+            .await?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|data| serde_json::from_str(data).ok())
+            .collect())
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use rusqlite::named_params;
+
+use crate::db::{Ban, BanStorage};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl BanStorage for SqliteDb {
+    async fn get_bans(&self) -> anyhow::Result<Vec<Ban>> {
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/get_bans.sql"))?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok(Ban {
+                        id: row.get(0)?,
+                        account_id: row.get(1)?,
+                        account_name: row.get(2)?,
+                        ip: row.get(3)?,
+                        hdid: row.get(4)?,
+                        duration: row.get(5)?,
+                        created_by: row.get(6)?,
+                        created_at: row.get(7)?,
+                    })
+                })?
+                .collect();
+            rows
+        })
+        .await
+    }
+
+    async fn get_active_bans(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        now: NaiveDateTime,
+    ) -> anyhow::Result<Vec<Ban>> {
+        let ip = ip.map(|ip| ip.to_owned());
+        let hdid = hdid.map(|hdid| hdid.to_owned());
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/sqlite/get_active_bans.sql"))?;
+            let rows = stmt
+                .query_map(
+                    named_params! {
+                        ":account_id": account_id,
+                        ":ip": ip,
+                        ":hdid": hdid,
+                        ":now": now,
+                    },
+                    |row| {
+                        Ok(Ban {
+                            id: row.get(0)?,
+                            account_id: row.get(1)?,
+                            account_name: row.get(2)?,
+                            ip: row.get(3)?,
+                            hdid: row.get(4)?,
+                            duration: row.get(5)?,
+                            created_by: row.get(6)?,
+                            created_at: row.get(7)?,
+                        })
+                    },
+                )?
+                .collect();
+            rows
+        })
+        .await
+    }
+
+    async fn create_ban(
+        &self,
+        account_id: Option<i32>,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        duration: Option<i32>,
+        admin_name: &str,
+    ) -> anyhow::Result<()> {
+        let ip = ip.map(|ip| ip.to_owned());
+        let hdid = hdid.map(|hdid| hdid.to_owned());
+        let admin_name = admin_name.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/create_ban.sql"),
+                named_params! {
+                    ":account_id": account_id,
+                    ":ip": ip,
+                    ":hdid": hdid,
+                    ":duration": duration,
+                    ":admin_name": admin_name,
+                    ":created_at": Utc::now().naive_utc(),
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn lift_ban(&self, id: i32) -> anyhow::Result<()> {
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/lift_ban.sql"),
+                named_params! {
+                    ":id": id,
+                    ":lifted_at": Utc::now().naive_utc(),
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use rusqlite::{named_params, OptionalExtension};
+
+use crate::db::{BoardPost, BoardStorage};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl BoardStorage for SqliteDb {
+    async fn get_board_posts(&self, board_id: i32, limit: i32) -> anyhow::Result<Vec<BoardPost>> {
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/sqlite/get_board_posts.sql"))?;
+            let posts = stmt
+                .query_map(
+                    named_params! { ":board_id": board_id, ":limit": limit },
+                    |row| {
+                        Ok(BoardPost {
+                            id: row.get(0)?,
+                            author: row.get(1)?,
+                            subject: row.get(2)?,
+                            created_at: row.get(3)?,
+                        })
+                    },
+                )?
+                .collect();
+            posts
+        })
+        .await
+    }
+
+    async fn get_board_post(&self, board_id: i32, post_id: i32) -> anyhow::Result<Option<String>> {
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_board_post.sql"),
+                named_params! { ":board_id": board_id, ":post_id": post_id },
+                |row| row.get(0),
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn create_board_post(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        subject: &str,
+        body: &str,
+    ) -> anyhow::Result<()> {
+        let (subject, body) = (subject.to_owned(), body.to_owned());
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/create_board_post.sql"),
+                named_params! {
+                    ":board_id": board_id,
+                    ":character_id": character_id,
+                    ":subject": subject,
+                    ":body": body,
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_board_post(&self, post_id: i32) -> anyhow::Result<()> {
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/delete_board_post.sql"),
+                named_params! { ":id": post_id },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_recent_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        minutes: i32,
+    ) -> anyhow::Result<i32> {
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/sqlite/get_recent_post_count.sql"),
+                named_params! {
+                    ":board_id": board_id,
+                    ":character_id": character_id,
+                    ":post_time": minutes,
+                },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn get_total_post_count(
+        &self,
+        board_id: i32,
+        character_id: i32,
+        limit: i32,
+    ) -> anyhow::Result<i32> {
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/sqlite/get_total_post_count.sql"),
+                named_params! {
+                    ":board_id": board_id,
+                    ":character_id": character_id,
+                    ":limit": limit,
+                },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+}
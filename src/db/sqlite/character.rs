@@ -0,0 +1,542 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use eolib::protocol::{
+    net::{
+        server::{CharacterSelectionListEntry, EquipmentCharacterSelect, SitState},
+        Item, Spell,
+    },
+    AdminLevel, Direction, Gender,
+};
+use rusqlite::{named_params, OptionalExtension, Transaction};
+
+use crate::{
+    character::{Character, QuestProgress},
+    db::{npc_kills_from_json, npc_kills_to_json, CharacterAccount, CharacterStorage},
+    SETTINGS, STATUS_EFFECTS,
+};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl CharacterStorage for SqliteDb {
+    async fn character_exists(&self, name: &str) -> anyhow::Result<bool> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/character_exists.sql"),
+                named_params! { ":name": name },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn get_character_list(
+        &self,
+        account_id: i32,
+    ) -> anyhow::Result<Vec<CharacterSelectionListEntry>> {
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/get_character_list.sql"))?;
+            let characters = stmt
+                .query_map(named_params! { ":account_id": account_id }, |row| {
+                    Ok(CharacterSelectionListEntry {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                        level: row.get(2)?,
+                        gender: Gender::from(row.get::<usize, i32>(3)?),
+                        hair_style: row.get(4)?,
+                        hair_color: row.get(5)?,
+                        skin: row.get(6)?,
+                        admin: AdminLevel::from(row.get::<usize, i32>(7)?),
+                        equipment: EquipmentCharacterSelect {
+                            boots: row.get(8)?,
+                            armor: row.get(9)?,
+                            hat: row.get(10)?,
+                            shield: row.get(11)?,
+                            weapon: row.get(12)?,
+                        },
+                    })
+                })?
+                .collect();
+            characters
+        })
+        .await
+    }
+
+    async fn get_num_of_characters(&self, account_id: i32) -> anyhow::Result<usize> {
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_num_of_characters.sql"),
+                named_params! { ":account_id": account_id },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn get_character_account(&self, name: &str) -> anyhow::Result<Option<CharacterAccount>> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/sqlite/get_character_account.sql"),
+                named_params! { ":name": name },
+                |row| {
+                    Ok(CharacterAccount {
+                        account_id: row.get(0)?,
+                        last_login_ip: row.get(1)?,
+                        hdid: row.get(2)?,
+                    })
+                },
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn get_character_id(&self, name: &str) -> anyhow::Result<Option<i32>> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_character_id.sql"),
+                named_params! { ":name": name },
+                |row| row.get(0),
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn load_character(&self, id: i32) -> anyhow::Result<Character> {
+        let character = self
+            .call(move |conn| {
+                let character = conn
+                    .query_row(
+                        include_str!("../../sql/sqlite/get_character.sql"),
+                        named_params! { ":character_id": id },
+                        |row| {
+                            let mut character = Character {
+                                id,
+                                account_id: row.get(0)?,
+                                name: row.get(1)?,
+                                title: row.get(2)?,
+                                home: row.get(3)?,
+                                fiance: row.get(4)?,
+                                partner: row.get(5)?,
+                                admin_level: AdminLevel::from(row.get::<usize, i32>(6)?),
+                                class: row.get(7)?,
+                                gender: Gender::from(row.get::<usize, i32>(8)?),
+                                skin: row.get(9)?,
+                                hair_style: row.get(10)?,
+                                hair_color: row.get(11)?,
+                                bank_level: row.get(12)?,
+                                gold_bank: row.get(13)?,
+                                guild_rank: row.get(14)?,
+                                guild_rank_string: row.get(15)?,
+                                ..Default::default()
+                            };
+                            character.equipment.boots = row.get(16)?;
+                            character.equipment.accessory = row.get(17)?;
+                            character.equipment.gloves = row.get(18)?;
+                            character.equipment.belt = row.get(19)?;
+                            character.equipment.armor = row.get(20)?;
+                            character.equipment.necklace = row.get(21)?;
+                            character.equipment.hat = row.get(22)?;
+                            character.equipment.shield = row.get(23)?;
+                            character.equipment.weapon = row.get(24)?;
+                            character.equipment.ring[0] = row.get(25)?;
+                            character.equipment.ring[1] = row.get(26)?;
+                            character.equipment.armlet[0] = row.get(27)?;
+                            character.equipment.armlet[1] = row.get(28)?;
+                            character.equipment.bracer[0] = row.get(29)?;
+                            character.equipment.bracer[1] = row.get(30)?;
+                            character.level = row.get(31)?;
+                            character.experience = row.get(32)?;
+                            character.hp = row.get(33)?;
+                            character.tp = row.get(34)?;
+                            character.base_strength = row.get(35)?;
+                            character.base_intelligence = row.get(36)?;
+                            character.base_wisdom = row.get(37)?;
+                            character.base_agility = row.get(38)?;
+                            character.base_constitution = row.get(39)?;
+                            character.base_charisma = row.get(40)?;
+                            character.stat_points = row.get(41)?;
+                            character.skill_points = row.get(42)?;
+                            character.karma = row.get(43)?;
+                            character.usage = row.get(44)?;
+                            character.map_id = row.get(45)?;
+                            character.coords.x = row.get(46)?;
+                            character.coords.y = row.get(47)?;
+                            character.direction = Direction::from(row.get::<usize, i32>(48)?);
+                            character.sit_state = SitState::from(row.get::<usize, i32>(49)?);
+                            character.hidden = row.get::<usize, i32>(50)? == 1;
+                            character.guild_name = row.get(51)?;
+                            character.guild_tag = row.get(52)?;
+                            Ok(character)
+                        },
+                    )
+                    .optional()?;
+
+                let mut character = match character {
+                    Some(character) => character,
+                    None => return Ok(None),
+                };
+
+                let params = named_params! { ":character_id": id };
+
+                character.items = conn
+                    .prepare(include_str!("../../sql/get_character_inventory.sql"))?
+                    .query_map(params, |row| {
+                        Ok(Item {
+                            id: row.get(0)?,
+                            amount: row.get(1)?,
+                        })
+                    })?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                character.bank = conn
+                    .prepare(include_str!("../../sql/get_character_bank.sql"))?
+                    .query_map(params, |row| {
+                        Ok(Item {
+                            id: row.get(0)?,
+                            amount: row.get(1)?,
+                        })
+                    })?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                character.spells = conn
+                    .prepare(include_str!("../../sql/get_character_spells.sql"))?
+                    .query_map(params, |row| {
+                        Ok(Spell {
+                            id: row.get(0)?,
+                            level: row.get(1)?,
+                        })
+                    })?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                character.quests = conn
+                    .prepare(include_str!("../../sql/get_character_quest_progress.sql"))?
+                    .query_map(params, |row| {
+                        Ok(QuestProgress {
+                            id: row.get(0)?,
+                            state: row.get(1)?,
+                            npc_kills: npc_kills_from_json(&row.get::<usize, String>(2)?),
+                            player_kills: row.get(3)?,
+                            done_at: row.get(4)?,
+                            completions: row.get(5)?,
+                            ..Default::default()
+                        })
+                    })?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                character.auto_pickup_items = conn
+                    .prepare(include_str!("../../sql/get_character_auto_pickup.sql"))?
+                    .query_map(params, |row| row.get(0))?
+                    .collect::<rusqlite::Result<_>>()?;
+
+                let status_effects = conn
+                    .prepare(include_str!("../../sql/get_character_status_effects.sql"))?
+                    .query_map(params, |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<rusqlite::Result<Vec<(i32, i32)>>>()?;
+
+                for (effect_id, remaining) in status_effects {
+                    character
+                        .status_effects
+                        .resume(&STATUS_EFFECTS.load(), effect_id, remaining);
+                }
+
+                Ok(Some(character))
+            })
+            .await?;
+
+        let mut character = match character {
+            Some(character) => character,
+            None => return Err(anyhow!("Character {} not found", id)),
+        };
+
+        character.warp_suck_ticks = SETTINGS.world.warp_suck_rate;
+        character.ghost_ticks = SETTINGS.world.ghost_rate;
+
+        Ok(character)
+    }
+
+    async fn create_character(&self, character: &mut Character) -> anyhow::Result<()> {
+        let account_id = character.account_id;
+        let name = character.name.clone();
+        let gender = i32::from(character.gender);
+        let race = character.skin;
+        let hair_style = character.hair_style;
+        let hair_color = character.hair_color;
+
+        character.id = self
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+
+                tx.execute(
+                    include_str!("../../sql/sqlite/create_character.sql"),
+                    named_params! {
+                        ":account_id": account_id,
+                        ":name": name,
+                        ":home": SETTINGS.new_character.home,
+                        ":gender": gender,
+                        ":race": race,
+                        ":hair_style": hair_style,
+                        ":hair_color": hair_color,
+                    },
+                )?;
+
+                let id = tx.last_insert_rowid() as i32;
+
+                tx.execute(
+                    include_str!("../../sql/create_paperdoll.sql"),
+                    named_params! { ":character_id": id },
+                )?;
+
+                tx.execute(
+                    include_str!("../../sql/create_position.sql"),
+                    named_params! {
+                        ":character_id": id,
+                        ":map": SETTINGS.new_character.spawn_map,
+                        ":x": SETTINGS.new_character.spawn_x,
+                        ":y": SETTINGS.new_character.spawn_y,
+                        ":direction": SETTINGS.new_character.spawn_direction,
+                    },
+                )?;
+
+                tx.execute(
+                    include_str!("../../sql/create_stats.sql"),
+                    named_params! { ":character_id": id },
+                )?;
+
+                tx.commit()?;
+
+                Ok(id)
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_character(&self, character: &Character) -> anyhow::Result<()> {
+        self.update_characters(std::slice::from_ref(character))
+            .await
+    }
+
+    async fn update_characters(&self, characters: &[Character]) -> anyhow::Result<()> {
+        let characters = characters.to_vec();
+        self.call(move |conn| {
+            let tx = conn.transaction()?;
+            for character in &characters {
+                update_character_rows(&tx, character)?;
+            }
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn delete_character(&self, id: i32) -> anyhow::Result<()> {
+        // Child rows are removed by ON DELETE CASCADE
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/delete_character.sql"),
+                named_params! { ":character_id": id },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn set_character_position(
+        &self,
+        name: &str,
+        map: i32,
+        x: i32,
+        y: i32,
+    ) -> anyhow::Result<()> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/update_position_by_name.sql"),
+                named_params! { ":map": map, ":x": x, ":y": y, ":name": name },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn divorce_character(&self, name: &str) -> anyhow::Result<()> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/divorce_character.sql"),
+                named_params! { ":name": name },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+/// Writes one character as part of a larger transaction
+fn update_character_rows(tx: &Transaction, character: &Character) -> rusqlite::Result<()> {
+    let id = character.id;
+
+    tx.execute(
+        include_str!("../../sql/sqlite/update_character.sql"),
+        named_params! {
+            ":character_id": id,
+            ":title": character.title,
+            ":home": character.home,
+            ":fiance": character.fiance,
+            ":partner": character.partner,
+            ":admin_level": i32::from(character.admin_level),
+            ":class": character.class,
+            ":gender": i32::from(character.gender),
+            ":race": character.skin,
+            ":hair_style": character.hair_style,
+            ":hair_color": character.hair_color,
+            ":bank_level": character.bank_level,
+            ":gold_bank": character.gold_bank,
+            ":guild_tag": character.guild_tag,
+            ":guild_rank": character.guild_rank,
+            ":guild_rank_string": character.guild_rank_string,
+        },
+    )?;
+
+    let equipment = &character.equipment;
+    tx.execute(
+        include_str!("../../sql/update_paperdoll.sql"),
+        named_params! {
+            ":character_id": id,
+            ":boots": equipment.boots,
+            ":accessory": equipment.accessory,
+            ":gloves": equipment.gloves,
+            ":belt": equipment.belt,
+            ":armor": equipment.armor,
+            ":necklace": equipment.necklace,
+            ":hat": equipment.hat,
+            ":shield": equipment.shield,
+            ":weapon": equipment.weapon,
+            ":ring": equipment.ring[0],
+            ":ring2": equipment.ring[1],
+            ":armlet": equipment.armlet[0],
+            ":armlet2": equipment.armlet[1],
+            ":bracer": equipment.bracer[0],
+            ":bracer2": equipment.bracer[1],
+        },
+    )?;
+
+    tx.execute(
+        include_str!("../../sql/update_position.sql"),
+        named_params! {
+            ":character_id": id,
+            ":map_id": character.map_id,
+            ":x": character.coords.x,
+            ":y": character.coords.y,
+            ":direction": i32::from(character.direction),
+            ":sitting": i32::from(character.sit_state),
+            ":hidden": i32::from(character.hidden),
+        },
+    )?;
+
+    tx.execute(
+        include_str!("../../sql/update_stats.sql"),
+        named_params! {
+            ":character_id": id,
+            ":level": character.level,
+            ":experience": character.experience,
+            ":hp": character.hp,
+            ":tp": character.tp,
+            ":strength": character.base_strength,
+            ":intelligence": character.base_intelligence,
+            ":wisdom": character.base_wisdom,
+            ":agility": character.base_agility,
+            ":constitution": character.base_constitution,
+            ":charisma": character.base_charisma,
+            ":stat_points": character.stat_points,
+            ":skill_points": character.skill_points,
+            ":karma": character.karma,
+            ":usage": character.usage,
+        },
+    )?;
+
+    // Child rows are small enough that replacing them wholesale is
+    // simpler than diffing against what's stored
+    for query in [
+        include_str!("../../sql/delete_character_inventory.sql"),
+        include_str!("../../sql/delete_character_bank.sql"),
+        include_str!("../../sql/delete_character_spells.sql"),
+        include_str!("../../sql/delete_character_quest_progress.sql"),
+        include_str!("../../sql/delete_character_auto_pickup.sql"),
+        include_str!("../../sql/delete_character_status_effects.sql"),
+    ] {
+        tx.execute(query, named_params! { ":character_id": id })?;
+    }
+
+    for item in &character.items {
+        tx.execute(
+            include_str!("../../sql/create_inventory_item.sql"),
+            named_params! {
+                ":character_id": id,
+                ":item_id": item.id,
+                ":quantity": item.amount,
+            },
+        )?;
+    }
+
+    for item in &character.bank {
+        tx.execute(
+            include_str!("../../sql/create_bank_item.sql"),
+            named_params! {
+                ":character_id": id,
+                ":item_id": item.id,
+                ":quantity": item.amount,
+            },
+        )?;
+    }
+
+    for spell in &character.spells {
+        tx.execute(
+            include_str!("../../sql/create_spell.sql"),
+            named_params! {
+                ":character_id": id,
+                ":spell_id": spell.id,
+                ":level": spell.level,
+            },
+        )?;
+    }
+
+    for quest in &character.quests {
+        tx.execute(
+            include_str!("../../sql/create_quest_progress.sql"),
+            named_params! {
+                ":character_id": id,
+                ":quest_id": quest.id,
+                ":state": quest.state,
+                ":npc_kills": npc_kills_to_json(&quest.npc_kills),
+                ":player_kills": quest.player_kills,
+                ":done_at": quest.done_at,
+                ":completions": quest.completions,
+            },
+        )?;
+    }
+
+    for item_id in &character.auto_pickup_items {
+        tx.execute(
+            include_str!("../../sql/create_auto_pickup_item.sql"),
+            named_params! { ":character_id": id, ":item_id": item_id },
+        )?;
+    }
+
+    for effect in &character.status_effects.0 {
+        tx.execute(
+            include_str!("../../sql/create_status_effect.sql"),
+            named_params! {
+                ":character_id": id,
+                ":effect_id": effect.effect_id,
+                ":remaining": effect.remaining,
+            },
+        )?;
+    }
+
+    Ok(())
+}
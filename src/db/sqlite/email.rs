@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use rusqlite::named_params;
+
+use crate::{
+    db::{EmailStorage, QueuedEmail},
+    email::OutgoingEmail,
+};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl EmailStorage for SqliteDb {
+    async fn queue_email(&self, email: &OutgoingEmail, now: NaiveDateTime) -> anyhow::Result<i32> {
+        let email = email.clone();
+        let id = self
+            .call(move |conn| {
+                conn.execute(
+                    include_str!("../../sql/queue_email.sql"),
+                    named_params! {
+                        ":to_address": email.to_address,
+                        ":to_name": email.to_name,
+                        ":subject": email.subject,
+                        ":text_body": email.text_body,
+                        ":html_body": email.html_body,
+                        ":now": now,
+                    },
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
+            .await?;
+        Ok(id as i32)
+    }
+
+    async fn get_due_emails(
+        &self,
+        now: NaiveDateTime,
+        limit: usize,
+    ) -> anyhow::Result<Vec<QueuedEmail>> {
+        let emails = self
+            .call(move |conn| {
+                let mut stmt = conn.prepare(include_str!("../../sql/get_due_emails.sql"))?;
+                let rows = stmt
+                    .query_map(
+                        named_params! { ":now": now, ":limit": limit as i64 },
+                        |row| {
+                            Ok(QueuedEmail {
+                                id: row.get(0)?,
+                                email: OutgoingEmail {
+                                    to_address: row.get(1)?,
+                                    to_name: row.get(2)?,
+                                    subject: row.get(3)?,
+                                    text_body: row.get(4)?,
+                                    html_body: row.get(5)?,
+                                },
+                                attempts: row.get(6)?,
+                            })
+                        },
+                    )?
+                    .collect();
+                rows
+            })
+            .await?;
+        Ok(emails)
+    }
+
+    async fn mark_email_sent(&self, id: i32, now: NaiveDateTime) -> anyhow::Result<()> {
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/mark_email_sent.sql"),
+                named_params! { ":id": id, ":now": now },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_email_failed(
+        &self,
+        id: i32,
+        attempts: i32,
+        next_attempt_at: Option<NaiveDateTime>,
+        error: &str,
+    ) -> anyhow::Result<()> {
+        let error = error.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/mark_email_failed.sql"),
+                named_params! {
+                    ":id": id,
+                    ":attempts": attempts,
+                    ":next_attempt_at": next_attempt_at,
+                    ":error": error,
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+use async_trait::async_trait;
+use eolib::protocol::net::server::{GuildMember, GuildStaff};
+use rusqlite::{named_params, OptionalExtension};
+
+use crate::{
+    db::{GuildDetails, GuildStorage},
+    SETTINGS,
+};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl GuildStorage for SqliteDb {
+    async fn guild_exists(&self, tag: &str, name: &str) -> anyhow::Result<bool> {
+        let (tag, name) = (tag.to_owned(), name.to_owned());
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/guild_exists.sql"),
+                named_params! { ":name": name, ":tag": tag },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn create_guild(&self, tag: &str, name: &str, description: &str) -> anyhow::Result<()> {
+        let (tag, name, description) = (tag.to_owned(), name.to_owned(), description.to_owned());
+        self.call(move |conn| {
+            let tx = conn.transaction()?;
+
+            tx.execute(
+                include_str!("../../sql/create_guild.sql"),
+                named_params! { ":tag": tag, ":name": name, ":description": description },
+            )?;
+
+            let guild_id = tx.last_insert_rowid();
+
+            tx.execute(
+                include_str!("../../sql/create_guild_ranks.sql"),
+                named_params! {
+                    ":guild_id": guild_id,
+                    ":leader_rank_name": SETTINGS.guild.default_leader_rank_name,
+                    ":recruiter_rank_name": SETTINGS.guild.default_recruiter_rank_name,
+                    ":new_member_rank_name": SETTINGS.guild.default_new_member_rank_name,
+                },
+            )?;
+
+            tx.commit()
+        })
+        .await
+    }
+
+    async fn delete_guild(&self, tag: &str) -> anyhow::Result<()> {
+        let tag = tag.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/delete_guild.sql"),
+                named_params! { ":tag": tag },
+            )?;
+            conn.execute(
+                include_str!("../../sql/cleanup_guildless_characters.sql"),
+                [],
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_name(&self, tag: &str) -> anyhow::Result<Option<String>> {
+        let tag = tag.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_guild_name.sql"),
+                named_params! { ":tag": tag },
+                |row| row.get(0),
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn get_guild_description(&self, tag: &str) -> anyhow::Result<Option<String>> {
+        let tag = tag.to_owned();
+        let description: Option<Option<String>> = self
+            .call(move |conn| {
+                conn.query_row(
+                    include_str!("../../sql/get_guild_description.sql"),
+                    named_params! { ":tag": tag },
+                    |row| row.get(0),
+                )
+                .optional()
+            })
+            .await?;
+        Ok(description.flatten())
+    }
+
+    async fn set_guild_description(&self, tag: &str, description: &str) -> anyhow::Result<()> {
+        let (tag, description) = (tag.to_owned(), description.to_owned());
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/update_guild_description.sql"),
+                named_params! { ":description": description, ":tag": tag },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_ranks(&self, tag: &str) -> anyhow::Result<Vec<String>> {
+        let tag = tag.to_owned();
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/get_guild_ranks.sql"))?;
+            let ranks = stmt
+                .query_map(named_params! { ":tag": tag }, |row| row.get(0))?
+                .collect();
+            ranks
+        })
+        .await
+    }
+
+    async fn set_guild_rank(&self, tag: &str, index: usize, rank: &str) -> anyhow::Result<()> {
+        let (tag, rank) = (tag.to_owned(), rank.to_owned());
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/sqlite/update_guild_rank.sql"),
+                named_params! { ":rank": rank, ":tag": tag, ":index": index },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_bank(&self, tag: &str) -> anyhow::Result<i32> {
+        let tag = tag.to_owned();
+        let bank: Option<i32> = self
+            .call(move |conn| {
+                conn.query_row(
+                    include_str!("../../sql/get_guild_bank.sql"),
+                    named_params! { ":tag": tag },
+                    |row| row.get(0),
+                )
+                .optional()
+            })
+            .await?;
+        Ok(bank.unwrap_or(0))
+    }
+
+    async fn set_guild_bank(&self, tag: &str, bank: i32) -> anyhow::Result<()> {
+        let tag = tag.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/update_guild_bank.sql"),
+                named_params! { ":bank": bank, ":tag": tag },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_guild_details(&self, identity: &str) -> anyhow::Result<Option<GuildDetails>> {
+        let identity = identity.to_owned();
+        self.call(move |conn| {
+            let params = named_params! { ":guild_identity": identity };
+
+            let details = conn
+                .query_row(
+                    include_str!("../../sql/get_guild_details.sql"),
+                    params,
+                    |row| {
+                        Ok(GuildDetails {
+                            tag: row.get(0)?,
+                            name: row.get(1)?,
+                            description: row.get::<usize, Option<String>>(2)?.unwrap_or_default(),
+                            created_at: row.get(3)?,
+                            bank: row.get(4)?,
+                            ranks: Vec::new(),
+                            staff: Vec::new(),
+                        })
+                    },
+                )
+                .optional()?;
+
+            let mut details = match details {
+                Some(details) => details,
+                None => return Ok(None),
+            };
+
+            details.ranks = conn
+                .prepare(include_str!("../../sql/get_guild_ranks_by_identity.sql"))?
+                .query_map(params, |row| row.get(0))?
+                .collect::<rusqlite::Result<_>>()?;
+
+            details.staff = conn
+                .prepare(include_str!("../../sql/get_guild_staff.sql"))?
+                .query_map(params, |row| {
+                    Ok(GuildStaff {
+                        name: row.get(0)?,
+                        rank: row.get(1)?,
+                    })
+                })?
+                .collect::<rusqlite::Result<_>>()?;
+
+            Ok(Some(details))
+        })
+        .await
+    }
+
+    async fn get_guild_members(&self, identity: &str) -> anyhow::Result<Vec<GuildMember>> {
+        let identity = identity.to_owned();
+        self.call(move |conn| {
+            let mut stmt = conn.prepare(include_str!("../../sql/get_guild_memberlist.sql"))?;
+            let members = stmt
+                .query_map(named_params! { ":guild_identity": identity }, |row| {
+                    Ok(GuildMember {
+                        name: row.get(0)?,
+                        rank: row.get(1)?,
+                        rank_name: row.get(2)?,
+                    })
+                })?
+                .collect();
+            members
+        })
+        .await
+    }
+
+    async fn get_guild_leader_count(&self, tag: &str) -> anyhow::Result<i32> {
+        let tag = tag.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_count_guild_leader.sql"),
+                named_params! { ":guild_tag": tag },
+                |row| row.get(0),
+            )
+        })
+        .await
+    }
+
+    async fn set_guild_member_rank(
+        &self,
+        name: &str,
+        rank: i32,
+        rank_str: &str,
+    ) -> anyhow::Result<()> {
+        let (name, rank_str) = (name.to_owned(), rank_str.to_owned());
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/update_guild_member_rank.sql"),
+                named_params! {
+                    ":name": name,
+                    ":guild_rank": rank,
+                    ":guild_rank_string": rank_str,
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_guild_member(&self, name: &str) -> anyhow::Result<()> {
+        let name = name.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/remove_guild_member.sql"),
+                named_params! { ":name": name },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use rusqlite::{named_params, OptionalExtension};
+
+use crate::db::{LoginLock, LoginLockKind, LoginLockStorage};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl LoginLockStorage for SqliteDb {
+    async fn get_login_lock(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+    ) -> anyhow::Result<Option<LoginLock>> {
+        let value = value.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/get_login_lock.sql"),
+                named_params! { ":kind": kind.as_str(), ":value": value },
+                |row| {
+                    Ok(LoginLock {
+                        failures: row.get(0)?,
+                        lockouts: row.get(1)?,
+                        locked_until: row.get(2)?,
+                    })
+                },
+            )
+            .optional()
+        })
+        .await
+    }
+
+    async fn increment_login_failures(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        now: NaiveDateTime,
+        reset_before: NaiveDateTime,
+    ) -> anyhow::Result<LoginLock> {
+        let value = value.to_owned();
+        self.call(move |conn| {
+            conn.query_row(
+                include_str!("../../sql/sqlite/increment_login_failures.sql"),
+                named_params! {
+                    ":kind": kind.as_str(),
+                    ":value": value,
+                    ":now": now,
+                    ":reset_before": reset_before,
+                },
+                |row| {
+                    Ok(LoginLock {
+                        failures: row.get(0)?,
+                        lockouts: row.get(1)?,
+                        locked_until: row.get(2)?,
+                    })
+                },
+            )
+        })
+        .await
+    }
+
+    async fn start_login_lockout(
+        &self,
+        kind: LoginLockKind,
+        value: &str,
+        max_failures: i32,
+        locked_until: NaiveDateTime,
+    ) -> anyhow::Result<bool> {
+        let value = value.to_owned();
+        let updated = self
+            .call(move |conn| {
+                conn.execute(
+                    include_str!("../../sql/start_login_lockout.sql"),
+                    named_params! {
+                        ":kind": kind.as_str(),
+                        ":value": value,
+                        ":max_failures": max_failures,
+                        ":locked_until": locked_until,
+                    },
+                )
+            })
+            .await?;
+        Ok(updated > 0)
+    }
+
+    async fn delete_login_lock(&self, kind: LoginLockKind, value: &str) -> anyhow::Result<()> {
+        let value = value.to_owned();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/delete_login_lock.sql"),
+                named_params! { ":kind": kind.as_str(), ":value": value },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_login_locks(&self, value: &str) -> anyhow::Result<u64> {
+        let value = value.to_owned();
+        let deleted = self
+            .call(move |conn| {
+                conn.execute(
+                    include_str!("../../sql/delete_login_locks.sql"),
+                    named_params! { ":value": value },
+                )
+            })
+            .await?;
+        Ok(deleted as u64)
+    }
+}
@@ -0,0 +1,165 @@
+CREATE TABLE IF NOT EXISTS `Account` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `name` TEXT NOT NULL UNIQUE,
+  `password_hash` TEXT NOT NULL,
+  `real_name` TEXT NOT NULL,
+  `location` TEXT NOT NULL,
+  `email` TEXT NOT NULL,
+  `computer` TEXT NOT NULL,
+  `hdid` INTEGER NOT NULL,
+  `register_ip` TEXT NOT NULL,
+  `last_login_ip` TEXT DEFAULT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` TEXT DEFAULT NULL
+);
+
+CREATE TABLE IF NOT EXISTS `Guild` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `tag` TEXT NOT NULL,
+  `name` TEXT NOT NULL,
+  `description` TEXT,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `bank` INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS `GuildRank` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `guild_id` INTEGER NOT NULL REFERENCES `Guild` (`id`) ON DELETE CASCADE,
+  `index` INTEGER NOT NULL,
+  `rank` TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `guild_rank_guild_id` ON `GuildRank` (`guild_id`);
+
+CREATE TABLE IF NOT EXISTS `Character` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `account_id` INTEGER NOT NULL REFERENCES `Account` (`id`) ON DELETE CASCADE,
+  `name` TEXT NOT NULL UNIQUE,
+  `title` TEXT DEFAULT NULL,
+  `home` TEXT DEFAULT NULL,
+  `fiance` TEXT DEFAULT NULL,
+  `partner` TEXT DEFAULT NULL,
+  `admin_level` INTEGER NOT NULL DEFAULT 0,
+  `class` INTEGER NOT NULL DEFAULT 1,
+  `gender` INTEGER NOT NULL DEFAULT 0,
+  `race` INTEGER NOT NULL DEFAULT 0,
+  `hair_style` INTEGER NOT NULL DEFAULT 0,
+  `hair_color` INTEGER NOT NULL DEFAULT 0,
+  `bank_level` INTEGER NOT NULL DEFAULT 0,
+  `gold_bank` INTEGER NOT NULL DEFAULT 0,
+  `guild_id` INTEGER DEFAULT NULL REFERENCES `Guild` (`id`) ON DELETE SET NULL,
+  `guild_rank` INTEGER DEFAULT NULL,
+  `guild_rank_string` TEXT DEFAULT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` TEXT DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `character_account_id` ON `Character` (`account_id`);
+CREATE INDEX IF NOT EXISTS `character_guild_id` ON `Character` (`guild_id`);
+
+CREATE TABLE IF NOT EXISTS `Ban` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `account_id` INTEGER NOT NULL REFERENCES `Account` (`id`) ON DELETE CASCADE,
+  `ip` TEXT NOT NULL,
+  `duration` INTEGER DEFAULT NULL,
+  `created_by` INTEGER NOT NULL REFERENCES `Character` (`id`),
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS `ban_account_id` ON `Ban` (`account_id`);
+
+CREATE TABLE IF NOT EXISTS `Bank` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `item_id` INTEGER NOT NULL,
+  `quantity` INTEGER NOT NULL,
+  PRIMARY KEY (`character_id`, `item_id`)
+);
+
+CREATE TABLE IF NOT EXISTS `BoardPost` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `board_id` INTEGER NOT NULL,
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `subject` TEXT NOT NULL,
+  `body` TEXT NOT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS `board_post_board_id` ON `BoardPost` (`board_id`);
+
+CREATE TABLE IF NOT EXISTS `Inventory` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `item_id` INTEGER NOT NULL,
+  `quantity` INTEGER NOT NULL,
+  PRIMARY KEY (`character_id`, `item_id`)
+);
+
+CREATE TABLE IF NOT EXISTS `Paperdoll` (
+  `character_id` INTEGER PRIMARY KEY REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `boots` INTEGER NOT NULL DEFAULT 0,
+  `accessory` INTEGER NOT NULL DEFAULT 0,
+  `gloves` INTEGER NOT NULL DEFAULT 0,
+  `belt` INTEGER NOT NULL DEFAULT 0,
+  `armor` INTEGER NOT NULL DEFAULT 0,
+  `necklace` INTEGER NOT NULL DEFAULT 0,
+  `hat` INTEGER NOT NULL DEFAULT 0,
+  `shield` INTEGER NOT NULL DEFAULT 0,
+  `weapon` INTEGER NOT NULL DEFAULT 0,
+  `ring` INTEGER NOT NULL DEFAULT 0,
+  `ring2` INTEGER NOT NULL DEFAULT 0,
+  `armlet` INTEGER NOT NULL DEFAULT 0,
+  `armlet2` INTEGER NOT NULL DEFAULT 0,
+  `bracer` INTEGER NOT NULL DEFAULT 0,
+  `bracer2` INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS `Position` (
+  `character_id` INTEGER PRIMARY KEY REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `map` INTEGER NOT NULL DEFAULT 192,
+  `x` INTEGER NOT NULL DEFAULT 7,
+  `y` INTEGER NOT NULL DEFAULT 6,
+  `direction` INTEGER NOT NULL DEFAULT 2,
+  `sitting` INTEGER NOT NULL DEFAULT 0,
+  `hidden` INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS `QuestProgress` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `quest_id` INTEGER NOT NULL,
+  `state` INTEGER NOT NULL,
+  `npc_kills` TEXT NOT NULL,
+  `player_kills` INTEGER NOT NULL,
+  `done_at` TEXT DEFAULT NULL,
+  `completions` INTEGER NOT NULL,
+  PRIMARY KEY (`character_id`, `quest_id`)
+);
+
+CREATE TABLE IF NOT EXISTS `Spell` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `spell_id` INTEGER NOT NULL,
+  `level` INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (`character_id`, `spell_id`)
+);
+
+CREATE TABLE IF NOT EXISTS `Stats` (
+  `character_id` INTEGER PRIMARY KEY REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `level` INTEGER NOT NULL DEFAULT 0,
+  `experience` INTEGER NOT NULL DEFAULT 0,
+  `hp` INTEGER NOT NULL DEFAULT 10,
+  `tp` INTEGER NOT NULL DEFAULT 10,
+  `strength` INTEGER NOT NULL DEFAULT 0,
+  `intelligence` INTEGER NOT NULL DEFAULT 0,
+  `wisdom` INTEGER NOT NULL DEFAULT 0,
+  `agility` INTEGER NOT NULL DEFAULT 0,
+  `constitution` INTEGER NOT NULL DEFAULT 0,
+  `charisma` INTEGER NOT NULL DEFAULT 0,
+  `stat_points` INTEGER NOT NULL DEFAULT 0,
+  `skill_points` INTEGER NOT NULL DEFAULT 0,
+  `karma` INTEGER NOT NULL DEFAULT 1000,
+  `usage` INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS `AutoPickup` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `item_id` INTEGER NOT NULL,
+  PRIMARY KEY (`character_id`, `item_id`)
+);
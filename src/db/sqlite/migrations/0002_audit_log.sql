@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS `AuditEvent` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `character_id` INTEGER NOT NULL,
+  `character_name` TEXT NOT NULL,
+  `event` TEXT NOT NULL,
+  `map_id` INTEGER NOT NULL,
+  `x` INTEGER NOT NULL,
+  `y` INTEGER NOT NULL,
+  `data` TEXT NOT NULL,
+  `created_at` TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `audit_event_character_name` ON `AuditEvent` (`character_name`, `created_at`);
+CREATE INDEX IF NOT EXISTS `audit_event_character_id` ON `AuditEvent` (`character_id`);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS `Sanction` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `type` TEXT NOT NULL,
+  `reason` TEXT NOT NULL DEFAULT '',
+  `duration` INTEGER DEFAULT NULL,
+  `created_by` TEXT NOT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `lifted_at` TEXT DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `sanction_character_id` ON `Sanction` (`character_id`);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS `BanTargets` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `account_id` INTEGER DEFAULT NULL REFERENCES `Account` (`id`) ON DELETE CASCADE,
+  `ip` TEXT DEFAULT NULL,
+  `hdid` TEXT DEFAULT NULL,
+  `duration` INTEGER DEFAULT NULL,
+  `created_by` INTEGER DEFAULT NULL REFERENCES `Character` (`id`) ON DELETE SET NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `lifted_at` TEXT DEFAULT NULL
+);
+
+INSERT INTO `BanTargets` (`id`, `account_id`, `ip`, `duration`, `created_by`, `created_at`)
+SELECT `id`, `account_id`, `ip`, `duration`, `created_by`, `created_at` FROM `Ban`;
+
+DROP TABLE `Ban`;
+
+ALTER TABLE `BanTargets` RENAME TO `Ban`;
+
+CREATE INDEX IF NOT EXISTS `ban_account_id` ON `Ban` (`account_id`);
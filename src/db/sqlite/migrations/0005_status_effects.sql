@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS `StatusEffect` (
+  `character_id` INTEGER NOT NULL REFERENCES `Character` (`id`) ON DELETE CASCADE,
+  `effect_id` INTEGER NOT NULL,
+  `remaining` INTEGER NOT NULL,
+  PRIMARY KEY (`character_id`, `effect_id`)
+);
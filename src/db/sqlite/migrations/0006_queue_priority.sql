@@ -0,0 +1 @@
+ALTER TABLE `Account` ADD COLUMN `queue_priority` INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS `LoginLock` (
+  `kind` TEXT NOT NULL,
+  `value` TEXT NOT NULL,
+  `failures` INTEGER NOT NULL DEFAULT 0,
+  `lockouts` INTEGER NOT NULL DEFAULT 0,
+  `last_failure_at` TEXT NOT NULL,
+  `locked_until` TEXT DEFAULT NULL,
+  PRIMARY KEY (`kind`, `value`)
+);
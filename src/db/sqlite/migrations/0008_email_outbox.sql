@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS `EmailOutbox` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `to_address` TEXT NOT NULL,
+  `to_name` TEXT NOT NULL,
+  `subject` TEXT NOT NULL,
+  `text_body` TEXT NOT NULL,
+  `html_body` TEXT DEFAULT NULL,
+  `attempts` INTEGER NOT NULL DEFAULT 0,
+  `last_error` TEXT DEFAULT NULL,
+  `next_attempt_at` TEXT DEFAULT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `sent_at` TEXT DEFAULT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `email_outbox_next_attempt_at` ON `EmailOutbox` (`next_attempt_at`);
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use rusqlite::named_params;
+
+use crate::db::{Sanction, SanctionKind, SanctionStorage};
+
+use super::SqliteDb;
+
+#[async_trait]
+impl SanctionStorage for SqliteDb {
+    async fn create_sanction(&self, character_id: i32, sanction: &Sanction) -> anyhow::Result<()> {
+        let sanction = sanction.clone();
+        self.call(move |conn| {
+            conn.execute(
+                include_str!("../../sql/create_sanction.sql"),
+                named_params! {
+                    ":character_id": character_id,
+                    ":type": sanction.kind.as_str(),
+                    ":reason": sanction.reason,
+                    ":duration": if sanction.duration > 0 { Some(sanction.duration) } else { None },
+                    ":created_by": sanction.created_by,
+                    ":created_at": sanction.created_at,
+                },
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn get_sanctions(&self, character_id: i32) -> anyhow::Result<Vec<Sanction>> {
+        #[allow(clippy::type_complexity)]
+        let rows: Vec<(
+            String,
+            String,
+            i32,
+            String,
+            NaiveDateTime,
+            Option<NaiveDateTime>,
+        )> = self
+            .call(move |conn| {
+                let mut stmt = conn.prepare(include_str!("../../sql/get_sanctions.sql"))?;
+                let rows = stmt
+                    .query_map(named_params! { ":character_id": character_id }, |row| {
+                        Ok((
+                            row.get(0)?,
+                            row.get(1)?,
+                            row.get(2)?,
+                            row.get(3)?,
+                            row.get(4)?,
+                            row.get(5)?,
+                        ))
+                    })?
+                    .collect();
+                rows
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(
+                |(kind, reason, duration, created_by, created_at, lifted_at)| {
+                    Some(Sanction {
+                        kind: SanctionKind::parse(&kind)?,
+                        reason,
+                        duration,
+                        created_by,
+                        created_at,
+                        lifted_at,
+                    })
+                },
+            )
+            .collect())
+    }
+
+    async fn lift_mutes(&self, character_id: i32) -> anyhow::Result<u64> {
+        let now = Utc::now().naive_utc();
+        let lifted = self
+            .call(move |conn| {
+                conn.execute(
+                    include_str!("../../sql/sqlite/lift_mutes.sql"),
+                    named_params! { ":character_id": character_id, ":now": now },
+                )
+            })
+            .await?;
+        Ok(lifted as u64)
+    }
+}
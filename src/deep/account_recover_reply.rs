@@ -1,5 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
 pub enum AccountRecoverReply {
+    #[default]
     AccountNotFound,
     RequestAccepted,
     RequestAcceptedShowEmail,
@@ -39,9 +40,3 @@ impl From<AccountRecoverReply> for i32 {
         }
     }
 }
-
-impl Default for AccountRecoverReply {
-    fn default() -> Self {
-        Self::AccountNotFound
-    }
-}
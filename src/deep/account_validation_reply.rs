@@ -1,5 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
 pub enum AccountValidationReply {
+    #[default]
     Busy,
     OK,
     TooManyAttempts,
@@ -27,9 +28,3 @@ impl From<AccountValidationReply> for i32 {
         }
     }
 }
-
-impl Default for AccountValidationReply {
-    fn default() -> Self {
-        Self::Busy
-    }
-}
@@ -1,5 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
 pub enum LookupType {
+    #[default]
     Item,
     Npc,
     Unrecognized(i32),
@@ -24,9 +25,3 @@ impl From<LookupType> for i32 {
         }
     }
 }
-
-impl Default for LookupType {
-    fn default() -> Self {
-        Self::Item
-    }
-}
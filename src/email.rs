@@ -0,0 +1,13 @@
+mod directory_transport;
+pub use directory_transport::DirectoryTransport;
+mod email_transport;
+pub use email_transport::{transport_from_settings, EmailTransport};
+mod outbox;
+#[cfg(test)]
+pub use outbox::get_retry_at;
+mod outbox_handle;
+pub use outbox_handle::OutboxHandle;
+mod outgoing_email;
+pub use outgoing_email::OutgoingEmail;
+mod smtp_transport;
+pub use smtp_transport::SmtpTransport;
@@ -0,0 +1,41 @@
+use std::{fs, path::PathBuf};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use super::{EmailTransport, OutgoingEmail};
+
+/// Writes each email to its own .eml file instead of sending it
+#[derive(Debug)]
+pub struct DirectoryTransport {
+    dir: PathBuf,
+}
+
+impl DirectoryTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl EmailTransport for DirectoryTransport {
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let recipient: String = email
+            .to_address
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S%f"),
+            recipient
+        ));
+
+        fs::write(path, email.to_message().write_to_vec()?)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{settings::EmailTransportKind, SETTINGS};
+
+use super::{DirectoryTransport, OutgoingEmail, SmtpTransport};
+
+/// Delivers emails taken from the outbox
+#[async_trait]
+pub trait EmailTransport: std::fmt::Debug + Send + Sync {
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()>;
+}
+
+/// The transport picked in the `[email]` section
+pub fn transport_from_settings() -> Arc<dyn EmailTransport> {
+    match SETTINGS.email.transport {
+        EmailTransportKind::Smtp => Arc::new(SmtpTransport),
+        EmailTransportKind::Directory => {
+            Arc::new(DirectoryTransport::new(&SETTINGS.email.directory))
+        }
+    }
+}
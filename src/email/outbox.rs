@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use tokio::{sync::mpsc::UnboundedReceiver, time};
+
+use crate::{db::Db, settings::Email, SETTINGS};
+
+use super::{EmailTransport, OutgoingEmail};
+
+/// Emails sent per check of the outbox
+const BATCH_SIZE: usize = 50;
+
+#[derive(Debug)]
+pub enum Command {
+    Queue(Box<OutgoingEmail>),
+    #[cfg(test)]
+    Flush {
+        respond_to: tokio::sync::oneshot::Sender<()>,
+    },
+}
+
+pub struct Outbox {
+    pub rx: UnboundedReceiver<Command>,
+    db: Db,
+    transport: Arc<dyn EmailTransport>,
+}
+
+impl Outbox {
+    pub fn new(rx: UnboundedReceiver<Command>, db: Db, transport: Arc<dyn EmailTransport>) -> Self {
+        Self { rx, db, transport }
+    }
+
+    /// Saves the email so it's sent even if the first attempt fails or the
+    /// server restarts. If it can't be saved it's sent right away instead
+    pub async fn queue(&self, email: OutgoingEmail) {
+        if let Err(e) = self.db.queue_email(&email, Utc::now().naive_utc()).await {
+            error!("Failed to queue email to {}: {}", email.to_address, e);
+            if let Err(e) = self.send(&email).await {
+                error!("Failed to send email to {}: {}", email.to_address, e);
+            }
+        }
+    }
+
+    /// Gives up on a send that takes longer than `send_timeout` so one hung
+    /// connection doesn't hold up the rest of the outbox
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        let timeout = std::time::Duration::from_secs(SETTINGS.email.send_timeout);
+        match time::timeout(timeout, self.transport.send(email)).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!(
+                "timed out after {} seconds",
+                SETTINGS.email.send_timeout
+            )),
+        }
+    }
+
+    /// Sends every email that's due, scheduling a retry for those that fail
+    pub async fn deliver_due(&self) {
+        let now = Utc::now().naive_utc();
+        let emails = match self.db.get_due_emails(now, BATCH_SIZE).await {
+            Ok(emails) => emails,
+            Err(e) => {
+                error!("Failed to get emails from outbox: {}", e);
+                return;
+            }
+        };
+
+        for queued in emails {
+            let result = match self.send(&queued.email).await {
+                Ok(()) => self.db.mark_email_sent(queued.id, now).await,
+                Err(e) => {
+                    let attempts = queued.attempts + 1;
+                    let next_attempt_at = get_retry_at(attempts, now, &SETTINGS.email);
+                    match next_attempt_at {
+                        Some(next_attempt_at) => warn!(
+                            "Failed to send email to {}, retrying at {}: {}",
+                            queued.email.to_address, next_attempt_at, e
+                        ),
+                        None => error!(
+                            "Giving up on email to {} after {} attempts: {}",
+                            queued.email.to_address, attempts, e
+                        ),
+                    }
+
+                    self.db
+                        .mark_email_failed(queued.id, attempts, next_attempt_at, &e.to_string())
+                        .await
+                }
+            };
+
+            if let Err(e) = result {
+                error!("Failed to update email {} in outbox: {}", queued.id, e);
+            }
+        }
+    }
+}
+
+/// When to try again after `attempts` failures, `None` once there have been
+/// too many
+pub fn get_retry_at(attempts: i32, now: NaiveDateTime, settings: &Email) -> Option<NaiveDateTime> {
+    if attempts >= settings.max_attempts {
+        return None;
+    }
+
+    let delay = settings
+        .retry_delay
+        .saturating_mul(1 << (attempts - 1).clamp(0, 30))
+        .min(settings.max_retry_delay);
+
+    Some(now + Duration::seconds(delay))
+}
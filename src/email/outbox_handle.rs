@@ -0,0 +1,65 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{sync::mpsc, time};
+
+use crate::{db::Db, SETTINGS};
+
+use super::{
+    outbox::{Command, Outbox},
+    transport_from_settings, EmailTransport, OutgoingEmail,
+};
+
+#[derive(Debug, Clone)]
+pub struct OutboxHandle {
+    tx: mpsc::UnboundedSender<Command>,
+}
+
+impl OutboxHandle {
+    pub fn new(db: Db, transport: Arc<dyn EmailTransport>) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let outbox = Outbox::new(rx, db, transport);
+        tokio::spawn(run_outbox(outbox));
+
+        Self { tx }
+    }
+
+    /// Uses the transport configured in the `[email]` section
+    pub fn from_settings(db: &Db) -> Self {
+        Self::new(db.clone(), transport_from_settings())
+    }
+
+    /// Sends the email in the background, retrying until it goes through
+    pub fn queue(&self, email: OutgoingEmail) {
+        let _ = self.tx.send(Command::Queue(Box::new(email)));
+    }
+
+    /// Waits until everything queued so far has had a delivery attempt
+    #[cfg(test)]
+    pub async fn flush(&self) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let _ = self.tx.send(Command::Flush { respond_to: tx });
+        let _ = rx.await;
+    }
+}
+
+async fn run_outbox(mut outbox: Outbox) {
+    let mut interval = time::interval(Duration::from_secs(SETTINGS.email.poll_rate.max(1)));
+
+    loop {
+        tokio::select! {
+            command = outbox.rx.recv() => match command {
+                Some(Command::Queue(email)) => {
+                    outbox.queue(*email).await;
+                    outbox.deliver_due().await;
+                }
+                #[cfg(test)]
+                Some(Command::Flush { respond_to }) => {
+                    outbox.deliver_due().await;
+                    let _ = respond_to.send(());
+                }
+                None => break,
+            },
+            _ = interval.tick() => outbox.deliver_due().await,
+        }
+    }
+}
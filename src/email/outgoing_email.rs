@@ -0,0 +1,32 @@
+use mail_builder::MessageBuilder;
+
+use crate::SETTINGS;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingEmail {
+    pub to_address: String,
+    pub to_name: String,
+    pub subject: String,
+    pub text_body: String,
+    /// Sent alongside the text body for clients that show HTML
+    pub html_body: Option<String>,
+}
+
+impl OutgoingEmail {
+    /// A multipart message when there's an HTML body, plain text otherwise
+    pub fn to_message(&self) -> MessageBuilder<'_> {
+        let message = MessageBuilder::new()
+            .from((
+                SETTINGS.smtp.from_name.as_str(),
+                SETTINGS.smtp.from_address.as_str(),
+            ))
+            .to((self.to_name.as_str(), self.to_address.as_str()))
+            .subject(self.subject.as_str())
+            .text_body(self.text_body.as_str());
+
+        match self.html_body.as_deref() {
+            Some(html_body) => message.html_body(html_body),
+            None => message,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use mail_send::SmtpClientBuilder;
+
+use crate::SETTINGS;
+
+use super::{EmailTransport, OutgoingEmail};
+
+/// Sends through the server in the `[smtp]` section, connecting for each
+/// email
+#[derive(Debug)]
+pub struct SmtpTransport;
+
+#[async_trait]
+impl EmailTransport for SmtpTransport {
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        SmtpClientBuilder::new(SETTINGS.smtp.host.to_owned(), SETTINGS.smtp.port)
+            .implicit_tls(false)
+            .credentials((
+                SETTINGS.smtp.username.to_owned(),
+                SETTINGS.smtp.password.to_owned(),
+            ))
+            .connect()
+            .await?
+            .send(email.to_message())
+            .await?;
+
+        Ok(())
+    }
+}
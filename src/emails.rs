@@ -1,15 +1,78 @@
 use config::{Config, ConfigError, File};
 
+use crate::{email::OutgoingEmail, SETTINGS};
+
 #[derive(Debug, Deserialize)]
 pub struct EmailTemplate {
     pub subject: String,
     pub body: String,
+    /// Optional HTML version of `body`, sent as a multipart email
+    #[serde(default)]
+    pub html: String,
+}
+
+impl EmailTemplate {
+    /// Fills in `{key}` placeholders, plus `{server_name}` which is always
+    /// available. Values are escaped in the HTML part
+    pub fn render(
+        &self,
+        to_address: &str,
+        to_name: &str,
+        placeholders: &[(&str, &str)],
+    ) -> OutgoingEmail {
+        let server_name = SETTINGS.sln.server_name.as_str();
+        let placeholders: Vec<(&str, &str)> = [("server_name", server_name)]
+            .into_iter()
+            .chain(placeholders.iter().copied())
+            .collect();
+
+        let fill = |template: &str, escape: bool| {
+            placeholders
+                .iter()
+                .fold(template.to_owned(), |result, (key, value)| {
+                    let value = if escape {
+                        escape_html(value)
+                    } else {
+                        value.to_string()
+                    };
+                    result.replace(&format!("{{{}}}", key), &value)
+                })
+        };
+
+        OutgoingEmail {
+            to_address: to_address.to_owned(),
+            to_name: to_name.to_owned(),
+            subject: fill(&self.subject, false),
+            text_body: fill(&self.body, false),
+            html_body: if self.html.is_empty() {
+                None
+            } else {
+                Some(fill(&self.html, true))
+            },
+        }
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Emails {
     pub validation: EmailTemplate,
     pub recovery: EmailTemplate,
+    pub lockout: EmailTemplate,
 }
 
 impl Emails {
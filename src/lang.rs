@@ -8,6 +8,10 @@ pub struct Lang {
     pub announce_unfreeze: String,
     pub announce_remove: String,
     pub announce_mute: String,
+    pub announce_global_mute: String,
+    pub announce_unmute: String,
+    pub muted: String,
+    pub warning: String,
     pub announce_global: String,
     pub global_locked: String,
     pub wedding_start: String,
@@ -22,6 +26,9 @@ pub struct Lang {
     pub wedding_error: String,
     pub evacuate_warning: String,
     pub evacuate_last_warning: String,
+    pub bot_challenge: String,
+    pub bot_challenge_passed: String,
+    pub login_queued: String,
 }
 
 impl Lang {
@@ -1,12 +1,18 @@
 const VERSION: &str = "1.7.4";
 
+#[cfg(not(test))]
+const DATA_DIR: &str = "data";
+#[cfg(test)]
+const DATA_DIR: &str = "tests/fixtures/data";
+
 #[macro_use]
 extern crate log;
 #[macro_use]
 extern crate serde_derive;
 
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
+use arc_swap::ArcSwap;
 use chrono::Utc;
 use eolib::protocol::r#pub::{
     server::{DropFile, InnFile, ShopFile, SkillMasterFile, TalkFile},
@@ -17,7 +23,9 @@ use lazy_static::lazy_static;
 
 #[macro_use]
 mod utils;
+mod admin_api;
 mod arenas;
+mod audit;
 mod character;
 mod deep;
 use arenas::Arenas;
@@ -26,12 +34,18 @@ use commands::Commands;
 mod player_commands;
 use player_commands::PlayerCommands;
 mod connection_log;
+mod data_watcher;
+mod db;
 mod formulas;
 use formulas::Formulas;
+mod email;
 mod emails;
 mod errors;
 mod lang;
 mod map;
+mod metrics;
+mod npc_abilities;
+use npc_abilities::NpcAbilities;
 mod player;
 mod settings;
 use settings::Settings;
@@ -39,44 +53,67 @@ mod packet_rate_limits;
 use packet_rate_limits::PacketRateLimits;
 mod sln;
 use sln::ping_sln;
+mod status_effects;
+use status_effects::StatusEffects;
+#[cfg(test)]
+mod tests;
+mod validation;
 mod world;
-use mysql_async::prelude::*;
 
-use tokio::{net::TcpListener, signal, time};
+use tokio::{net::TcpListener, signal, sync::Notify, time};
 use world::WorldHandle;
 
 use crate::{
+    audit::AuditHandle,
+    email::OutboxHandle,
     emails::Emails,
     lang::Lang,
     player::PlayerHandle,
     utils::{
         load_class_file, load_drop_file, load_inn_file, load_item_file, load_npc_file, load_quests,
-        load_shop_file, load_skill_master_file, load_spell_file, load_talk_file,
+        load_shop_file, load_skill_master_file, load_spell_file, load_talk_file, ClientPubFiles,
     },
 };
 
 lazy_static! {
     static ref SETTINGS: Settings = Settings::new().expect("Failed to load settings!");
-    static ref ARENAS: Arenas = Arenas::new().expect("Failed to load arenas!");
-    static ref PACKET_RATE_LIMITS: PacketRateLimits =
-        PacketRateLimits::new().expect("Failed to load packet rate limits!");
-    static ref COMMANDS: Commands = Commands::new().expect("Failed to load commands!");
-    static ref PLAYER_COMMANDS: PlayerCommands =
-        PlayerCommands::new().expect("Failed to load player commands!");
-    static ref FORMULAS: Formulas = Formulas::new().expect("Failed to load formulas!");
+    static ref ARENAS: ArcSwap<Arenas> =
+        ArcSwap::from_pointee(Arenas::new().expect("Failed to load arenas!"));
+    static ref PACKET_RATE_LIMITS: ArcSwap<PacketRateLimits> =
+        ArcSwap::from_pointee(PacketRateLimits::new().expect("Failed to load packet rate limits!"));
+    static ref COMMANDS: ArcSwap<Commands> =
+        ArcSwap::from_pointee(Commands::new().expect("Failed to load commands!"));
+    static ref PLAYER_COMMANDS: ArcSwap<PlayerCommands> =
+        ArcSwap::from_pointee(PlayerCommands::new().expect("Failed to load player commands!"));
+    static ref FORMULAS: ArcSwap<Formulas> =
+        ArcSwap::from_pointee(Formulas::new().expect("Failed to load formulas!"));
+    static ref NPC_ABILITIES: ArcSwap<NpcAbilities> =
+        ArcSwap::from_pointee(NpcAbilities::new().expect("Failed to load NPC abilities!"));
+    static ref STATUS_EFFECTS: ArcSwap<StatusEffects> =
+        ArcSwap::from_pointee(StatusEffects::new().expect("Failed to load status effects!"));
     static ref LANG: Lang = Lang::new().expect("Failed to load lang!");
     static ref EMAILS: Emails = Emails::new().expect("Failed to load emails!");
-    static ref CLASS_DB: Ecf = load_class_file().expect("Failed to load ECF file!");
-    static ref DROP_DB: DropFile = load_drop_file().expect("Failed to load Drop file!");
-    static ref INN_DB: InnFile = load_inn_file().expect("Failed to load Inn file!");
-    static ref ITEM_DB: Eif = load_item_file().expect("Failed to load EIF file!");
-    static ref NPC_DB: Enf = load_npc_file().expect("Failed to load ENF file!");
-    static ref SHOP_DB: ShopFile = load_shop_file().expect("Failed to load Shop file!");
-    static ref SKILL_MASTER_DB: SkillMasterFile =
-        load_skill_master_file().expect("Failed to load Skill Master file!");
-    static ref SPELL_DB: Esf = load_spell_file().expect("Failed to load ESF file!");
-    static ref TALK_DB: TalkFile = load_talk_file().expect("Failed to load Talk file!");
-    static ref QUEST_DB: HashMap<i32, Quest> = load_quests();
+    static ref CLASS_DB: ArcSwap<Ecf> =
+        ArcSwap::from_pointee(load_class_file().expect("Failed to load ECF file!"));
+    static ref DROP_DB: ArcSwap<DropFile> =
+        ArcSwap::from_pointee(load_drop_file().expect("Failed to load Drop file!"));
+    static ref INN_DB: ArcSwap<InnFile> =
+        ArcSwap::from_pointee(load_inn_file().expect("Failed to load Inn file!"));
+    static ref ITEM_DB: ArcSwap<Eif> =
+        ArcSwap::from_pointee(load_item_file().expect("Failed to load EIF file!"));
+    static ref NPC_DB: ArcSwap<Enf> =
+        ArcSwap::from_pointee(load_npc_file().expect("Failed to load ENF file!"));
+    static ref SHOP_DB: ArcSwap<ShopFile> =
+        ArcSwap::from_pointee(load_shop_file().expect("Failed to load Shop file!"));
+    static ref SKILL_MASTER_DB: ArcSwap<SkillMasterFile> =
+        ArcSwap::from_pointee(load_skill_master_file().expect("Failed to load Skill Master file!"));
+    static ref SPELL_DB: ArcSwap<Esf> =
+        ArcSwap::from_pointee(load_spell_file().expect("Failed to load ESF file!"));
+    static ref TALK_DB: ArcSwap<TalkFile> =
+        ArcSwap::from_pointee(load_talk_file().expect("Failed to load Talk file!"));
+    static ref QUEST_DB: ArcSwap<HashMap<i32, Quest>> = ArcSwap::from_pointee(load_quests());
+    static ref CLIENT_PUB_FILES: ArcSwap<ClientPubFiles> =
+        ArcSwap::from_pointee(ClientPubFiles::load());
     static ref EXP_TABLE: [i32; 254] = load_exp_table();
 }
 
@@ -89,6 +126,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::env::set_var("RUST_LOG", "info");
     }
     pretty_env_logger::init();
+
+    let json = std::env::args().any(|arg| arg == "--json");
+    match std::env::args().nth(1).as_deref() {
+        Some("check-quests") => std::process::exit(validation::check_quests().print(json)),
+        Some("validate") => std::process::exit(validation::validate().print(json)),
+        _ => {}
+    }
+
     println!(
         "__________
 \\______   \\ ____  ____  ______ ______________  __
@@ -99,56 +144,57 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         VERSION
     );
 
-    let database_url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        SETTINGS.database.username,
-        SETTINGS.database.password,
-        SETTINGS.database.host,
-        SETTINGS.database.port,
-        SETTINGS.database.name
-    );
+    let migrate_only = std::env::args().any(|arg| arg == "--migrate-only");
+
+    let db = db::connect()
+        .await
+        .expect("Failed to connect to the database! Is it configured correctly?");
+
+    if let Err(e) = db::migrate(&db).await {
+        error!("Failed to migrate database: {}", e);
+        std::process::exit(1);
+    }
+
+    if migrate_only {
+        return Ok(());
+    }
 
-    let pool = mysql_async::Pool::new(mysql_async::Opts::from_url(&database_url).unwrap());
     {
-        let conn = pool
-            .get_conn()
-            .await
-            .expect("Failed to get connection from pool! Is MySQL running?");
-        let mut results = r"SELECT
-        (SELECT COUNT(*) FROM `Account`) 'accounts',
-        (SELECT COUNT(*) FROM `Character`) 'characters',
-        (SELECT COUNT(*) FROM `Guild`) 'guilds',
-        (SELECT COUNT(*) FROM `Character` WHERE `admin_level` > 0) 'admins'"
-            .with(())
-            .run(conn)
-            .await?;
-
-        results
-            .for_each(|row| {
-                info!("Accounts: {}", row.get::<i64, usize>(0).unwrap());
-                info!(
-                    "Characters: {} (Admins: {})",
-                    row.get::<i64, usize>(1).unwrap(),
-                    row.get::<i64, usize>(3).unwrap()
-                );
-                info!("Guilds: {}", row.get::<i64, usize>(2).unwrap());
-            })
-            .await
-            .unwrap();
+        let stats = db.get_server_stats().await?;
+        info!("Accounts: {}", stats.accounts);
+        info!(
+            "Characters: {} (Admins: {})",
+            stats.characters, stats.admins
+        );
+        info!("Guilds: {}", stats.guilds);
     }
 
-    info!("Classes: {}", CLASS_DB.classes.len());
-    info!("Items: {}", ITEM_DB.items.len());
-    info!("NPCs: {}", NPC_DB.npcs.len());
-    info!("Skills: {}", SPELL_DB.skills.len());
-    info!("Quests: {}", QUEST_DB.len());
+    info!("Classes: {}", CLASS_DB.load().classes.len());
+    info!("Items: {}", ITEM_DB.load().items.len());
+    info!("NPCs: {}", NPC_DB.load().npcs.len());
+    info!("Skills: {}", SPELL_DB.load().skills.len());
+    info!("Quests: {}", QUEST_DB.load().len());
 
-    let world = WorldHandle::new(pool.clone());
+    let audit = AuditHandle::from_settings(&db);
+    let outbox = OutboxHandle::from_settings(&db);
+    let world = WorldHandle::new(db.clone(), audit, outbox);
     {
         let world = world.clone();
         world.load_maps().await;
     }
 
+    let _data_watcher = if SETTINGS.server.hot_reload {
+        match data_watcher::watch_data(world.clone()) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                error!("Failed to watch data files for changes: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let mut tick_interval = time::interval(Duration::from_millis(SETTINGS.world.tick_rate as u64));
     let tick_world = world.clone();
     tokio::spawn(async move {
@@ -180,6 +226,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    if SETTINGS.metrics.enabled {
+        tokio::spawn(metrics::serve());
+    }
+
+    let shutdown = Arc::new(Notify::new());
+
+    if SETTINGS.admin_api.enabled {
+        let api_world = world.clone();
+        let api_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            admin_api::serve(api_world, api_shutdown).await;
+        });
+    }
+
     let tcp_listener =
         TcpListener::bind(format!("{}:{}", SETTINGS.server.host, SETTINGS.server.port))
             .await
@@ -227,12 +287,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 continue;
             }
 
+            // Bans aren't checked here. The player's Init handler replies to
+            // banned addresses with how long the ban lasts, which the client
+            // can only be sent once it has started the handshake
             server_world.add_connection(&ip).await;
 
             let player_id = server_world.get_next_player_id().await.unwrap();
 
             let player =
-                PlayerHandle::new(player_id, socket, now, server_world.clone(), pool.clone());
+                PlayerHandle::new(player_id, socket, now, server_world.clone(), db.clone());
             server_world.add_player(player_id, player).await.unwrap();
 
             info!(
@@ -256,6 +319,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Err(err) => {
                 error!("Unable to listen for shutdown signal: {}", err);
             }
+        },
+        _ = shutdown.notified() => {
+            info!("Shutdown requested through the admin API");
         }
     }
 
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, sync::Arc};
+
 use bytes::Bytes;
 use eolib::protocol::{
     map::Emf,
@@ -8,6 +10,7 @@ use eolib::protocol::{
     },
     Coords, Direction, Emote,
 };
+use eoplus::Quest;
 use tokio::sync::oneshot;
 
 use crate::{
@@ -15,6 +18,8 @@ use crate::{
     player::PartyRequest,
 };
 
+use super::MapStats;
+
 #[derive(Debug)]
 pub enum Command {
     AcceptGuildCreationRequest {
@@ -181,6 +186,9 @@ pub enum Command {
         respond_to: oneshot::Sender<usize>,
         filter: fn(&Character) -> bool,
     },
+    GetStats {
+        respond_to: oneshot::Sender<MapStats>,
+    },
     AwardExperience {
         player_id: i32,
         amount: i32,
@@ -194,10 +202,23 @@ pub enum Command {
         player_id: i32,
         amount: i32,
     },
+    GiveSpell {
+        player_id: i32,
+        spell_id: i32,
+    },
+    GiveStat {
+        player_id: i32,
+        stat: String,
+        amount: i32,
+    },
     RemoveKarma {
         player_id: i32,
         amount: i32,
     },
+    RemoveSpell {
+        player_id: i32,
+        spell_id: i32,
+    },
     LoseItem {
         player_id: i32,
         item_id: i32,
@@ -312,6 +333,9 @@ pub enum Command {
     },
     RecoverNpcs,
     RecoverPlayers,
+    ReloadQuests {
+        previous: Arc<HashMap<i32, Quest>>,
+    },
     Reload {
         file: Box<Emf>,
         file_size: i32,
@@ -428,6 +452,11 @@ pub enum Command {
         player_id: i32,
         class_id: i32,
     },
+    SetStat {
+        player_id: i32,
+        stat: String,
+        value: i32,
+    },
     Sit {
         player_id: i32,
     },
@@ -473,6 +502,7 @@ pub enum Command {
     TimedEvacuate,
     TimedDropProtection,
     TimedGhost,
+    TimedStatusEffects,
     TimedAutoPickup,
     ToggleHidden {
         player_id: i32,
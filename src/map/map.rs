@@ -4,10 +4,9 @@ use eolib::protocol::{
     map::{Emf, MapTileSpec},
     Coords,
 };
-use mysql_async::Pool;
 use tokio::sync::mpsc::UnboundedReceiver;
 
-use crate::{character::Character, world::WorldHandle, SETTINGS};
+use crate::{character::Character, db::Db, world::WorldHandle, SETTINGS};
 
 use super::{Chest, Command, Door, Item, Npc, Wedding};
 
@@ -23,7 +22,7 @@ pub struct Map {
     npcs: HashMap<i32, Npc>,
     npcs_initialized: bool,
     characters: HashMap<i32, Character>,
-    pool: Pool,
+    db: Db,
     quake_ticks: i32,
     arena_ticks: i32,
     arena_players: Vec<ArenaPlayer>,
@@ -48,6 +47,7 @@ mod auto_pickup;
 mod bank;
 mod barber;
 mod board;
+mod boss;
 mod character;
 mod chest;
 mod events;
@@ -63,13 +63,14 @@ mod shop;
 mod skill_master;
 mod trade;
 mod utils;
+pub use utils::is_tile_spec_walkable;
 
 impl Map {
     pub fn new(
         id: i32,
         file_size: i32,
         file: Emf,
-        pool: Pool,
+        db: Db,
         world: WorldHandle,
         rx: UnboundedReceiver<Command>,
     ) -> Self {
@@ -114,7 +115,7 @@ impl Map {
             npcs: HashMap::new(),
             npcs_initialized: false,
             characters: HashMap::new(),
-            pool,
+            db,
             arena_ticks: 0,
             arena_players: Vec::new(),
             quake_ticks: 0,
@@ -146,7 +147,9 @@ impl Map {
                 chest_index,
                 item,
             } => self.add_chest_item(player_id, chest_index, item),
-            Command::AddLockerItem { player_id, item } => self.add_locker_item(player_id, item),
+            Command::AddLockerItem { player_id, item } => {
+                self.add_locker_item(player_id, item).await
+            }
             Command::AddTradeItem {
                 player_id,
                 partner_id,
@@ -165,7 +168,7 @@ impl Map {
                 player_id,
                 npc_index,
                 item,
-            } => self.buy_item(player_id, npc_index, item),
+            } => self.buy_item(player_id, npc_index, item).await,
 
             Command::BuyHaircut {
                 player_id,
@@ -193,13 +196,13 @@ impl Map {
             Command::CompleteTrade {
                 player_id,
                 partner_id,
-            } => self.complete_trade(player_id, partner_id),
+            } => self.complete_trade(player_id, partner_id).await,
 
             Command::CraftItem {
                 player_id,
                 npc_index,
                 item_id,
-            } => self.craft_item(player_id, npc_index, item_id),
+            } => self.craft_item(player_id, npc_index, item_id).await,
 
             Command::CreateBoardPost {
                 player_id,
@@ -219,7 +222,7 @@ impl Map {
                 player_id,
                 npc_index,
                 amount,
-            } => self.deposit_gold(player_id, npc_index, amount),
+            } => self.deposit_gold(player_id, npc_index, amount).await,
 
             Command::DepositGuildGold {
                 player_id,
@@ -330,6 +333,7 @@ impl Map {
             Command::GetPlayerCount { respond_to, filter } => {
                 let _ = respond_to.send(self.characters.values().filter(|c| filter(c)).count());
             }
+            Command::GetStats { respond_to } => self.get_stats(respond_to),
             Command::AwardExperience { player_id, amount } => {
                 self.award_experience(player_id, amount)
             }
@@ -342,8 +346,24 @@ impl Map {
 
             Command::GiveKarma { player_id, amount } => self.give_karma(player_id, amount),
 
+            Command::GiveSpell {
+                player_id,
+                spell_id,
+            } => self.give_spell(player_id, spell_id),
+
+            Command::GiveStat {
+                player_id,
+                stat,
+                amount,
+            } => self.give_stat(player_id, stat, amount),
+
             Command::RemoveKarma { player_id, amount } => self.remove_karma(player_id, amount),
 
+            Command::RemoveSpell {
+                player_id,
+                spell_id,
+            } => self.remove_spell(player_id, spell_id),
+
             Command::LoseItem {
                 player_id,
                 item_id,
@@ -467,7 +487,10 @@ impl Map {
                 player_id,
                 instrument_id,
                 note_id,
-            } => self.play_instrument(player_id, instrument_id, note_id),
+            } => {
+                self.play_instrument(player_id, instrument_id, note_id)
+                    .await
+            }
 
             Command::PlayJukeboxTrack {
                 player_id,
@@ -518,6 +541,8 @@ impl Map {
 
             Command::Reload { file, file_size } => self.reload(file, file_size),
 
+            Command::ReloadQuests { previous } => self.reload_quests(&previous),
+
             Command::RequestDivorce {
                 player_id,
                 npc_index,
@@ -575,7 +600,7 @@ impl Map {
                 player_id,
                 npc_index,
                 item,
-            } => self.sell_item(player_id, npc_index, item),
+            } => self.sell_item(player_id, npc_index, item).await,
 
             Command::SendChatMessage {
                 player_id: target_player_id,
@@ -596,6 +621,12 @@ impl Map {
                 class_id,
             } => self.set_class(player_id, class_id),
 
+            Command::SetStat {
+                player_id,
+                stat,
+                value,
+            } => self.set_stat(player_id, stat, value),
+
             Command::Sit { player_id } => self.sit(player_id),
 
             Command::SitChair { player_id, coords } => self.sit_chair(player_id, coords),
@@ -648,7 +679,7 @@ impl Map {
             }
 
             Command::TakeLockerItem { player_id, item_id } => {
-                self.take_locker_item(player_id, item_id)
+                self.take_locker_item(player_id, item_id).await
             }
 
             Command::TimedArena => self.timed_arena(),
@@ -671,6 +702,8 @@ impl Map {
 
             Command::TimedGhost => self.timed_ghost(),
 
+            Command::TimedStatusEffects => self.timed_status_effects().await,
+
             Command::TimedAutoPickup => self.timed_auto_pickup(),
 
             Command::ToggleHidden { player_id } => self.toggle_hidden(player_id),
@@ -692,7 +725,7 @@ impl Map {
             Command::UpgradeLocker {
                 player_id,
                 npc_index,
-            } => self.upgrade_locker(player_id, npc_index),
+            } => self.upgrade_locker(player_id, npc_index).await,
 
             Command::UseItem { player_id, item_id } => self.use_item(player_id, item_id),
 
@@ -722,7 +755,7 @@ impl Map {
                 player_id,
                 npc_index,
                 amount,
-            } => self.withdraw_gold(player_id, npc_index, amount),
+            } => self.withdraw_gold(player_id, npc_index, amount).await,
             Command::FindPlayer { player_id, name } => self.find_player(player_id, name),
             Command::RequestNpcs {
                 player_id,
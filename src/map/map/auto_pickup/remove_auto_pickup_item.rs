@@ -9,7 +9,8 @@ impl Map {
             None => return,
         };
 
-        let item_name = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_name = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item.name.as_str(),
             None => return,
         };
@@ -5,12 +5,12 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS};
+use crate::{audit::AuditEvent, NPC_DB, SETTINGS};
 
 use super::super::Map;
 
 impl Map {
-    pub fn deposit_gold(&mut self, player_id: i32, npc_index: i32, amount: i32) {
+    pub async fn deposit_gold(&mut self, player_id: i32, npc_index: i32, amount: i32) {
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -31,7 +31,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -40,6 +41,8 @@ impl Map {
             return;
         }
 
+        let snapshot = self.snapshot_characters(&[player_id]);
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -48,6 +51,19 @@ impl Map {
         character.remove_item(1, amount);
         character.gold_bank += amount;
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.world
+            .audit
+            .record(character, AuditEvent::BankDeposit { amount });
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Reply,
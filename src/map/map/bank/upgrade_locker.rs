@@ -8,7 +8,7 @@ use crate::{NPC_DB, SETTINGS};
 use super::super::Map;
 
 impl Map {
-    pub fn upgrade_locker(&mut self, player_id: i32, npc_index: i32) {
+    pub async fn upgrade_locker(&mut self, player_id: i32, npc_index: i32) {
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -30,7 +30,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -39,6 +40,8 @@ impl Map {
             return;
         }
 
+        let snapshot = self.snapshot_characters(&[player_id]);
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -47,6 +50,15 @@ impl Map {
         character.remove_item(1, cost);
         character.bank_level += 1;
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Buy,
@@ -1,9 +1,7 @@
-use chrono::NaiveDateTime;
 use eolib::protocol::net::{
     server::{BoardOpenServerPacket, BoardPostListing},
     PacketAction, PacketFamily,
 };
-use mysql_async::{params, prelude::Queryable, Row};
 
 use crate::{
     utils::{format_duration, get_board_tile_spec},
@@ -12,13 +10,6 @@ use crate::{
 
 use super::super::Map;
 
-struct BoardPost {
-    id: i32,
-    author: String,
-    subject: String,
-    created_at: NaiveDateTime,
-}
-
 impl Map {
     pub fn open_board(&mut self, player_id: i32, board_id: i32) {
         let character = match self.characters.get(&player_id) {
@@ -46,38 +37,15 @@ impl Map {
 
         player.set_board_id(board_id);
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Failed to get connection from pool: {}", e);
-                    return;
-                }
-            };
-
             let limit = if board_id == SETTINGS.board.admin_board {
                 SETTINGS.board.admin_max_posts
             } else {
                 SETTINGS.board.max_posts
             };
 
-            let posts = match conn
-                .exec_map(
-                    include_str!("../../../sql/get_board_posts.sql"),
-                    params! {
-                        "board_id" => board_id,
-                        "limit" => limit,
-                    },
-                    |mut row: Row| BoardPost {
-                        id: row.take("id").unwrap(),
-                        author: row.take("author").unwrap(),
-                        subject: row.take("subject").unwrap(),
-                        created_at: row.take("created_at").unwrap(),
-                    },
-                )
-                .await
-            {
+            let posts = match db.get_board_posts(board_id, limit).await {
                 Ok(posts) => posts,
                 Err(e) => {
                     error!("Failed to get board posts: {}", e);
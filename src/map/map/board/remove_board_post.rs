@@ -1,5 +1,3 @@
-use mysql_async::{params, prelude::Queryable, Conn};
-
 use crate::utils::get_board_tile_spec;
 
 use super::super::Map;
@@ -29,16 +27,8 @@ impl Map {
             return;
         }
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Failed to get connection from pool: {}", e);
-                    return;
-                }
-            };
-
             let map = match player.get_map().await {
                 Ok(map) => map,
                 Err(e) => {
@@ -47,7 +37,7 @@ impl Map {
                 }
             };
 
-            if let Err(e) = delete_post(&mut conn, post_id).await {
+            if let Err(e) = db.delete_board_post(post_id).await {
                 error!("Failed to delete post: {}", e);
             }
 
@@ -55,13 +45,3 @@ impl Map {
         });
     }
 }
-
-async fn delete_post(conn: &mut Conn, post_id: i32) -> Result<(), mysql_async::Error> {
-    conn.exec_drop(
-        include_str!("../../../sql/delete_board_post.sql"),
-        params! {
-            "id" => post_id,
-        },
-    )
-    .await
-}
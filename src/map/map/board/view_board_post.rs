@@ -1,5 +1,4 @@
 use eolib::protocol::net::{server::BoardPlayerServerPacket, PacketAction, PacketFamily};
-use mysql_async::{params, prelude::Queryable, Row};
 
 use crate::utils::get_board_tile_spec;
 
@@ -26,38 +25,21 @@ impl Map {
             return;
         }
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
+            let post_body = match db.get_board_post(board_id, post_id).await {
+                Ok(Some(body)) => body,
+                Ok(None) => return,
                 Err(e) => {
-                    error!("Failed to get sql connection: {}", e);
+                    error!("Failed to get board post: {}", e);
                     return;
                 }
             };
 
-            let row = conn
-                .exec_first(
-                    include_str!("../../../sql/get_board_post.sql"),
-                    params! {
-                        "board_id" => board_id,
-                        "post_id" => post_id,
-                    },
-                )
-                .await;
-
-            let mut row: Row = match row {
-                Ok(Some(row)) => row,
-                _ => return,
-            };
-
             player.send(
                 PacketAction::Player,
                 PacketFamily::Board,
-                &BoardPlayerServerPacket {
-                    post_id,
-                    post_body: row.take::<String, &str>("body").unwrap(),
-                },
+                &BoardPlayerServerPacket { post_id, post_body },
             );
         });
     }
@@ -0,0 +1,38 @@
+use eolib::protocol::net::{server::NpcJunkServerPacket, PacketAction, PacketFamily};
+
+use super::super::Map;
+
+impl Map {
+    /// Kills every child linked to the boss without rewards. Spawned children
+    /// come back when the boss respawns
+    pub fn despawn_boss_children(&mut self, boss_index: i32) {
+        let mut child_ids = Vec::new();
+
+        for child in self
+            .npcs
+            .values_mut()
+            .filter(|npc| npc.alive && npc.boss_index == Some(boss_index))
+        {
+            child.alive = false;
+            child.hp = 0;
+            child.opponents.clear();
+            child.path = None;
+
+            if child.spawn_index.is_some() {
+                child.spawn_ticks = child.spawn_time;
+            }
+
+            if !child_ids.contains(&child.id) {
+                child_ids.push(child.id);
+            }
+        }
+
+        for npc_id in child_ids {
+            self.send_packet_all(
+                PacketAction::Junk,
+                PacketFamily::Npc,
+                NpcJunkServerPacket { npc_id },
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::utils::get_distance;
+
+use super::super::Map;
+
+impl Map {
+    /// Links every spawned child to the boss whose spawn point is nearest
+    /// to its own
+    pub fn link_boss_children(&mut self) {
+        let bosses = self
+            .npcs
+            .iter()
+            .filter(|(_, npc)| npc.boss)
+            .filter_map(|(index, npc)| {
+                let spawn = self.file.npcs.get(npc.spawn_index?)?;
+                Some((*index, spawn.coords))
+            })
+            .collect::<Vec<_>>();
+
+        for npc in self.npcs.values_mut().filter(|npc| npc.child) {
+            let coords = match npc.spawn_index.and_then(|index| self.file.npcs.get(index)) {
+                Some(spawn) => spawn.coords,
+                None => continue,
+            };
+
+            npc.boss_index = bosses
+                .iter()
+                .min_by_key(|(index, boss_coords)| (get_distance(&coords, boss_coords), *index))
+                .map(|(index, _)| *index);
+        }
+    }
+}
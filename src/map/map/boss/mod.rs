@@ -0,0 +1,2 @@
+mod despawn_boss_children;
+mod link_boss_children;
@@ -16,7 +16,7 @@ use crate::{
     character::Character,
     map::map::ArenaPlayer,
     utils::{get_distance, get_next_coords},
-    ITEM_DB, NPC_DB, SETTINGS,
+    ITEM_DB, NPC_DB, SETTINGS, STATUS_EFFECTS,
 };
 
 use super::super::Map;
@@ -74,10 +74,7 @@ impl Map {
         direction: Direction,
         party_player_ids: &[i32],
     ) -> Option<AttackTarget> {
-        let attacker = match self.characters.get(&player_id) {
-            Some(character) => character,
-            None => return None,
-        };
+        let attacker = self.characters.get(&player_id)?;
 
         let range = if self
             .arena_players
@@ -166,7 +163,8 @@ impl Map {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -287,6 +285,20 @@ impl Map {
 
         let arena_player = arena_player.to_owned();
 
+        if let Some(character) = self.characters.get_mut(&player_id) {
+            character.killed_arena_player(arena_player.kills);
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let target_character = match self.characters.get(&target_player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
         if let Some(player) = target_character.player.as_ref() {
             player.arena_die(Coords {
                 x: self.file.relog_x,
@@ -404,6 +416,12 @@ impl Map {
 
             player.update_party_hp(target_character.get_hp_percentage());
         }
+
+        if target_character.hp == 0 {
+            if let Some(character) = self.characters.get_mut(&player_id) {
+                character.killed_player();
+            }
+        }
     }
 }
 
@@ -412,6 +430,10 @@ fn can_attack(character: &Character) -> bool {
         return false;
     }
 
+    if character.status_effects.is_stunned(&STATUS_EFFECTS.load()) {
+        return false;
+    }
+
     let weapon = character.equipment.weapon;
     let shield = character.equipment.shield;
 
@@ -429,7 +451,8 @@ fn can_attack(character: &Character) -> bool {
             return true;
         }
 
-        let shield_data = match ITEM_DB.items.get(shield as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let shield_data = match item_db.items.get(shield as usize - 1) {
             Some(data) => data,
             None => return false,
         };
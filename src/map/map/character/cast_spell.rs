@@ -14,7 +14,7 @@ use eolib::protocol::{
 use rand::Rng;
 
 use crate::utils::in_client_range;
-use crate::{character::SpellTarget, NPC_DB, SPELL_DB};
+use crate::{character::SpellTarget, NPC_DB, SETTINGS, SPELL_DB, STATUS_EFFECTS};
 
 use super::super::Map;
 
@@ -22,14 +22,17 @@ impl Map {
     pub async fn cast_spell(&mut self, player_id: i32, spell_id: i32, target: SpellTarget) {
         match self.characters.get(&player_id) {
             Some(character) => {
-                if !character.has_spell(spell_id) {
+                if !character.has_spell(spell_id)
+                    || character.status_effects.is_stunned(&STATUS_EFFECTS.load())
+                {
                     return;
                 }
             }
             None => return,
         }
 
-        let spell_data = match SPELL_DB.skills.get(spell_id as usize - 1) {
+        let spell_db = SPELL_DB.load();
+        let spell_data = match spell_db.skills.get(spell_id as usize - 1) {
             Some(spell_data) => spell_data,
             None => return,
         };
@@ -43,10 +46,49 @@ impl Map {
                 self.cast_damage_spell(player_id, spell_id, spell_data, target)
                     .await
             }
+            SkillType::Bard => self.cast_bard_spell(player_id, spell_id).await,
             _ => {}
         }
     }
 
+    /// Puts the spell's status effects on each of `target_player_ids`
+    pub fn apply_spell_status_effects(
+        &mut self,
+        player_id: i32,
+        spell_id: i32,
+        target_player_ids: &[i32],
+    ) {
+        let status_effects = STATUS_EFFECTS.load();
+        let effects = status_effects.for_spell(spell_id).collect::<Vec<_>>();
+        if effects.is_empty() {
+            return;
+        }
+
+        for target_player_id in target_player_ids {
+            self.apply_player_status_effects(*target_player_id, &effects, Some(player_id));
+        }
+    }
+
+    /// Bard spells with a melody have to be played, the rest can be cast
+    /// while holding an instrument
+    async fn cast_bard_spell(&mut self, player_id: i32, spell_id: i32) {
+        if SETTINGS
+            .bard
+            .songs
+            .iter()
+            .any(|song| song.spell_id == spell_id)
+        {
+            return;
+        }
+
+        match self.characters.get(&player_id) {
+            Some(character) if character.get_instrument().is_some() => {}
+            _ => return,
+        }
+
+        self.perform_song(player_id, spell_id).await;
+    }
+
     async fn cast_heal_spell(
         &mut self,
         player_id: i32,
@@ -123,6 +165,8 @@ impl Map {
                 tp: None,
             },
         );
+
+        self.apply_spell_status_effects(player_id, spell_id, &[player_id]);
     }
 
     async fn cast_heal_group(&mut self, player_id: i32, spell_id: i32, spell: &EsfRecord) {
@@ -207,6 +251,12 @@ impl Map {
                 );
             }
         }
+
+        let healed_player_ids = healed_players
+            .iter()
+            .map(|healed| healed.player_id)
+            .collect::<Vec<_>>();
+        self.apply_spell_status_effects(player_id, spell_id, &healed_player_ids);
     }
 
     fn cast_heal_player(
@@ -293,6 +343,8 @@ impl Map {
 
             player.send(PacketAction::Player, PacketFamily::Recover, &packet);
         }
+
+        self.apply_spell_status_effects(player_id, spell_id, &[target_player_id]);
     }
 
     async fn cast_damage_spell(
@@ -344,7 +396,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -395,6 +448,12 @@ impl Map {
         }
 
         if npc.alive {
+            if !protected {
+                let status_effects = STATUS_EFFECTS.load();
+                let effects = status_effects.for_spell(spell_id).collect::<Vec<_>>();
+                self.apply_npc_status_effects(npc_index, &effects, player_id);
+            }
+
             self.attack_npc_reply(
                 player_id,
                 npc_index,
@@ -510,5 +569,9 @@ impl Map {
 
             player.update_party_hp(target_character.get_hp_percentage());
         }
+
+        if target_character.hp > 0 {
+            self.apply_spell_status_effects(player_id, spell_id, &[target_player_id]);
+        }
     }
 }
@@ -10,7 +10,7 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::{utils::get_distance, ITEM_DB, SETTINGS};
+use crate::{audit::AuditEvent, utils::get_distance, ITEM_DB, SETTINGS};
 
 use super::super::Map;
 
@@ -23,7 +23,8 @@ impl Map {
             return;
         }
 
-        let item_record = match ITEM_DB.items.get(item.id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_record = match item_db.items.get(item.id as usize - 1) {
             Some(item) => item,
             None => return,
         };
@@ -76,6 +77,14 @@ impl Map {
             };
 
             character.remove_item(item.id, amount_to_drop);
+
+            self.world.audit.record(
+                character,
+                AuditEvent::DropItem {
+                    item_id: item.id,
+                    amount: amount_to_drop,
+                },
+            );
         }
 
         let item_index = self.get_next_item_index(1);
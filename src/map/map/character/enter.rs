@@ -46,7 +46,8 @@ impl Map {
         if character.is_deep {
             if let Some(player) = &character.player {
                 for (npc_index, npc) in self.npcs.iter().filter(|(_, npc)| {
-                    let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                    let npc_db = NPC_DB.load();
+                    let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                         Some(npc) => npc,
                         None => return false,
                     };
@@ -3,7 +3,7 @@ use eolib::protocol::net::{
     PacketAction, PacketFamily, ThreeItem,
 };
 
-use crate::{utils::get_distance, SETTINGS};
+use crate::{audit::AuditEvent, utils::get_distance, SETTINGS};
 
 use super::super::Map;
 
@@ -42,6 +42,14 @@ impl Map {
 
         character.add_item(item_id, amount_picked_up);
 
+        self.world.audit.record(
+            character,
+            AuditEvent::PickupItem {
+                item_id,
+                amount: amount_picked_up,
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Get,
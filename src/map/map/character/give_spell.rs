@@ -0,0 +1,37 @@
+use eolib::protocol::net::{server::StatSkillTakeServerPacket, PacketAction, PacketFamily};
+
+use crate::SPELL_DB;
+
+use super::super::Map;
+
+impl Map {
+    pub fn give_spell(&mut self, player_id: i32, spell_id: i32) {
+        if spell_id <= 0 || spell_id as usize > SPELL_DB.load().skills.len() {
+            return;
+        }
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if character.has_spell(spell_id) {
+            return;
+        }
+
+        character.add_spell(spell_id);
+
+        if let Some(player) = character.player.as_ref() {
+            player.send(
+                PacketAction::Take,
+                PacketFamily::StatSkill,
+                &StatSkillTakeServerPacket {
+                    spell_id,
+                    gold_amount: character.get_item_amount(1),
+                },
+            );
+        }
+
+        character.check_quest_rules();
+    }
+}
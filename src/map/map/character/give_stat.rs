@@ -0,0 +1,16 @@
+use super::super::Map;
+
+impl Map {
+    pub fn give_stat(&mut self, player_id: i32, stat: String, amount: i32) {
+        let current = match self
+            .characters
+            .get(&player_id)
+            .and_then(|character| character.get_quest_stat(&stat))
+        {
+            Some(current) => current,
+            None => return,
+        };
+
+        self.set_stat(player_id, stat, current.saturating_add(amount));
+    }
+}
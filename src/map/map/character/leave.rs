@@ -22,7 +22,7 @@ impl Map {
 
         let target = self.characters.remove(&player_id).unwrap();
 
-        if let Some(config) = ARENAS.arenas.iter().find(|a| a.map == self.id) {
+        if let Some(config) = ARENAS.load().arenas.iter().find(|a| a.map == self.id) {
             if self.arena_players.iter().any(|p| p.player_id == player_id)
                 && !config
                     .spawns
@@ -53,5 +53,7 @@ impl Map {
                 },
             );
         }
+
+        character.check_quest_rules();
     }
 }
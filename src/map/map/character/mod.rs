@@ -9,6 +9,8 @@ mod face;
 mod find_player;
 mod get_item;
 mod give_karma;
+mod give_spell;
+mod give_stat;
 mod junk_item;
 mod leave;
 mod level_skill;
@@ -18,6 +20,7 @@ mod open_captcha;
 mod open_door;
 mod party_request;
 mod remove_karma;
+mod remove_spell;
 mod request_book;
 mod request_npcs;
 mod request_paperdoll;
@@ -26,6 +29,7 @@ mod request_players_and_npcs;
 mod request_refresh;
 mod send_chat_message;
 mod set_class;
+mod set_stat;
 mod sit;
 mod sit_chair;
 mod stand;
@@ -0,0 +1,28 @@
+use eolib::protocol::net::{server::StatSkillRemoveServerPacket, PacketAction, PacketFamily};
+
+use super::super::Map;
+
+impl Map {
+    pub fn remove_spell(&mut self, player_id: i32, spell_id: i32) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if !character.has_spell(spell_id) {
+            return;
+        }
+
+        character.remove_spell(spell_id);
+
+        if let Some(player) = character.player.as_ref() {
+            player.send(
+                PacketAction::Remove,
+                PacketFamily::StatSkill,
+                &StatSkillRemoveServerPacket { spell_id },
+            );
+        }
+
+        character.check_quest_rules();
+    }
+}
@@ -22,5 +22,7 @@ impl Map {
                 },
             );
         }
+
+        character.check_quest_rules();
     }
 }
@@ -0,0 +1,73 @@
+use eolib::protocol::net::{
+    server::{RecoverListServerPacket, RecoverPlayerServerPacket, RecoverReplyServerPacket},
+    PacketAction, PacketFamily,
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn set_stat(&mut self, player_id: i32, stat: String, value: i32) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if !character.set_quest_stat(&stat, value) {
+            return;
+        }
+
+        let stat = stat.to_lowercase();
+
+        if let Some(player) = character.player.as_ref() {
+            match stat.as_str() {
+                "level" | "exp" | "experience" | "karma" | "statpoints" | "skillpoints" => {
+                    player.send(
+                        PacketAction::Reply,
+                        PacketFamily::Recover,
+                        &RecoverReplyServerPacket {
+                            experience: character.experience,
+                            karma: character.karma,
+                            level_up: if stat == "level" {
+                                Some(character.level)
+                            } else {
+                                None
+                            },
+                            stat_points: Some(character.stat_points),
+                            skill_points: Some(character.skill_points),
+                        },
+                    );
+                }
+                "hp" | "tp" => {
+                    player.send(
+                        PacketAction::Player,
+                        PacketFamily::Recover,
+                        &RecoverPlayerServerPacket {
+                            hp: character.hp,
+                            tp: character.tp,
+                        },
+                    );
+                }
+                "goldbank" => {}
+                _ => {
+                    player.send(
+                        PacketAction::List,
+                        PacketFamily::Recover,
+                        &RecoverListServerPacket {
+                            class_id: character.class,
+                            stats: character.get_character_stats_update(),
+                        },
+                    );
+                }
+            }
+        }
+
+        character.check_quest_rules();
+
+        if matches!(
+            stat.as_str(),
+            "race" | "skin" | "gender" | "hairstyle" | "haircolor"
+        ) {
+            self.refresh_avatar(player_id);
+        }
+    }
+}
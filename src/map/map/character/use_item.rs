@@ -22,7 +22,7 @@ use eolib::{
 
 use crate::{
     character::EquipmentSlot, deep::AVATAR_CHANGE_TYPE_SKIN, utils::in_client_range, INN_DB,
-    ITEM_DB, SETTINGS, SPELL_DB,
+    ITEM_DB, SETTINGS, SPELL_DB, STATUS_EFFECTS,
 };
 
 use super::super::Map;
@@ -47,7 +47,8 @@ impl Map {
             return;
         }
 
-        let item = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => {
                 return;
@@ -98,7 +99,12 @@ impl Map {
 
                 let (map_id, coords) = {
                     match item.spec1 {
-                        0 => match INN_DB.inns.iter().find(|inn| inn.name == character.home) {
+                        0 => match INN_DB
+                            .load()
+                            .inns
+                            .iter()
+                            .find(|inn| inn.name == character.home)
+                        {
                             Some(inn) => (
                                 inn.spawn_map,
                                 Coords {
@@ -198,7 +204,7 @@ impl Map {
                 }
             }
             ItemType::Reserved7 => {
-                if SPELL_DB.skills.len() < item.spec1 as usize {
+                if SPELL_DB.load().skills.len() < item.spec1 as usize {
                     return;
                 }
 
@@ -213,7 +219,8 @@ impl Map {
                         continue;
                     }
 
-                    let item = match ITEM_DB.items.get(*item_id as usize - 1) {
+                    let item_db = ITEM_DB.load();
+                    let item = match item_db.items.get(*item_id as usize - 1) {
                         Some(item) => item,
                         None => {
                             continue;
@@ -397,5 +404,11 @@ impl Map {
                 writer.to_byte_array(),
             );
         }
+
+        let status_effects = STATUS_EFFECTS.load();
+        let effects = status_effects.for_item(item_id).collect::<Vec<_>>();
+        if !effects.is_empty() {
+            self.apply_player_status_effects(player_id, &effects, None);
+        }
     }
 }
@@ -20,7 +20,8 @@ impl Map {
             return;
         }
 
-        let item = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => {
                 return;
@@ -1,3 +1,4 @@
+use chrono::{Duration, Utc};
 use eolib::protocol::{
     map::MapTileSpec,
     net::{
@@ -8,8 +9,9 @@ use eolib::protocol::{
 };
 
 use crate::{
-    utils::{get_next_coords, in_client_range},
-    SETTINGS,
+    player::MovementViolation,
+    utils::{get_distance, get_next_coords, in_client_range},
+    SETTINGS, STATUS_EFFECTS,
 };
 
 use super::super::Map;
@@ -17,7 +19,7 @@ use super::super::Map;
 impl Map {
     pub fn walk(&mut self, player_id: i32, direction: Direction, client_coords: Coords) {
         if let Some((previous_coords, coords, player, hidden)) = {
-            let (coords, admin_level, player, hidden, ghost_ticks) =
+            let (coords, admin_level, player, hidden, ghost_ticks, stunned) =
                 match self.characters.get(&player_id) {
                     Some(character) => (
                         character.coords,
@@ -25,17 +27,37 @@ impl Map {
                         character.player.clone(),
                         character.hidden,
                         character.ghost_ticks,
+                        character.status_effects.is_stunned(&STATUS_EFFECTS.load()),
                     ),
                     None => return,
                 };
 
+            if stunned {
+                self.rubber_band(player_id, client_coords);
+                return;
+            }
+
             let previous_coords = coords;
             let coords = get_next_coords(&coords, direction, self.file.width, self.file.height);
+
+            // The client says where the walk takes it, which should never be
+            // more than a step away from where the server has it going. This
+            // only counts a violation, the walk still goes ahead from the
+            // server's coords and the player is put back there below
+            if get_distance(&coords, &client_coords) > 1 {
+                if let Some(player) = player.as_ref() {
+                    player.add_movement_violation(MovementViolation::Teleport);
+                }
+            }
+
             let is_tile_walkable = i32::from(admin_level) >= 1
                 || (self.is_tile_walkable(&coords)
                     && (!self.is_tile_occupied(&coords) || ghost_ticks == 0));
 
             if !self.is_in_bounds(coords) || !is_tile_walkable {
+                // Put the player back so they don't walk on through a tile
+                // they never got onto
+                self.rubber_band(player_id, client_coords);
                 return;
             }
 
@@ -44,7 +66,7 @@ impl Map {
             if let Some(character) = self.characters.get_mut(&player_id) {
                 character.coords = coords;
                 character.direction = direction;
-                character.entered_coord();
+                character.entered_coord(previous_coords);
                 character.warp_suck_ticks = SETTINGS.world.warp_suck_rate;
                 character.ghost_ticks = SETTINGS.world.ghost_rate;
             }
@@ -141,9 +163,33 @@ impl Map {
                 }
             }
 
-            if coords != client_coords {
-                self.request_refresh(player_id);
-            }
+            self.rubber_band(player_id, client_coords);
+        }
+    }
+
+    /// Refreshes a player whose client has them somewhere other than the
+    /// server does. Refreshes are heavy so they're sent at most once every
+    /// `min_refresh_interval`
+    fn rubber_band(&mut self, player_id: i32, client_coords: Coords) {
+        let now = Utc::now();
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if character.coords == client_coords {
+            return;
         }
+
+        let interval = Duration::milliseconds(SETTINGS.movement.min_refresh_interval);
+        if character
+            .rubber_banded_at
+            .is_some_and(|rubber_banded_at| now - rubber_banded_at < interval)
+        {
+            return;
+        }
+
+        character.rubber_banded_at = Some(now);
+        self.request_refresh(player_id);
     }
 }
@@ -10,6 +10,7 @@ use eolib::{
 };
 
 use crate::{
+    audit::AuditEvent,
     map::{chest::ChestItem, Chest},
     utils::get_distance,
     SETTINGS,
@@ -76,6 +77,16 @@ impl Map {
             chest_full = true;
         }
 
+        if !chest_full {
+            self.world.audit.record(
+                character,
+                AuditEvent::ChestDeposit {
+                    item_id: item.id,
+                    amount,
+                },
+            );
+        }
+
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -7,7 +7,7 @@ use eolib::{
     },
 };
 
-use crate::{map::Chest, utils::get_distance};
+use crate::{audit::AuditEvent, map::Chest, utils::get_distance};
 
 use super::super::Map;
 
@@ -82,6 +82,14 @@ impl Map {
 
         character.add_item(item.item_id, item.amount);
 
+        self.world.audit.record(
+            character,
+            AuditEvent::ChestTake {
+                item_id: item.item_id,
+                amount: item.amount,
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Get,
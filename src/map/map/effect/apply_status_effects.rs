@@ -0,0 +1,91 @@
+use eolib::protocol::net::{
+    server::{RecoverListServerPacket, WalkCloseServerPacket},
+    PacketAction, PacketFamily,
+};
+
+use crate::{
+    status_effects::{StatModifiers, StatusEffect},
+    STATUS_EFFECTS,
+};
+
+use super::super::Map;
+
+impl Map {
+    /// Puts `effects` on a player, showing their graphics and sending any
+    /// stats they change. NPC-only effects are skipped
+    pub fn apply_player_status_effects(
+        &mut self,
+        player_id: i32,
+        effects: &[&StatusEffect],
+        caster_id: Option<i32>,
+    ) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let effects = effects
+            .iter()
+            .filter(|effect| !effect.kind.is_npc_only())
+            .copied()
+            .collect::<Vec<_>>();
+
+        let status_effects = STATUS_EFFECTS.load();
+        let was_stunned = character.status_effects.is_stunned(&status_effects);
+
+        for effect in &effects {
+            character.status_effects.apply(effect, caster_id);
+        }
+
+        let stats_changed = effects
+            .iter()
+            .any(|effect| effect.modifiers != StatModifiers::default());
+
+        if stats_changed {
+            character.calculate_stats();
+        }
+
+        if let Some(player) = character.player.as_ref() {
+            if stats_changed {
+                player.send(
+                    PacketAction::List,
+                    PacketFamily::Recover,
+                    &RecoverListServerPacket {
+                        class_id: character.class,
+                        stats: character.get_character_stats_update(),
+                    },
+                );
+            }
+
+            if !was_stunned && character.status_effects.is_stunned(&status_effects) {
+                player.send(
+                    PacketAction::Close,
+                    PacketFamily::Walk,
+                    &WalkCloseServerPacket::new(),
+                );
+            }
+        }
+
+        for effect in &effects {
+            if effect.graphic_id > 0 {
+                self.effect_on_players(&[player_id], effect.graphic_id);
+            }
+        }
+    }
+
+    pub fn apply_npc_status_effects(
+        &mut self,
+        npc_index: i32,
+        effects: &[&StatusEffect],
+        caster_id: i32,
+    ) {
+        let npc = match self.npcs.get_mut(&npc_index) {
+            Some(npc) if npc.alive => npc,
+            _ => return,
+        };
+
+        for effect in effects {
+            npc.status_effects.apply(effect, Some(caster_id));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod apply_status_effects;
 mod effect_on_coords;
 mod effect_on_players;
 mod quake;
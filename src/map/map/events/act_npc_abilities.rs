@@ -0,0 +1,280 @@
+use std::cmp;
+
+use eolib::{
+    data::CHAR_MAX,
+    protocol::{
+        net::server::{NpcUpdateAttack, PlayerKilledState},
+        r#pub::SkillType,
+        Coords, Direction,
+    },
+};
+use rand::Rng;
+
+use crate::{
+    map::Npc,
+    npc_abilities::{NpcAbility, NpcAbilityType, NpcPhase},
+    utils::get_distance,
+    NPC_DB, SETTINGS, SPELL_DB,
+};
+
+use super::super::Map;
+
+/// What an NPC did with its turn after using an ability
+pub enum NpcAbilityUse {
+    Attack(NpcUpdateAttack),
+    Other,
+}
+
+impl Map {
+    /// Rolls each of the phase's abilities in order and uses the first one
+    /// that succeeds and has something to act on
+    pub fn act_npc_abilities(&mut self, index: i32, phase: &NpcPhase) -> Option<NpcAbilityUse> {
+        let mut rng = rand::thread_rng();
+        for ability in &phase.abilities {
+            if rng.gen_range(0..100) >= ability.chance {
+                continue;
+            }
+
+            if let Some(ability_use) = self.use_npc_ability(index, ability) {
+                if let Some(npc) = self.npcs.get_mut(&index) {
+                    npc.act_ticks = 0;
+                }
+                return Some(ability_use);
+            }
+        }
+
+        None
+    }
+
+    pub fn use_npc_ability(&mut self, index: i32, ability: &NpcAbility) -> Option<NpcAbilityUse> {
+        match ability.r#type {
+            NpcAbilityType::Spell => self
+                .npc_cast_damage_spell(index, ability)
+                .map(NpcAbilityUse::Attack),
+            NpcAbilityType::Heal => self
+                .npc_cast_heal_spell(index, ability)
+                .then_some(NpcAbilityUse::Other),
+            NpcAbilityType::Summon => self
+                .npc_summon(index, ability)
+                .then_some(NpcAbilityUse::Other),
+        }
+    }
+
+    fn npc_cast_damage_spell(
+        &mut self,
+        index: i32,
+        ability: &NpcAbility,
+    ) -> Option<NpcUpdateAttack> {
+        if ability.spell_id < 1 {
+            return None;
+        }
+
+        let spell_db = SPELL_DB.load();
+        let spell = spell_db.skills.get(ability.spell_id as usize - 1)?;
+        if spell.r#type != SkillType::Attack {
+            return None;
+        }
+
+        let npc = self.npcs.get(&index)?;
+        let npc_db = NPC_DB.load();
+        let npc_data = npc_db.npcs.get(npc.id as usize - 1)?;
+
+        let target_player_id = self.npc_get_chase_target_player_id(index, npc.id)?;
+        let npc_coords = npc.coords;
+
+        let target = self.characters.get_mut(&target_player_id)?;
+        if get_distance(&npc_coords, &target.coords) > ability.range {
+            return None;
+        }
+
+        let amount = {
+            let mut rng = rand::thread_rng();
+            npc.get_rage_damage(
+                rng.gen_range(
+                    npc_data.min_damage + spell.min_damage..=npc_data.max_damage + spell.max_damage,
+                ),
+                &SETTINGS.bosses,
+            )
+        };
+
+        let critical = target.hp == target.max_hp;
+        let damage = target.damage(amount, npc_data.accuracy, critical);
+        let hp_percentage = target.get_hp_percentage();
+        let killed = if target.hp == 0 {
+            PlayerKilledState::Killed
+        } else {
+            PlayerKilledState::Alive
+        };
+
+        if damage > 0 {
+            if let Some(player) = target.player.as_ref() {
+                player.update_party_hp(hp_percentage);
+            }
+        }
+
+        let direction = get_direction_towards(&npc_coords, &target.coords);
+
+        self.effect_on_players(&[target_player_id], spell.graphic_id);
+
+        let npc = self.npcs.get_mut(&index)?;
+        npc.direction = direction;
+        if killed == PlayerKilledState::Killed {
+            npc.opponents
+                .retain(|opponent| opponent.player_id != target_player_id);
+        }
+
+        Some(NpcUpdateAttack {
+            npc_index: index,
+            killed,
+            direction,
+            player_id: target_player_id,
+            damage,
+            hp_percentage,
+        })
+    }
+
+    /// Heals the caster or the most hurt boss in range
+    fn npc_cast_heal_spell(&mut self, index: i32, ability: &NpcAbility) -> bool {
+        if ability.spell_id < 1 {
+            return false;
+        }
+
+        let spell_db = SPELL_DB.load();
+        let spell = match spell_db.skills.get(ability.spell_id as usize - 1) {
+            Some(spell) if spell.r#type == SkillType::Heal => spell,
+            _ => return false,
+        };
+
+        let caster_coords = match self.npcs.get(&index) {
+            Some(npc) => npc.coords,
+            None => return false,
+        };
+
+        let target_index = self
+            .npcs
+            .iter()
+            .filter(|(npc_index, npc)| {
+                npc.alive
+                    && npc.hp < npc.max_hp
+                    && (**npc_index == index
+                        || (npc.boss && get_distance(&caster_coords, &npc.coords) <= ability.range))
+            })
+            .min_by_key(|(_, npc)| npc.get_hp_percentage())
+            .map(|(npc_index, _)| *npc_index);
+
+        let target_index = match target_index {
+            Some(target_index) => target_index,
+            None => return false,
+        };
+
+        let target = match self.npcs.get_mut(&target_index) {
+            Some(npc) => npc,
+            None => return false,
+        };
+
+        target.hp = cmp::min(target.hp + spell.hp_heal, target.max_hp);
+
+        let target_coords = target.coords;
+        self.effect_on_coords(&[target_coords], spell.graphic_id);
+        self.send_boss_ping(target_index);
+
+        true
+    }
+
+    /// Spawns up to `amount` NPCs on free tiles next to the caster
+    fn npc_summon(&mut self, index: i32, ability: &NpcAbility) -> bool {
+        if ability.npc_id < 1 {
+            return false;
+        }
+
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(ability.npc_id as usize - 1) {
+            Some(npc_data) => npc_data,
+            None => return false,
+        };
+
+        // Stationary summoners still summon NPCs that move
+        let (caster_coords, spawn_type, boss_index) = match self.npcs.get(&index) {
+            Some(npc) => (
+                npc.coords,
+                if npc.spawn_type == 7 {
+                    3
+                } else {
+                    npc.spawn_type
+                },
+                // Bosses take their summoned children with them when they die
+                (npc.boss && npc_data.child).then_some(index),
+            ),
+            None => return false,
+        };
+
+        let alive = self
+            .npcs
+            .values()
+            .filter(|npc| npc.alive && npc.spawn_index.is_none() && npc.id == ability.npc_id)
+            .count() as i32;
+
+        let amount = cmp::min(ability.amount, ability.max - alive);
+        if amount <= 0 {
+            return false;
+        }
+
+        let tiles = self
+            .get_adjacent_tiles(&caster_coords)
+            .into_iter()
+            .filter(|coords| self.is_tile_walkable_npc(coords) && !self.is_tile_occupied(coords))
+            .take(amount as usize)
+            .collect::<Vec<_>>();
+
+        // Summoned NPCs are removed once they die so their indexes get reused
+        let mut next_index = 0;
+        let mut summoned = false;
+        for coords in tiles {
+            while self.npcs.contains_key(&next_index) {
+                next_index += 1;
+            }
+
+            if next_index >= CHAR_MAX {
+                break;
+            }
+
+            self.npcs.insert(
+                next_index,
+                Npc {
+                    id: ability.npc_id,
+                    coords,
+                    direction: Direction::Down,
+                    spawn_type,
+                    spawn_index: None,
+                    alive: true,
+                    hp: npc_data.hp,
+                    max_hp: npc_data.hp,
+                    boss: npc_data.boss,
+                    child: npc_data.child,
+                    boss_index,
+                    ..Default::default()
+                },
+            );
+            summoned = true;
+        }
+
+        summoned
+    }
+}
+
+fn get_direction_towards(from: &Coords, to: &Coords) -> Direction {
+    let xdiff = to.x - from.x;
+    let ydiff = to.y - from.y;
+
+    if xdiff.abs() > ydiff.abs() {
+        if xdiff > 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        }
+    } else if ydiff > 0 {
+        Direction::Down
+    } else {
+        Direction::Up
+    }
+}
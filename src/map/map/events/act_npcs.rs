@@ -17,24 +17,20 @@ use rand::{seq::SliceRandom, Rng};
 
 use crate::{
     character::Character,
-    map::Npc,
+    map::{Npc, NpcPath},
     utils::{get_distance, get_next_coords, in_range},
-    FORMULAS, NPC_DB, SETTINGS, TALK_DB,
+    FORMULAS, NPC_ABILITIES, NPC_DB, SETTINGS, STATUS_EFFECTS, TALK_DB,
 };
 
 use super::super::Map;
+use super::act_npc_abilities::NpcAbilityUse;
 
 impl Map {
     fn act_npc_talk(&mut self, index: i32, npc_id: i32) -> Option<NpcUpdateChat> {
-        let talk_record = match TALK_DB.npcs.iter().find(|record| record.npc_id == npc_id) {
-            Some(record) => record,
-            None => return None,
-        };
+        let talk_db = TALK_DB.load();
+        let talk_record = talk_db.npcs.iter().find(|record| record.npc_id == npc_id)?;
 
-        let npc = match self.npcs.get_mut(&index) {
-            Some(npc) => npc,
-            None => return None,
-        };
+        let npc = self.npcs.get_mut(&index)?;
 
         if !npc.alive || npc.talk_ticks < SETTINGS.npcs.talk_rate {
             return None;
@@ -77,76 +73,150 @@ impl Map {
             None => return None,
         };
 
+        // No path within the chase distance (e.g. the target is behind a
+        // wall), step towards them like NPCs did before pathfinding
+        let mut path = match self.npc_chase_path(index, npc_coords, target_coords) {
+            Some(path) => path,
+            None => return self.act_npc_move_towards(index, npc_coords, target_coords),
+        };
+
+        let next_coords = *path.steps.front()?;
+
+        // Wait for whoever is in the way to move (or attack them)
+        let blocked = self.is_tile_occupied(&next_coords);
+        if !blocked {
+            path.steps.pop_front();
+        }
+
+        let npc = self.npcs.get_mut(&index)?;
+        npc.path = Some(path);
+
+        if blocked {
+            return None;
+        }
+
+        npc.direction = match (next_coords.x - npc_coords.x, next_coords.y - npc_coords.y) {
+            (0, -1) => Direction::Up,
+            (0, 1) => Direction::Down,
+            (-1, 0) => Direction::Left,
+            _ => Direction::Right,
+        };
+        npc.coords = next_coords;
+        npc.act_ticks = 0;
+
+        Some(NpcUpdatePosition {
+            npc_index: index,
+            coords: npc.coords,
+            direction: npc.direction,
+        })
+    }
+
+    /// Steps straight towards the target, then sideways, then in a random
+    /// direction, taking the first step that's free
+    fn act_npc_move_towards(
+        &mut self,
+        index: i32,
+        npc_coords: Coords,
+        target_coords: Coords,
+    ) -> Option<NpcUpdatePosition> {
         let x_delta = npc_coords.x - target_coords.x;
         let y_delta = npc_coords.y - target_coords.y;
 
-        let mut direction = if x_delta.abs() > y_delta.abs() {
-            if x_delta < 0 {
-                Direction::Right
-            } else {
-                Direction::Left
-            }
+        let horizontal = if x_delta < 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        };
+
+        let direction = if x_delta.abs() > y_delta.abs() {
+            horizontal
         } else if y_delta < 0 {
             Direction::Down
         } else {
             Direction::Up
         };
 
-        let new_coords = get_next_coords(&npc_coords, direction, self.file.width, self.file.height);
+        let sideways = if matches!(direction, Direction::Up | Direction::Down) {
+            horizontal
+        } else {
+            direction
+        };
 
-        if self.is_tile_walkable_npc(&new_coords) && !self.is_tile_occupied(&new_coords) {
-            let npc = self.npcs.get_mut(&index).unwrap();
-            npc.direction = direction;
-            npc.coords = new_coords;
-            npc.act_ticks = 0;
-            Some(NpcUpdatePosition {
-                npc_index: index,
-                coords: npc.coords,
-                direction: npc.direction,
+        let random = Direction::from(rand::thread_rng().gen_range(0..=3));
+
+        let (direction, new_coords) = [direction, sideways, random]
+            .into_iter()
+            .map(|direction| {
+                (
+                    direction,
+                    get_next_coords(&npc_coords, direction, self.file.width, self.file.height),
+                )
             })
-        } else {
-            if matches!(direction, Direction::Up | Direction::Down) {
-                direction = if x_delta < 0 {
-                    Direction::Right
-                } else {
-                    Direction::Left
-                };
-            }
-            let new_coords =
-                get_next_coords(&npc_coords, direction, self.file.width, self.file.height);
+            .find(|(_, coords)| {
+                self.is_tile_walkable_npc(coords) && !self.is_tile_occupied(coords)
+            })?;
 
-            if self.is_tile_walkable_npc(&new_coords) && !self.is_tile_occupied(&new_coords) {
-                let npc = self.npcs.get_mut(&index).unwrap();
-                npc.direction = direction;
-                npc.coords = new_coords;
-                npc.act_ticks = 0;
-                Some(NpcUpdatePosition {
-                    npc_index: index,
-                    coords: npc.coords,
-                    direction: npc.direction,
-                })
-            } else {
-                let mut rng = rand::thread_rng();
-                direction = Direction::from(rng.gen_range(0..=3));
+        let npc = self.npcs.get_mut(&index)?;
+        npc.direction = direction;
+        npc.coords = new_coords;
+        npc.act_ticks = 0;
 
-                let new_coords =
-                    get_next_coords(&npc_coords, direction, self.file.width, self.file.height);
+        Some(NpcUpdatePosition {
+            npc_index: index,
+            coords: npc.coords,
+            direction: npc.direction,
+        })
+    }
 
-                if self.is_tile_walkable_npc(&new_coords) && !self.is_tile_occupied(&new_coords) {
-                    let npc = self.npcs.get_mut(&index).unwrap();
-                    npc.direction = direction;
-                    npc.coords = new_coords;
-                    npc.act_ticks = 0;
-                    Some(NpcUpdatePosition {
-                        npc_index: index,
-                        coords: npc.coords,
-                        direction: npc.direction,
-                    })
-                } else {
-                    None
-                }
+    /// Reuses the NPC's cached path while the target hasn't moved and the
+    /// next step is still clear, otherwise finds a new one. Paths go around
+    /// players if possible and through them if not
+    fn npc_chase_path(
+        &mut self,
+        index: i32,
+        npc_coords: Coords,
+        target_coords: Coords,
+    ) -> Option<NpcPath> {
+        let cached = self.npcs.get_mut(&index)?.path.take();
+
+        if let Some(path) = cached {
+            let still_valid = path.target == target_coords
+                && path.steps.front().is_some_and(|step| {
+                    get_distance(step, &npc_coords) == 1
+                        && self.is_tile_walkable_npc(step)
+                        && !self
+                            .npcs
+                            .values()
+                            .any(|npc| npc.alive && npc.coords == *step)
+                        && (path.through_players
+                            || *step == target_coords
+                            || !self
+                                .characters
+                                .values()
+                                .any(|character| !character.hidden && character.coords == *step))
+                });
+
+            if still_valid {
+                return Some(path);
             }
         }
+
+        let chase_distance = SETTINGS.npcs.chase_distance;
+
+        if let Some(steps) = self.find_npc_path(npc_coords, target_coords, chase_distance, false) {
+            return Some(NpcPath {
+                target: target_coords,
+                steps,
+                through_players: false,
+            });
+        }
+
+        self.find_npc_path(npc_coords, target_coords, chase_distance, true)
+            .map(|steps| NpcPath {
+                target: target_coords,
+                steps,
+                through_players: true,
+            })
     }
 
     fn npc_get_chase_target_coords(&self, index: i32, npc_id: i32) -> Option<Coords> {
@@ -160,16 +230,11 @@ impl Map {
     }
 
     // TODO: Party stuff
-    fn npc_get_chase_target_player_id(&self, index: i32, npc_id: i32) -> Option<i32> {
-        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
-            Some(npc_data) => npc_data,
-            None => return None,
-        };
+    pub fn npc_get_chase_target_player_id(&self, index: i32, npc_id: i32) -> Option<i32> {
+        let npc_db = NPC_DB.load();
+        let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
-        let npc = match self.npcs.get(&index) {
-            Some(npc) => npc,
-            None => return None,
-        };
+        let npc = self.npcs.get(&index)?;
 
         if !npc.opponents.is_empty() {
             let opponents_in_range = npc.opponents.iter().filter(|opponent| {
@@ -209,10 +274,7 @@ impl Map {
     }
 
     fn npc_get_attack_target_player_id(&self, index: i32) -> Option<i32> {
-        let npc = match self.npcs.get(&index) {
-            Some(npc) => npc,
-            None => return None,
-        };
+        let npc = self.npcs.get(&index)?;
 
         let adjacent_tiles = self.get_adjacent_tiles(&npc.coords);
 
@@ -236,12 +298,27 @@ impl Map {
         if let Some(opponent) = adjacent_opponent {
             Some(opponent.player_id)
         } else {
-            let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
-                Some(npc_data) => npc_data,
-                None => return None,
-            };
+            let npc_db = NPC_DB.load();
+            let npc_data = npc_db.npcs.get(npc.id as usize - 1)?;
+
+            // Attack whoever is standing in the way of the target
+            let blocking_player_id = npc
+                .path
+                .as_ref()
+                .filter(|path| path.through_players)
+                .and_then(|path| path.steps.front())
+                .and_then(|step| {
+                    adjacent_player_ids.iter().copied().find(|player_id| {
+                        self.characters
+                            .get(player_id)
+                            .is_some_and(|character| character.coords == *step)
+                    })
+                });
+
+            if blocking_player_id.is_some() {
+                return blocking_player_id;
+            }
 
-            // TODO: also attack adjacent players if blocking path to opponent(s)
             // Choose a random player if npc is aggressive
             if npc_data.r#type == NpcType::Aggressive {
                 let mut rng = rand::thread_rng();
@@ -312,10 +389,8 @@ impl Map {
 
         let idle_rate = act_rate + walk_idle_for;
 
-        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
-            Some(npc_data) => npc_data,
-            None => return None,
-        };
+        let npc_db = NPC_DB.load();
+        let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
         if npc_data.r#type == NpcType::Aggressive || has_opponent {
             self.act_npc_move_chase(index, npc_id, npc_data.r#type)
@@ -327,26 +402,15 @@ impl Map {
     }
 
     fn act_npc_attack(&mut self, index: i32, npc_id: i32) -> Option<NpcUpdateAttack> {
-        let target_player_id = match self.npc_get_attack_target_player_id(index) {
-            Some(player_id) => player_id,
-            None => return None,
-        };
+        let target_player_id = self.npc_get_attack_target_player_id(index)?;
 
         let (damage, direction) = {
-            let character = match self.characters.get(&target_player_id) {
-                Some(character) => character,
-                None => return None,
-            };
+            let character = self.characters.get(&target_player_id)?;
 
-            let npc = match self.npcs.get(&index) {
-                Some(npc) => npc,
-                None => return None,
-            };
+            let npc = self.npcs.get(&index)?;
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
-                Some(npc_data) => npc_data,
-                None => return None,
-            };
+            let npc_db = NPC_DB.load();
+            let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
             let xdiff = npc.coords.x - character.coords.x;
             let ydiff = npc.coords.y - character.coords.y;
@@ -364,10 +428,7 @@ impl Map {
         };
 
         let (killed_state, hp_percentage) = {
-            let character = match self.characters.get_mut(&target_player_id) {
-                Some(character) => character,
-                None => return None,
-            };
+            let character = self.characters.get_mut(&target_player_id)?;
 
             character.hp -= damage;
 
@@ -418,18 +479,32 @@ impl Map {
         Option<NpcUpdateChat>,
         Option<NpcUpdateAttack>,
     ) {
-        let (npc_id, spawn_type, act_ticks) = match self.npcs.get_mut(&index) {
+        let status_effects = STATUS_EFFECTS.load();
+        let (npc_id, spawn_type, act_ticks, hp_percentage, speed) = match self.npcs.get_mut(&index)
+        {
             Some(npc) => {
-                if !npc.alive {
+                if !npc.alive || npc.status_effects.is_stunned(&status_effects) {
                     return (None, None, None);
                 } else {
                     for opponent in npc.opponents.iter_mut() {
                         opponent.bored_ticks += SETTINGS.npcs.act_rate;
                     }
 
+                    if npc.boss && !npc.opponents.is_empty() {
+                        npc.rage_ticks += SETTINGS.npcs.act_rate;
+                    } else {
+                        npc.rage_ticks = 0;
+                    }
+
                     npc.act_ticks += SETTINGS.npcs.act_rate;
                     npc.talk_ticks += SETTINGS.npcs.act_rate;
-                    (npc.id, npc.spawn_type, npc.act_ticks)
+                    (
+                        npc.id,
+                        npc.spawn_type,
+                        npc.act_ticks,
+                        npc.get_hp_percentage(),
+                        npc.status_effects.get_speed(&status_effects),
+                    )
                 }
             }
             None => return (None, None, None),
@@ -447,13 +522,30 @@ impl Map {
             _ => unreachable!("Invalid act rate {} for NPC {}", spawn_type, npc_id),
         };
 
+        // Haste and slow scale how long the NPC waits between actions
+        let act_rate = if act_rate > 0 && speed != 0 {
+            cmp::max(act_rate * 100 / cmp::max(100 + speed, 10), 1)
+        } else {
+            act_rate
+        };
+
         let talk_update = self.act_npc_talk(index, npc_id);
 
         if act_rate == 0 || act_ticks == 0 || act_ticks < act_rate {
             (None, talk_update, None)
         } else {
             self.drop_opponents(index);
-            let attack_update = self.act_npc_attack(index, npc_id);
+
+            let npc_abilities = NPC_ABILITIES.load();
+            let phase = npc_abilities.get_phase(npc_id, hp_percentage);
+
+            let ability_use = phase.and_then(|phase| self.act_npc_abilities(index, phase));
+            let attack_update = match ability_use {
+                Some(NpcAbilityUse::Attack(attack_update)) => Some(attack_update),
+                Some(NpcAbilityUse::Other) => return (None, talk_update, None),
+                None if phase.is_none_or(|phase| phase.melee) => self.act_npc_attack(index, npc_id),
+                None => None,
+            };
             let pos_update = if attack_update.is_some() {
                 None
             } else {
@@ -592,7 +684,10 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
     let mut rng = rand::thread_rng();
     let rand = rng.gen_range(0.0..=1.0);
 
-    let amount = rng.gen_range(npc_data.min_damage..=npc_data.max_damage);
+    let modifiers = npc.get_modifiers();
+    let min_damage = cmp::max(npc_data.min_damage + modifiers.min_damage, 0);
+    let max_damage = cmp::max(npc_data.max_damage + modifiers.max_damage, min_damage);
+    let amount = npc.get_rage_damage(rng.gen_range(min_damage..=max_damage), &SETTINGS.bosses);
 
     let npc_facing_player_back_or_side =
         (i32::from(character.direction) - i32::from(npc.direction)).abs() != 2;
@@ -602,7 +697,7 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
         "damage" => float amount,
         "target_armor" => float character.armor,
         "target_sitting" => character.sit_state != SitState::Stand,
-        "accuracy" => float npc_data.accuracy,
+        "accuracy" => float cmp::max(npc_data.accuracy + modifiers.accuracy, 0),
         "target_evade" => float character.evasion,
     } {
         Ok(context) => context,
@@ -612,7 +707,7 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
         }
     };
 
-    let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+    let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
         Ok(hit_rate) => hit_rate,
         Err(e) => {
             error!("Failed to calculate hit rate: {}", e);
@@ -624,7 +719,7 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
         return 0;
     }
 
-    match eval_float_with_context(&FORMULAS.damage, &context) {
+    match eval_float_with_context(&FORMULAS.load().damage, &context) {
         Ok(amount) => cmp::min(amount.floor() as i32, character.hp),
         Err(e) => {
             error!("Failed to calculate damage: {}", e);
@@ -1,4 +1,5 @@
 mod abandon_arena;
+mod act_npc_abilities;
 mod act_npcs;
 mod recover_npcs;
 mod recover_players;
@@ -13,5 +14,6 @@ mod timed_evacuate;
 mod timed_ghost;
 mod timed_quake;
 mod timed_spikes;
+mod timed_status_effects;
 mod timed_warp_suck;
 mod timed_wedding;
@@ -2,13 +2,23 @@ use super::super::Map;
 
 impl Map {
     pub fn recover_npcs(&mut self) {
-        for npc in self.npcs.values_mut() {
+        let mut healed_bosses = Vec::new();
+
+        for (index, npc) in self.npcs.iter_mut() {
             if npc.alive && npc.hp < npc.max_hp {
                 npc.hp += (npc.max_hp / 10) + 1;
                 if npc.hp > npc.max_hp {
                     npc.hp = npc.max_hp;
                 }
+
+                if npc.boss {
+                    healed_bosses.push(*index);
+                }
             }
         }
+
+        for index in healed_bosses {
+            self.send_boss_ping(index);
+        }
     }
 }
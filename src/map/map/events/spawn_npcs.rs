@@ -18,8 +18,9 @@ impl Map {
         if self.npcs.is_empty() {
             let mut npc_index: i32 = 0;
 
+            let npc_db = NPC_DB.load();
             for (spawn_index, spawn) in self.file.npcs.iter().enumerate() {
-                let data_record = match NPC_DB.npcs.get(spawn.id as usize - 1) {
+                let data_record = match npc_db.npcs.get(spawn.id as usize - 1) {
                     Some(npc) => npc,
                     None => {
                         error!(
@@ -55,13 +56,15 @@ impl Map {
                     npc_index += 1;
                 }
             }
+
+            self.link_boss_children();
         }
 
         let mut rng = rand::thread_rng();
         let indexes = self.npcs.keys().cloned().collect::<Vec<i32>>();
 
         for index in indexes {
-            let (child, alive, spawn_ticks, spawn_coords, spawn_type, npc_type) = {
+            let (boss_index, alive, spawn_ticks, spawn_coords, spawn_type, npc_type) = {
                 match self.npcs.get_mut(&index) {
                     Some(npc) => {
                         let spawn_index = match npc.spawn_index {
@@ -72,12 +75,13 @@ impl Map {
                         npc.spawn_ticks = cmp::max(npc.spawn_ticks - 1, 0);
 
                         let spawn = &self.file.npcs[spawn_index];
-                        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                        let npc_db = NPC_DB.load();
+                        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                             Some(npc_data) => npc_data,
                             None => continue,
                         };
                         (
-                            npc.child,
+                            npc.boss_index,
                             npc.alive,
                             npc.spawn_ticks,
                             spawn.coords,
@@ -89,11 +93,10 @@ impl Map {
                 }
             };
 
-            if child {
-                if let Some((_, boss)) = self.npcs.iter().find(|(_, n)| n.boss) {
-                    if !boss.alive {
-                        continue;
-                    }
+            // Children only spawn alongside their boss
+            if let Some(boss_index) = boss_index {
+                if !self.npcs.get(&boss_index).is_some_and(|boss| boss.alive) {
+                    continue;
                 }
             }
 
@@ -154,6 +157,9 @@ impl Map {
 
             npc.alive = true;
             npc.hp = npc.max_hp;
+            npc.path = None;
+            npc.opponents.clear();
+            npc.rage_ticks = 0;
             npc.coords = spawn_coords;
             npc.direction = if spawn_type == 7 {
                 Direction::from(spawn_type & 0x03)
@@ -166,6 +172,16 @@ impl Map {
                     _ => unreachable!(),
                 }
             };
+
+            if npc.boss {
+                for child in self
+                    .npcs
+                    .values_mut()
+                    .filter(|child| !child.alive && child.boss_index == Some(index))
+                {
+                    child.spawn_ticks = 0;
+                }
+            }
         }
     }
 }
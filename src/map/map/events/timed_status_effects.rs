@@ -0,0 +1,209 @@
+use std::cmp;
+
+use eolib::protocol::net::{
+    server::{
+        EffectAdminServerPacket, EffectSpecServerPacket, EffectSpecServerPacketMapDamageTypeData,
+        EffectSpecServerPacketMapDamageTypeDataSpikes, MapDamageType, NpcReplyServerPacket,
+        RecoverListServerPacket, RecoverPlayerServerPacket, WalkOpenServerPacket,
+    },
+    PacketAction, PacketFamily,
+};
+
+use crate::{status_effects::StatusEffectTick, STATUS_EFFECTS};
+
+use super::super::Map;
+
+impl Map {
+    pub async fn timed_status_effects(&mut self) {
+        let status_effects = STATUS_EFFECTS.load();
+
+        let player_ids = self
+            .characters
+            .iter()
+            .filter(|(_, character)| !character.status_effects.is_empty())
+            .map(|(player_id, _)| *player_id)
+            .collect::<Vec<_>>();
+
+        for player_id in player_ids {
+            let tick = match self.characters.get_mut(&player_id) {
+                Some(character) => character.status_effects.tick(&status_effects),
+                None => continue,
+            };
+
+            self.status_effect_tick_player(player_id, &tick);
+        }
+
+        let npc_indexes = self
+            .npcs
+            .iter()
+            .filter(|(_, npc)| npc.alive && !npc.status_effects.is_empty())
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>();
+
+        for index in npc_indexes {
+            let tick = match self.npcs.get_mut(&index) {
+                Some(npc) => {
+                    let tick = npc.status_effects.tick(&status_effects);
+                    npc.hp = cmp::min(npc.hp + tick.hp, npc.max_hp);
+                    tick
+                }
+                None => continue,
+            };
+
+            if tick.damage > 0 {
+                self.status_effect_damage_npc(index, &tick).await;
+            }
+        }
+    }
+
+    fn status_effect_tick_player(&mut self, player_id: i32, tick: &StatusEffectTick) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if tick.stats_changed {
+            character.calculate_stats();
+        }
+
+        let original_hp = character.hp;
+        let regenerated = (tick.hp > 0 || tick.tp > 0) && character.hp > 0;
+        if regenerated {
+            character.hp = cmp::min(character.hp + tick.hp, character.max_hp);
+            character.tp = cmp::min(character.tp + tick.tp, character.max_tp);
+        }
+
+        let status_effects = STATUS_EFFECTS.load();
+
+        if let Some(player) = character.player.as_ref() {
+            if tick.stats_changed {
+                player.send(
+                    PacketAction::List,
+                    PacketFamily::Recover,
+                    &RecoverListServerPacket {
+                        class_id: character.class,
+                        stats: character.get_character_stats_update(),
+                    },
+                );
+            }
+
+            if tick.stun_ended && !character.status_effects.is_stunned(&status_effects) {
+                player.send(
+                    PacketAction::Open,
+                    PacketFamily::Walk,
+                    &WalkOpenServerPacket::new(),
+                );
+            }
+
+            if regenerated {
+                player.send(
+                    PacketAction::Player,
+                    PacketFamily::Recover,
+                    &RecoverPlayerServerPacket {
+                        hp: character.hp,
+                        tp: character.tp,
+                    },
+                );
+
+                if original_hp != character.hp {
+                    player.update_party_hp(character.get_hp_percentage());
+                }
+            }
+        }
+
+        if tick.damage > 0 {
+            self.status_effect_damage_player(player_id, tick.damage);
+        }
+    }
+
+    fn status_effect_damage_player(&mut self, player_id: i32, damage: i32) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if character.hp == 0 {
+            return;
+        }
+
+        let damage = cmp::min(damage, character.hp);
+        character.hp -= damage;
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.send_packet_near_player(
+            player_id,
+            PacketAction::Admin,
+            PacketFamily::Effect,
+            &EffectAdminServerPacket {
+                player_id,
+                hp_percentage: character.get_hp_percentage(),
+                died: character.hp == 0,
+                damage,
+            },
+        );
+
+        if let Some(player) = character.player.as_ref() {
+            player.send(
+                PacketAction::Spec,
+                PacketFamily::Effect,
+                &EffectSpecServerPacket {
+                    map_damage_type: MapDamageType::Spikes,
+                    map_damage_type_data: Some(EffectSpecServerPacketMapDamageTypeData::Spikes(
+                        EffectSpecServerPacketMapDamageTypeDataSpikes {
+                            hp_damage: damage,
+                            hp: character.hp,
+                            max_hp: character.max_hp,
+                        },
+                    )),
+                },
+            );
+
+            player.update_party_hp(character.get_hp_percentage());
+
+            if character.hp == 0 {
+                player.die();
+            }
+        }
+    }
+
+    /// Damage is credited to the caster, so it only lands while they're
+    /// still on the map
+    async fn status_effect_damage_npc(&mut self, index: i32, tick: &StatusEffectTick) {
+        let (caster_id, direction) = match tick
+            .caster_id
+            .and_then(|caster_id| self.characters.get(&caster_id))
+        {
+            Some(character) => (character.player_id.unwrap_or_default(), character.direction),
+            None => return,
+        };
+
+        let npc = match self.npcs.get_mut(&index) {
+            Some(npc) => npc,
+            None => return,
+        };
+
+        let damage = npc.take_damage(caster_id, tick.damage);
+
+        if npc.alive {
+            let packet = NpcReplyServerPacket {
+                player_id: caster_id,
+                player_direction: direction,
+                npc_index: index,
+                damage,
+                hp_percentage: npc.get_hp_percentage(),
+                kill_steal_protection: None,
+            };
+
+            let coords = npc.coords;
+            self.send_packet_near(&coords, PacketAction::Reply, PacketFamily::Npc, packet);
+            self.send_boss_ping(index);
+        } else {
+            self.attack_npc_killed_reply(caster_id, index, damage, None)
+                .await;
+        }
+    }
+}
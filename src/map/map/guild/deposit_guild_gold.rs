@@ -4,10 +4,8 @@ use eolib::protocol::{
     net::{server::GuildBuyServerPacket, PacketAction, PacketFamily},
     r#pub::NpcType,
 };
-use mysql_async::{prelude::Queryable, Params};
-use mysql_common::{params, Row};
 
-use crate::{NPC_DB, SETTINGS};
+use crate::{audit::AuditEvent, NPC_DB, SETTINGS};
 
 use super::super::Map;
 
@@ -24,7 +22,7 @@ impl Map {
         };
 
         match self.npcs.get(&npc_index) {
-            Some(npc) => match NPC_DB.npcs.get(npc.id as usize - 1) {
+            Some(npc) => match NPC_DB.load().npcs.get(npc.id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -39,6 +37,14 @@ impl Map {
 
         character.remove_item(1, amount);
 
+        self.world.audit.record(
+            character,
+            AuditEvent::GuildBankDeposit {
+                guild_tag: tag.clone(),
+                amount,
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Buy,
@@ -49,28 +55,11 @@ impl Map {
             );
         }
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            let current_bank_amount = match conn
-                .exec_first::<Row, &str, Params>(
-                    "SELECT `bank` FROM Guild WHERE `tag` = :tag",
-                    params! {
-                        "tag" => &tag,
-                    },
-                )
-                .await
-            {
-                Ok(Some(row)) => row.get::<i32, usize>(0).unwrap(),
-                Ok(None) => return,
+            let current_bank_amount = match db.get_guild_bank(&tag).await {
+                Ok(amount) => amount,
                 Err(e) => {
                     error!("Error getting guild bank amount: {}", e);
                     return;
@@ -83,16 +72,7 @@ impl Map {
 
             let amount = cmp::min(SETTINGS.guild.bank_max_gold - current_bank_amount, amount);
 
-            if let Err(e) = conn
-                .exec_drop(
-                    "UPDATE Guild SET `bank` = `bank` + :amount WHERE `tag` = :tag",
-                    params! {
-                        "amount" => amount,
-                        "tag" => tag,
-                    },
-                )
-                .await
-            {
+            if let Err(e) = db.set_guild_bank(&tag, current_bank_amount + amount).await {
                 error!("Error updating guild bank: {}", e);
             }
         });
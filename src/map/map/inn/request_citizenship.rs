@@ -19,7 +19,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -28,7 +29,8 @@ impl Map {
             return;
         }
 
-        let inn_data = match INN_DB
+        let inn_db = INN_DB.load();
+        let inn_data = match inn_db
             .inns
             .iter()
             .find(|inn| inn.behavior_id == npc_data.behavior_id)
@@ -61,5 +63,9 @@ impl Map {
                 &CitizenReplyServerPacket { questions_wrong },
             );
         }
+
+        if questions_wrong == 0 {
+            character.check_quest_rules();
+        }
     }
 }
@@ -1,4 +1,5 @@
 mod jukebox_timer;
 mod open_jukebox;
+mod perform_song;
 mod play_instrument;
 mod play_jukebox_track;
@@ -0,0 +1,79 @@
+use std::cmp;
+
+use eolib::protocol::{
+    net::{server::RecoverPlayerServerPacket, PacketAction, PacketFamily},
+    r#pub::SkillType,
+};
+
+use crate::{utils::get_distance, SETTINGS, SPELL_DB};
+
+use super::super::Map;
+
+impl Map {
+    /// Heals and buffs the bard and the party members close enough to hear
+    /// them
+    pub async fn perform_song(&mut self, player_id: i32, spell_id: i32) {
+        let spell_db = SPELL_DB.load();
+        let spell = match spell_db.skills.get(spell_id as usize - 1) {
+            Some(spell) if spell.r#type == SkillType::Bard => spell,
+            _ => return,
+        };
+
+        let (coords, tp) = match self.characters.get(&player_id) {
+            Some(character) => (character.coords, character.tp),
+            None => return,
+        };
+
+        if tp < spell.tp_cost {
+            return;
+        }
+
+        let party_player_ids = match self.world.get_player_party(player_id).await {
+            Some(party) => party.members,
+            None => Vec::new(),
+        };
+
+        let mut target_player_ids = vec![player_id];
+        target_player_ids.extend(party_player_ids.into_iter().filter(|id| {
+            *id != player_id
+                && self.characters.get(id).is_some_and(|character| {
+                    get_distance(&coords, &character.coords) <= SETTINGS.bard.song_range
+                })
+        }));
+
+        if let Some(character) = self.characters.get_mut(&player_id) {
+            character.tp -= spell.tp_cost;
+        }
+
+        for target_player_id in &target_player_ids {
+            let character = match self.characters.get_mut(target_player_id) {
+                Some(character) => character,
+                None => continue,
+            };
+
+            let original_hp = character.hp;
+            character.hp = cmp::min(character.hp + spell.hp_heal, character.max_hp);
+
+            if let Some(player) = character.player.as_ref() {
+                player.send(
+                    PacketAction::Player,
+                    PacketFamily::Recover,
+                    &RecoverPlayerServerPacket {
+                        hp: character.hp,
+                        tp: character.tp,
+                    },
+                );
+
+                if character.hp != original_hp {
+                    player.update_party_hp(character.get_hp_percentage());
+                }
+            }
+        }
+
+        if spell.graphic_id > 0 {
+            self.effect_on_players(&target_player_ids, spell.graphic_id);
+        }
+
+        self.apply_spell_status_effects(player_id, spell_id, &target_player_ids);
+    }
+}
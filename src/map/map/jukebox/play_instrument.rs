@@ -1,66 +1,64 @@
+use chrono::Utc;
 use eolib::protocol::{
     net::{server::JukeboxMsgServerPacket, PacketAction, PacketFamily},
     r#pub::SkillType,
 };
 
-use crate::{ITEM_DB, SETTINGS, SPELL_DB};
+use crate::{SETTINGS, SPELL_DB};
 
 use super::super::Map;
 
 impl Map {
-    pub fn play_instrument(&mut self, player_id: i32, instrument_id: i32, note_id: i32) {
+    pub async fn play_instrument(&mut self, player_id: i32, instrument_id: i32, note_id: i32) {
         if instrument_id <= 0 || note_id <= 0 || note_id > SETTINGS.bard.max_note_id {
             return;
         }
 
-        let character = match self.characters.get(&player_id) {
+        let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
         };
 
-        if character.equipment.weapon == 0 {
+        if character.get_instrument() != Some(instrument_id) {
             return;
         }
 
-        if !SETTINGS
-            .bard
-            .instrument_items
-            .iter()
-            .any(|id| instrument_id == *id)
-        {
-            return;
-        }
-
-        match ITEM_DB.items.get(character.equipment.weapon as usize - 1) {
-            Some(item_data) => {
-                if item_data.spec1 != instrument_id {
-                    return;
-                }
-            }
-            None => return,
+        let spell_db = SPELL_DB.load();
+        let spells = &character.spells;
+        let knows_spell = |spell_id: i32| {
+            spells.iter().any(|spell| {
+                spell.id == spell_id
+                    && spell_db
+                        .skills
+                        .get(spell.id as usize - 1)
+                        .is_some_and(|spell| spell.r#type == SkillType::Bard)
+            })
         };
 
-        if !character.spells.iter().any(|s| {
-            let spell_data = match SPELL_DB.skills.get(s.id as usize - 1) {
-                Some(spell) => spell,
-                None => return false,
-            };
-
-            spell_data.r#type == SkillType::Bard
-        }) {
+        if !spells.iter().any(|spell| knows_spell(spell.id)) {
             return;
         }
 
+        let spell_id =
+            character
+                .played_notes
+                .play(note_id, Utc::now(), &SETTINGS.bard, knows_spell);
+        let direction = character.direction;
+
         self.send_packet_near_player(
             player_id,
             PacketAction::Msg,
             PacketFamily::Jukebox,
             &JukeboxMsgServerPacket {
                 player_id,
-                direction: character.direction,
+                direction,
                 instrument_id,
                 note_id,
             },
         );
+
+        if let Some(spell_id) = spell_id {
+            self.perform_song(player_id, spell_id).await;
+        }
     }
 }
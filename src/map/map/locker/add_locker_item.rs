@@ -9,12 +9,12 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::SETTINGS;
+use crate::{audit::AuditEvent, SETTINGS};
 
 use super::super::Map;
 
 impl Map {
-    pub fn add_locker_item(&mut self, player_id: i32, item: Item) {
+    pub async fn add_locker_item(&mut self, player_id: i32, item: Item) {
         if item.id <= 1 || item.amount <= 0 || item.amount > SETTINGS.limits.max_item {
             return;
         }
@@ -74,6 +74,8 @@ impl Map {
             return;
         }
 
+        let snapshot = self.snapshot_characters(&[player_id]);
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -82,6 +84,23 @@ impl Map {
         character.remove_item(item.id, amount);
         character.add_bank_item(item.id, amount);
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.world.audit.record(
+            character,
+            AuditEvent::LockerDeposit {
+                item_id: item.id,
+                amount,
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Reply,
@@ -6,10 +6,12 @@ use eolib::protocol::{
     Coords,
 };
 
+use crate::audit::AuditEvent;
+
 use super::super::Map;
 
 impl Map {
-    pub fn take_locker_item(&mut self, player_id: i32, item_id: i32) {
+    pub async fn take_locker_item(&mut self, player_id: i32, item_id: i32) {
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -51,6 +53,8 @@ impl Map {
             return;
         }
 
+        let snapshot = self.snapshot_characters(&[player_id]);
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -59,6 +63,19 @@ impl Map {
         character.remove_bank_item(item_id, amount);
         character.add_item(item_id, amount);
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.world
+            .audit
+            .record(character, AuditEvent::LockerWithdraw { item_id, amount });
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Get,
@@ -8,8 +8,6 @@ use eolib::protocol::{
     },
     r#pub::NpcType,
 };
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
 use crate::{NPC_DB, SETTINGS};
 
@@ -19,7 +17,8 @@ impl Map {
     pub fn request_divorce(&mut self, player_id: i32, npc_index: i32, name: String) {
         match self.npcs.get(&npc_index) {
             Some(npc) => {
-                let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                let npc_db = NPC_DB.load();
+                let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                     Some(npc_data) => npc_data,
                     None => return,
                 };
@@ -107,7 +106,7 @@ impl Map {
         }
 
         let world = self.world.clone();
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
             if let Ok(character) = world.get_character_by_name(&name).await {
@@ -117,23 +116,7 @@ impl Map {
                 }
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Failed to get sql connection: {}", e);
-                    return;
-                }
-            };
-
-            if let Err(e) = conn
-                .exec_drop(
-                    include_str!("../../../sql/divorce_character.sql"),
-                    params! {
-                        "name" => name
-                    },
-                )
-                .await
-            {
+            if let Err(e) = db.divorce_character(&name).await {
                 error!("Failed to divorce character: {}", e);
             }
         });
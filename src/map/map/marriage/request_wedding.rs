@@ -18,7 +18,8 @@ impl Map {
     pub fn request_wedding(&mut self, player_id: i32, npc_index: i32, name: String) {
         match self.npcs.get(&npc_index) {
             Some(npc) => {
-                let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                let npc_db = NPC_DB.load();
+                let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                     Some(npc_data) => npc_data,
                     None => return,
                 };
@@ -1,4 +1,5 @@
 mod award_experience;
+mod reload_quests;
 mod reply_to_quest_npc;
 mod talk_to_quest_npc;
 mod view_quest_history;
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use eoplus::Quest;
+
+use crate::QUEST_DB;
+
+use super::super::Map;
+
+impl Map {
+    /// Moves each character's quest progress to the state with the same name
+    /// in the reloaded quest, restarting the quest if that state was removed
+    pub fn reload_quests(&mut self, previous: &HashMap<i32, Quest>) {
+        let quest_db = QUEST_DB.load();
+
+        for character in self.characters.values_mut() {
+            for progress in character.quests.iter_mut() {
+                let (old_quest, new_quest) =
+                    match (previous.get(&progress.id), quest_db.get(&progress.id)) {
+                        (Some(old_quest), Some(new_quest)) => (old_quest, new_quest),
+                        _ => continue,
+                    };
+
+                let state_name = match old_quest.states.get(progress.state as usize) {
+                    Some(state) => &state.name,
+                    None => continue,
+                };
+
+                let state = match new_quest
+                    .states
+                    .iter()
+                    .position(|state| state.name.eq_ignore_ascii_case(state_name))
+                {
+                    Some(state) => state as i32,
+                    None => {
+                        warn!(
+                            "{}'s quest {} state \"{}\" no longer exists, restarting quest",
+                            character.name, progress.id, state_name
+                        );
+                        0
+                    }
+                };
+
+                if state != progress.state {
+                    progress.state = state;
+                    progress.npc_kills.clear();
+                    progress.player_kills = 0;
+                }
+            }
+        }
+    }
+}
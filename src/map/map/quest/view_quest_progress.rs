@@ -31,7 +31,8 @@ impl Map {
                     return None;
                 }
 
-                let quest = match QUEST_DB.get(&q.id) {
+                let quest_db = QUEST_DB.load();
+                let quest = match quest_db.get(&q.id) {
                     Some(quest) => quest,
                     None => return None,
                 };
@@ -83,8 +84,8 @@ impl Map {
                         description: state.description.to_owned(),
                         icon: QuestRequirementIcon::Kill,
                         progress: q.player_kills,
-                        target: if let Arg::Int(amount) = rule.args[1] {
-                            amount
+                        target: if let Some(Arg::Int(amount)) = rule.args.first() {
+                            *amount
                         } else {
                             0
                         },
@@ -92,7 +93,10 @@ impl Map {
                 }
 
                 if state.rules.iter().any(|rule| {
-                    rule.name == "EnterCoord" || rule.name == "EnterMap" || rule.name == "LeaveMap"
+                    rule.name == "EnterCoord"
+                        || rule.name == "LeaveCoord"
+                        || rule.name == "EnterMap"
+                        || rule.name == "LeaveMap"
                 }) {
                     return Some(QuestProgressEntry {
                         name: quest.name.to_owned(),
@@ -3,13 +3,16 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SHOP_DB};
+use crate::{
+    audit::{AuditEvent, AuditItem},
+    NPC_DB, SHOP_DB,
+};
 
 use super::super::Map;
 
 impl Map {
-    pub fn craft_item(&mut self, player_id: i32, npc_index: i32, item_id: i32) {
-        let character = match self.characters.get_mut(&player_id) {
+    pub async fn craft_item(&mut self, player_id: i32, npc_index: i32, item_id: i32) {
+        let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
         };
@@ -19,7 +22,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -28,7 +32,8 @@ impl Map {
             return;
         }
 
-        let shop = match SHOP_DB
+        let shop_db = SHOP_DB.load();
+        let shop = match shop_db
             .shops
             .iter()
             .find(|shop| shop.behavior_id == npc_data.behavior_id)
@@ -50,6 +55,14 @@ impl Map {
             }
         }
 
+        let npc_id = npc.id;
+        let snapshot = self.snapshot_characters(&[player_id]);
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
         for ingredient in craft.ingredients.iter() {
             if ingredient.item_id > 0 {
                 character.remove_item(ingredient.item_id, ingredient.amount);
@@ -58,6 +71,32 @@ impl Map {
 
         character.add_item(item_id, 1);
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.world.audit.record(
+            character,
+            AuditEvent::ShopCraft {
+                npc_id,
+                item_id,
+                ingredients: craft
+                    .ingredients
+                    .iter()
+                    .filter(|ingredient| ingredient.item_id > 0)
+                    .map(|ingredient| AuditItem {
+                        id: ingredient.item_id,
+                        amount: ingredient.amount,
+                    })
+                    .collect(),
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Create,
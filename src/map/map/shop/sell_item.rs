@@ -8,17 +8,17 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS, SHOP_DB};
+use crate::{audit::AuditEvent, NPC_DB, SETTINGS, SHOP_DB};
 
 use super::super::Map;
 
 impl Map {
-    pub fn sell_item(&mut self, player_id: i32, npc_index: i32, item: Item) {
+    pub async fn sell_item(&mut self, player_id: i32, npc_index: i32, item: Item) {
         if item.amount <= 0 || item.amount > SETTINGS.limits.max_item {
             return;
         }
 
-        let character = match self.characters.get_mut(&player_id) {
+        let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
         };
@@ -28,7 +28,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -37,7 +38,8 @@ impl Map {
             return;
         }
 
-        let shop = match SHOP_DB
+        let shop_db = SHOP_DB.load();
+        let shop = match shop_db
             .shops
             .iter()
             .find(|shop| shop.behavior_id == npc_data.behavior_id)
@@ -65,9 +67,36 @@ impl Map {
 
         let price = cmp::min(trade.sell_price * amount, SETTINGS.limits.max_item);
 
+        let npc_id = npc.id;
+        let snapshot = self.snapshot_characters(&[player_id]);
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
         character.remove_item(item.id, amount);
         character.add_item(1, price);
 
+        if !self.commit_characters(snapshot).await {
+            return;
+        }
+
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.world.audit.record(
+            character,
+            AuditEvent::ShopSell {
+                npc_id,
+                item_id: item.id,
+                amount,
+                price,
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Sell,
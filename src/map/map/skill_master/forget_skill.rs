@@ -27,7 +27,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -45,5 +46,7 @@ impl Map {
                 &StatSkillRemoveServerPacket { spell_id: skill_id },
             );
         }
+
+        character.check_quest_rules();
     }
 }
@@ -3,17 +3,20 @@ use std::cmp;
 use eolib::protocol::{
     net::{
         server::{TradeItemData, TradeUseServerPacket},
-        PacketAction, PacketFamily,
+        Item, PacketAction, PacketFamily,
     },
     Emote,
 };
 
-use crate::SETTINGS;
+use crate::{
+    audit::{AuditEvent, AuditItem},
+    SETTINGS,
+};
 
 use super::super::Map;
 
 impl Map {
-    pub fn complete_trade(&mut self, player_id: i32, partner_id: i32) {
+    pub async fn complete_trade(&mut self, player_id: i32, partner_id: i32) {
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -28,6 +31,13 @@ impl Map {
 
         let partner_trade_items = partner_character.trade_items.clone();
 
+        // Both players agreeing at once can complete the trade twice
+        if trade_items.is_empty() || partner_trade_items.is_empty() {
+            return;
+        }
+
+        let snapshot = self.snapshot_characters(&[player_id, partner_id]);
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -74,6 +84,20 @@ impl Map {
             character.add_item(item.id, amount);
         }
 
+        // Both inventories are saved together before either player sees the
+        // trade go through
+        if !self.commit_characters(snapshot).await {
+            for player_id in [player_id, partner_id] {
+                if let Some(character) = self.characters.get_mut(&player_id) {
+                    character.trade_items.clear();
+                    if let Some(player) = character.player.as_ref() {
+                        player.cancel_trade();
+                    }
+                }
+            }
+            return;
+        }
+
         let character = match self.characters.get(&player_id) {
             Some(character) => character,
             None => return,
@@ -84,6 +108,26 @@ impl Map {
             None => return,
         };
 
+        self.world.audit.record(
+            character,
+            AuditEvent::Trade {
+                partner_id: partner_character.id,
+                partner_name: partner_character.name.clone(),
+                gave: audit_items(&trade_items),
+                received: audit_items(&partner_trade_items),
+            },
+        );
+
+        self.world.audit.record(
+            partner_character,
+            AuditEvent::Trade {
+                partner_id: character.id,
+                partner_name: character.name.clone(),
+                gave: audit_items(&partner_trade_items),
+                received: audit_items(&trade_items),
+            },
+        );
+
         if let Some(player) = character.player.as_ref() {
             player.set_trading(false);
             player.set_trade_accepted(false);
@@ -132,3 +176,13 @@ impl Map {
         self.emote(partner_id, Emote::Trade);
     }
 }
+
+fn audit_items(items: &[Item]) -> Vec<AuditItem> {
+    items
+        .iter()
+        .map(|item| AuditItem {
+            id: item.id,
+            amount: item.amount,
+        })
+        .collect()
+}
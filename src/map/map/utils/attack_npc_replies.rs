@@ -1,28 +1,19 @@
-use eolib::{
-    data::{EoSerialize, EoWriter},
-    protocol::{
-        net::{
-            server::{
-                AttackPlayerServerPacket, CastAcceptServerPacket, CastReplyServerPacket,
-                CastSpecServerPacket, LevelUpStats, NpcAcceptServerPacket, NpcJunkServerPacket,
-                NpcKillStealProtectionState, NpcKilledData, NpcReplyServerPacket,
-                NpcSpecServerPacket, PartyExpShare, RecoverReplyServerPacket,
-                RecoverTargetGroupServerPacket,
-            },
-            PacketAction, PacketFamily,
+use eolib::protocol::{
+    net::{
+        server::{
+            AttackPlayerServerPacket, CastAcceptServerPacket, CastReplyServerPacket,
+            CastSpecServerPacket, ItemAddServerPacket, LevelUpStats, NpcAcceptServerPacket,
+            NpcKillStealProtectionState, NpcKilledData, NpcReplyServerPacket, NpcSpecServerPacket,
+            PartyExpShare, RecoverReplyServerPacket, RecoverTargetGroupServerPacket,
         },
-        Coords, Direction,
+        PacketAction, PacketFamily,
     },
+    Coords, Direction,
 };
 use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
 use rand::Rng;
 
-use crate::{
-    deep::{BossPingServerPacket, FAMILY_BOSS},
-    map::Item,
-    utils::in_client_range,
-    DROP_DB, FORMULAS, NPC_DB, SETTINGS,
-};
+use crate::{map::Item, DROP_DB, FORMULAS, NPC_DB, SETTINGS};
 
 use super::super::Map;
 
@@ -135,38 +126,7 @@ impl Map {
             );
         }
 
-        if npc.boss {
-            let packet = BossPingServerPacket {
-                npc_index,
-                npc_id: npc.id,
-                hp: npc.hp,
-                hp_percentage: npc.get_hp_percentage(),
-                killed: false,
-            };
-
-            let mut writer = EoWriter::new();
-
-            if let Err(e) = packet.serialize(&mut writer) {
-                error!("Failed to serialize BossPingServerPacket: {}", e);
-                return;
-            }
-
-            let buf = writer.to_byte_array();
-
-            for player in self.characters.values().filter_map(|c| {
-                if c.is_deep && in_client_range(&c.coords, &npc.coords) {
-                    c.player.as_ref()
-                } else {
-                    None
-                }
-            }) {
-                player.send_buf(
-                    PacketAction::Ping,
-                    PacketFamily::Unrecognized(FAMILY_BOSS),
-                    buf.clone(),
-                );
-            }
-        }
+        self.send_boss_ping(npc_index);
     }
 
     pub async fn attack_npc_killed_reply(
@@ -181,7 +141,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -209,7 +170,7 @@ impl Map {
                     }
                 };
 
-                match eval_float_with_context(&FORMULAS.party_exp_share, &context) {
+                match eval_float_with_context(&FORMULAS.load().party_exp_share, &context) {
                     Ok(experience) => experience as i32,
                     Err(e) => {
                         error!("Failed to calculate party experience share: {}", e);
@@ -243,9 +204,23 @@ impl Map {
             });
         }
 
-        let drop = get_drop(killer_player_id, npc_id, &npc_coords);
+        let looters = match self.npcs.get_mut(&npc_index) {
+            Some(npc) => {
+                let looters = npc.get_looters(killer_player_id, &SETTINGS.bosses);
+                npc.opponents.clear();
+                npc.rage_ticks = 0;
+                looters
+            }
+            None => return,
+        };
+
+        let mut drops = looters
+            .into_iter()
+            .filter_map(|player_id| get_drop(player_id, npc_id, &npc_coords))
+            .collect::<Vec<_>>()
+            .into_iter();
 
-        let (drop_index, drop_item_id, drop_amount) = match drop {
+        let (drop_index, drop_item_id, drop_amount) = match drops.next() {
             Some(drop) => {
                 let index = self.get_next_item_index(1);
                 let drop_item_id = drop.id;
@@ -256,6 +231,20 @@ impl Map {
             None => (0, 0, 0),
         };
 
+        // Only the first drop fits in the kill packet, the rest appear
+        // like dropped items
+        for drop in drops {
+            let index = self.get_next_item_index(1);
+            let packet = ItemAddServerPacket {
+                item_id: drop.id,
+                item_index: index,
+                item_amount: drop.amount,
+                coords: npc_coords,
+            };
+            self.items.insert(index, drop);
+            self.send_packet_near(&npc_coords, PacketAction::Add, PacketFamily::Item, packet);
+        }
+
         let killer = match self.characters.get(&killer_player_id) {
             Some(character) => character,
             None => return,
@@ -400,59 +389,8 @@ impl Map {
         }
 
         if is_boss {
-            self.npcs
-                .iter_mut()
-                .filter(|(_, n)| n.child)
-                .for_each(|(_, child)| {
-                    child.alive = false;
-                    child.hp = 0;
-                    child.opponents.clear();
-
-                    if child.spawn_index.is_some() {
-                        child.spawn_ticks = child.spawn_time;
-                    }
-                });
-
-            if let Some((_, child_npc)) = self.npcs.iter().find(|(_, n)| n.child) {
-                self.send_packet_all(
-                    PacketAction::Junk,
-                    PacketFamily::Npc,
-                    NpcJunkServerPacket {
-                        npc_id: child_npc.id,
-                    },
-                );
-            }
-
-            let packet = BossPingServerPacket {
-                npc_index,
-                npc_id,
-                hp: 0,
-                hp_percentage: 0,
-                killed: true,
-            };
-
-            let mut writer = EoWriter::new();
-
-            if let Err(e) = packet.serialize(&mut writer) {
-                error!("Failed to serialize BossPingServerPacket: {}", e);
-                return;
-            }
-
-            let buf = writer.to_byte_array();
-
-            for player in self.characters.values().filter_map(|c| {
-                if c.is_deep && in_client_range(&c.coords, &npc_coords) {
-                    c.player.as_ref()
-                } else {
-                    None
-                }
-            }) {
-                player.send_buf(
-                    PacketAction::Ping,
-                    PacketFamily::Unrecognized(FAMILY_BOSS),
-                    buf.clone(),
-                );
-            }
+            self.despawn_boss_children(npc_index);
+            self.send_boss_ping(npc_index);
         }
 
         for gain in &exp_gains {
@@ -460,6 +398,12 @@ impl Map {
                 character.killed_npc(npc_id);
             }
         }
+
+        if let Some(character) = self.characters.get(&killer_player_id) {
+            if let Some(player) = character.player.as_ref() {
+                player.add_npc_kill(character.coords);
+            }
+        }
     }
 
     fn attack_npc_killed_leveled_up_party_reply(
@@ -512,10 +456,10 @@ impl Map {
 }
 
 fn get_drop(target_player_id: i32, npc_id: i32, npc_coords: &Coords) -> Option<Item> {
-    if let Some(drop_npc) = DROP_DB.npcs.iter().find(|d| d.npc_id == npc_id) {
+    if let Some(drop_npc) = DROP_DB.load().npcs.iter().find(|d| d.npc_id == npc_id) {
         let mut rng = rand::thread_rng();
         let mut drops = drop_npc.drops.clone();
-        drops.sort_by(|a, b| a.rate.cmp(&b.rate));
+        drops.sort_by_key(|a| a.rate);
 
         for drop in drops {
             let roll = rng.gen_range(0..=64000);
@@ -0,0 +1,46 @@
+use crate::character::Character;
+
+use super::super::Map;
+
+impl Map {
+    /// Copies of characters taken before an economy change so it can be
+    /// undone if saving it fails
+    pub fn snapshot_characters(&self, player_ids: &[i32]) -> Vec<(i32, Character)> {
+        player_ids
+            .iter()
+            .filter_map(|player_id| {
+                self.characters
+                    .get(player_id)
+                    .map(|character| (*player_id, character.clone()))
+            })
+            .collect()
+    }
+
+    /// Saves the characters in `snapshot` as they are now in a single
+    /// transaction. If the save fails they are put back the way they were
+    /// when the snapshot was taken and false is returned
+    pub async fn commit_characters(&mut self, snapshot: Vec<(i32, Character)>) -> bool {
+        // Characters that were never created have no rows to update
+        let characters = snapshot
+            .iter()
+            .filter_map(|(player_id, _)| self.characters.get(player_id))
+            .filter(|character| character.id > 0)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if characters.is_empty() {
+            return true;
+        }
+
+        match self.db.update_characters(&characters).await {
+            Ok(()) => true,
+            Err(e) => {
+                error!("Failed to save characters: {}", e);
+                for (player_id, character) in snapshot {
+                    self.characters.insert(player_id, character);
+                }
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+use eolib::protocol::Coords;
+
+use crate::utils::get_distance;
+
+use super::super::Map;
+
+impl Map {
+    /// A* search for the shortest walk from `from` to `to` that never leaves
+    /// `max_distance` of `to`.
+    ///
+    /// The steps exclude `from` and end on `to`, which is always considered
+    /// passable. Tiles with players on them are only passable when
+    /// `through_players` is set
+    pub fn find_npc_path(
+        &self,
+        from: Coords,
+        to: Coords,
+        max_distance: i32,
+        through_players: bool,
+    ) -> Option<VecDeque<Coords>> {
+        if from == to {
+            return Some(VecDeque::new());
+        }
+
+        // Coords isn't hashable so tiles are keyed by (x, y)
+        let mut occupied: HashSet<(i32, i32)> = self
+            .npcs
+            .values()
+            .filter(|npc| npc.alive)
+            .map(|npc| (npc.coords.x, npc.coords.y))
+            .collect();
+
+        if !through_players {
+            occupied.extend(
+                self.characters
+                    .values()
+                    .filter(|character| !character.hidden)
+                    .map(|character| (character.coords.x, character.coords.y)),
+            );
+        }
+
+        let mut came_from: HashMap<(i32, i32), Coords> = HashMap::new();
+        let mut cost: HashMap<(i32, i32), i32> = HashMap::from([((from.x, from.y), 0)]);
+        // Ties on estimated cost go to the tile nearest the goal
+        let mut open = BinaryHeap::from([Reverse((
+            get_distance(&from, &to),
+            get_distance(&from, &to),
+            from.x,
+            from.y,
+        ))]);
+
+        while let Some(Reverse((_, _, x, y))) = open.pop() {
+            let current = Coords { x, y };
+            if current == to {
+                let mut steps = VecDeque::new();
+                let mut step = current;
+                while step != from {
+                    steps.push_front(step);
+                    step = came_from[&(step.x, step.y)];
+                }
+                return Some(steps);
+            }
+
+            let next_cost = cost[&(x, y)] + 1;
+
+            for next in self.get_adjacent_tiles(&current) {
+                if get_distance(&next, &to) > max_distance
+                    || (next != to
+                        && (occupied.contains(&(next.x, next.y))
+                            || !self.is_tile_walkable_npc(&next)))
+                {
+                    continue;
+                }
+
+                let key = (next.x, next.y);
+                if cost.get(&key).is_some_and(|&known| known <= next_cost) {
+                    continue;
+                }
+
+                cost.insert(key, next_cost);
+                came_from.insert(key, current);
+
+                let remaining = get_distance(&next, &to);
+                open.push(Reverse((next_cost + remaining, remaining, next.x, next.y)));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,15 @@
+use tokio::sync::oneshot;
+
+use crate::map::MapStats;
+
+use super::super::Map;
+
+impl Map {
+    pub fn get_stats(&self, respond_to: oneshot::Sender<MapStats>) {
+        let _ = respond_to.send(MapStats {
+            players: self.characters.len(),
+            npcs: self.npcs.values().filter(|npc| npc.alive).count(),
+            items: self.items.len(),
+        });
+    }
+}
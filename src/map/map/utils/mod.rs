@@ -1,11 +1,14 @@
 mod attack_npc_replies;
+mod commit_characters;
 mod create_chests;
 pub use create_chests::create_chests;
+mod find_npc_path;
 mod get_adjacent_tiles;
 mod get_character;
 mod get_nearby_info;
 mod get_next_item_index;
 mod get_rid_and_size;
+mod get_stats;
 mod get_tile;
 mod get_warp;
 mod give_experience;
@@ -13,12 +16,15 @@ mod give_item;
 mod is_in_bounds;
 mod is_tile_occupied;
 mod is_tile_walkable;
+pub use is_tile_walkable::is_tile_spec_walkable;
 mod is_tile_walkable_npc;
 mod npc_chat;
 mod player_chat;
 mod player_in_range_of_tile;
+mod refresh_avatar;
 mod reload;
 mod save;
+mod send_boss_ping;
 mod send_packet_all;
 mod send_packet_near;
 mod send_packet_near_exclude_player;
@@ -0,0 +1,42 @@
+use eolib::protocol::net::{
+    server::{AvatarRemoveServerPacket, NearbyInfo, PlayersAgreeServerPacket},
+    PacketAction, PacketFamily,
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn refresh_avatar(&self, player_id: i32) {
+        let character = match self.characters.get(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if character.hidden {
+            return;
+        }
+
+        self.send_packet_near(
+            &character.coords,
+            PacketAction::Remove,
+            PacketFamily::Avatar,
+            AvatarRemoveServerPacket {
+                player_id,
+                warp_effect: None,
+            },
+        );
+
+        self.send_packet_near(
+            &character.coords,
+            PacketAction::Agree,
+            PacketFamily::Players,
+            PlayersAgreeServerPacket {
+                nearby: NearbyInfo {
+                    characters: vec![character.to_map_info()],
+                    npcs: Vec::default(),
+                    items: Vec::default(),
+                },
+            },
+        );
+    }
+}
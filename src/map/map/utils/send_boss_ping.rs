@@ -0,0 +1,40 @@
+use eolib::protocol::net::{PacketAction, PacketFamily};
+
+use crate::{
+    deep::{BossPingServerPacket, FAMILY_BOSS},
+    utils::in_client_range,
+};
+
+use super::super::Map;
+
+impl Map {
+    /// Sends a boss's HP to nearby deep clients
+    pub fn send_boss_ping(&self, npc_index: i32) {
+        let npc = match self.npcs.get(&npc_index) {
+            Some(npc) if npc.boss => npc,
+            _ => return,
+        };
+
+        let packet = BossPingServerPacket {
+            npc_index,
+            npc_id: npc.id,
+            hp: npc.hp,
+            hp_percentage: npc.get_hp_percentage(),
+            killed: !npc.alive,
+        };
+
+        for player in self.characters.values().filter_map(|c| {
+            if c.is_deep && in_client_range(&c.coords, &npc.coords) {
+                c.player.as_ref()
+            } else {
+                None
+            }
+        }) {
+            player.send(
+                PacketAction::Ping,
+                PacketFamily::Unrecognized(FAMILY_BOSS),
+                &packet,
+            );
+        }
+    }
+}
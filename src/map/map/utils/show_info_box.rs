@@ -86,7 +86,7 @@ impl Map {
                     spaces += 1;
                 }
 
-                content.extend(std::iter::repeat(' ').take(spaces));
+                content.extend(std::iter::repeat_n(' ', spaces));
                 content_line.clear();
             };
 
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, sync::Arc};
+
 use bytes::Bytes;
 use eolib::protocol::{
     map::Emf,
@@ -8,32 +10,34 @@ use eolib::protocol::{
     },
     Coords, Direction, Emote,
 };
-use mysql_async::Pool;
-use tokio::sync::{
-    mpsc::{self, UnboundedSender},
-    oneshot,
-};
+use eoplus::Quest;
+use tokio::sync::{mpsc, oneshot};
 
 use crate::{
     character::{Character, SpellTarget},
+    db::Db,
+    metrics::{self, QueueSender},
     player::PartyRequest,
     world::WorldHandle,
 };
 
-use super::{Command, Map};
+use super::{Command, Map, MapStats};
 
 #[derive(Debug, Clone)]
 pub struct MapHandle {
-    tx: UnboundedSender<Command>,
+    tx: QueueSender<Command>,
 }
 
 impl MapHandle {
-    pub fn new(id: i32, file_size: i32, pool: Pool, file: Emf, world: WorldHandle) -> Self {
+    pub fn new(id: i32, file_size: i32, db: Db, file: Emf, world: WorldHandle) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        let map = Map::new(id, file_size, file, pool, world, rx);
-        tokio::spawn(run_map(map));
+        let map = Map::new(id, file_size, file, db, world, rx);
+        tokio::spawn(run_map(id, map));
 
-        Self { tx }
+        let depth = metrics::MAP_MAILBOX_DEPTH.with_label_values(&[&id.to_string()]);
+        Self {
+            tx: QueueSender::new(tx, depth),
+        }
     }
 
     pub fn accept_guild_creation_request(&self, player_id: i32, invitee_player_id: i32) {
@@ -307,6 +311,12 @@ impl MapHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_stats(&self) -> MapStats {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetStats { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub fn award_experience(&self, player_id: i32, amount: i32) {
         let _ = self.tx.send(Command::AwardExperience { player_id, amount });
     }
@@ -323,14 +333,40 @@ impl MapHandle {
         let _ = self.tx.send(Command::GiveKarma { player_id, amount });
     }
 
+    pub fn give_spell(&self, player_id: i32, spell_id: i32) {
+        let _ = self.tx.send(Command::GiveSpell {
+            player_id,
+            spell_id,
+        });
+    }
+
+    pub fn give_stat(&self, player_id: i32, stat: String, amount: i32) {
+        let _ = self.tx.send(Command::GiveStat {
+            player_id,
+            stat,
+            amount,
+        });
+    }
+
     pub fn remove_karma(&self, player_id: i32, amount: i32) {
         let _ = self.tx.send(Command::RemoveKarma { player_id, amount });
     }
 
+    pub fn remove_spell(&self, player_id: i32, spell_id: i32) {
+        let _ = self.tx.send(Command::RemoveSpell {
+            player_id,
+            spell_id,
+        });
+    }
+
     pub fn reload(&self, file: Box<Emf>, file_size: i32) {
         let _ = self.tx.send(Command::Reload { file, file_size });
     }
 
+    pub fn reload_quests(&self, previous: Arc<HashMap<i32, Quest>>) {
+        let _ = self.tx.send(Command::ReloadQuests { previous });
+    }
+
     pub fn lose_item(&self, player_id: i32, item_id: i32, amount: i32) {
         let _ = self.tx.send(Command::LoseItem {
             player_id,
@@ -748,6 +784,14 @@ impl MapHandle {
         });
     }
 
+    pub fn set_stat(&self, player_id: i32, stat: String, value: i32) {
+        let _ = self.tx.send(Command::SetStat {
+            player_id,
+            stat,
+            value,
+        });
+    }
+
     pub fn spawn_items(&self) {
         let _ = self.tx.send(Command::SpawnItems);
     }
@@ -836,6 +880,10 @@ impl MapHandle {
         let _ = self.tx.send(Command::TimedGhost);
     }
 
+    pub fn timed_status_effects(&self) {
+        let _ = self.tx.send(Command::TimedStatusEffects);
+    }
+
     pub fn timed_auto_pickup(&self) {
         let _ = self.tx.send(Command::TimedAutoPickup);
     }
@@ -947,10 +995,17 @@ impl MapHandle {
     }
 }
 
-async fn run_map(mut map: Map) {
+async fn run_map(id: i32, mut map: Map) {
+    let label = id.to_string();
+    let depth = metrics::MAP_MAILBOX_DEPTH.with_label_values(&[&label]);
+    let duration = metrics::MAP_COMMAND_SECONDS.with_label_values(&[&label]);
+
     loop {
         if let Some(command) = map.rx.recv().await {
+            depth.dec();
+            let timer = duration.start_timer();
             map.handle_command(command).await;
+            timer.observe_duration();
         }
     }
 }
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MapStats {
+    pub players: usize,
+    pub npcs: usize,
+    pub items: usize,
+}
@@ -8,10 +8,14 @@ mod item;
 pub use item::Item;
 #[allow(clippy::module_inception)]
 mod map;
-pub use map::Map;
+pub use map::{is_tile_spec_walkable, Map};
 mod npc;
-pub use npc::{NPCBuilder, Npc};
+#[cfg(test)]
+pub use npc::NpcOpponent;
+pub use npc::{NPCBuilder, Npc, NpcPath};
 mod map_handle;
 pub use map_handle::MapHandle;
+mod map_stats;
+pub use map_stats::MapStats;
 mod wedding;
 pub use wedding::{Wedding, WeddingState};
@@ -1,10 +1,14 @@
-use std::cmp;
+use std::{cmp, collections::VecDeque};
 
 use eolib::protocol::{net::server::NpcMapInfo, Coords, Direction};
 use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
 use rand::Rng;
 
-use crate::{FORMULAS, NPC_DB};
+use crate::{
+    settings::{BossLootRule, Bosses},
+    status_effects::{ActiveStatusEffects, StatModifiers},
+    FORMULAS, NPC_DB, STATUS_EFFECTS,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct Npc {
@@ -22,8 +26,23 @@ pub struct Npc {
     pub hp: i32,
     pub max_hp: i32,
     pub opponents: Vec<NpcOpponent>,
+    pub path: Option<NpcPath>,
     pub boss: bool,
     pub child: bool,
+    /// Index of the boss a child spawns and dies with
+    pub boss_index: Option<i32>,
+    /// Ticks a boss has been fighting without losing all of its opponents
+    pub rage_ticks: i32,
+    pub status_effects: ActiveStatusEffects,
+}
+
+/// Cached route to where the chase target stood when it was found
+#[derive(Debug, Default, Clone)]
+pub struct NpcPath {
+    pub target: Coords,
+    pub steps: VecDeque<Coords>,
+    /// Found by walking through players because there was no way around them
+    pub through_players: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -39,6 +58,59 @@ impl Npc {
         percent.floor() as i32
     }
 
+    pub fn is_enraged(&self, rules: &Bosses) -> bool {
+        self.boss && rules.rage_timer > 0 && self.rage_ticks >= rules.rage_timer
+    }
+
+    /// Scales damage dealt by an enraged boss
+    pub fn get_rage_damage(&self, amount: i32, rules: &Bosses) -> i32 {
+        if self.is_enraged(rules) {
+            (amount as f32 * rules.rage_damage).floor() as i32
+        } else {
+            amount
+        }
+    }
+
+    /// Players who get a roll at the NPC's drops. Bosses share them between
+    /// their top damage dealers depending on `rules`, everything else only
+    /// drops for the killer
+    pub fn get_looters(&self, killer_player_id: i32, rules: &Bosses) -> Vec<i32> {
+        if !self.boss {
+            return vec![killer_player_id];
+        }
+
+        let limit = match rules.loot_rule {
+            BossLootRule::Killer => return vec![killer_player_id],
+            BossLootRule::TopDamage => 1,
+            BossLootRule::Split => cmp::max(rules.loot_players, 1) as usize,
+        };
+
+        let min_damage = self.max_hp * rules.loot_min_damage / 100;
+
+        let mut opponents = self
+            .opponents
+            .iter()
+            .filter(|opponent| opponent.damage_dealt >= min_damage)
+            .collect::<Vec<_>>();
+        opponents.sort_by_key(|opponent| cmp::Reverse(opponent.damage_dealt));
+
+        let looters = opponents
+            .into_iter()
+            .take(limit)
+            .map(|opponent| opponent.player_id)
+            .collect::<Vec<_>>();
+
+        if looters.is_empty() {
+            vec![killer_player_id]
+        } else {
+            looters
+        }
+    }
+
+    pub fn get_modifiers(&self) -> StatModifiers {
+        self.status_effects.get_modifiers(&STATUS_EFFECTS.load())
+    }
+
     pub fn to_map_info(&self, index: &i32) -> NpcMapInfo {
         NpcMapInfo {
             index: *index,
@@ -49,20 +121,23 @@ impl Npc {
     }
 
     pub fn damage(&mut self, player_id: i32, amount: i32, accuracy: i32, critical: bool) -> i32 {
-        let npc_data = match NPC_DB.npcs.get(self.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(self.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => {
                 return 0;
             }
         };
 
+        let modifiers = self.get_modifiers();
+
         let context: HashMapContext<DefaultNumericTypes> = match context_map! {
             "critical" => critical,
             "damage" => float amount,
-            "target_armor" => float npc_data.armor,
+            "target_armor" => float cmp::max(npc_data.armor + modifiers.armor, 0),
             "target_sitting" => false,
             "accuracy" => float accuracy,
-            "target_evade" => float npc_data.evade,
+            "target_evade" => float cmp::max(npc_data.evade + modifiers.evade, 0),
         } {
             Ok(context) => context,
             Err(e) => {
@@ -71,7 +146,7 @@ impl Npc {
             }
         };
 
-        let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+        let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
             Ok(hit_rate) => hit_rate,
             Err(e) => {
                 error!("Failed to calculate hit rate: {}", e);
@@ -85,7 +160,7 @@ impl Npc {
         let damage = if hit_rate < rand {
             0
         } else {
-            match eval_float_with_context(&FORMULAS.damage, &context) {
+            match eval_float_with_context(&FORMULAS.load().damage, &context) {
                 Ok(amount) => amount.floor() as i32,
                 Err(e) => {
                     error!("Failed to calculate damage: {}", e);
@@ -94,25 +169,32 @@ impl Npc {
             }
         };
 
-        self.hp -= cmp::min(damage, self.hp) as i32;
-        if self.hp > 0 {
-            match self.opponents.iter().position(|o| o.player_id == player_id) {
-                Some(index) => {
-                    let opponent = self.opponents.get_mut(index).unwrap();
-                    opponent.damage_dealt += damage;
-                    opponent.bored_ticks = 0;
-                }
-                None => {
-                    self.opponents.push(NpcOpponent {
-                        player_id,
-                        damage_dealt: damage,
-                        bored_ticks: 0,
-                    });
-                }
+        self.take_damage(player_id, damage)
+    }
+
+    /// Takes `damage` HP on behalf of `player_id`
+    pub fn take_damage(&mut self, player_id: i32, damage: i32) -> i32 {
+        self.hp -= cmp::min(damage, self.hp);
+
+        // Opponents are kept after death so the killing blow can share loot
+        match self.opponents.iter().position(|o| o.player_id == player_id) {
+            Some(index) => {
+                let opponent = self.opponents.get_mut(index).unwrap();
+                opponent.damage_dealt += damage;
+                opponent.bored_ticks = 0;
             }
-        } else {
+            None => {
+                self.opponents.push(NpcOpponent {
+                    player_id,
+                    damage_dealt: damage,
+                    bored_ticks: 0,
+                });
+            }
+        }
+
+        if self.hp == 0 {
             self.alive = false;
-            self.opponents.clear();
+            self.status_effects = ActiveStatusEffects::default();
 
             if self.spawn_index.is_some() {
                 self.spawn_ticks = self.spawn_time;
@@ -223,8 +305,12 @@ impl NPCBuilder {
             hp: self.hp,
             max_hp: self.max_hp,
             opponents: Vec::new(),
+            path: None,
             boss: self.boss,
             child: self.child,
+            boss_index: None,
+            rage_ticks: 0,
+            status_effects: ActiveStatusEffects::default(),
         }
     }
 }
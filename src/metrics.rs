@@ -0,0 +1,205 @@
+use std::{panic::Location, path::Path};
+
+use axum::{http::header, response::IntoResponse, routing::get, Router};
+use eolib::protocol::net::{PacketAction, PacketFamily};
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram, register_histogram_vec, register_int_counter_vec, register_int_gauge,
+    register_int_gauge_vec, Encoder, Histogram, HistogramTimer, HistogramVec, IntCounterVec,
+    IntGauge, IntGaugeVec, TextEncoder,
+};
+use tokio::{
+    net::TcpListener,
+    sync::mpsc::{error::SendError, UnboundedSender},
+};
+
+use crate::SETTINGS;
+
+lazy_static! {
+    pub static ref WORLD_TICK_SECONDS: Histogram = register_histogram!(
+        "reoserv_world_tick_seconds",
+        "Time spent handling a world tick",
+        vec![0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.125, 0.25]
+    )
+    .unwrap();
+    pub static ref WORLD_MAILBOX_DEPTH: IntGauge = register_int_gauge!(
+        "reoserv_world_mailbox_depth",
+        "Commands waiting in the world actor's queue"
+    )
+    .unwrap();
+    pub static ref MAP_COMMAND_SECONDS: HistogramVec = register_histogram_vec!(
+        "reoserv_map_command_seconds",
+        "Time spent handling a single map command",
+        &["map"],
+        vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.125, 0.5]
+    )
+    .unwrap();
+    pub static ref MAP_MAILBOX_DEPTH: IntGaugeVec = register_int_gauge_vec!(
+        "reoserv_map_mailbox_depth",
+        "Commands waiting in a map actor's queue",
+        &["map"]
+    )
+    .unwrap();
+    pub static ref PACKETS_RECEIVED: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packets_received_total",
+        "Packets received from clients",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref PACKET_BYTES_RECEIVED: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packet_bytes_received_total",
+        "Bytes received from clients",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref PACKETS_SENT: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packets_sent_total",
+        "Packets sent to clients",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref PACKET_BYTES_SENT: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packet_bytes_sent_total",
+        "Bytes sent to clients",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref PACKETS_RATE_LIMITED: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packets_rate_limited_total",
+        "Packets dropped by the limits in PacketRateLimits.ron",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref SAVE_SECONDS: Histogram = register_histogram!(
+        "reoserv_save_seconds",
+        "Time taken to save all maps and characters",
+        vec![0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]
+    )
+    .unwrap();
+    pub static ref DB_QUERY_SECONDS: HistogramVec = register_histogram_vec!(
+        "reoserv_db_query_seconds",
+        "Time spent on a storage call, including waiting for a connection",
+        &["storage"],
+        vec![0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 5.0]
+    )
+    .unwrap();
+    pub static ref CONNECTIONS: IntGauge =
+        register_int_gauge!("reoserv_connections", "Open client connections").unwrap();
+    pub static ref PLAYERS_ONLINE: IntGauge = register_int_gauge!(
+        "reoserv_players_online",
+        "Characters in game, refreshed every second"
+    )
+    .unwrap();
+}
+
+/// An actor's command sender that counts the commands waiting in its queue.
+/// The count goes up as commands are sent rather than when the actor gets to
+/// them, so it keeps climbing while the actor is stuck on a command
+pub struct QueueSender<T> {
+    tx: UnboundedSender<T>,
+    depth: IntGauge,
+}
+
+impl<T> QueueSender<T> {
+    pub fn new(tx: UnboundedSender<T>, depth: IntGauge) -> Self {
+        Self { tx, depth }
+    }
+
+    pub fn send(&self, command: T) -> Result<(), SendError<T>> {
+        self.depth.inc();
+        let result = self.tx.send(command);
+        if result.is_err() {
+            self.depth.dec();
+        }
+        result
+    }
+}
+
+impl<T> Clone for QueueSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            depth: self.depth.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for QueueSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueueSender")
+            .field("depth", &self.depth.get())
+            .finish()
+    }
+}
+
+pub fn record_packet_received(family: PacketFamily, action: PacketAction, bytes: usize) {
+    let (family, action) = (format!("{:?}", family), format!("{:?}", action));
+    PACKETS_RECEIVED
+        .with_label_values(&[&family, &action])
+        .inc();
+    PACKET_BYTES_RECEIVED
+        .with_label_values(&[&family, &action])
+        .inc_by(bytes as u64);
+}
+
+pub fn record_packet_sent(family: PacketFamily, action: PacketAction, bytes: usize) {
+    let (family, action) = (format!("{:?}", family), format!("{:?}", action));
+    PACKETS_SENT.with_label_values(&[&family, &action]).inc();
+    PACKET_BYTES_SENT
+        .with_label_values(&[&family, &action])
+        .inc_by(bytes as u64);
+}
+
+pub fn record_rate_limited(family: PacketFamily, action: PacketAction) {
+    PACKETS_RATE_LIMITED
+        .with_label_values(&[&format!("{:?}", family), &format!("{:?}", action)])
+        .inc();
+}
+
+/// Starts timing a storage call, labelled with the name of the file it's made
+/// from (`character`, `ban`, ...). The time is recorded when the timer drops
+#[track_caller]
+pub fn start_db_timer() -> HistogramTimer {
+    let storage = Path::new(Location::caller().file())
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("unknown");
+    DB_QUERY_SECONDS.with_label_values(&[storage]).start_timer()
+}
+
+/// Renders every registered metric in the Prometheus text format
+pub fn render() -> String {
+    let mut buf = Vec::new();
+    if let Err(e) = TextEncoder::new().encode(&prometheus::gather(), &mut buf) {
+        error!("Failed to encode metrics: {}", e);
+    }
+    String::from_utf8(buf).unwrap_or_default()
+}
+
+pub async fn serve() {
+    let addr = format!("{}:{}", SETTINGS.metrics.host, SETTINGS.metrics.port);
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind metrics endpoint to {}: {}", addr, e);
+            return;
+        }
+    };
+
+    info!("metrics listening at {}/metrics", addr);
+
+    let app = Router::new().route(
+        "/metrics",
+        get(|| async {
+            (
+                [(header::CONTENT_TYPE, TextEncoder::new().format_type())],
+                render(),
+            )
+                .into_response()
+        }),
+    );
+
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("Metrics endpoint stopped: {}", e);
+    }
+}
@@ -0,0 +1,87 @@
+use config::{Config, ConfigError, File};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum NpcAbilityType {
+    /// Casts an attack spell at the NPC's target
+    Spell,
+    /// Casts a heal spell on itself or a hurt boss
+    Heal,
+    /// Spawns more NPCs around itself
+    Summon,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NpcAbility {
+    pub r#type: NpcAbilityType,
+    /// Chance out of 100 to use the ability each time the NPC acts
+    pub chance: i32,
+    #[serde(default)]
+    pub spell_id: i32,
+    /// Furthest tile away the spell reaches. Heals with no range only
+    /// target the caster
+    #[serde(default)]
+    pub range: i32,
+    #[serde(default)]
+    pub npc_id: i32,
+    #[serde(default = "default_amount")]
+    pub amount: i32,
+    /// Most summoned NPCs of this kind alive at once
+    #[serde(default = "default_amount")]
+    pub max: i32,
+}
+
+fn default_amount() -> i32 {
+    1
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NpcPhase {
+    /// The phase is active while the NPC's HP is at or below this percent
+    #[serde(default = "default_hp_percentage")]
+    pub hp_percentage: i32,
+    /// Whether the NPC still attacks adjacent players
+    #[serde(default = "default_melee")]
+    pub melee: bool,
+    pub abilities: Vec<NpcAbility>,
+}
+
+fn default_hp_percentage() -> i32 {
+    100
+}
+
+fn default_melee() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NpcAbilitySet {
+    pub npc_id: i32,
+    pub phases: Vec<NpcPhase>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NpcAbilities {
+    pub npcs: Vec<NpcAbilitySet>,
+}
+
+impl NpcAbilities {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/NpcAbilities.ron"))
+            .add_source(File::with_name("config/NpcAbilities.local.ron").required(false))
+            .build()?;
+
+        s.try_deserialize()
+    }
+
+    /// The phase with the lowest threshold the NPC's HP has dropped to
+    pub fn get_phase(&self, npc_id: i32, hp_percentage: i32) -> Option<&NpcPhase> {
+        self.npcs
+            .iter()
+            .find(|set| set.npc_id == npc_id)?
+            .phases
+            .iter()
+            .filter(|phase| hp_percentage <= phase.hp_percentage)
+            .min_by_key(|phase| phase.hp_percentage)
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use eolib::protocol::Coords;
+
+use crate::{
+    settings::{BotDetection, BotPenalty},
+    utils::{get_distance, timestamp_diff},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotSignal {
+    /// Attacks spaced too evenly to be made by hand
+    AttackTiming,
+    /// Many NPCs killed without moving
+    StationaryKills,
+    /// Killing NPCs for hours without talking or passing a challenge
+    Unattended,
+}
+
+/// Scores a player on behaviour that suggests a macro is playing for them
+#[derive(Debug, Default)]
+pub struct BotDetector {
+    attack_timestamp: Option<i32>,
+    attack_intervals: VecDeque<i32>,
+    kill_coords: Option<Coords>,
+    stationary_kills: i32,
+    killed_since_attended: bool,
+    attended_at: Option<DateTime<Utc>>,
+    score: i32,
+    scored_signals: Vec<BotSignal>,
+    scored_at: Option<DateTime<Utc>>,
+    failures: usize,
+}
+
+impl BotDetector {
+    /// Records an attack made at the client's `timestamp`
+    pub fn attack(&mut self, timestamp: i32, settings: &BotDetection) -> Option<BotSignal> {
+        if let Some(previous) = self.attack_timestamp {
+            self.attack_intervals
+                .push_back(timestamp_diff(timestamp, previous));
+        }
+
+        self.attack_timestamp = Some(timestamp);
+
+        if settings.attack_samples < 2 || self.attack_intervals.len() < settings.attack_samples {
+            return None;
+        }
+
+        while self.attack_intervals.len() > settings.attack_samples {
+            self.attack_intervals.pop_front();
+        }
+
+        let count = self.attack_intervals.len() as f64;
+        let mean = self.attack_intervals.iter().sum::<i32>() as f64 / count;
+        let variance = self
+            .attack_intervals
+            .iter()
+            .map(|interval| (*interval as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        if variance.sqrt() < settings.min_attack_deviation {
+            self.attack_intervals.clear();
+            return Some(BotSignal::AttackTiming);
+        }
+
+        None
+    }
+
+    /// Records an NPC killed while the player stood at `coords`
+    pub fn kill(&mut self, coords: Coords, settings: &BotDetection) -> Option<BotSignal> {
+        self.killed_since_attended = true;
+
+        match self.kill_coords {
+            Some(kill_coords)
+                if get_distance(&kill_coords, &coords) <= settings.stationary_range =>
+            {
+                self.stationary_kills += 1;
+            }
+            _ => {
+                self.kill_coords = Some(coords);
+                self.stationary_kills = 1;
+            }
+        }
+
+        if settings.stationary_kills > 0 && self.stationary_kills >= settings.stationary_kills {
+            self.kill_coords = None;
+            self.stationary_kills = 0;
+            return Some(BotSignal::StationaryKills);
+        }
+
+        None
+    }
+
+    /// Records something only a person at the keyboard would do, like talking
+    pub fn attend(&mut self, now: DateTime<Utc>) {
+        self.attended_at = Some(now);
+        self.killed_since_attended = false;
+    }
+
+    /// Checks how long the player has gone unattended
+    pub fn check_unattended(
+        &mut self,
+        now: DateTime<Utc>,
+        settings: &BotDetection,
+    ) -> Option<BotSignal> {
+        let attended_at = *self.attended_at.get_or_insert(now);
+
+        if settings.unattended_minutes > 0
+            && self.killed_since_attended
+            && (now - attended_at).num_minutes() >= settings.unattended_minutes
+        {
+            self.attend(now);
+            return Some(BotSignal::Unattended);
+        }
+
+        None
+    }
+
+    /// Adds a signal to the player's score. Each signal only counts once until
+    /// the score starts over, so one habit alone can't add up to a challenge.
+    /// Returns true when the player should be challenged
+    pub fn add_signal(
+        &mut self,
+        signal: BotSignal,
+        now: DateTime<Utc>,
+        settings: &BotDetection,
+    ) -> bool {
+        if let Some(scored_at) = self.scored_at {
+            if (now - scored_at).num_minutes() >= settings.score_reset {
+                self.reset_score();
+            }
+        }
+
+        self.scored_at = Some(now);
+
+        if self.scored_signals.contains(&signal) {
+            return false;
+        }
+
+        self.scored_signals.push(signal);
+        self.score += match signal {
+            BotSignal::AttackTiming => settings.attack_timing_score,
+            BotSignal::StationaryKills => settings.stationary_score,
+            BotSignal::Unattended => settings.unattended_score,
+        };
+
+        if settings.challenge_score > 0 && self.score >= settings.challenge_score {
+            self.reset_score();
+            return true;
+        }
+
+        false
+    }
+
+    /// Records a passed challenge
+    pub fn pass(&mut self, now: DateTime<Utc>) {
+        self.failures = 0;
+        self.reset_score();
+        self.attend(now);
+    }
+
+    /// Records a failed challenge and returns the penalty for it
+    pub fn fail(&mut self, settings: &BotDetection) -> Option<BotPenalty> {
+        self.failures += 1;
+        settings
+            .penalties
+            .get(self.failures - 1)
+            .or(settings.penalties.last())
+            .copied()
+    }
+
+    fn reset_score(&mut self) {
+        self.score = 0;
+        self.scored_signals.clear();
+    }
+}
@@ -1,6 +1,22 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+
 #[derive(Debug, Default)]
 pub struct Captcha {
     pub challenge: String,
     pub reward: i32,
     pub attempts: i32,
+    /// When an automatic bot challenge is failed if it hasn't been answered
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Captcha {
+    pub fn generate_challenge() -> String {
+        let mut rng = rand::thread_rng();
+        (0..5)
+            .map(|_| {
+                rng.gen_range(65..=90) as u8 as char // ASCII codes for upper case letters
+            })
+            .collect()
+    }
 }
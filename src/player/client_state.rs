@@ -4,6 +4,7 @@ pub enum ClientState {
     Uninitialized,
     Initialized,
     Accepted,
+    Queued,
     LoggedIn,
     EnteringGame,
     InGame,
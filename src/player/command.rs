@@ -9,9 +9,9 @@ use eolib::protocol::{
 use eoplus::Arg;
 use tokio::sync::oneshot;
 
-use crate::{character::Character, errors::InvalidStateError, map::MapHandle};
+use crate::{character::Character, db::Sanction, errors::InvalidStateError, map::MapHandle};
 
-use super::{ClientState, PartyRequest};
+use super::{ClientState, MovementViolation, PartyRequest};
 
 #[derive(Debug)]
 pub enum Command {
@@ -19,12 +19,24 @@ pub enum Command {
         player_id: i32,
         name: String,
     },
+    AddMovementViolation(MovementViolation),
+    AddMute(Box<Sanction>),
+    AddNpcKill(Coords),
+    AdmitLogin,
     CancelTrade,
+    #[cfg(test)]
+    ChallengeBot,
+    ClearMutes,
     Close(String),
     ArenaDie {
         spawn_coords: Coords,
     },
     Die,
+    #[cfg(test)]
+    EnterGame {
+        character: Box<Character>,
+        respond_to: oneshot::Sender<bool>,
+    },
     GenerateSessionId {
         respond_to: oneshot::Sender<i32>,
     },
@@ -54,7 +54,7 @@ impl Player {
         match family {
             PacketFamily::Account => self.handle_account(action, reader).await,
             PacketFamily::AdminInteract => self.handle_admin_interact(action, reader),
-            PacketFamily::Attack => self.handle_attack(action, reader),
+            PacketFamily::Attack => self.handle_attack(action, reader).await,
             PacketFamily::Bank => self.handle_bank(action, reader),
             PacketFamily::Barber => self.handle_barber(action, reader),
             PacketFamily::Board => self.handle_board(action, reader),
@@ -89,9 +89,9 @@ impl Player {
             PacketFamily::Sit => self.handle_sit(action, reader),
             PacketFamily::Spell => self.handle_spell(action, reader),
             PacketFamily::StatSkill => self.handle_stat_skill(action, reader),
-            PacketFamily::Talk => self.handle_talk(action, reader),
+            PacketFamily::Talk => self.handle_talk(action, reader).await,
             PacketFamily::Trade => self.handle_trade(action, reader),
-            PacketFamily::Walk => self.handle_walk(reader),
+            PacketFamily::Walk => self.handle_walk(reader).await,
             PacketFamily::Warp => self.handle_warp(action, reader).await,
             PacketFamily::Unrecognized(0xfe) => {} // ignored packet
             PacketFamily::Unrecognized(FAMILY_CAPTCHA) => self.handle_captcha(action, reader).await,
@@ -1,3 +1,5 @@
+mod bot_detector;
+pub use bot_detector::{BotDetector, BotSignal};
 mod captcha;
 pub use captcha::Captcha;
 mod client_state;
@@ -13,6 +15,8 @@ mod player;
 pub use player::Player;
 mod player_handle;
 pub use player_handle::PlayerHandle;
+mod walk_validator;
+pub use walk_validator::{MovementViolation, WalkValidator};
 mod warp_session;
 pub use warp_session::WarpSession;
 mod party_request;
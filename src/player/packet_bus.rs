@@ -9,7 +9,7 @@ use eolib::{
 };
 use tokio::net::TcpStream;
 
-use crate::PACKET_RATE_LIMITS;
+use crate::{metrics, PACKET_RATE_LIMITS};
 
 use super::PacketLog;
 
@@ -91,6 +91,8 @@ impl PacketBus {
                         packet_size
                     ));
                 }
+
+                metrics::record_packet_sent(family, action, num_of_bytes_written);
             }
             Err(e) => {
                 return Err(e.into());
@@ -116,16 +118,29 @@ impl PacketBus {
 
                             let data_buf = Bytes::from(data_buf);
 
-                            if let Some(rate_limit) = PACKET_RATE_LIMITS.packets.iter().find(|l| {
-                                l.action == PacketAction::from(data_buf[0])
-                                    && l.family == PacketFamily::from(data_buf[1])
-                            }) {
+                            metrics::record_packet_received(
+                                PacketFamily::from(data_buf[1]),
+                                PacketAction::from(data_buf[0]),
+                                packet_length + 2,
+                            );
+
+                            if let Some(rate_limit) =
+                                PACKET_RATE_LIMITS.load().packets.iter().find(|l| {
+                                    l.action == PacketAction::from(data_buf[0])
+                                        && l.family == PacketFamily::from(data_buf[1])
+                                })
+                            {
                                 if let Some(last_processed) = self.log.last_processed(&data_buf) {
                                     if Utc::now()
                                         .signed_duration_since(last_processed)
                                         .num_milliseconds()
                                         < rate_limit.limit
                                     {
+                                        metrics::record_rate_limited(
+                                            rate_limit.family,
+                                            rate_limit.action,
+                                        );
+
                                         let mut buf = BytesMut::new();
                                         buf.put_u8(0xfe);
                                         buf.put_u8(0xfe);
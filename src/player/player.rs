@@ -3,12 +3,21 @@ use std::{cell::RefCell, collections::VecDeque};
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::GuildReplyServerPacket, PacketAction, PacketFamily, Version};
-use mysql_async::Pool;
 use tokio::{net::TcpStream, sync::mpsc::UnboundedReceiver};
 
-use crate::{character::Character, errors::InvalidStateError, map::MapHandle, world::WorldHandle};
+use crate::{
+    character::Character,
+    db::{Db, Sanction},
+    errors::InvalidStateError,
+    map::MapHandle,
+    world::WorldHandle,
+    SETTINGS,
+};
 
-use super::{packet_bus::PacketBus, Captcha, ClientState, Command, PartyRequest, WarpSession};
+use super::{
+    packet_bus::PacketBus, BotDetector, Captcha, ClientState, Command, PartyRequest, WalkValidator,
+    WarpSession,
+};
 
 pub struct Player {
     pub id: i32,
@@ -18,9 +27,10 @@ pub struct Player {
     pub world: WorldHandle,
     pub map: Option<MapHandle>,
     account_id: i32,
-    pool: Pool,
+    db: Db,
     pub state: ClientState,
     ip: String,
+    hdid: String,
     pub connected_at: DateTime<Utc>,
     pub closed: bool,
     login_attempts: i32,
@@ -39,29 +49,44 @@ pub struct Player {
     guild_create_members: Vec<i32>,
     version: Version,
     email_pin: Option<String>,
+    email_pin_expires_at: Option<DateTime<Utc>>,
     captcha: Option<Captcha>,
     timestamp: i32,
     spell_id: Option<i32>,
+    mutes: Vec<Sanction>,
+    walk_validator: WalkValidator,
+    bot_detector: BotDetector,
+    bot_frozen: bool,
 }
 
 mod account;
+mod add_bot_signal;
+mod add_movement_violation;
 mod arena_die;
 mod cancel_trade;
+mod challenge_bot;
+mod check_email_pin;
 mod close;
 mod die;
 mod enter_game;
+#[cfg(test)]
+mod enter_game_with;
+mod fail_bot_challenge;
 mod generate_session_id;
 mod get_ban_duration;
 mod get_file;
 mod get_welcome_request_data;
 mod handlers;
+mod is_muted;
 #[macro_use]
 mod guild;
 mod generate_email_pin;
+mod pass_bot_challenge;
 mod ping;
 mod quest_action;
 mod request_warp;
 mod send_server_message;
+mod send_status_effects_cleared;
 mod show_captcha;
 mod take_session_id;
 mod tick;
@@ -75,7 +100,7 @@ impl Player {
         connected_at: DateTime<Utc>,
         rx: UnboundedReceiver<Command>,
         world: WorldHandle,
-        pool: Pool,
+        db: Db,
     ) -> Self {
         let ip = socket.peer_addr().unwrap().ip().to_string();
         Self {
@@ -84,7 +109,7 @@ impl Player {
             connected_at,
             rx,
             world,
-            pool,
+            db,
             queue: RefCell::new(VecDeque::new()),
             map: None,
             closed: false,
@@ -92,6 +117,7 @@ impl Player {
             state: ClientState::Uninitialized,
             login_attempts: 0,
             ip,
+            hdid: String::new(),
             character: None,
             warp_session: None,
             session_id: None,
@@ -107,9 +133,14 @@ impl Player {
             guild_create_members: Vec::new(),
             version: Version::default(),
             email_pin: None,
+            email_pin_expires_at: None,
             captcha: None,
             timestamp: 0,
             spell_id: None,
+            mutes: Vec::new(),
+            walk_validator: WalkValidator::default(),
+            bot_detector: BotDetector::default(),
+            bot_frozen: false,
         }
     }
 
@@ -118,10 +149,24 @@ impl Player {
             Command::AddGuildCreationPlayer { player_id, name } => {
                 self.add_guild_creation_player(player_id, name).await
             }
+            Command::AdmitLogin => {
+                if self.state == ClientState::Queued {
+                    self.finish_login().await;
+                }
+            }
             Command::ArenaDie { spawn_coords } => self.arena_die(spawn_coords).await,
             Command::CancelTrade => self.cancel_trade().await,
+            #[cfg(test)]
+            Command::ChallengeBot => self.challenge_bot().await,
             Command::Close(reason) => self.close(reason).await,
             Command::Die => self.die().await,
+            #[cfg(test)]
+            Command::EnterGame {
+                character,
+                respond_to,
+            } => {
+                let _ = respond_to.send(self.enter_game_with(*character).await);
+            }
             Command::GenerateSessionId { respond_to } => {
                 let _ = respond_to.send(self.generate_session_id());
             }
@@ -199,6 +244,16 @@ impl Player {
             Command::SetPartyRequest(request) => {
                 self.party_request = request;
             }
+            Command::AddMovementViolation(violation) => {
+                self.add_movement_violation(violation).await
+            }
+            Command::AddMute(mute) => self.mutes.push(*mute),
+            Command::AddNpcKill(coords) => {
+                if let Some(signal) = self.bot_detector.kill(coords, &SETTINGS.bot_detection) {
+                    self.add_bot_signal(signal).await;
+                }
+            }
+            Command::ClearMutes => self.mutes.clear(),
             Command::SetSleepCost(cost) => {
                 self.sleep_cost = Some(cost);
             }
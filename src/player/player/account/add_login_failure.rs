@@ -0,0 +1,74 @@
+use chrono::Utc;
+
+use crate::{
+    db::{add_login_failure, LoginLockKind},
+    EMAILS, SETTINGS,
+};
+
+use super::super::Player;
+
+impl Player {
+    /// Counts a failed login against the username and this connection's
+    /// address, locking them out once they've failed too many times
+    pub async fn add_login_failure(&mut self, username: &str) {
+        let settings = &SETTINGS.login_lockout;
+        if !settings.enabled {
+            return;
+        }
+
+        let now = Utc::now().naive_utc();
+        let ip = self.ip.clone();
+
+        for (kind, value, max_failures) in [
+            (
+                LoginLockKind::Account,
+                username,
+                settings.max_account_failures,
+            ),
+            (LoginLockKind::Ip, ip.as_str(), settings.max_ip_failures),
+        ] {
+            let seconds =
+                match add_login_failure(&self.db, kind, value, now, max_failures, settings).await {
+                    Ok(Some(seconds)) => seconds,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        error!("Failed to count login failure for {}: {}", value, e);
+                        continue;
+                    }
+                };
+
+            warn!(
+                "Locked out {} {} for {} seconds after too many failed logins",
+                kind.as_str(),
+                value,
+                seconds
+            );
+
+            if kind == LoginLockKind::Account && settings.notify_email {
+                self.send_lockout_email(username, seconds).await;
+            }
+        }
+    }
+
+    async fn send_lockout_email(&mut self, username: &str, seconds: i64) {
+        let email = match self.db.get_account_email(username).await {
+            Ok(Some((_, email))) => email,
+            Ok(None) => return,
+            Err(e) => {
+                error!("Failed to get account email: {}", e);
+                return;
+            }
+        };
+
+        let minutes = (seconds + 59) / 60;
+        self.world.outbox.queue(EMAILS.lockout.render(
+            &email,
+            username,
+            &[
+                ("name", username),
+                ("ip", &self.ip),
+                ("minutes", &minutes.to_string()),
+            ],
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+use eolib::protocol::net::{
+    server::{
+        LoginReply, LoginReplyServerPacket, LoginReplyServerPacketReplyCodeData,
+        LoginReplyServerPacketReplyCodeDataOk,
+    },
+    PacketAction, PacketFamily,
+};
+
+use crate::{
+    deep::{LoginConfigServerPacket, ACTION_CONFIG},
+    player::ClientState,
+    utils::is_deep,
+    SETTINGS,
+};
+
+use super::{super::Player, get_character_list};
+
+impl Player {
+    /// Sends the character list once the world has made room for the account
+    pub async fn finish_login(&mut self) {
+        if let Err(e) = self
+            .db
+            .update_last_login_ip(self.account_id, &self.ip)
+            .await
+        {
+            self.close(format!("Error updating last login IP: {}", e))
+                .await;
+            return;
+        }
+
+        let characters = match get_character_list(&self.db, self.account_id).await {
+            Ok(characters) => characters,
+            Err(e) => {
+                self.close(format!("Error getting character list: {}", e))
+                    .await;
+                return;
+            }
+        };
+
+        self.state = ClientState::LoggedIn;
+
+        if is_deep(&self.version) {
+            let _ = self
+                .bus
+                .send(
+                    PacketAction::Unrecognized(ACTION_CONFIG),
+                    PacketFamily::Login,
+                    LoginConfigServerPacket {
+                        max_skins: SETTINGS.character.max_skin + 1,
+                        max_hair_modals: SETTINGS.character.max_hair_style,
+                        max_character_name: SETTINGS.character.max_name_length as i32,
+                    },
+                )
+                .await;
+        }
+
+        let _ = self
+            .bus
+            .send(
+                PacketAction::Reply,
+                PacketFamily::Login,
+                LoginReplyServerPacket {
+                    reply_code: LoginReply::OK,
+                    reply_code_data: Some(LoginReplyServerPacketReplyCodeData::OK(
+                        LoginReplyServerPacketReplyCodeDataOk { characters },
+                    )),
+                },
+            )
+            .await;
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::Utc;
+
+use crate::{db::LoginLockKind, SETTINGS};
+
+use super::super::Player;
+
+impl Player {
+    /// Seconds until the username and this connection's address can try to
+    /// log in again, `None` if neither is locked
+    pub async fn get_login_lock(&mut self, username: &str) -> anyhow::Result<Option<i64>> {
+        if !SETTINGS.login_lockout.enabled {
+            return Ok(None);
+        }
+
+        let now = Utc::now().naive_utc();
+        let mut remaining = None;
+
+        for (kind, value) in [
+            (LoginLockKind::Account, username),
+            (LoginLockKind::Ip, self.ip.as_str()),
+        ] {
+            if let Some(seconds) = self
+                .db
+                .get_login_lock(kind, value)
+                .await?
+                .and_then(|lock| lock.seconds_remaining(now))
+            {
+                remaining = remaining.max(Some(seconds));
+            }
+        }
+
+        Ok(remaining)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    db::{AccountCredentials, Db},
+    SETTINGS,
+};
+
+/// Whether the account skips ahead of regular players in the login queue,
+/// either by flag or by having a character with a high enough admin level
+pub async fn has_queue_priority(db: &Db, credentials: &AccountCredentials) -> anyhow::Result<bool> {
+    let settings = &SETTINGS.login_queue;
+
+    if settings.priority_accounts && credentials.queue_priority {
+        return Ok(true);
+    }
+
+    if settings.priority_admin_level <= 0 {
+        return Ok(false);
+    }
+
+    let characters = db.get_character_list(credentials.id).await?;
+    Ok(characters
+        .iter()
+        .any(|character| i32::from(character.admin) >= settings.priority_admin_level))
+}
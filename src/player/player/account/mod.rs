@@ -1,13 +1,10 @@
-mod account_banned;
-pub(super) use account_banned::account_banned;
-mod account_exists;
-pub(super) use account_exists::account_exists;
+mod add_login_failure;
+mod finish_login;
 mod get_character_list;
 pub(super) use get_character_list::get_character_list;
-mod get_num_of_characters;
-pub(super) use get_num_of_characters::get_num_of_characters;
+mod get_login_lock;
+mod has_queue_priority;
+pub(super) use has_queue_priority::has_queue_priority;
 mod password_hash;
-pub(super) use password_hash::{generate_password_hash, validate_password};
+pub(super) use password_hash::{dummy_password_hash, generate_password_hash, validate_password};
 mod select_character;
-mod update_last_login_ip;
-pub(super) use update_last_login_ip::update_last_login_ip;
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use argon2::{password_hash::SaltString, Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use rand::rngs::OsRng;
 
@@ -24,3 +26,10 @@ pub fn validate_password(username: &str, password: &str, password_hash: &str) ->
         .verify_password(hash_input.as_bytes(), &parsed_hash)
         .is_ok()
 }
+
+/// Checked against when the account doesn't exist so unknown usernames
+/// take as long to turn away as wrong passwords
+pub fn dummy_password_hash() -> &'static str {
+    static HASH: OnceLock<String> = OnceLock::new();
+    HASH.get_or_init(|| generate_password_hash("", ""))
+}
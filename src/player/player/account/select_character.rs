@@ -4,7 +4,6 @@ use eolib::protocol::net::server::{
 use eolib::protocol::net::{PacketAction, PacketFamily};
 use eolib::protocol::Coords;
 
-use crate::character::Character;
 use crate::errors::DataNotFoundError;
 use crate::player::ClientState;
 use crate::SETTINGS;
@@ -39,16 +38,7 @@ impl Player {
             return true;
         }
 
-        let mut conn = match self.pool.get_conn().await {
-            Ok(conn) => conn,
-            Err(e) => {
-                self.close(format!("Error getting connection from pool: {}", e))
-                    .await;
-                return false;
-            }
-        };
-
-        let mut character = match Character::load(&mut conn, character_id).await {
+        let mut character = match self.db.load_character(character_id).await {
             Ok(character) => character,
             Err(e) => {
                 self.close(format!("Failed to load character {}: {}", character_id, e))
@@ -66,6 +56,18 @@ impl Player {
             return false;
         }
 
+        // Mutes are kept by the server so relogging doesn't get rid of them
+        self.mutes = match self.db.get_sanctions(character.id).await {
+            Ok(sanctions) => sanctions
+                .into_iter()
+                .filter(|sanction| sanction.is_active_mute())
+                .collect(),
+            Err(e) => {
+                error!("Failed to load sanctions for {}: {}", character.name, e);
+                Vec::new()
+            }
+        };
+
         character.player_id = Some(self.id);
         character.player = Some(player);
         character.logged_in_at = Some(chrono::Utc::now());
@@ -0,0 +1,27 @@
+use chrono::Utc;
+
+use crate::{player::BotSignal, SETTINGS};
+
+use super::Player;
+
+impl Player {
+    pub async fn add_bot_signal(&mut self, signal: BotSignal) {
+        if !SETTINGS.bot_detection.enabled {
+            return;
+        }
+
+        if !self
+            .bot_detector
+            .add_signal(signal, Utc::now(), &SETTINGS.bot_detection)
+        {
+            return;
+        }
+
+        warn!(
+            "Player {} ({}) looks like a bot, last signal: {:?}",
+            self.id, self.ip, signal
+        );
+
+        self.challenge_bot().await;
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::Utc;
+
+use crate::{
+    player::MovementViolation, settings::MovementViolationResponse, utils::is_deep, SETTINGS,
+};
+
+use super::Player;
+
+impl Player {
+    pub async fn add_movement_violation(&mut self, violation: MovementViolation) {
+        if !self
+            .walk_validator
+            .add_violation(Utc::now(), &SETTINGS.movement)
+        {
+            return;
+        }
+
+        warn!(
+            "Player {} ({}) reached the movement violation limit, last violation: {:?}",
+            self.id, self.ip, violation
+        );
+
+        match SETTINGS.movement.violation_response {
+            MovementViolationResponse::Log => {}
+            MovementViolationResponse::Captcha if is_deep(&self.version) => {
+                if self.captcha.is_none() {
+                    self.show_captcha(0).await;
+                }
+            }
+            MovementViolationResponse::Captcha | MovementViolationResponse::Kick => {
+                self.close(format!("Movement violations ({:?})", violation))
+                    .await;
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{Duration, Utc};
+
+use crate::{player::Captcha, utils::is_deep, LANG, SETTINGS};
+
+use super::Player;
+
+impl Player {
+    /// Asks the player to prove they are at the keyboard. Deep clients get a
+    /// captcha, everyone else has to type a code in chat
+    pub async fn challenge_bot(&mut self) {
+        if self.captcha.is_some() {
+            return;
+        }
+
+        let settings = &SETTINGS.bot_detection;
+
+        if is_deep(&self.version) {
+            self.show_captcha(settings.challenge_reward).await;
+        } else {
+            let challenge = Captcha::generate_challenge();
+            self.send_server_message(&get_lang_string!(
+                &LANG.bot_challenge,
+                code = challenge,
+                seconds = settings.challenge_timeout
+            ))
+            .await;
+
+            self.captcha = Some(Captcha {
+                challenge,
+                ..Default::default()
+            });
+        }
+
+        if let Some(captcha) = self.captcha.as_mut() {
+            captcha.expires_at = Some(Utc::now() + Duration::seconds(settings.challenge_timeout));
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use chrono::Utc;
+
+use super::super::Player;
+
+impl Player {
+    /// Whether `pin` matches the last emailed pin and it hasn't expired
+    pub fn check_email_pin(&self, pin: &str) -> bool {
+        match (&self.email_pin, self.email_pin_expires_at) {
+            (Some(email_pin), Some(expires_at)) => *email_pin == pin && Utc::now() < expires_at,
+            _ => false,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use eolib::protocol::Coords;
 
+use crate::{audit::AuditEvent, SETTINGS};
+
 use super::Player;
 
 impl Player {
@@ -11,6 +13,15 @@ impl Player {
             .leave(self.id, None, self.interact_player_id)
             .await;
 
+        self.world.audit.record(&character, AuditEvent::Death);
+
+        let cleared = character.clear_status_effects(SETTINGS.status_effects.clear_on_death);
+        if cleared.stats_changed || cleared.stun_ended {
+            let stats = character.get_character_stats_update();
+            self.send_status_effects_cleared(&cleared, character.class, stats)
+                .await;
+        }
+
         character.map_id = 0;
         character.coords = Coords { x: 0, y: 0 };
 
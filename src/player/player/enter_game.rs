@@ -8,7 +8,7 @@ use eolib::protocol::net::{
 use std::{io::Cursor, path::Path};
 use tokio::io::{AsyncBufReadExt, AsyncReadExt};
 
-use crate::{errors::WrongSessionIdError, player::ClientState, utils::is_deep};
+use crate::{errors::WrongSessionIdError, player::ClientState, utils::is_deep, DATA_DIR};
 
 use super::Player;
 
@@ -89,7 +89,7 @@ impl Player {
 }
 
 async fn get_news() -> [String; 9] {
-    match tokio::fs::File::open(Path::new("data/news.txt")).await {
+    match tokio::fs::File::open(Path::new(&format!("{}/news.txt", DATA_DIR))).await {
         Ok(mut file) => {
             let mut buf: Vec<u8> = Vec::new();
             file.read_to_end(&mut buf).await.unwrap();
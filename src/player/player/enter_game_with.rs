@@ -0,0 +1,29 @@
+use crate::{character::Character, player::ClientState};
+
+use super::Player;
+
+impl Player {
+    /// Skips login and character selection, placing the given character
+    /// straight into the world. Only used by the test harness.
+    pub async fn enter_game_with(&mut self, mut character: Character) -> bool {
+        let player = match self.world.get_player(self.id).await {
+            Some(player) => player,
+            None => return false,
+        };
+
+        character.player_id = Some(self.id);
+        character.player = Some(player);
+        character.logged_in_at = Some(chrono::Utc::now());
+        character.calculate_stats();
+
+        self.world
+            .add_character(self.id, character.name.clone(), character.guild_tag.clone());
+
+        self.account_id = character.account_id;
+        self.character = Some(character);
+        self.state = ClientState::EnteringGame;
+
+        let session_id = self.generate_session_id();
+        self.enter_game(session_id).await
+    }
+}
@@ -0,0 +1,62 @@
+use eolib::protocol::{
+    net::{
+        server::{WalkCloseServerPacket, WarpEffect},
+        PacketAction, PacketFamily,
+    },
+    Coords,
+};
+
+use crate::{settings::BotPenalty, SETTINGS};
+
+use super::Player;
+
+impl Player {
+    /// Penalizes a player who let a bot challenge expire and challenges them
+    /// again unless they were kicked
+    pub async fn fail_bot_challenge(&mut self) {
+        let penalty = self.bot_detector.fail(&SETTINGS.bot_detection);
+
+        warn!(
+            "Player {} ({}) failed a bot challenge, penalty: {:?}",
+            self.id, self.ip, penalty
+        );
+
+        self.captcha = None;
+        if let Some(map) = &self.map {
+            map.close_captcha(self.id, 0);
+        }
+
+        match penalty {
+            Some(BotPenalty::Freeze) => {
+                self.bot_frozen = true;
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Close,
+                        PacketFamily::Walk,
+                        WalkCloseServerPacket::new(),
+                    )
+                    .await;
+            }
+            Some(BotPenalty::Jail) => {
+                self.request_warp(
+                    SETTINGS.jail.map,
+                    Coords {
+                        x: SETTINGS.jail.x,
+                        y: SETTINGS.jail.y,
+                    },
+                    false,
+                    Some(WarpEffect::Admin),
+                )
+                .await;
+            }
+            Some(BotPenalty::Kick) => {
+                self.close("Failed bot challenge".to_string()).await;
+                return;
+            }
+            None => return,
+        }
+
+        self.challenge_bot().await;
+    }
+}
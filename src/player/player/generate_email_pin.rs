@@ -1,5 +1,8 @@
+use chrono::{Duration, Utc};
 use rand::Rng;
 
+use crate::SETTINGS;
+
 use super::super::Player;
 
 impl Player {
@@ -7,6 +10,8 @@ impl Player {
         let mut rng = rand::thread_rng();
         let pin: u32 = rng.gen_range(1000000..9999999);
         self.email_pin = Some(pin.to_string());
+        self.email_pin_expires_at =
+            Some(Utc::now() + Duration::minutes(SETTINGS.account.email_pin_expiry));
         pin.to_string()
     }
 }
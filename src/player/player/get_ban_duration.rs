@@ -1,49 +1,23 @@
-use std::time::Duration;
-
-use chrono::{NaiveDateTime, Utc};
-use mysql_async::prelude::*;
-use mysql_common::{params, Row};
+use crate::db::{find_ban, BanTarget};
 
 use super::Player;
 
 impl Player {
+    /// Minutes left on a ban matching the connection's address or hardware
+    /// id, 0 = permanent
     pub async fn get_ban_duration(&mut self) -> Option<i32> {
-        let mut conn = match self.pool.get_conn().await {
-            Ok(conn) => conn,
-            Err(err) => {
-                error!("Failed to get connection from pool: {}", err);
-                return None;
-            }
-        };
-
-        let mut row: Row = match conn
-            .exec_first(
-                include_str!("../../sql/get_ban_duration.sql"),
-                params! {
-                    "ip" => &self.ip,
-                },
-            )
-            .await
-        {
-            Ok(Some(row)) => row,
-            _ => return None,
+        let target = BanTarget {
+            ip: Some(&self.ip),
+            hdid: Some(&self.hdid),
+            ..Default::default()
         };
 
-        let duration: i32 = row.take("duration").unwrap();
-        // 0 = permanent
-        if duration == 0 {
-            return Some(0);
-        }
-
-        let now = Utc::now();
-        let created_at: NaiveDateTime = row.take("created_at").unwrap();
-        let expires_at = created_at + Duration::from_secs(duration as u64 * 60);
-        let diff = expires_at - now.naive_utc();
-        let remaining = diff.num_minutes();
-        if remaining <= 0 {
-            return None;
+        match find_ban(&self.db, &target).await {
+            Ok(ban) => ban.and_then(|ban| ban.minutes_remaining()),
+            Err(err) => {
+                error!("Failed to get ban duration: {}", err);
+                None
+            }
         }
-
-        Some(diff.num_minutes() as i32)
     }
 }
@@ -1,21 +1,14 @@
-use eolib::{
-    data::{EoSerialize, EoWriter},
-    protocol::net::{
-        client::FileType,
-        server::{
-            InitInitServerPacket, InitInitServerPacketReplyCodeData,
-            InitInitServerPacketReplyCodeDataFileEcf, InitInitServerPacketReplyCodeDataFileEif,
-            InitInitServerPacketReplyCodeDataFileEmf, InitInitServerPacketReplyCodeDataFileEnf,
-            InitInitServerPacketReplyCodeDataFileEsf, InitInitServerPacketReplyCodeDataWarpMap,
-            InitReply, MapFile, PubFile,
-        },
-        PacketAction, PacketFamily,
+use eolib::protocol::net::{
+    client::FileType,
+    server::{
+        InitInitServerPacket, InitInitServerPacketReplyCodeData,
+        InitInitServerPacketReplyCodeDataFileEmf, InitInitServerPacketReplyCodeDataWarpMap,
+        InitReply, MapFile,
     },
+    PacketAction, PacketFamily,
 };
 
-use crate::{
-    errors::WrongSessionIdError, player::ClientState, CLASS_DB, ITEM_DB, NPC_DB, SPELL_DB,
-};
+use crate::{errors::WrongSessionIdError, player::ClientState, utils::get_pub_file_reply};
 
 use super::Player;
 
@@ -24,7 +17,7 @@ impl Player {
         &mut self,
         file_type: FileType,
         session_id: i32,
-        _file_id: Option<i32>,
+        file_id: Option<i32>,
         warp: bool,
     ) -> bool {
         if warp && self.state != ClientState::InGame {
@@ -111,67 +104,18 @@ impl Player {
                 });
                 reply
             }
-            FileType::Eif => {
-                let mut writer = EoWriter::new();
-                ITEM_DB.serialize(&mut writer).unwrap();
-                InitInitServerPacket {
-                    reply_code: InitReply::FileEif,
-                    reply_code_data: Some(InitInitServerPacketReplyCodeData::FileEif(
-                        InitInitServerPacketReplyCodeDataFileEif {
-                            pub_file: PubFile {
-                                file_id: 1, // TODO: Pub splitting
-                                content: writer.to_byte_array().to_vec(),
-                            },
-                        },
-                    )),
-                }
-            }
-            FileType::Enf => {
-                let mut writer = EoWriter::new();
-                NPC_DB.serialize(&mut writer).unwrap();
-                InitInitServerPacket {
-                    reply_code: InitReply::FileEnf,
-                    reply_code_data: Some(InitInitServerPacketReplyCodeData::FileEnf(
-                        InitInitServerPacketReplyCodeDataFileEnf {
-                            pub_file: PubFile {
-                                file_id: 1, // TODO: Pub splitting
-                                content: writer.to_byte_array().to_vec(),
-                            },
-                        },
-                    )),
-                }
-            }
-            FileType::Esf => {
-                let mut writer = EoWriter::new();
-                SPELL_DB.serialize(&mut writer).unwrap();
-                InitInitServerPacket {
-                    reply_code: InitReply::FileEsf,
-                    reply_code_data: Some(InitInitServerPacketReplyCodeData::FileEsf(
-                        InitInitServerPacketReplyCodeDataFileEsf {
-                            pub_file: PubFile {
-                                file_id: 1, // TODO: Pub splitting
-                                content: writer.to_byte_array().to_vec(),
-                            },
-                        },
-                    )),
-                }
-            }
-            FileType::Ecf => {
-                let mut writer = EoWriter::new();
-                CLASS_DB.serialize(&mut writer).unwrap();
-                InitInitServerPacket {
-                    reply_code: InitReply::FileEcf,
-                    reply_code_data: Some(InitInitServerPacketReplyCodeData::FileEcf(
-                        InitInitServerPacketReplyCodeDataFileEcf {
-                            pub_file: PubFile {
-                                file_id: 1, // TODO: Pub splitting
-                                content: writer.to_byte_array().to_vec(),
-                            },
-                        },
-                    )),
+            _ => match get_pub_file_reply(file_type, file_id.unwrap_or(1)) {
+                Some(reply) => reply,
+                None => {
+                    self.close(format!(
+                        "Requested {:?} file {} not found",
+                        file_type,
+                        file_id.unwrap_or(1)
+                    ))
+                    .await;
+                    return false;
                 }
-            }
-            _ => return false,
+            },
         };
 
         let _ = self
@@ -5,9 +5,7 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::{
-    character::Character, utils::pad_string, CLASS_DB, ITEM_DB, NPC_DB, SETTINGS, SPELL_DB,
-};
+use crate::{character::Character, utils::pad_string, CLIENT_PUB_FILES, SETTINGS};
 
 use super::Player;
 
@@ -24,13 +22,12 @@ impl Player {
             map.get_rid_and_size().await
         };
 
-        let (eif_rid, eif_length) = { (ITEM_DB.rid, ITEM_DB.items.len() as i32) };
-
-        let (ecf_rid, ecf_length) = { (CLASS_DB.rid, CLASS_DB.classes.len() as i32) };
-
-        let (enf_rid, enf_length) = { (NPC_DB.rid, NPC_DB.npcs.len() as i32) };
-
-        let (esf_rid, esf_length) = { (SPELL_DB.rid, SPELL_DB.skills.len() as i32) };
+        // Clients check these against the first piece of each split file
+        let client_pub_files = CLIENT_PUB_FILES.load();
+        let (eif_rid, eif_length) = (client_pub_files.eif.rid, client_pub_files.eif.length);
+        let (ecf_rid, ecf_length) = (client_pub_files.ecf.rid, client_pub_files.ecf.length);
+        let (enf_rid, enf_length) = (client_pub_files.enf.rid, client_pub_files.enf.length);
+        let (esf_rid, esf_length) = (client_pub_files.esf.rid, client_pub_files.esf.length);
 
         let settings = ServerSettings {
             jail_map: SETTINGS.jail.map,
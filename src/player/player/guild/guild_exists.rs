@@ -1,16 +1,5 @@
-use mysql_async::{prelude::Queryable, Conn, Params};
-use mysql_common::{params, Row};
+use crate::db::Db;
 
-pub async fn guild_exists(conn: &mut Conn, guild_tag: &str, guild_name: &str) -> bool {
-    matches!(
-        conn.exec_first::<Row, &str, Params>(
-            "SELECT id FROM Guild WHERE name = :name OR tag = :tag",
-            params! {
-                "name" => guild_name,
-                "tag" => guild_tag,
-            },
-        )
-        .await,
-        Ok(Some(_))
-    )
+pub async fn guild_exists(db: &Db, guild_tag: &str, guild_name: &str) -> bool {
+    matches!(db.guild_exists(guild_tag, guild_name).await, Ok(true))
 }
@@ -1,3 +1,17 @@
+use crate::{
+    db::{find_ban, BanTarget, NewAccount},
+    deep::{
+        AccountAcceptClientPacket, AccountAcceptServerPacket, AccountConfigServerPacket,
+        ACCOUNT_REPLY_WRONG_PIN, ACTION_CONFIG,
+    },
+    errors::WrongSessionIdError,
+    player::{
+        player::account::{generate_password_hash, validate_password},
+        ClientState,
+    },
+    utils::is_deep,
+    EMAILS, SETTINGS,
+};
 use eolib::{
     data::{EoReader, EoSerialize},
     protocol::net::{
@@ -13,21 +27,6 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::{params, prelude::Queryable, Params, Row};
-
-use crate::{
-    deep::{
-        AccountAcceptClientPacket, AccountAcceptServerPacket, AccountConfigServerPacket,
-        ACCOUNT_REPLY_WRONG_PIN, ACTION_CONFIG,
-    },
-    errors::WrongSessionIdError,
-    player::{
-        player::account::{account_exists, generate_password_hash, validate_password},
-        ClientState,
-    },
-    utils::{is_deep, send_email},
-    EMAILS, SETTINGS,
-};
 
 use super::super::Player;
 
@@ -41,10 +40,10 @@ impl Player {
             }
         };
 
-        if let Some(email_pin) = &self.email_pin {
+        if self.email_pin.is_some() {
             reader.set_chunked_reading_mode(true);
             let pin = reader.get_string();
-            if pin != *email_pin {
+            if !self.check_email_pin(&pin) {
                 let _ = self
                     .bus
                     .send(
@@ -64,17 +63,6 @@ impl Player {
             return;
         }
 
-        let conn = self.pool.get_conn();
-
-        let mut conn = match conn.await {
-            Ok(conn) => conn,
-            Err(e) => {
-                self.close(format!("Error getting connection from pool: {}", e))
-                    .await;
-                return;
-            }
-        };
-
         let session_id = match self.take_session_id() {
             Ok(session_id) => session_id,
             Err(e) => {
@@ -92,9 +80,29 @@ impl Player {
             return;
         }
 
+        let target = BanTarget {
+            ip: Some(&self.ip),
+            hdid: Some(&create.hdid),
+            ..Default::default()
+        };
+
+        match find_ban(&self.db, &target).await {
+            Ok(None) => {}
+            Ok(Some(_)) => {
+                self.close("Banned player tried to create an account".to_string())
+                    .await;
+                return;
+            }
+            Err(e) => {
+                self.close(format!("Error checking if player is banned: {}", e))
+                    .await;
+                return;
+            }
+        }
+
         // TODO: validate name
 
-        let exists = match account_exists(&mut conn, &create.username).await {
+        let exists = match self.db.account_exists(&create.username).await {
             Ok(exists) => exists,
             Err(e) => {
                 self.close(format!("Error checking if account exists: {}", e))
@@ -122,20 +130,18 @@ impl Player {
 
         let password_hash = generate_password_hash(&create.username, &create.password);
 
-        match conn
-            .exec_drop(
-                include_str!("../../../sql/create_account.sql"),
-                params! {
-                    "name" => &create.username,
-                    "password_hash" => &password_hash,
-                    "real_name" => &create.full_name,
-                    "location" => &create.location,
-                    "email" => &create.email,
-                    "computer" => &create.computer,
-                    "hdid" => &create.hdid,
-                    "register_ip" => &self.ip,
-                },
-            )
+        match self
+            .db
+            .create_account(&NewAccount {
+                name: create.username.clone(),
+                password_hash,
+                real_name: create.full_name,
+                location: create.location,
+                email: create.email,
+                computer: create.computer,
+                hdid: create.hdid,
+                register_ip: self.ip.clone(),
+            })
             .await
         {
             Ok(_) => {
@@ -176,16 +182,7 @@ impl Player {
 
         // TODO: validate name
 
-        let mut conn = match self.pool.get_conn().await {
-            Ok(conn) => conn,
-            Err(e) => {
-                self.close(format!("Error getting connection from pool: {}", e))
-                    .await;
-                return;
-            }
-        };
-
-        let exists = match account_exists(&mut conn, &request.username).await {
+        let exists = match self.db.account_exists(&request.username).await {
             Ok(exists) => exists,
             Err(e) => {
                 self.close(format!("Error checking if account exists: {}", e))
@@ -256,17 +253,7 @@ impl Player {
             return;
         }
 
-        let conn = self.pool.get_conn();
-        let mut conn = match conn.await {
-            Ok(conn) => conn,
-            Err(e) => {
-                self.close(format!("Error getting connection from pool: {}", e))
-                    .await;
-                return;
-            }
-        };
-
-        let exists = match account_exists(&mut conn, &agree.username).await {
+        let exists = match self.db.account_exists(&agree.username).await {
             Ok(exists) => exists,
             Err(e) => {
                 self.close(format!("Error checking if account exists: {}", e))
@@ -300,16 +287,9 @@ impl Player {
             return;
         }
 
-        let row = match conn
-            .exec_first::<Row, &str, Params>(
-                include_str!("../../../sql/get_password_hash.sql"),
-                params! {
-                    "name" => &agree.username,
-                },
-            )
-            .await
-        {
-            Ok(row) => row,
+        let credentials = match self.db.get_account_credentials(&agree.username).await {
+            Ok(Some(credentials)) => credentials,
+            Ok(None) => return,
             Err(e) => {
                 error!("Error getting password hash: {}", e);
 
@@ -330,12 +310,10 @@ impl Player {
                     .await;
                 return;
             }
-        }
-        .unwrap();
+        };
 
-        let username: String = row.get("name").unwrap();
-        let password_hash: String = row.get("password_hash").unwrap();
-        if !validate_password(&username, &agree.old_password, &password_hash) {
+        let username = credentials.name;
+        if !validate_password(&username, &agree.old_password, &credentials.password_hash) {
             if self.login_attempts >= SETTINGS.server.max_login_attempts {
                 self.close("Too many password change attempts".to_string())
                     .await;
@@ -360,17 +338,10 @@ impl Player {
 
         self.login_attempts = 0;
 
-        let account_id: i32 = row.get("id").unwrap();
-
         let password_hash = generate_password_hash(&username, &agree.new_password);
-        if let Err(e) = conn
-            .exec_drop(
-                include_str!("../../../sql/update_password_hash.sql"),
-                params! {
-                    "id" => account_id,
-                    "password_hash" => &password_hash,
-                },
-            )
+        if let Err(e) = self
+            .db
+            .update_password_hash(credentials.id, &password_hash)
             .await
         {
             self.close(format!("Error updating password hash: {}", e))
@@ -404,21 +375,16 @@ impl Player {
 
         let code = self.generate_email_pin();
 
-        if let Err(e) = send_email(
+        self.world.outbox.queue(EMAILS.validation.render(
             &accept.email_address,
             &accept.account_name,
-            &get_lang_string!(&EMAILS.validation.subject, name = accept.account_name),
-            &get_lang_string!(
-                &EMAILS.validation.body,
-                name = accept.account_name,
-                code = code
-            ),
-        )
-        .await
-        {
-            self.close(format!("Failed to send email: {}", e)).await;
-            return;
-        }
+            &[
+                ("name", &accept.account_name),
+                ("code", &code),
+                ("expiry", &SETTINGS.account.email_pin_expiry.to_string()),
+                ("ip", &self.ip),
+            ],
+        ));
 
         let _ = self
             .bus
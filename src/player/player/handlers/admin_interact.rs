@@ -76,13 +76,13 @@ impl Player {
 
         let mut lines = Vec::new();
 
-        for npc in DROP_DB.npcs.iter() {
+        for npc in DROP_DB.load().npcs.iter() {
             if let Some(drop) = npc
                 .drops
                 .iter()
                 .find(|drop| drop.item_id == id && drop.min_amount > 0 && drop.max_amount > 0)
             {
-                let npc_name = match NPC_DB.npcs.get(npc.npc_id as usize - 1) {
+                let npc_name = match NPC_DB.load().npcs.get(npc.npc_id as usize - 1) {
                     Some(npc) => npc.name.to_owned(),
                     None => continue,
                 };
@@ -139,14 +139,15 @@ impl Player {
 
         let mut lines = Vec::new();
 
-        let npc = match DROP_DB.npcs.iter().find(|npc| npc.npc_id == id) {
+        let drop_db = DROP_DB.load();
+        let npc = match drop_db.npcs.iter().find(|npc| npc.npc_id == id) {
             Some(npc) => npc,
             None => return,
         };
 
         for drop in npc.drops.iter() {
             if drop.min_amount > 0 && drop.max_amount > 0 {
-                let item_name = match ITEM_DB.items.get(drop.item_id as usize - 1) {
+                let item_name = match ITEM_DB.load().items.get(drop.item_id as usize - 1) {
                     Some(item) => item.name.to_owned(),
                     None => continue,
                 };
@@ -3,13 +3,13 @@ use eolib::{
     protocol::net::{client::AttackUseClientPacket, PacketAction},
 };
 
-use crate::utils::timestamp_diff;
+use crate::{utils::timestamp_diff, SETTINGS};
 
 use super::super::Player;
 
 impl Player {
-    fn attack_use(&mut self, reader: EoReader) {
-        if let Some(map) = &self.map {
+    async fn attack_use(&mut self, reader: EoReader) {
+        if let Some(map) = self.map.clone() {
             let packet = match AttackUseClientPacket::deserialize(&reader) {
                 Ok(packet) => packet,
                 Err(e) => {
@@ -25,16 +25,23 @@ impl Player {
             self.timestamp = packet.timestamp;
 
             map.attack(self.id, packet.direction);
+
+            if let Some(signal) = self
+                .bot_detector
+                .attack(packet.timestamp, &SETTINGS.bot_detection)
+            {
+                self.add_bot_signal(signal).await;
+            }
         }
     }
 
-    pub fn handle_attack(&mut self, action: PacketAction, reader: EoReader) {
-        if self.captcha.is_some() {
+    pub async fn handle_attack(&mut self, action: PacketAction, reader: EoReader) {
+        if self.captcha.is_some() || self.bot_frozen {
             return;
         }
 
         match action {
-            PacketAction::Use => self.attack_use(reader),
+            PacketAction::Use => self.attack_use(reader).await,
             _ => error!("Unhandled packet Attack_{:?}", action),
         }
     }
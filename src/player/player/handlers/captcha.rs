@@ -44,6 +44,7 @@ impl Player {
             self.captcha = None;
 
             map.close_captcha(self.id, reward);
+            self.pass_bot_challenge().await;
         }
     }
 
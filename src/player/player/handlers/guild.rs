@@ -1,4 +1,3 @@
-use chrono::NaiveDateTime;
 use eolib::{
     data::{EoReader, EoSerialize},
     protocol::{
@@ -12,19 +11,19 @@ use eolib::{
                 GuildTellClientPacket, GuildUseClientPacket,
             },
             server::{
-                GuildAcceptServerPacket, GuildAgreeServerPacket, GuildMember,
-                GuildRankServerPacket, GuildReply, GuildReplyServerPacket, GuildReportServerPacket,
-                GuildSellServerPacket, GuildStaff, GuildTakeServerPacket, GuildTellServerPacket,
+                GuildAcceptServerPacket, GuildAgreeServerPacket, GuildRankServerPacket, GuildReply,
+                GuildReplyServerPacket, GuildReportServerPacket, GuildSellServerPacket,
+                GuildTakeServerPacket, GuildTellServerPacket,
             },
             PacketAction, PacketFamily,
         },
         r#pub::NpcType,
     },
 };
-use futures::StreamExt;
-use mysql_async::{params, prelude::Queryable, Conn, Params, Row};
 
 use crate::{
+    character::Character,
+    db::Db,
     player::{
         player::guild::{
             guild_exists, validate_guild_description, validate_guild_name, validate_guild_rank,
@@ -82,7 +81,7 @@ impl Player {
         };
 
         let player_id = self.id;
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         self.guild_create_members = Vec::with_capacity(SETTINGS.guild.min_players);
 
@@ -108,7 +107,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -123,15 +123,7 @@ impl Player {
                 return;
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting SQL connection: {}", e);
-                    return;
-                }
-            };
-
-            if guild_exists(&mut conn, &request.guild_tag, &request.guild_name).await {
+            if guild_exists(&db, &request.guild_tag, &request.guild_name).await {
                 player.send_guild_reply(GuildReply::Exists);
                 return;
             }
@@ -200,7 +192,7 @@ impl Player {
         };
 
         let player_id = self.id;
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         let guild_create_members = self.guild_create_members.clone();
         self.guild_create_members.clear();
 
@@ -229,28 +221,16 @@ impl Player {
                 return;
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting SQL connection: {}", e);
-                    return;
-                }
-            };
-
-            if guild_exists(&mut conn, &create.guild_tag, &create.guild_name).await {
+            if guild_exists(&db, &create.guild_tag, &create.guild_name).await {
                 player.send_guild_reply(GuildReply::Exists);
                 return;
             }
 
             let guild_tag = create.guild_tag.to_uppercase();
 
-            if let Err(e) = create_guild(
-                &mut conn,
-                &guild_tag,
-                &create.guild_name,
-                &create.description,
-            )
-            .await
+            if let Err(e) = db
+                .create_guild(&guild_tag, &create.guild_name, &create.description)
+                .await
             {
                 error!("Error creating guild: {}", e);
                 return;
@@ -315,7 +295,7 @@ impl Player {
 
         let recruiter_id = self.id;
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
             let character = match map.get_character(recruiter_id).await {
@@ -337,15 +317,7 @@ impl Player {
                 None => return,
             };
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            let guild_bank = get_guild_bank(&mut conn, character.guild_tag.as_ref().unwrap()).await;
+            let guild_bank = get_guild_bank(&db, character.guild_tag.as_ref().unwrap()).await;
             if guild_bank < SETTINGS.guild.recruit_cost {
                 player.send(
                     PacketAction::Reply,
@@ -358,19 +330,20 @@ impl Player {
                 return;
             }
 
-            if let Err(e) =
-                set_guild_bank(&mut conn, tag, guild_bank - SETTINGS.guild.recruit_cost).await
+            if let Err(e) = db
+                .set_guild_bank(tag, guild_bank - SETTINGS.guild.recruit_cost)
+                .await
             {
                 error!("Error setting guild bank: {}", e);
                 return;
             }
 
-            let guild_name = match get_guild_name(&mut conn, tag).await {
+            let guild_name = match get_guild_name(&db, tag).await {
                 Some(guild_name) => guild_name,
                 None => return,
             };
 
-            let rank_string = match get_new_member_guild_rank(&mut conn, tag).await {
+            let rank_string = match get_new_member_guild_rank(&db, tag).await {
                 Some(rank_string) => rank_string,
                 None => return,
             };
@@ -410,6 +383,7 @@ impl Player {
 
         let player_id = self.id;
         let world = self.world.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
             let character = match map.get_character(player_id).await {
@@ -426,10 +400,7 @@ impl Player {
                 return;
             }
 
-            match world
-                .get_character_by_name(&packet.member_name)
-                .await
-            {
+            match world.get_character_by_name(&packet.member_name).await {
                 Ok(member) => {
                     if member.guild_tag != character.guild_tag {
                         player.send_guild_reply(GuildReply::RemoveNotMember);
@@ -457,8 +428,24 @@ impl Player {
                     player.send_guild_reply(GuildReply::Removed);
                 }
                 Err(_) => {
-                    // TODO: Offline kick
-                    player.send_server_message("Offline kicking not currently supported");
+                    let member = match get_offline_guild_member(
+                        &db,
+                        player,
+                        character.guild_tag.as_deref(),
+                        &packet.member_name,
+                        GuildReply::RemoveNotMember,
+                        GuildReply::RemoveLeader,
+                    )
+                    .await
+                    {
+                        Some(member) => member,
+                        None => return,
+                    };
+
+                    match db.remove_guild_member(&member.name).await {
+                        Ok(()) => player.send_guild_reply(GuildReply::Removed),
+                        Err(e) => error!("Error removing guild member: {}", e),
+                    }
                 }
             }
         });
@@ -492,24 +479,17 @@ impl Player {
             None => return,
         };
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         let player_id = self.id;
 
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
             let npc_id = match map.get_npc_id_for_index(npc_index).await {
                 Some(npc_id) => npc_id,
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -535,8 +515,7 @@ impl Player {
             match packet.info_type {
                 GuildInfoType::Description => {
                     let description =
-                        get_guild_description(&mut conn, character.guild_tag.as_ref().unwrap())
-                            .await;
+                        get_guild_description(&db, character.guild_tag.as_ref().unwrap()).await;
 
                     player.send(
                         PacketAction::Take,
@@ -545,8 +524,7 @@ impl Player {
                     );
                 }
                 GuildInfoType::Ranks => {
-                    let ranks =
-                        get_guild_ranks(&mut conn, character.guild_tag.as_ref().unwrap()).await;
+                    let ranks = get_guild_ranks(&db, character.guild_tag.as_ref().unwrap()).await;
 
                     player.send(
                         PacketAction::Rank,
@@ -568,7 +546,7 @@ impl Player {
                 }
                 GuildInfoType::Bank => {
                     let gold_amount =
-                        get_guild_bank(&mut conn, character.guild_tag.as_ref().unwrap()).await;
+                        get_guild_bank(&db, character.guild_tag.as_ref().unwrap()).await;
 
                     player.send(
                         PacketAction::Sell,
@@ -651,25 +629,17 @@ impl Player {
             None => return,
         };
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         let player_id = self.id;
 
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Failed to get SQL connection: {}", e);
-                    return;
-                }
-            };
-
             let npc_id = match map.get_npc_id_for_index(npc_index).await {
                 Some(npc_id) => npc_id,
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -705,7 +675,7 @@ impl Player {
                         player,
                         character.guild_tag.as_ref().unwrap(),
                         description.description,
-                        &mut conn,
+                        &db,
                     )
                     .await
                 }
@@ -714,7 +684,7 @@ impl Player {
                         player,
                         character.guild_tag.as_ref().unwrap(),
                         ranks.ranks,
-                        &mut conn,
+                        &db,
                     )
                     .await
                 }
@@ -756,7 +726,7 @@ impl Player {
 
         let world = self.world.clone();
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         let player_id = self.id;
 
@@ -766,7 +736,7 @@ impl Player {
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -796,32 +766,37 @@ impl Player {
                 None => return,
             };
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            let ranks = get_guild_ranks(&mut conn, guild_tag).await;
+            let ranks = get_guild_ranks(&db, guild_tag).await;
             let rank_str = match ranks.get(packet.rank as usize - 1) {
                 Some(rank) => rank,
                 None => return,
             };
 
-            let target_character = match world
-                .get_character_by_name(&packet.member_name)
-                .await
-            {
+            let target_character = match world.get_character_by_name(&packet.member_name).await {
                 Ok(character) => character,
                 Err(_) => {
-                    player.send_server_message("Offline rank updating not currently supported");
+                    let member = match get_offline_guild_member(
+                        &db,
+                        player,
+                        Some(guild_tag),
+                        &packet.member_name,
+                        GuildReply::RankingNotMember,
+                        GuildReply::RankingLeader,
+                    )
+                    .await
+                    {
+                        Some(member) => member,
+                        None => return,
+                    };
+
+                    match db
+                        .set_guild_member_rank(&member.name, packet.rank, rank_str)
+                        .await
+                    {
+                        Ok(()) => player.send_guild_reply(GuildReply::Updated),
+                        Err(e) => error!("Error updating guild member rank: {}", e),
+                    }
                     return;
-                    // TODO: handle offline
-                    /* self.assign_guild_rank_offline(guild_tag, member_name, rank, rank_str)
-                        .await;
-                    return; */
                 }
             };
 
@@ -882,7 +857,7 @@ impl Player {
             None => return,
         };
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         let player_id = self.id;
 
         tokio::spawn(async move {
@@ -899,7 +874,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -908,136 +884,41 @@ impl Player {
                 return;
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
+            let details = match db.get_guild_details(&report.guild_identity).await {
+                Ok(Some(details)) => details,
+                Ok(None) => {
+                    player.send_guild_reply(GuildReply::NotFound);
                     return;
                 }
-            };
-
-            let mut result = match conn
-                .exec_iter(
-                    "CALL GetGuildDetails(:guild_identity);",
-                    params! {
-                        "guild_identity" => &report.guild_identity,
-                    },
-                )
-                .await
-            {
-                Ok(result) => result,
                 Err(e) => {
                     error!("Error getting guild details: {}", e);
                     return;
                 }
             };
 
-            let mut packet = GuildReportServerPacket::default();
-
-            {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
-
-                let mut row = match stream.next().await {
-                    Some(Ok(row)) => row,
-                    Some(Err(e)) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                    None => {
-                        error!("Error getting guild details: no rows returned");
-                        return;
-                    }
-                };
-
-                packet.tag = row.take("tag").unwrap();
-                packet.name = row.take("name").unwrap();
-                packet.description = row.take("description").unwrap();
-
-                let created_at: NaiveDateTime = row.take("created_at").unwrap();
-                packet.create_date = created_at.format("%Y-%m-%d").to_string();
-
-                let bank: i32 = row.take("bank").unwrap();
-                packet.wealth = if bank < 2000 {
+            let mut packet = GuildReportServerPacket {
+                tag: details.tag,
+                name: details.name,
+                description: details.description,
+                create_date: details.created_at.format("%Y-%m-%d").to_string(),
+                wealth: if details.bank < 2000 {
                     "bankrupt".to_string()
-                } else if bank < 10_000 {
+                } else if details.bank < 10_000 {
                     "poor".to_string()
-                } else if bank < 50_000 {
+                } else if details.bank < 50_000 {
                     "normal".to_string()
-                } else if bank < 100_000 {
+                } else if details.bank < 100_000 {
                     "wealthy".to_string()
                 } else {
                     "very wealthy".to_string()
-                };
-            }
-
-            {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
-
-                let mut index = 0;
-                while let Some(row) = stream.next().await {
-                    let mut row = match row {
-                        Ok(row) => row,
-                        Err(e) => {
-                            error!("Error getting guild details: {}", e);
-                            return;
-                        }
-                    };
-
-                    // Client won't display ranks less than 4 characters long
-                    packet.ranks[index] =
-                        format!("{:<4}", row.take::<String, &str>("rank").unwrap());
-
-                    index += 1;
-                }
-            }
-
-            {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
-
-                while let Some(row) = stream.next().await {
-                    let mut row = match row {
-                        Ok(row) => row,
-                        Err(e) => {
-                            error!("Error getting guild details: {}", e);
-                            return;
-                        }
-                    };
+                },
+                staff: details.staff,
+                ..Default::default()
+            };
 
-                    packet.staff.push(GuildStaff {
-                        rank: row.take("guild_rank").unwrap(),
-                        name: row.take("name").unwrap(),
-                    });
-                }
+            for (index, rank) in details.ranks.iter().take(packet.ranks.len()).enumerate() {
+                // Client won't display ranks less than 4 characters long
+                packet.ranks[index] = format!("{:<4}", rank);
             }
 
             player.send(PacketAction::Report, PacketFamily::Guild, &packet);
@@ -1072,7 +953,7 @@ impl Player {
             None => return,
         };
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         let player_id = self.id;
 
@@ -1090,7 +971,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -1099,28 +981,7 @@ impl Player {
                 return;
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            let members: Vec<GuildMember> = match conn
-                .exec_map(
-                    include_str!("../../../sql/get_guild_memberlist.sql"),
-                    params! {
-                        "guild_identity" => &tell.guild_identity,
-                    },
-                    |mut row: Row| GuildMember {
-                        rank: row.take("guild_rank").unwrap(),
-                        name: row.take("name").unwrap(),
-                        rank_name: row.take("guild_rank_string").unwrap(),
-                    },
-                )
-                .await
-            {
+            let members = match db.get_guild_members(&tell.guild_identity).await {
                 Ok(members) => members,
                 Err(e) => {
                     error!("Error getting guild memberlist: {}", e);
@@ -1166,7 +1027,7 @@ impl Player {
 
         let player_id = self.id;
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
             let character = match map.get_character(player_id).await {
@@ -1184,29 +1045,9 @@ impl Player {
                 None => return,
             };
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
             if character.guild_rank == Some(1) {
-                let leader_count = match conn
-                    .exec_map(
-                        include_str!("../../../sql/get_count_guild_leader.sql"),
-                        params! {
-                            "guild_tag" => guild_tag,
-                        },
-                        |mut row: Row| row.take::<i32, usize>(0).unwrap(),
-                    )
-                    .await
-                {
-                    Ok(leader_counts) => match leader_counts.first() {
-                        Some(leader_count) => *leader_count,
-                        None => 0,
-                    },
+                let leader_count = match db.get_guild_leader_count(guild_tag).await {
+                    Ok(leader_count) => leader_count,
                     Err(e) => {
                         error!("Error getting leader count: {}", e);
                         return;
@@ -1279,7 +1120,7 @@ impl Player {
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -1330,26 +1171,12 @@ impl Player {
     }
 }
 
-async fn update_guild_description(
-    player: &PlayerHandle,
-    tag: &str,
-    description: String,
-    conn: &mut Conn,
-) {
+async fn update_guild_description(player: &PlayerHandle, tag: &str, description: String, db: &Db) {
     if !validate_guild_description(&description) {
         return;
     }
 
-    match conn
-        .exec_drop(
-            "UPDATE Guild SET `description` = :description WHERE `tag` = :tag",
-            params! {
-                "description" => description,
-                "tag" => tag,
-            },
-        )
-        .await
-    {
+    match db.set_guild_description(tag, &description).await {
         Ok(_) => {
             player.send_guild_reply(GuildReply::Updated);
         }
@@ -1359,29 +1186,19 @@ async fn update_guild_description(
     };
 }
 
-async fn update_guild_ranks(player: &PlayerHandle, tag: &str, ranks: [String; 9], conn: &mut Conn) {
+async fn update_guild_ranks(player: &PlayerHandle, tag: &str, ranks: [String; 9], db: &Db) {
     if ranks.iter().any(|rank| !validate_guild_rank(rank)) {
         return;
     }
 
-    let existing_ranks = get_guild_ranks(conn, tag).await;
+    let existing_ranks = get_guild_ranks(db, tag).await;
 
     for (index, rank) in ranks.iter().enumerate() {
         if existing_ranks[index].eq(rank) {
             continue;
         }
 
-        if let Err(e) = conn
-            .exec_drop(
-                include_str!("../../../sql/update_guild_rank.sql"),
-                params! {
-                    "rank" => rank,
-                    "tag" => tag,
-                    "index" => index,
-                },
-            )
-            .await
-        {
+        if let Err(e) = db.set_guild_rank(tag, index, rank).await {
             error!("Error updating guild rank: {}", e);
             return;
         }
@@ -1390,18 +1207,9 @@ async fn update_guild_ranks(player: &PlayerHandle, tag: &str, ranks: [String; 9]
     player.send_guild_reply(GuildReply::RanksUpdated);
 }
 
-async fn get_guild_description(conn: &mut Conn, tag: &str) -> String {
-    match conn
-        .exec_first::<Row, &str, Params>(
-            include_str!("../../../sql/get_guild_description.sql"),
-            params! {
-                "tag" => tag,
-            },
-        )
-        .await
-    {
-        Ok(Some(row)) => {
-            let description: String = row.get(0).unwrap();
+async fn get_guild_description(db: &Db, tag: &str) -> String {
+    match db.get_guild_description(tag).await {
+        Ok(Some(description)) => {
             if description.is_empty() {
                 " ".to_owned()
             } else {
@@ -1416,102 +1224,67 @@ async fn get_guild_description(conn: &mut Conn, tag: &str) -> String {
     }
 }
 
-async fn get_guild_bank(conn: &mut Conn, tag: &str) -> i32 {
-    match conn
-        .exec_first::<Row, &str, Params>(
-            "SELECT `bank` FROM Guild WHERE `tag` = :tag",
-            params! {
-                "tag" => tag,
-            },
-        )
-        .await
-    {
-        Ok(Some(row)) => row.get::<i32, usize>(0).unwrap(),
+async fn get_guild_bank(db: &Db, tag: &str) -> i32 {
+    match db.get_guild_bank(tag).await {
+        Ok(bank) => bank,
         Err(e) => {
             error!("Error getting guild bank: {}", e);
             0
         }
-        _ => 0,
     }
 }
 
-async fn set_guild_bank(conn: &mut Conn, tag: &str, bank: i32) -> Result<(), mysql_async::Error> {
-    conn.exec_drop(
-        "UPDATE Guild SET `bank` = :bank WHERE `tag` = :tag",
-        params! {
-            "bank" => bank,
-            "tag" => tag,
-        },
-    )
-    .await
-}
-
-async fn get_guild_name(conn: &mut Conn, tag: &str) -> Option<String> {
-    match conn
-        .exec_first::<Row, &str, Params>(
-            "SELECT `name` FROM Guild WHERE `tag` = :tag",
-            params! {
-                "tag" => tag,
-            },
-        )
-        .await
-    {
-        Ok(Some(row)) => Some(row.get(0).unwrap()),
+async fn get_guild_name(db: &Db, tag: &str) -> Option<String> {
+    match db.get_guild_name(tag).await {
+        Ok(name) => name,
         Err(e) => {
             error!("Error getting guild name: {}", e);
             None
         }
-        _ => None,
     }
 }
 
-async fn get_new_member_guild_rank(conn: &mut Conn, tag: &str) -> Option<String> {
-    match conn
-        .exec_first::<Row, &str, Params>(
-            "SELECT `rank` FROM Guild INNER JOIN GuildRank ON GuildRank.`guild_id` = Guild.`id` AND GuildRank.`index` = 8 WHERE `tag` = :tag",
-            params! {
-                "tag" => tag,
-            },
-        )
-        .await
-    {
-        Ok(Some(row)) => Some(row.get(0).unwrap()),
+async fn get_new_member_guild_rank(db: &Db, tag: &str) -> Option<String> {
+    match db.get_guild_ranks(tag).await {
+        Ok(ranks) => ranks.get(8).cloned(),
         Err(e) => {
             error!("Error getting guild rank: {}", e);
             None
         }
-        _ => None,
     }
 }
 
-async fn create_guild(
-    conn: &mut Conn,
-    tag: &str,
+/// Loads a member of `guild_tag` who isn't online. Replies to the leader and
+/// returns None if they aren't in the guild or are a leader themselves
+async fn get_offline_guild_member(
+    db: &Db,
+    player: &PlayerHandle,
+    guild_tag: Option<&str>,
     name: &str,
-    description: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    conn.exec_drop(
-        include_str!("../../../sql/create_guild.sql"),
-        params! {
-            "tag" => tag,
-            "name" => name,
-            "description" => description,
-        },
-    )
-    .await?;
-
-    let guild_id = conn.last_insert_id().unwrap();
-
-    conn.exec_drop(
-        include_str!("../../../sql/create_guild_ranks.sql"),
-        params! {
-            "guild_id" => guild_id,
-            "leader_rank_name" => SETTINGS.guild.default_leader_rank_name.clone(),
-            "recruiter_rank_name" => SETTINGS.guild.default_recruiter_rank_name.clone(),
-            "new_member_rank_name" => SETTINGS.guild.default_new_member_rank_name.clone(),
-        },
-    )
-    .await?;
+    not_member_reply: GuildReply,
+    leader_reply: GuildReply,
+) -> Option<Character> {
+    let member = match Character::load(db, name).await {
+        Ok(Some(member)) => member,
+        Ok(None) => {
+            player.send_guild_reply(not_member_reply);
+            return None;
+        }
+        Err(e) => {
+            error!("Error loading guild member: {}", e);
+            return None;
+        }
+    };
+
+    if guild_tag.is_none() || member.guild_tag.as_deref() != guild_tag {
+        player.send_guild_reply(not_member_reply);
+        return None;
+    }
+
+    if member.is_guild_leader() {
+        player.send_guild_reply(leader_reply);
+        return None;
+    }
 
-    Ok(())
+    Some(member)
 }
@@ -3,7 +3,13 @@ use eolib::protocol::net::{PacketAction, PacketFamily};
 use eolib::protocol::Coords;
 
 use crate::commands::{ArgType, Command};
-use crate::{character::Character, player::PlayerHandle, world::WorldHandle};
+use crate::{
+    audit::AuditEvent,
+    character::Character,
+    db::parse_ip_range,
+    player::PlayerHandle,
+    world::{ReloadKind, WorldHandle},
+};
 
 use crate::{COMMANDS, ITEM_DB, NPC_DB};
 
@@ -80,7 +86,7 @@ async fn evacuate(character: &Character, world: &WorldHandle) {
     map.start_evacuate();
 }
 
-async fn spawn_item(args: &[String], character: &Character) {
+async fn spawn_item(args: &[String], character: &Character, world: &WorldHandle) {
     let player = match character.player.as_ref() {
         Some(player) => player,
         None => return,
@@ -93,6 +99,7 @@ async fn spawn_item(args: &[String], character: &Character) {
         Err(_) => {
             // find matches from item db where name starts with identifier
             match ITEM_DB
+                .load()
                 .items
                 .iter()
                 .position(|item| item.name.to_lowercase() == identifier.to_lowercase())
@@ -125,10 +132,13 @@ async fn spawn_item(args: &[String], character: &Character) {
         };
 
         map.give_item(player_id, item_id, amount);
+        world
+            .audit
+            .record(character, AuditEvent::AdminSpawnItem { item_id, amount });
     }
 }
 
-async fn spawn_npc(args: &[String], character: &Character) {
+async fn spawn_npc(args: &[String], character: &Character, world: &WorldHandle) {
     let player = match character.player.as_ref() {
         Some(player) => player,
         None => return,
@@ -141,6 +151,7 @@ async fn spawn_npc(args: &[String], character: &Character) {
         Err(_) => {
             // find matches from item db where name starts with identifier
             match NPC_DB
+                .load()
                 .npcs
                 .iter()
                 .position(|npc| npc.name.to_lowercase() == identifier.to_lowercase())
@@ -179,6 +190,27 @@ async fn spawn_npc(args: &[String], character: &Character) {
         };
 
         map.spawn_npc(player_id, npc_id, amount, speed);
+        world
+            .audit
+            .record(character, AuditEvent::AdminSpawnNpc { npc_id, amount });
+    }
+}
+
+async fn audit(args: &[String], player: &PlayerHandle, world: &WorldHandle) {
+    let limit = match args.get(1) {
+        Some(limit) => limit.parse::<usize>().unwrap(),
+        None => 10,
+    };
+
+    let entries = world.audit.get_entries(&args[0], limit).await;
+    if entries.is_empty() {
+        send_error_message(player, format!("No audit entries for \"{}\".", args[0]));
+        return;
+    }
+
+    // Oldest first so the newest ends up at the bottom of the chat
+    for entry in entries.iter().rev() {
+        player.send_server_message(&entry.to_string());
     }
 }
 
@@ -270,11 +302,18 @@ pub async fn handle_command(
     let mut args: Vec<String> = args[1..].iter().map(|s| s.to_string()).collect();
 
     match COMMANDS
+        .load()
         .commands
         .iter()
         .find(|c| c.name == command || c.alias == command)
     {
         Some(command) => {
+            if command.name.as_str() == "warn" && args.len() > 2 {
+                // the reason can be more than one word
+                let reason = args[1..].join(" ");
+                args = vec![args[0].to_owned(), reason];
+            }
+
             if command.name.as_str() == "spawnitem" && args.len() > 1 {
                 if let Ok(amount) = args.last().unwrap().parse::<u32>() {
                     // join all but the last arg into a single string
@@ -290,9 +329,10 @@ pub async fn handle_command(
                 && validate_args(&args, command, &player)
             {
                 match command.name.as_str() {
+                    "audit" => audit(&args, &player, &world).await,
                     "hide" => hide(character).await,
-                    "spawnitem" => spawn_item(&args, character).await,
-                    "spawnnpc" => spawn_npc(&args, character).await,
+                    "spawnitem" => spawn_item(&args, character, &world).await,
+                    "spawnnpc" => spawn_npc(&args, character, &world).await,
                     "warp" => warp(&args, character, &world).await,
                     "warptome" => warp_to_me(&args, character, &world).await,
                     "warpmeto" => warp_me_to(&args, character, &world).await,
@@ -314,6 +354,7 @@ pub async fn handle_command(
                         },
                         character.name.to_owned(),
                         false,
+                        false,
                     ),
                     "sban" => world.ban_player(
                         args[0].to_owned(),
@@ -324,13 +365,71 @@ pub async fn handle_command(
                         },
                         character.name.to_owned(),
                         true,
+                        false,
+                    ),
+                    "banall" => world.ban_player(
+                        args[0].to_owned(),
+                        if args.len() > 1 {
+                            args[1].to_owned()
+                        } else {
+                            "".to_string()
+                        },
+                        character.name.to_owned(),
+                        false,
+                        true,
                     ),
                     "quake" => world.quake(if args.is_empty() {
                         1
                     } else {
                         args[0].parse::<i32>().unwrap()
                     }),
-                    "mute" => world.mute_player(args[0].to_owned(), character.name.to_owned()),
+                    "banip" => match parse_ip_range(&args[0]) {
+                        Some(range) => world.ban_ip(
+                            range.trunc().to_string(),
+                            character.name.to_owned(),
+                            args.get(1).cloned().unwrap_or_default(),
+                        ),
+                        None => send_error_message(
+                            &player,
+                            format!("\"{}\" is not an address or CIDR range.", args[0]),
+                        ),
+                    },
+                    "banhdid" => world.ban_hdid(
+                        args[0].to_owned(),
+                        character.name.to_owned(),
+                        args.get(1).cloned().unwrap_or_default(),
+                    ),
+                    "unban" => world.unban(args[0].to_owned(), character.name.to_owned()),
+                    "unlock" => world.unlock_login(args[0].to_owned(), character.name.to_owned()),
+                    "baninfo" => {
+                        world.request_ban_info(character.player_id.unwrap(), args[0].to_owned())
+                    }
+                    "bans" => world.request_bans(
+                        character.player_id.unwrap(),
+                        args.first()
+                            .map_or(10, |count| count.parse::<usize>().unwrap()),
+                    ),
+                    "mute" => world.mute_player(
+                        args[0].to_owned(),
+                        character.name.to_owned(),
+                        args.get(1).cloned().unwrap_or_default(),
+                        false,
+                    ),
+                    "globalmute" => world.mute_player(
+                        args[0].to_owned(),
+                        character.name.to_owned(),
+                        args.get(1).cloned().unwrap_or_default(),
+                        true,
+                    ),
+                    "unmute" => world.unmute_player(args[0].to_owned(), character.name.to_owned()),
+                    "warn" => world.warn_player(
+                        args[0].to_owned(),
+                        character.name.to_owned(),
+                        args[1].to_owned(),
+                    ),
+                    "sanctions" => {
+                        world.request_sanctions(character.player_id.unwrap(), args[0].to_owned())
+                    }
                     "player" => {
                         world.request_player_info(character.player_id.unwrap(), args[0].to_owned())
                     }
@@ -342,6 +441,13 @@ pub async fn handle_command(
                     }
                     "global" => world.toggle_global(character.name.to_owned()),
                     "remap" => world.reload_map(character.map_id),
+                    "reload" => match ReloadKind::parse(args.first().map_or("", |a| a.as_str())) {
+                        Some(kind) => world.reload(kind),
+                        None => send_error_message(
+                            &player,
+                            format!("Unknown reload type. (usage: \"{}\")", command.usage),
+                        ),
+                    },
                     "captcha" => {
                         world.show_captcha(args[0].to_owned(), args[1].parse::<i32>().unwrap())
                     }
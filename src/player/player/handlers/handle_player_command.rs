@@ -48,6 +48,7 @@ async fn autopickup(player_id: i32, args: &[String], player: &PlayerHandle, map:
         Err(_) => {
             // find matches from item db where name starts with identifier
             match ITEM_DB
+                .load()
                 .items
                 .iter()
                 .position(|item| item.name.to_lowercase() == identifier.to_lowercase())
@@ -143,6 +144,7 @@ pub async fn handle_player_command(
     let mut args: Vec<String> = args[1..].iter().map(|s| s.to_string()).collect();
 
     match PLAYER_COMMANDS
+        .load()
         .commands
         .iter()
         .find(|c| c.name == command || c.alias == command)
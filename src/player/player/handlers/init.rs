@@ -31,6 +31,8 @@ impl Player {
             }
         };
 
+        self.hdid = request.hdid.clone();
+
         if let Some(duration) = self.get_ban_duration().await {
             let _ = self
                 .bus
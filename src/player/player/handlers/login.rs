@@ -1,36 +1,34 @@
-use eolib::{
-    data::{EoReader, EoSerialize},
-    protocol::net::{
-        client::LoginRequestClientPacket,
-        server::{
-            LoginReply, LoginReplyServerPacket, LoginReplyServerPacketReplyCodeData,
-            LoginReplyServerPacketReplyCodeDataBanned, LoginReplyServerPacketReplyCodeDataBusy,
-            LoginReplyServerPacketReplyCodeDataLoggedIn, LoginReplyServerPacketReplyCodeDataOk,
-            LoginReplyServerPacketReplyCodeDataWrongUser,
-            LoginReplyServerPacketReplyCodeDataWrongUserPassword,
-        },
-        PacketAction, PacketFamily,
-    },
-};
-use mysql_async::{params, prelude::Queryable, Params, Row};
-
 use crate::{
+    db::{find_ban, BanTarget, LoginLockKind},
     deep::{
         AccountRecoverPinReply, AccountRecoverReply, AccountRecoverUpdateReply,
         LoginAcceptClientPacket, LoginAcceptServerPacket, LoginAgreeClientPacket,
-        LoginAgreeServerPacket, LoginConfigServerPacket, LoginCreateClientPacket,
-        LoginCreateServerPacket, LoginTakeClientPacket, LoginTakeServerPacket, ACTION_CONFIG,
+        LoginAgreeServerPacket, LoginCreateClientPacket, LoginCreateServerPacket,
+        LoginTakeClientPacket, LoginTakeServerPacket,
     },
     player::{
         player::account::{
-            account_banned, account_exists, generate_password_hash, get_character_list,
-            update_last_login_ip, validate_password,
+            dummy_password_hash, generate_password_hash, has_queue_priority, validate_password,
         },
         ClientState,
     },
-    utils::{is_deep, mask_email, send_email},
+    utils::mask_email,
+    world::LoginQueueResult,
     EMAILS, SETTINGS,
 };
+use eolib::{
+    data::{EoReader, EoSerialize},
+    protocol::net::{
+        client::LoginRequestClientPacket,
+        server::{
+            LoginReply, LoginReplyServerPacket, LoginReplyServerPacketReplyCodeData,
+            LoginReplyServerPacketReplyCodeDataBanned, LoginReplyServerPacketReplyCodeDataBusy,
+            LoginReplyServerPacketReplyCodeDataLoggedIn,
+            LoginReplyServerPacketReplyCodeDataWrongUserPassword,
+        },
+        PacketAction, PacketFamily,
+    },
+};
 
 use super::super::Player;
 
@@ -50,41 +48,43 @@ impl Player {
             return;
         }
 
-        let player_count = self.world.get_player_count().await;
-        if player_count >= SETTINGS.server.max_players {
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Reply,
-                    PacketFamily::Login,
-                    LoginReplyServerPacket {
-                        reply_code: LoginReply::Busy,
-                        reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
-                            LoginReplyServerPacketReplyCodeDataBusy::new(),
-                        )),
-                    },
-                )
-                .await;
-
-            self.close("Server busy".to_string()).await;
-
-            return;
-        }
+        // Locks are kept by lowercase name so changing case doesn't get around them
+        let lock_name = request.username.to_lowercase();
 
-        let conn = self.pool.get_conn();
-        let mut conn = match conn.await {
-            Ok(conn) => conn,
+        match self.get_login_lock(&lock_name).await {
+            Ok(Some(seconds)) => {
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Reply,
+                        PacketFamily::Login,
+                        LoginReplyServerPacket {
+                            reply_code: LoginReply::Busy,
+                            reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
+                                LoginReplyServerPacketReplyCodeDataBusy::new(),
+                            )),
+                        },
+                    )
+                    .await;
+                self.close(format!(
+                    "Login to {} locked for another {} seconds",
+                    request.username, seconds
+                ))
+                .await;
+                return;
+            }
+            Ok(None) => {}
             Err(e) => {
-                self.close(format!("Error getting connection from pool: {}", e))
+                self.close(format!("Error checking login locks: {}", e))
                     .await;
                 return;
             }
-        };
+        }
 
-        let exists = match account_exists(&mut conn, &request.username).await {
-            Ok(exists) => exists,
+        let credentials = match self.db.get_account_credentials(&request.username).await {
+            Ok(credentials) => credentials,
             Err(e) => {
-                self.close(format!("Error checking if account exists: {}", e))
+                self.close(format!("Error getting password hash: {}", e))
                     .await;
                 return;
             }
@@ -92,30 +92,69 @@ impl Player {
 
         self.login_attempts += 1;
 
-        if !exists {
-            if self.login_attempts >= SETTINGS.server.max_login_attempts {
-                self.close("Too many login attempts".to_string()).await;
+        // Unknown usernames get the same reply, in the same time, as wrong
+        // passwords so they can't be used to find out which accounts exist
+        let valid = match credentials.as_ref() {
+            Some(credentials) => validate_password(
+                &credentials.name,
+                &request.password,
+                &credentials.password_hash,
+            ),
+            None => {
+                validate_password(&request.username, &request.password, dummy_password_hash());
+                false
+            }
+        };
+
+        let credentials = match credentials {
+            Some(credentials) if valid => credentials,
+            _ => {
+                self.add_login_failure(&lock_name).await;
+
+                if self.login_attempts >= SETTINGS.server.max_login_attempts {
+                    self.close("Too many login attempts".to_string()).await;
+                    return;
+                }
+
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Reply,
+                        PacketFamily::Login,
+                        LoginReplyServerPacket {
+                            reply_code: LoginReply::WrongUserPassword,
+                            reply_code_data: Some(
+                                LoginReplyServerPacketReplyCodeData::WrongUserPassword(
+                                    LoginReplyServerPacketReplyCodeDataWrongUserPassword::new(),
+                                ),
+                            ),
+                        },
+                    )
+                    .await;
                 return;
             }
+        };
 
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Reply,
-                    PacketFamily::Login,
-                    LoginReplyServerPacket {
-                        reply_code: LoginReply::WrongUser,
-                        reply_code_data: Some(LoginReplyServerPacketReplyCodeData::WrongUser(
-                            LoginReplyServerPacketReplyCodeDataWrongUser::new(),
-                        )),
-                    },
-                )
-                .await;
-            return;
+        if SETTINGS.login_lockout.enabled {
+            let ip = self.ip.clone();
+            for (kind, value) in [
+                (LoginLockKind::Account, lock_name.as_str()),
+                (LoginLockKind::Ip, ip.as_str()),
+            ] {
+                if let Err(e) = self.db.delete_login_lock(kind, value).await {
+                    error!("Failed to clear login lock for {}: {}", value, e);
+                }
+            }
         }
 
-        let banned = match account_banned(&mut conn, &request.username).await {
-            Ok(banned) => banned,
+        let target = BanTarget {
+            account_id: Some(credentials.id),
+            ip: Some(&self.ip),
+            hdid: Some(&self.hdid),
+        };
+
+        let banned = match find_ban(&self.db, &target).await {
+            Ok(ban) => ban.is_some(),
             Err(e) => {
                 self.close(format!("Error checking if account is banned: {}", e))
                     .await;
@@ -141,55 +180,10 @@ impl Player {
             return;
         }
 
-        let row = match conn
-            .exec_first::<Row, &str, Params>(
-                include_str!("../../../sql/get_password_hash.sql"),
-                params! {
-                    "name" => &request.username,
-                },
-            )
-            .await
-        {
-            Ok(row) => row,
-            Err(e) => {
-                self.close(format!("Error getting password hash: {}", e))
-                    .await;
-                return;
-            }
-        }
-        .unwrap();
-
-        let username: String = row.get("name").unwrap();
-        let password_hash: String = row.get("password_hash").unwrap();
-        let account_id: i32 = row.get("id").unwrap();
+        let account_id = credentials.id;
         let logged_in = self.world.is_logged_in(account_id).await;
         self.world.add_pending_login(account_id);
 
-        if !validate_password(&username, &request.password, &password_hash) {
-            self.world.remove_pending_login(account_id);
-            if self.login_attempts >= SETTINGS.server.max_login_attempts {
-                self.close("Too many login attempts".to_string()).await;
-                return;
-            }
-
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Reply,
-                    PacketFamily::Login,
-                    LoginReplyServerPacket {
-                        reply_code: LoginReply::WrongUserPassword,
-                        reply_code_data: Some(
-                            LoginReplyServerPacketReplyCodeData::WrongUserPassword(
-                                LoginReplyServerPacketReplyCodeDataWrongUserPassword::new(),
-                            ),
-                        ),
-                    },
-                )
-                .await;
-            return;
-        }
-
         if logged_in {
             self.world.remove_pending_login(account_id);
             if self.login_attempts >= SETTINGS.server.max_login_attempts {
@@ -213,54 +207,52 @@ impl Player {
             return;
         }
 
-        if let Err(e) = update_last_login_ip(&mut conn, account_id, &self.ip).await {
-            self.world.remove_pending_login(account_id);
-            self.close(format!("Error updating last login IP: {}", e))
-                .await;
-            return;
-        }
-
-        let characters = match get_character_list(&mut conn, account_id).await {
-            Ok(characters) => characters,
+        let priority = match has_queue_priority(&self.db, &credentials).await {
+            Ok(priority) => priority,
             Err(e) => {
-                self.close(format!("Error getting character list: {}", e))
+                self.world.remove_pending_login(account_id);
+                self.close(format!("Error checking login queue priority: {}", e))
                     .await;
                 return;
             }
         };
 
-        self.account_id = account_id;
-        self.world.add_logged_in_account(account_id);
-        self.state = ClientState::LoggedIn;
+        match self
+            .world
+            .request_login(self.id, account_id, priority)
+            .await
+        {
+            LoginQueueResult::Admitted => {
+                self.account_id = account_id;
+                self.finish_login().await;
+            }
+            LoginQueueResult::Queued { position } => {
+                self.account_id = account_id;
+                self.state = ClientState::Queued;
+                info!(
+                    "Server full, {} queued for login at position {}",
+                    credentials.name, position
+                );
+            }
+            LoginQueueResult::Full => {
+                self.world.remove_pending_login(account_id);
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Reply,
+                        PacketFamily::Login,
+                        LoginReplyServerPacket {
+                            reply_code: LoginReply::Busy,
+                            reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
+                                LoginReplyServerPacketReplyCodeDataBusy::new(),
+                            )),
+                        },
+                    )
+                    .await;
 
-        if is_deep(&self.version) {
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Unrecognized(ACTION_CONFIG),
-                    PacketFamily::Login,
-                    LoginConfigServerPacket {
-                        max_skins: SETTINGS.character.max_skin + 1,
-                        max_hair_modals: SETTINGS.character.max_hair_style,
-                        max_character_name: SETTINGS.character.max_name_length as i32,
-                    },
-                )
-                .await;
+                self.close("Server busy".to_string()).await;
+            }
         }
-
-        let _ = self
-            .bus
-            .send(
-                PacketAction::Reply,
-                PacketFamily::Login,
-                LoginReplyServerPacket {
-                    reply_code: LoginReply::OK,
-                    reply_code_data: Some(LoginReplyServerPacketReplyCodeData::OK(
-                        LoginReplyServerPacketReplyCodeDataOk { characters },
-                    )),
-                },
-            )
-            .await;
     }
 
     async fn login_take(&mut self, reader: EoReader) {
@@ -294,24 +286,8 @@ impl Player {
             }
         };
 
-        let mut conn = match self.pool.get_conn().await {
-            Ok(conn) => conn,
-            Err(e) => {
-                error!("Failed to get SQL connection: {}", e);
-                return;
-            }
-        };
-
-        let mut row: Row = match conn
-            .exec_first(
-                include_str!("../../../sql/get_account_email.sql"),
-                params! {
-                    "name" => &create.account_name,
-                },
-            )
-            .await
-        {
-            Ok(Some(row)) => row,
+        let (account_id, email) = match self.db.get_account_email(&create.account_name).await {
+            Ok(Some(account)) => account,
             Ok(None) => {
                 let _ = self
                     .bus
@@ -332,34 +308,20 @@ impl Player {
             }
         };
 
-        self.account_id = match row.take(0) {
-            Some(id) => id,
-            None => return,
-        };
-
-        let email: String = match row.take(1) {
-            Some(email) => email,
-            None => return,
-        };
+        self.account_id = account_id;
 
         let code = self.generate_email_pin();
 
-        if let Err(e) = send_email(
+        self.world.outbox.queue(EMAILS.recovery.render(
             &email,
             &create.account_name,
-            &get_lang_string!(&EMAILS.recovery.subject, name = create.account_name),
-            &get_lang_string!(
-                &EMAILS.recovery.body,
-                name = create.account_name,
-                code = code
-            ),
-        )
-        .await
-        {
-            self.close(format!("Failed to send recovery email: {}", e))
-                .await;
-            return;
-        }
+            &[
+                ("name", &create.account_name),
+                ("code", &code),
+                ("expiry", &SETTINGS.account.email_pin_expiry.to_string()),
+                ("ip", &self.ip),
+            ],
+        ));
 
         let _ = self
             .bus
@@ -395,10 +357,9 @@ impl Player {
             }
         };
 
-        let pin = match &self.email_pin {
-            Some(pin) => pin,
-            None => return,
-        };
+        if self.email_pin.is_none() {
+            return;
+        }
 
         let _ = self
             .bus
@@ -406,10 +367,10 @@ impl Player {
                 PacketAction::Accept,
                 PacketFamily::Login,
                 LoginAcceptServerPacket {
-                    reply_code: if *pin != accept.pin {
-                        AccountRecoverPinReply::WrongPin
-                    } else {
+                    reply_code: if self.check_email_pin(&accept.pin) {
                         AccountRecoverPinReply::OK
+                    } else {
+                        AccountRecoverPinReply::WrongPin
                     },
                 },
             )
@@ -431,25 +392,11 @@ impl Player {
             return;
         }
 
-        let mut conn = match self.pool.get_conn().await {
-            Ok(conn) => conn,
-            Err(e) => {
-                error!("Failed to get SQL connection: {}", e);
-                self.send_login_agree_error().await;
-                return;
-            }
-        };
-
         let password_hash = generate_password_hash(&agree.account_name, &agree.password);
 
-        if let Err(e) = conn
-            .exec_drop(
-                include_str!("../../../sql/update_password_hash.sql"),
-                params! {
-                    "id" => self.account_id,
-                    "password_hash" => &password_hash,
-                },
-            )
+        if let Err(e) = self
+            .db
+            .update_password_hash(self.account_id, &password_hash)
             .await
         {
             error!("Error updating password hash: {}", e);
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use eolib::{
     data::{EoReader, EoSerialize},
     protocol::{
@@ -13,6 +14,8 @@ use eolib::{
     },
 };
 
+use crate::{utils::is_deep, LANG};
+
 use super::{
     super::Player,
     handle_command::handle_command,
@@ -74,7 +77,7 @@ impl Player {
         });
     }
 
-    fn talk_msg(&mut self, reader: EoReader) {
+    async fn talk_msg(&mut self, reader: EoReader) {
         let msg = match TalkMsgClientPacket::deserialize(&reader) {
             Ok(msg) => msg,
             Err(e) => {
@@ -83,6 +86,11 @@ impl Player {
             }
         };
 
+        if self.is_muted(true) {
+            self.send_server_message(&LANG.muted).await;
+            return;
+        }
+
         let map = match &self.map {
             Some(map) => map.to_owned(),
             None => return,
@@ -103,7 +111,7 @@ impl Player {
         });
     }
 
-    fn talk_report(&mut self, reader: EoReader) {
+    async fn talk_report(&mut self, reader: EoReader) {
         let report = match TalkReportClientPacket::deserialize(&reader) {
             Ok(report) => report,
             Err(e) => {
@@ -112,6 +120,29 @@ impl Player {
             }
         };
 
+        // Clients that can't show a captcha answer bot challenges in chat
+        if !is_deep(&self.version) {
+            if let Some(captcha) = &self.captcha {
+                if report
+                    .message
+                    .trim()
+                    .eq_ignore_ascii_case(&captcha.challenge)
+                {
+                    self.captcha = None;
+                    self.pass_bot_challenge().await;
+                    self.send_server_message(&LANG.bot_challenge_passed).await;
+                    return;
+                }
+            }
+        }
+
+        // Commands still work while muted
+        let muted = self.is_muted(false);
+        if muted && !report.message.starts_with(['$', '#']) {
+            self.send_server_message(&LANG.muted).await;
+            return;
+        }
+
         let map = match &self.map {
             Some(map) => map.to_owned(),
             None => return,
@@ -150,6 +181,7 @@ impl Player {
 
                     if handle_player_command(player_id, args.as_slice(), &player, &map).await
                         == PlayerCommandResult::NotFound
+                        && !muted
                     {
                         map.send_chat_message(player_id, report.message);
                     }
@@ -157,11 +189,13 @@ impl Player {
                 }
             }
 
-            map.send_chat_message(player_id, report.message);
+            if !muted {
+                map.send_chat_message(player_id, report.message);
+            }
         });
     }
 
-    fn talk_tell(&mut self, reader: EoReader) {
+    async fn talk_tell(&mut self, reader: EoReader) {
         let tell = match TalkTellClientPacket::deserialize(&reader) {
             Ok(tell) => tell,
             Err(e) => {
@@ -170,11 +204,16 @@ impl Player {
             }
         };
 
+        if self.is_muted(false) {
+            self.send_server_message(&LANG.muted).await;
+            return;
+        }
+
         self.world
             .send_private_message(self.id, tell.name, tell.message);
     }
 
-    fn talk_open(&mut self, reader: EoReader) {
+    async fn talk_open(&mut self, reader: EoReader) {
         let open = match TalkOpenClientPacket::deserialize(&reader) {
             Ok(open) => open,
             Err(e) => {
@@ -183,10 +222,15 @@ impl Player {
             }
         };
 
+        if self.is_muted(false) {
+            self.send_server_message(&LANG.muted).await;
+            return;
+        }
+
         self.world.broadcast_party_message(self.id, open.message);
     }
 
-    fn talk_request(&mut self, reader: EoReader) {
+    async fn talk_request(&mut self, reader: EoReader) {
         let request = match TalkRequestClientPacket::deserialize(&reader) {
             Ok(request) => request,
             Err(e) => {
@@ -195,6 +239,11 @@ impl Player {
             }
         };
 
+        if self.is_muted(false) {
+            self.send_server_message(&LANG.muted).await;
+            return;
+        }
+
         let map = match &self.map {
             Some(map) => map.to_owned(),
             None => return,
@@ -224,15 +273,17 @@ impl Player {
         });
     }
 
-    pub fn handle_talk(&mut self, action: PacketAction, reader: EoReader) {
+    pub async fn handle_talk(&mut self, action: PacketAction, reader: EoReader) {
+        self.bot_detector.attend(Utc::now());
+
         match action {
             PacketAction::Admin => self.talk_admin(reader),
             PacketAction::Announce => self.talk_announce(reader),
-            PacketAction::Msg => self.talk_msg(reader),
-            PacketAction::Report => self.talk_report(reader),
-            PacketAction::Tell => self.talk_tell(reader),
-            PacketAction::Open => self.talk_open(reader),
-            PacketAction::Request => self.talk_request(reader),
+            PacketAction::Msg => self.talk_msg(reader).await,
+            PacketAction::Report => self.talk_report(reader).await,
+            PacketAction::Tell => self.talk_tell(reader).await,
+            PacketAction::Open => self.talk_open(reader).await,
+            PacketAction::Request => self.talk_request(reader).await,
             _ => error!("Unhandled packet Talk_{:?}", action),
         }
     }
@@ -1,38 +1,50 @@
+use chrono::Utc;
 use eolib::{
     data::{EoReader, EoSerialize},
     protocol::net::client::WalkPlayerClientPacket,
 };
 
-use crate::utils::timestamp_diff;
+use crate::{utils::timestamp_diff, SETTINGS};
 
 use super::super::Player;
 
 impl Player {
-    pub fn handle_walk(&mut self, reader: EoReader) {
-        if self.captcha.is_some() {
+    pub async fn handle_walk(&mut self, reader: EoReader) {
+        // Frozen players are only told to stop walking, a bot could ignore it
+        if self.captcha.is_some() || self.bot_frozen {
             return;
         }
 
-        if let Some(map) = &self.map {
-            let packet = match WalkPlayerClientPacket::deserialize(&reader) {
-                Ok(packet) => packet,
-                Err(e) => {
-                    error!("Error deserializing WalkPlayerClientPacket {}", e);
-                    return;
-                }
-            };
+        let map = match &self.map {
+            Some(map) => map.to_owned(),
+            None => return,
+        };
 
-            if timestamp_diff(packet.walk_action.timestamp, self.timestamp) < 36 {
+        let packet = match WalkPlayerClientPacket::deserialize(&reader) {
+            Ok(packet) => packet,
+            Err(e) => {
+                error!("Error deserializing WalkPlayerClientPacket {}", e);
                 return;
             }
+        };
 
-            self.timestamp = packet.walk_action.timestamp;
-
-            map.walk(
-                self.id,
-                packet.walk_action.direction,
-                packet.walk_action.coords,
-            );
+        let client_elapsed = timestamp_diff(packet.walk_action.timestamp, self.timestamp);
+        if let Some(violation) =
+            self.walk_validator
+                .check_walk(client_elapsed, Utc::now(), &SETTINGS.movement)
+        {
+            // Put the player back where the server has them
+            map.request_refresh(self.id);
+            self.add_movement_violation(violation).await;
+            return;
         }
+
+        self.timestamp = packet.walk_action.timestamp;
+
+        map.walk(
+            self.id,
+            packet.walk_action.direction,
+            packet.walk_action.coords,
+        );
     }
 }
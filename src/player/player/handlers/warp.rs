@@ -13,7 +13,7 @@ use eolib::{
     },
 };
 
-use crate::{errors::WrongSessionIdError, SETTINGS};
+use crate::{errors::WrongSessionIdError, status_effects::StatusEffectTick, SETTINGS};
 
 use super::super::Player;
 
@@ -72,6 +72,14 @@ impl Player {
         character.coords = warp_session.coords;
         character.sit_state = SitState::Stand;
 
+        let cleared = if warp_session.local {
+            StatusEffectTick::default()
+        } else {
+            character.clear_status_effects(SETTINGS.status_effects.clear_on_warp)
+        };
+        let class_id = character.class;
+        let stats = character.get_character_stats_update();
+
         self.character = None;
 
         let agree = if warp_session.local {
@@ -147,6 +155,9 @@ impl Player {
             .bus
             .send(PacketAction::Agree, PacketFamily::Warp, agree)
             .await;
+
+        self.send_status_effects_cleared(&cleared, class_id, stats)
+            .await;
     }
 
     async fn warp_take(&mut self, reader: EoReader) {
@@ -0,0 +1,14 @@
+use crate::db::SanctionKind;
+
+use super::Player;
+
+impl Player {
+    /// Whether the player can't talk in the chat they're using. Global chat is
+    /// blocked by global mutes as well as full ones
+    pub fn is_muted(&mut self, global: bool) -> bool {
+        self.mutes.retain(|mute| mute.is_active_mute());
+        self.mutes
+            .iter()
+            .any(|mute| global || mute.kind == SanctionKind::Mute)
+    }
+}
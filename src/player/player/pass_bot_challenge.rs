@@ -0,0 +1,24 @@
+use chrono::Utc;
+use eolib::protocol::net::{server::WalkOpenServerPacket, PacketAction, PacketFamily};
+
+use super::Player;
+
+impl Player {
+    /// Clears the player's bot score and lifts any freeze from failing
+    /// earlier challenges
+    pub async fn pass_bot_challenge(&mut self) {
+        self.bot_detector.pass(Utc::now());
+
+        if self.bot_frozen {
+            self.bot_frozen = false;
+            let _ = self
+                .bus
+                .send(
+                    PacketAction::Open,
+                    PacketFamily::Walk,
+                    WalkOpenServerPacket::new(),
+                )
+                .await;
+        }
+    }
+}
@@ -7,6 +7,8 @@ use eolib::protocol::{
 };
 use eoplus::Arg;
 
+use crate::audit::AuditEvent;
+
 use super::Player;
 
 impl Player {
@@ -36,6 +38,24 @@ impl Player {
                 self.request_warp(map_id, Coords { x, y }, false, None)
                     .await;
             }
+            "SetCoord" => {
+                let x = match args.first() {
+                    Some(Arg::Int(x)) => *x,
+                    _ => return,
+                };
+
+                let y = match args.get(1) {
+                    Some(Arg::Int(y)) => *y,
+                    _ => return,
+                };
+
+                let map_id = match map.get_character(self.id).await {
+                    Some(character) => character.map_id,
+                    None => return,
+                };
+
+                self.request_warp(map_id, Coords { x, y }, true, None).await;
+            }
             "GiveItem" => {
                 let item_id = match args.first() {
                     Some(Arg::Int(item_id)) => *item_id,
@@ -48,6 +68,12 @@ impl Player {
                 };
 
                 map.give_item(self.id, item_id, amount);
+
+                if let Some(character) = map.get_character(self.id).await {
+                    self.world
+                        .audit
+                        .record(&character, AuditEvent::QuestGiveItem { item_id, amount });
+                }
             }
             "RemoveItem" => {
                 let item_id = match args.first() {
@@ -70,6 +96,43 @@ impl Player {
 
                 map.set_class(self.id, class_id);
             }
+            "SetRace" => {
+                if let Some(Arg::Int(race)) = args.first() {
+                    map.set_stat(self.id, "race".to_owned(), *race);
+                }
+            }
+            "SetGender" => {
+                if let Some(Arg::Int(gender)) = args.first() {
+                    map.set_stat(self.id, "gender".to_owned(), *gender);
+                }
+            }
+            "GiveSpell" => {
+                if let Some(Arg::Int(spell_id)) = args.first() {
+                    map.give_spell(self.id, *spell_id);
+                }
+            }
+            "RemoveSpell" => {
+                if let Some(Arg::Int(spell_id)) = args.first() {
+                    map.remove_spell(self.id, *spell_id);
+                }
+            }
+            "GiveStat" | "RemoveStat" | "SetStat" => {
+                let stat = match args.first() {
+                    Some(Arg::Str(stat)) => stat.to_owned(),
+                    _ => return,
+                };
+
+                let value = match args.get(1) {
+                    Some(Arg::Int(value)) => *value,
+                    _ => return,
+                };
+
+                match action.as_str() {
+                    "GiveStat" => map.give_stat(self.id, stat, value),
+                    "RemoveStat" => map.give_stat(self.id, stat, -value),
+                    _ => map.set_stat(self.id, stat, value),
+                }
+            }
             "PlayMusic" => {
                 if let Some(Arg::Int(sound_id)) = args.first() {
                     let _ = self
@@ -139,6 +202,14 @@ impl Player {
                     }
                 }
             }
+            "QuakeWorld" => {
+                let magnitude = match args.first() {
+                    Some(Arg::Int(magnitude)) => *magnitude,
+                    _ => 5,
+                };
+
+                self.world.quake(magnitude);
+            }
             "EffectOnPlayer" => {
                 let effect_id = match args.first() {
                     Some(Arg::Int(effect_id)) => *effect_id,
@@ -0,0 +1,40 @@
+use eolib::protocol::net::{
+    server::{CharacterStatsUpdate, RecoverListServerPacket, WalkOpenServerPacket},
+    PacketAction, PacketFamily,
+};
+
+use crate::status_effects::StatusEffectTick;
+
+use super::Player;
+
+impl Player {
+    /// Updates the client after status effects were cleared on death or warp
+    pub async fn send_status_effects_cleared(
+        &mut self,
+        cleared: &StatusEffectTick,
+        class_id: i32,
+        stats: CharacterStatsUpdate,
+    ) {
+        if cleared.stats_changed {
+            let _ = self
+                .bus
+                .send(
+                    PacketAction::List,
+                    PacketFamily::Recover,
+                    RecoverListServerPacket { class_id, stats },
+                )
+                .await;
+        }
+
+        if cleared.stun_ended {
+            let _ = self
+                .bus
+                .send(
+                    PacketAction::Open,
+                    PacketFamily::Walk,
+                    WalkOpenServerPacket::new(),
+                )
+                .await;
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use eolib::protocol::net::{PacketAction, PacketFamily};
-use rand::Rng;
 
 use crate::{
     deep::{CaptchaOpenServerPacket, FAMILY_CAPTCHA},
@@ -15,19 +14,13 @@ impl Player {
             return;
         }
 
-        let captcha: String = {
-            let mut rng = rand::thread_rng();
-            (0..5)
-                .map(|_| {
-                    rng.gen_range(65..=90) as u8 as char // ASCII codes for upper case letters
-                })
-                .collect()
-        };
+        let captcha = Captcha::generate_challenge();
 
         self.captcha = Some(Captcha {
             challenge: captcha.to_owned(),
             reward: experience,
             attempts: 0,
+            expires_at: None,
         });
 
         if let Some(map) = &self.map {
@@ -24,6 +24,26 @@ impl Player {
             }
         }
 
+        let now = Utc::now();
+
+        if self
+            .captcha
+            .as_ref()
+            .and_then(|captcha| captcha.expires_at)
+            .is_some_and(|expires_at| now >= expires_at)
+        {
+            self.fail_bot_challenge().await;
+        }
+
+        if self.state == ClientState::InGame {
+            if let Some(signal) = self
+                .bot_detector
+                .check_unattended(now, &SETTINGS.bot_detection)
+            {
+                self.add_bot_signal(signal).await;
+            }
+        }
+
         self.bus.log.clean_old_entries();
     }
 }
@@ -1,7 +1,8 @@
+use crate::{
+    deep::{CaptchaAgreeServerPacket, FAMILY_CAPTCHA},
+    player::Captcha,
+};
 use eolib::protocol::net::{PacketAction, PacketFamily};
-use rand::Rng;
-
-use crate::deep::{CaptchaAgreeServerPacket, FAMILY_CAPTCHA};
 
 use super::super::Player;
 
@@ -12,14 +13,7 @@ impl Player {
             None => return,
         };
 
-        captcha.challenge = {
-            let mut rng = rand::thread_rng();
-            (0..5)
-                .map(|_| {
-                    rng.gen_range(65..=90) as u8 as char // ASCII codes for upper case letters
-                })
-                .collect()
-        };
+        captcha.challenge = Captcha::generate_challenge();
 
         captcha.attempts = 0;
 
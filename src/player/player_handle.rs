@@ -11,15 +11,19 @@ use eolib::{
     },
 };
 use eoplus::Arg;
-use mysql_async::Pool;
 use tokio::{
     net::TcpStream,
     sync::{mpsc, oneshot},
 };
 
-use crate::{character::Character, map::MapHandle, world::WorldHandle};
+use crate::{
+    character::Character,
+    db::{Db, Sanction},
+    map::MapHandle,
+    world::WorldHandle,
+};
 
-use super::{player::Player, ClientState, Command, PartyRequest};
+use super::{player::Player, ClientState, Command, MovementViolation, PartyRequest};
 
 #[derive(Debug, Clone)]
 pub struct PlayerHandle {
@@ -32,10 +36,10 @@ impl PlayerHandle {
         socket: TcpStream,
         connected_at: DateTime<Utc>,
         world: WorldHandle,
-        pool: Pool,
+        db: Db,
     ) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        let player = Player::new(id, socket, connected_at, rx, world, pool);
+        let player = Player::new(id, socket, connected_at, rx, world, db);
         tokio::spawn(run_player(player));
 
         Self { tx }
@@ -47,6 +51,22 @@ impl PlayerHandle {
             .send(Command::AddGuildCreationPlayer { player_id, name });
     }
 
+    pub fn add_movement_violation(&self, violation: MovementViolation) {
+        let _ = self.tx.send(Command::AddMovementViolation(violation));
+    }
+
+    pub fn add_mute(&self, mute: Sanction) {
+        let _ = self.tx.send(Command::AddMute(Box::new(mute)));
+    }
+
+    pub fn add_npc_kill(&self, coords: Coords) {
+        let _ = self.tx.send(Command::AddNpcKill(coords));
+    }
+
+    pub fn admit_login(&self) {
+        let _ = self.tx.send(Command::AdmitLogin);
+    }
+
     pub fn arena_die(&self, spawn_coords: Coords) {
         let _ = self.tx.send(Command::ArenaDie { spawn_coords });
     }
@@ -55,6 +75,15 @@ impl PlayerHandle {
         let _ = self.tx.send(Command::CancelTrade);
     }
 
+    #[cfg(test)]
+    pub fn challenge_bot(&self) {
+        let _ = self.tx.send(Command::ChallengeBot);
+    }
+
+    pub fn clear_mutes(&self) {
+        let _ = self.tx.send(Command::ClearMutes);
+    }
+
     pub fn close(&self, reason: String) {
         let _ = self.tx.send(Command::Close(reason));
     }
@@ -63,6 +92,16 @@ impl PlayerHandle {
         let _ = self.tx.send(Command::Die);
     }
 
+    #[cfg(test)]
+    pub async fn enter_game(&self, character: Character) -> bool {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::EnterGame {
+            character: Box::new(character),
+            respond_to: tx,
+        });
+        rx.await.unwrap_or(false)
+    }
+
     pub async fn generate_session_id(
         &self,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+
+use crate::settings::Movement;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementViolation {
+    /// Walk timestamps closer together than a player can walk
+    TooFast,
+    /// Walks arriving faster than a player can walk by the server's clock
+    Speeding,
+    /// Client position more than a step away from where the server has them
+    Teleport,
+}
+
+/// Tracks a player's walks to catch speed hacks
+#[derive(Debug, Default)]
+pub struct WalkValidator {
+    walked_at: Option<DateTime<Utc>>,
+    /// Milliseconds the player's walks have got ahead of the server's clock
+    drift: i64,
+    violations: i32,
+    violated_at: Option<DateTime<Utc>>,
+}
+
+impl WalkValidator {
+    /// Checks a walk made `client_elapsed` hundredths of a second after the
+    /// previous one by the client's clock
+    pub fn check_walk(
+        &mut self,
+        client_elapsed: i32,
+        now: DateTime<Utc>,
+        settings: &Movement,
+    ) -> Option<MovementViolation> {
+        if client_elapsed < settings.min_walk_interval {
+            return Some(MovementViolation::TooFast);
+        }
+
+        // Walks that arrive early build up drift and walks that arrive late
+        // pay it back, so lag bunching packets together evens out
+        if let Some(walked_at) = self.walked_at {
+            let elapsed = (now - walked_at).num_milliseconds();
+            self.drift = (self.drift + settings.min_walk_interval as i64 * 10 - elapsed).max(0);
+        }
+
+        self.walked_at = Some(now);
+
+        if self.drift > settings.max_walk_drift {
+            self.drift = 0;
+            return Some(MovementViolation::Speeding);
+        }
+
+        None
+    }
+
+    /// Records a violation. Returns true when the player has reached the
+    /// configured maximum, which starts the count over
+    pub fn add_violation(&mut self, now: DateTime<Utc>, settings: &Movement) -> bool {
+        if let Some(violated_at) = self.violated_at {
+            if (now - violated_at).num_seconds() >= settings.violation_reset {
+                self.violations = 0;
+            }
+        }
+
+        self.violated_at = Some(now);
+        self.violations += 1;
+
+        if settings.max_violations > 0 && self.violations >= settings.max_violations {
+            self.violations = 0;
+            return true;
+        }
+
+        false
+    }
+}
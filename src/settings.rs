@@ -16,11 +16,40 @@ pub struct Server {
     pub max_version: String,
     pub save_rate: i32,
     pub generate_pub: bool,
+    pub hot_reload: bool,
     pub lang: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct LoginQueue {
+    pub enabled: bool,
+    pub priority_admin_level: i32,
+    pub priority_accounts: bool,
+    pub position_message_rate: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginLockout {
+    pub enabled: bool,
+    pub max_account_failures: i32,
+    pub max_ip_failures: i32,
+    pub lockout_duration: i64,
+    pub max_lockout_duration: i64,
+    pub reset_after: i64,
+    pub notify_email: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseDriver {
+    MySql,
+    Sqlite,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Database {
+    pub driver: DatabaseDriver,
+    pub path: String,
     pub host: String,
     pub port: String,
     pub name: String,
@@ -90,6 +119,15 @@ pub struct World {
 pub struct Bard {
     pub instrument_items: Vec<i32>,
     pub max_note_id: i32,
+    pub note_timeout: i64,
+    pub song_range: i32,
+    pub songs: Vec<BardSong>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BardSong {
+    pub spell_id: i32,
+    pub notes: Vec<i32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -109,6 +147,81 @@ pub struct NPCs {
     pub talk_rate: i32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BossLootRule {
+    Killer,
+    TopDamage,
+    Split,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Bosses {
+    pub rage_timer: i32,
+    pub rage_damage: f32,
+    pub loot_rule: BossLootRule,
+    pub loot_players: i32,
+    pub loot_min_damage: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MovementViolationResponse {
+    Log,
+    Captcha,
+    Kick,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Movement {
+    pub min_walk_interval: i32,
+    pub max_walk_drift: i64,
+    pub max_violations: i32,
+    pub violation_reset: i64,
+    pub violation_response: MovementViolationResponse,
+    pub min_refresh_interval: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusEffectClearPolicy {
+    All,
+    Harmful,
+    Keep,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StatusEffects {
+    pub clear_on_death: StatusEffectClearPolicy,
+    pub clear_on_warp: StatusEffectClearPolicy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotPenalty {
+    Freeze,
+    Jail,
+    Kick,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BotDetection {
+    pub enabled: bool,
+    pub attack_samples: usize,
+    pub min_attack_deviation: f64,
+    pub stationary_kills: i32,
+    pub stationary_range: i32,
+    pub unattended_minutes: i64,
+    pub attack_timing_score: i32,
+    pub stationary_score: i32,
+    pub unattended_score: i32,
+    pub challenge_score: i32,
+    pub score_reset: i64,
+    pub challenge_timeout: i64,
+    pub challenge_reward: i32,
+    pub penalties: Vec<BotPenalty>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Sln {
     pub enabled: bool,
@@ -204,6 +317,7 @@ pub struct Account {
     pub recovery: bool,
     pub recovery_show_email: bool,
     pub recovery_mask_email: bool,
+    pub email_pin_expiry: i64,
     pub max_characters: usize,
 }
 
@@ -260,15 +374,58 @@ pub struct Smtp {
     pub password: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailTransportKind {
+    Smtp,
+    Directory,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Email {
+    pub transport: EmailTransportKind,
+    pub directory: String,
+    pub poll_rate: u64,
+    pub retry_delay: i64,
+    pub max_retry_delay: i64,
+    pub max_attempts: i32,
+    pub send_timeout: u64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AutoPickup {
     pub enabled: bool,
     pub rate: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AdminApi {
+    pub enabled: bool,
+    pub host: String,
+    pub port: String,
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Metrics {
+    pub enabled: bool,
+    pub host: String,
+    pub port: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Audit {
+    pub enabled: bool,
+    pub directory: String,
+    pub retention_days: i32,
+    pub database: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Settings {
     pub server: Server,
+    pub login_queue: LoginQueue,
+    pub login_lockout: LoginLockout,
     pub database: Database,
     pub account: Account,
     pub new_character: NewCharacter,
@@ -277,7 +434,14 @@ pub struct Settings {
     pub world: World,
     pub character: Character,
     pub npcs: NPCs,
+    pub bosses: Bosses,
+    pub status_effects: StatusEffects,
+    pub movement: Movement,
+    pub bot_detection: BotDetection,
     pub sln: Sln,
+    pub admin_api: AdminApi,
+    pub metrics: Metrics,
+    pub audit: Audit,
     pub bank: Bank,
     pub limits: Limits,
     pub board: Board,
@@ -292,6 +456,7 @@ pub struct Settings {
     pub items: Items,
     pub bard: Bard,
     pub smtp: Smtp,
+    pub email: Email,
     pub auto_pickup: AutoPickup,
 }
 
@@ -0,0 +1,5 @@
+INSERT INTO `AuditEvent` (
+    `character_id`, `character_name`, `event`, `map_id`, `x`, `y`, `data`, `created_at`
+) VALUES (
+    :character_id, :character_name, :event, :map_id, :x, :y, :data, :created_at
+)
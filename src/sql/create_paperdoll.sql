@@ -0,0 +1,3 @@
+INSERT INTO `Paperdoll` (
+    `character_id`
+) VALUES (:character_id);
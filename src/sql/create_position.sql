@@ -0,0 +1,13 @@
+INSERT INTO `Position` (
+    `character_id`,
+    `map`,
+    `x`,
+    `y`,
+    `direction`
+) VALUES (
+    :character_id,
+    :map,
+    :x,
+    :y,
+    :direction
+);
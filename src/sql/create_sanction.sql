@@ -0,0 +1,5 @@
+INSERT INTO `Sanction` (
+    `character_id`, `type`, `reason`, `duration`, `created_by`, `created_at`
+) VALUES (
+    :character_id, :type, :reason, :duration, :created_by, :created_at
+)
@@ -0,0 +1 @@
+INSERT INTO `SchemaVersion` (`version`) VALUES (:version);
@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS `SchemaVersion` (
+    `version` INT NOT NULL PRIMARY KEY,
+    `applied_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1 @@
+INSERT INTO `Stats` (`character_id`) VALUES (:character_id)
@@ -0,0 +1,2 @@
+INSERT INTO StatusEffect (character_id, effect_id, remaining)
+VALUES (:character_id, :effect_id, :remaining);
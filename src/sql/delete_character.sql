@@ -0,0 +1 @@
+DELETE FROM `Character` WHERE `id` = :character_id;
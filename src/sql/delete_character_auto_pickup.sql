@@ -0,0 +1 @@
+DELETE FROM `AutoPickup` WHERE `character_id` = :character_id;
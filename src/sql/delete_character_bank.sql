@@ -0,0 +1 @@
+DELETE FROM `Bank` WHERE `character_id` = :character_id;
@@ -0,0 +1 @@
+DELETE FROM `Inventory` WHERE `character_id` = :character_id;
@@ -0,0 +1 @@
+DELETE FROM `Paperdoll` WHERE `character_id` = :character_id;
@@ -0,0 +1 @@
+DELETE FROM `Position` WHERE `character_id` = :character_id;
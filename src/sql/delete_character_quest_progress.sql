@@ -0,0 +1 @@
+DELETE FROM `QuestProgress` WHERE `character_id` = :character_id;
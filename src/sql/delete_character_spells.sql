@@ -0,0 +1 @@
+DELETE FROM `Spell` WHERE `character_id` = :character_id;
@@ -0,0 +1 @@
+DELETE FROM `Stats` WHERE `character_id` = :character_id;
@@ -0,0 +1,2 @@
+DELETE FROM StatusEffect
+WHERE character_id = :character_id;
@@ -0,0 +1 @@
+DELETE FROM `LoginLock` WHERE `kind` = :kind AND `value` = :value
@@ -0,0 +1 @@
+DELETE FROM `LoginLock` WHERE `value` = :value
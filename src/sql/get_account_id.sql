@@ -0,0 +1 @@
+SELECT id FROM `Account` WHERE `name` = :name
@@ -0,0 +1,5 @@
+SELECT `data`
+FROM `AuditEvent`
+WHERE `character_name` = :character_name
+ORDER BY `created_at` DESC, `id` DESC
+LIMIT :limit
@@ -0,0 +1,8 @@
+SELECT b.`id`, b.`account_id`, a.`name` AS `account_name`, b.`ip`, b.`hdid`, IFNULL(b.`duration`, 0) AS `duration`, c.`name` AS `created_by`, b.`created_at`
+FROM `Ban` b
+LEFT JOIN `Account` a
+  ON a.`id` = b.`account_id`
+LEFT JOIN `Character` c
+  ON c.`id` = b.`created_by`
+WHERE b.`lifted_at` IS NULL
+ORDER BY b.`created_at` DESC, b.`id` DESC
@@ -0,0 +1,3 @@
+SELECT `id`
+FROM `Character`
+WHERE `name` = :name;
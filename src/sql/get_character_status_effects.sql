@@ -0,0 +1,3 @@
+SELECT effect_id, remaining
+FROM StatusEffect
+WHERE character_id = :character_id;
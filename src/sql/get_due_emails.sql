@@ -0,0 +1,7 @@
+SELECT `id`, `to_address`, `to_name`, `subject`, `text_body`, `html_body`, `attempts`
+FROM `EmailOutbox`
+WHERE `sent_at` IS NULL
+AND `next_attempt_at` IS NOT NULL
+AND `next_attempt_at` <= :now
+ORDER BY `next_attempt_at`, `id`
+LIMIT :limit
@@ -0,0 +1 @@
+SELECT `bank` FROM Guild WHERE `tag` = :tag
@@ -0,0 +1,7 @@
+SELECT `tag`,
+       `name`,
+       `description`,
+       `created_at`,
+       `bank`
+FROM `Guild`
+WHERE :guild_identity IN (`tag`, `name`);
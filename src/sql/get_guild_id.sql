@@ -0,0 +1 @@
+SELECT id FROM Guild WHERE name = :name OR tag = :tag
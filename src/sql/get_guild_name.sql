@@ -0,0 +1 @@
+SELECT `name` FROM Guild WHERE `tag` = :tag
@@ -0,0 +1,7 @@
+SELECT `rank`
+FROM `GuildRank`
+INNER JOIN `Guild`
+	ON `Guild`.id = `GuildRank`.`guild_id`
+WHERE :guild_identity IN (`Guild`.`tag`, `Guild`.`name`)
+ORDER BY `guild_id`, `index`
+LIMIT 9;
@@ -0,0 +1,7 @@
+SELECT `Character`.`name`,
+       `Character`.`guild_rank`
+FROM `Guild`
+INNER JOIN `Character`
+	ON `Character`.`guild_id` = `Guild`.id
+	AND `Character`.`guild_rank` <= 2
+WHERE :guild_identity IN (`Guild`.`tag`, `Guild`.`name`);
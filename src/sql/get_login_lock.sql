@@ -0,0 +1,3 @@
+SELECT `failures`, `lockouts`, `locked_until`
+FROM `LoginLock`
+WHERE `kind` = :kind AND `value` = :value
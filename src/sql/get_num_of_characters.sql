@@ -0,0 +1 @@
+SELECT COUNT(id) FROM `Character` WHERE `account_id` = :account_id
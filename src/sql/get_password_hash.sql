@@ -1,3 +1,3 @@
-SELECT id, name, password_hash
+SELECT id, name, password_hash, queue_priority
 FROM Account
 WHERE name = :name
@@ -0,0 +1,4 @@
+SELECT `type`, `reason`, IFNULL(`duration`, 0) AS `duration`, `created_by`, `created_at`, `lifted_at`
+FROM `Sanction`
+WHERE `character_id` = :character_id
+ORDER BY `created_at` DESC, `id` DESC
@@ -0,0 +1 @@
+SELECT IFNULL(MAX(`version`), 0) FROM `SchemaVersion`;
@@ -0,0 +1,5 @@
+SELECT
+    (SELECT COUNT(*) FROM `Account`) 'accounts',
+    (SELECT COUNT(*) FROM `Character`) 'characters',
+    (SELECT COUNT(*) FROM `Guild`) 'guilds',
+    (SELECT COUNT(*) FROM `Character` WHERE `admin_level` > 0) 'admins';
@@ -0,0 +1 @@
+SELECT EXISTS(SELECT 1 FROM Guild WHERE name = :name OR tag = :tag)
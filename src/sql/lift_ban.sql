@@ -0,0 +1 @@
+UPDATE `Ban` SET `lifted_at` = :lifted_at WHERE `id` = :id;
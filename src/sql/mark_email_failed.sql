@@ -0,0 +1,7 @@
+UPDATE `EmailOutbox`
+SET `attempts` = :attempts,
+    `next_attempt_at` = :next_attempt_at,
+    `last_error` = :error,
+    `text_body` = CASE WHEN :next_attempt_at IS NULL THEN '' ELSE `text_body` END,
+    `html_body` = CASE WHEN :next_attempt_at IS NULL THEN NULL ELSE `html_body` END
+WHERE `id` = :id
@@ -0,0 +1,3 @@
+UPDATE `EmailOutbox`
+SET `sent_at` = :now, `next_attempt_at` = NULL, `text_body` = '', `html_body` = NULL
+WHERE `id` = :id
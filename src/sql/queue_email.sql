@@ -0,0 +1,7 @@
+INSERT INTO `EmailOutbox` (
+    `to_address`, `to_name`, `subject`, `text_body`, `html_body`,
+    `next_attempt_at`, `created_at`
+) VALUES (
+    :to_address, :to_name, :subject, :text_body, :html_body,
+    :now, :now
+)
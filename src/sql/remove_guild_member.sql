@@ -0,0 +1,3 @@
+UPDATE `Character`
+SET `guild_id` = NULL, `guild_rank` = NULL, `guild_rank_string` = NULL
+WHERE `name` = :name;
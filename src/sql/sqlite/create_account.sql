@@ -0,0 +1,7 @@
+INSERT INTO `Account` (
+    `name`, `password_hash`, `real_name`, `location`,
+    `email`, `computer`, `hdid`, `register_ip`
+) VALUES (
+    :name, :password_hash, :real_name, :location,
+    :email, :computer, :hdid, :register_ip
+)
@@ -0,0 +1,7 @@
+INSERT INTO `Character` (
+    `account_id`, `name`, `home`, `gender`, `race`,
+    `hair_style`, `hair_color`
+) VALUES (
+    :account_id, :name, :home, :gender, :race,
+    :hair_style, :hair_color
+)
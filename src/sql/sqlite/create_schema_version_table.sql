@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS `SchemaVersion` (
+    `version` INTEGER NOT NULL PRIMARY KEY,
+    `applied_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
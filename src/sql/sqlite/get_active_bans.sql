@@ -0,0 +1,18 @@
+SELECT b.`id`, b.`account_id`, a.`name` AS `account_name`, b.`ip`, b.`hdid`, IFNULL(b.`duration`, 0) AS `duration`, c.`name` AS `created_by`, b.`created_at`
+FROM `Ban` b
+LEFT JOIN `Account` a
+  ON a.`id` = b.`account_id`
+LEFT JOIN `Character` c
+  ON c.`id` = b.`created_by`
+WHERE b.`lifted_at` IS NULL
+AND (
+    IFNULL(b.`duration`, 0) = 0
+    OR DATETIME(b.`created_at`, '+' || b.`duration` || ' minutes') > DATETIME(:now)
+)
+AND (
+    b.`account_id` = :account_id
+    OR b.`hdid` = :hdid
+    OR b.`ip` = :ip
+    OR (:ip IS NOT NULL AND b.`ip` LIKE '%/%')
+)
+ORDER BY b.`created_at` DESC, b.`id` DESC
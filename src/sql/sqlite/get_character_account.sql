@@ -0,0 +1,5 @@
+SELECT a.id, a.last_login_ip, CAST(a.hdid AS TEXT)
+FROM `Character` c
+INNER JOIN `Account` a
+    ON a.id = c.account_id
+WHERE c.name = :name
@@ -0,0 +1,5 @@
+SELECT COUNT(1)
+FROM `BoardPost`
+WHERE `board_id` = :board_id
+AND `character_id` = :character_id
+AND `created_at` > datetime('now', '-' || :post_time || ' minutes')
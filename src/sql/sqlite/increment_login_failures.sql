@@ -0,0 +1,7 @@
+INSERT INTO `LoginLock` (`kind`, `value`, `failures`, `lockouts`, `last_failure_at`)
+VALUES (:kind, :value, 1, 0, :now)
+ON CONFLICT (`kind`, `value`) DO UPDATE SET
+    `failures` = CASE WHEN `last_failure_at` <= :reset_before THEN 1 ELSE `failures` + 1 END,
+    `lockouts` = CASE WHEN `last_failure_at` <= :reset_before THEN 0 ELSE `lockouts` END,
+    `last_failure_at` = :now
+RETURNING `failures`, `lockouts`, `locked_until`
@@ -0,0 +1,9 @@
+UPDATE `Sanction`
+SET `lifted_at` = :now
+WHERE `character_id` = :character_id
+AND `type` IN ('mute', 'global_mute')
+AND `lifted_at` IS NULL
+AND (
+    `duration` IS NULL
+    OR DATETIME(`created_at`, '+' || `duration` || ' minutes') > DATETIME(:now)
+)
@@ -0,0 +1,4 @@
+UPDATE `GuildRank`
+SET `rank` = :rank
+WHERE `guild_id` = (SELECT `id` FROM `Guild` WHERE `tag` = :tag)
+AND `index` = :index
@@ -0,0 +1,3 @@
+UPDATE `Account`
+SET `last_login_ip` = :ip, `updated_at` = CURRENT_TIMESTAMP
+WHERE `id` = :account_id
@@ -0,0 +1,3 @@
+UPDATE `LoginLock`
+SET `failures` = 0, `lockouts` = `lockouts` + 1, `locked_until` = :locked_until
+WHERE `kind` = :kind AND `value` = :value AND `failures` >= :max_failures
@@ -0,0 +1 @@
+UPDATE Guild SET `bank` = :bank WHERE `tag` = :tag
@@ -0,0 +1,3 @@
+UPDATE `Character`
+SET `guild_rank` = :guild_rank, `guild_rank_string` = :guild_rank_string
+WHERE `name` = :name;
@@ -0,0 +1,3 @@
+UPDATE `Position`
+SET `map` = :map, `x` = :x, `y` = :y
+WHERE `character_id` = (SELECT `id` FROM `Character` WHERE `name` = :name)
@@ -0,0 +1,322 @@
+use config::{Config, ConfigError, File};
+
+use crate::settings::StatusEffectClearPolicy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum StatusEffectKind {
+    /// Only changes stats
+    Modifier,
+    /// Takes `amount` HP every `interval` seconds
+    DamageOverTime,
+    /// Restores `amount` HP and `tp` TP every `interval` seconds
+    Regen,
+    /// Can't walk, attack or cast
+    Stun,
+    /// NPCs act `amount` percent faster. Does nothing to players
+    Haste,
+    /// NPCs act `amount` percent slower. Does nothing to players
+    Slow,
+}
+
+impl StatusEffectKind {
+    pub fn is_npc_only(&self) -> bool {
+        matches!(self, Self::Haste | Self::Slow)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct StatModifiers {
+    pub max_hp: i32,
+    pub max_tp: i32,
+    pub min_damage: i32,
+    pub max_damage: i32,
+    pub accuracy: i32,
+    pub evade: i32,
+    pub armor: i32,
+    pub str: i32,
+    pub intl: i32,
+    pub wis: i32,
+    pub agi: i32,
+    pub con: i32,
+    pub cha: i32,
+}
+
+impl StatModifiers {
+    pub fn add(&mut self, other: &StatModifiers) {
+        self.max_hp += other.max_hp;
+        self.max_tp += other.max_tp;
+        self.min_damage += other.min_damage;
+        self.max_damage += other.max_damage;
+        self.accuracy += other.accuracy;
+        self.evade += other.evade;
+        self.armor += other.armor;
+        self.str += other.str;
+        self.intl += other.intl;
+        self.wis += other.wis;
+        self.agi += other.agi;
+        self.con += other.con;
+        self.cha += other.cha;
+    }
+
+    fn total(&self) -> i32 {
+        self.max_hp
+            + self.max_tp
+            + self.min_damage
+            + self.max_damage
+            + self.accuracy
+            + self.evade
+            + self.armor
+            + self.str
+            + self.intl
+            + self.wis
+            + self.agi
+            + self.con
+            + self.cha
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusEffect {
+    pub id: i32,
+    pub name: String,
+    pub kind: StatusEffectKind,
+    /// Seconds the effect lasts
+    pub duration: i32,
+    /// Seconds between each damage or regen tick
+    #[serde(default = "default_interval")]
+    pub interval: i32,
+    #[serde(default)]
+    pub amount: i32,
+    #[serde(default)]
+    pub tp: i32,
+    #[serde(default)]
+    pub modifiers: StatModifiers,
+    /// Effect shown on players it's applied to
+    #[serde(default)]
+    pub graphic_id: i32,
+    /// Spells that apply the effect to their target
+    #[serde(default)]
+    pub spells: Vec<i32>,
+    /// Items that apply the effect to whoever uses them
+    #[serde(default)]
+    pub items: Vec<i32>,
+}
+
+fn default_interval() -> i32 {
+    1
+}
+
+impl StatusEffect {
+    pub fn is_harmful(&self) -> bool {
+        match self.kind {
+            StatusEffectKind::DamageOverTime | StatusEffectKind::Stun | StatusEffectKind::Slow => {
+                true
+            }
+            _ => self.modifiers.total() < 0,
+        }
+    }
+
+    fn is_cleared_by(&self, policy: StatusEffectClearPolicy) -> bool {
+        match policy {
+            StatusEffectClearPolicy::All => true,
+            StatusEffectClearPolicy::Harmful => self.is_harmful(),
+            StatusEffectClearPolicy::Keep => false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/StatusEffects.ron"))
+            .add_source(File::with_name("config/StatusEffects.local.ron").required(false))
+            .build()?;
+
+        s.try_deserialize()
+    }
+
+    pub fn get(&self, id: i32) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.id == id)
+    }
+
+    pub fn for_spell(&self, spell_id: i32) -> impl Iterator<Item = &StatusEffect> {
+        self.effects
+            .iter()
+            .filter(move |effect| effect.spells.contains(&spell_id))
+    }
+
+    pub fn for_item(&self, item_id: i32) -> impl Iterator<Item = &StatusEffect> {
+        self.effects
+            .iter()
+            .filter(move |effect| effect.items.contains(&item_id))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveStatusEffect {
+    pub effect_id: i32,
+    /// Seconds left before the effect wears off
+    pub remaining: i32,
+    /// Seconds until the next damage or regen tick
+    pub next_tick: i32,
+    /// Player whose spell applied the effect
+    pub caster_id: Option<i32>,
+}
+
+/// What ticking or clearing status effects did to a character or NPC
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StatusEffectTick {
+    pub damage: i32,
+    /// Player credited with the damage
+    pub caster_id: Option<i32>,
+    pub hp: i32,
+    pub tp: i32,
+    /// Effects that changed stats wore off
+    pub stats_changed: bool,
+    /// A stun wore off
+    pub stun_ended: bool,
+}
+
+/// The status effects currently on a character or NPC
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActiveStatusEffects(pub Vec<ActiveStatusEffect>);
+
+impl ActiveStatusEffects {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Starts `effect`, or starts it over if it's already active
+    pub fn apply(&mut self, effect: &StatusEffect, caster_id: Option<i32>) {
+        self.0.retain(|active| active.effect_id != effect.id);
+        self.0.push(ActiveStatusEffect {
+            effect_id: effect.id,
+            remaining: effect.duration,
+            next_tick: effect.interval,
+            caster_id,
+        });
+    }
+
+    /// Picks an effect back up with `remaining` seconds left, like after a
+    /// relog
+    pub fn resume(&mut self, effects: &StatusEffects, effect_id: i32, remaining: i32) {
+        let effect = match effects.get(effect_id) {
+            Some(effect) => effect,
+            None => return,
+        };
+
+        self.0.retain(|active| active.effect_id != effect_id);
+        self.0.push(ActiveStatusEffect {
+            effect_id,
+            remaining,
+            next_tick: effect.interval,
+            caster_id: None,
+        });
+    }
+
+    pub fn has_kind(&self, effects: &StatusEffects, kind: StatusEffectKind) -> bool {
+        self.iter(effects).any(|effect| effect.kind == kind)
+    }
+
+    pub fn is_stunned(&self, effects: &StatusEffects) -> bool {
+        self.has_kind(effects, StatusEffectKind::Stun)
+    }
+
+    pub fn get_modifiers(&self, effects: &StatusEffects) -> StatModifiers {
+        let mut modifiers = StatModifiers::default();
+        for effect in self.iter(effects) {
+            modifiers.add(&effect.modifiers);
+        }
+        modifiers
+    }
+
+    /// Percent faster (or slower when negative) haste and slow make NPCs act
+    pub fn get_speed(&self, effects: &StatusEffects) -> i32 {
+        self.iter(effects)
+            .map(|effect| match effect.kind {
+                StatusEffectKind::Haste => effect.amount,
+                StatusEffectKind::Slow => -effect.amount,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Advances every effect by a second
+    pub fn tick(&mut self, effects: &StatusEffects) -> StatusEffectTick {
+        let mut tick = StatusEffectTick::default();
+
+        for active in self.0.iter_mut() {
+            let effect = match effects.get(active.effect_id) {
+                Some(effect) => effect,
+                None => continue,
+            };
+
+            active.remaining -= 1;
+            active.next_tick -= 1;
+
+            if active.next_tick <= 0 {
+                active.next_tick = effect.interval.max(1);
+                match effect.kind {
+                    StatusEffectKind::DamageOverTime => {
+                        tick.damage += effect.amount;
+                        tick.caster_id = tick.caster_id.or(active.caster_id);
+                    }
+                    StatusEffectKind::Regen => {
+                        tick.hp += effect.amount;
+                        tick.tp += effect.tp;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Effects removed from the config since they were applied go too
+        self.0.retain(|active| {
+            let effect = match effects.get(active.effect_id) {
+                Some(effect) => effect,
+                None => return false,
+            };
+
+            if active.remaining > 0 {
+                return true;
+            }
+
+            tick.stats_changed |= effect.modifiers != StatModifiers::default();
+            tick.stun_ended |= effect.kind == StatusEffectKind::Stun;
+            false
+        });
+
+        tick
+    }
+
+    /// Removes the effects `policy` says to
+    pub fn clear(
+        &mut self,
+        effects: &StatusEffects,
+        policy: StatusEffectClearPolicy,
+    ) -> StatusEffectTick {
+        let mut cleared = StatusEffectTick::default();
+        self.0.retain(|active| match effects.get(active.effect_id) {
+            Some(effect) if effect.is_cleared_by(policy) => {
+                cleared.stats_changed |= effect.modifiers != StatModifiers::default();
+                cleared.stun_ended |= effect.kind == StatusEffectKind::Stun;
+                false
+            }
+            Some(_) => true,
+            None => false,
+        });
+        cleared
+    }
+
+    fn iter<'a>(&'a self, effects: &'a StatusEffects) -> impl Iterator<Item = &'a StatusEffect> {
+        self.0
+            .iter()
+            .filter_map(|active| effects.get(active.effect_id))
+    }
+}
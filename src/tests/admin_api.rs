@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use eolib::protocol::net::{server::TalkAnnounceServerPacket, PacketAction, PacketFamily};
+use reqwest::StatusCode;
+use tokio::{net::TcpListener, sync::Notify};
+
+use crate::{admin_api, world::WorldHandle};
+
+use super::harness::{test_character, TestServer};
+
+const TOKEN: &str = "secret";
+
+async fn start_api(world: WorldHandle) -> (String, Arc<Notify>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let shutdown = Arc::new(Notify::new());
+
+    let api_shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        admin_api::serve_on(listener, world, api_shutdown, TOKEN).await;
+    });
+
+    (url, shutdown)
+}
+
+async fn get_json(url: &str) -> serde_json::Value {
+    let response = reqwest::Client::new()
+        .get(url)
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn rejects_requests_without_the_token() {
+    let server = TestServer::start().await;
+    let (url, _) = start_api(server.world.clone()).await;
+    let client = reqwest::Client::new();
+
+    let missing = client.get(format!("{}/players", url)).send().await.unwrap();
+    assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+
+    let wrong = client
+        .post(format!("{}/save", url))
+        .bearer_auth("guess")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(wrong.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn lists_players_and_map_counts() {
+    let mut server = TestServer::start().await;
+    let _alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let (url, _) = start_api(server.world.clone()).await;
+
+    let players = get_json(&format!("{}/players", url)).await;
+    assert_eq!(players[0]["name"], "alice");
+    assert_eq!(players[0]["map_id"], 1);
+
+    let map = get_json(&format!("{}/maps/1", url)).await;
+    assert_eq!(map["players"], 1);
+
+    let missing = reqwest::Client::new()
+        .get(format!("{}/maps/999", url))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn announcements_reach_players() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let (url, _) = start_api(server.world.clone()).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/announce", url))
+        .bearer_auth(TOKEN)
+        .header("Content-Type", "application/json")
+        .body(r#"{"admin":"Ops","message":"restart soon"}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let announce: TalkAnnounceServerPacket = alice
+        .expect(PacketAction::Announce, PacketFamily::Talk)
+        .await;
+    assert_eq!(announce.player_name, "Ops");
+    assert_eq!(announce.message, "restart soon");
+}
+
+#[tokio::test]
+async fn kicking_an_offline_player_is_not_found() {
+    let server = TestServer::start().await;
+    let (url, _) = start_api(server.world.clone()).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/players/nobody/kick", url))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn shutdown_notifies_main() {
+    let server = TestServer::start().await;
+    let (url, shutdown) = start_api(server.world.clone()).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/shutdown", url))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    tokio::time::timeout(std::time::Duration::from_secs(1), shutdown.notified())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn muting_with_an_invalid_duration_is_a_bad_request() {
+    let server = TestServer::start().await;
+    let (url, _) = start_api(server.world.clone()).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/players/alice/mute", url))
+        .bearer_auth(TOKEN)
+        .header("Content-Type", "application/json")
+        .body(r#"{"duration":"30x"}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
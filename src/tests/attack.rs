@@ -0,0 +1,60 @@
+use eolib::protocol::{
+    net::{
+        client::{AttackUseClientPacket, WalkAction, WalkPlayerClientPacket},
+        server::AttackPlayerServerPacket,
+        PacketAction, PacketFamily,
+    },
+    Coords, Direction,
+};
+
+use super::harness::{test_character, TestServer};
+
+#[tokio::test]
+async fn attacking_npc_is_seen_by_nearby_players() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 4, 4)).await;
+
+    // Spawn a stationary training dummy under alice then step away from it
+    server.map(1).await.spawn_npc(alice.player_id, 1, 1, 7);
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Player,
+            PacketFamily::Walk,
+            &WalkPlayerClientPacket {
+                walk_action: WalkAction {
+                    direction: Direction::Down,
+                    timestamp,
+                    coords: Coords { x: 2, y: 3 },
+                },
+            },
+        )
+        .await;
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Use,
+            PacketFamily::Attack,
+            &AttackUseClientPacket {
+                direction: Direction::Up,
+                timestamp,
+            },
+        )
+        .await;
+
+    let attack: AttackPlayerServerPacket =
+        bob.expect(PacketAction::Player, PacketFamily::Attack).await;
+    assert_eq!(attack.player_id, alice.player_id);
+    assert_eq!(attack.direction, Direction::Up);
+
+    loop {
+        match alice.recv().await {
+            Some((_, PacketFamily::Npc, _)) => break,
+            Some(_) => continue,
+            None => panic!("Attacker never received an npc reply"),
+        }
+    }
+}
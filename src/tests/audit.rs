@@ -0,0 +1,70 @@
+use std::fs;
+
+use eolib::protocol::net::{
+    client::ByteCoords,
+    server::{ItemDropServerPacket, ItemGetServerPacket},
+    PacketAction, PacketFamily, ThreeItem,
+};
+
+use crate::audit::{AuditEvent, AuditHandle};
+
+use super::harness::{test_character, TestServer};
+
+#[tokio::test]
+async fn drops_and_pickups_are_recorded() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+
+    let map = server.map(1).await;
+    map.drop_item(
+        alice.player_id,
+        ThreeItem { id: 1, amount: 30 },
+        ByteCoords { x: 0xFF, y: 0xFF },
+    );
+    let dropped: ItemDropServerPacket = alice.expect(PacketAction::Drop, PacketFamily::Item).await;
+
+    map.get_item(alice.player_id, dropped.item_index);
+    let _: ItemGetServerPacket = alice.expect(PacketAction::Get, PacketFamily::Item).await;
+
+    let entries = server.world.audit.get_entries("Alice", 10).await;
+    let events: Vec<AuditEvent> = entries.iter().map(|entry| entry.event.clone()).collect();
+    assert_eq!(
+        events,
+        vec![
+            AuditEvent::PickupItem {
+                item_id: 1,
+                amount: 30
+            },
+            AuditEvent::DropItem {
+                item_id: 1,
+                amount: 30
+            },
+        ]
+    );
+    assert_eq!((entries[0].map_id, entries[0].x, entries[0].y), (1, 2, 2));
+
+    assert!(server.world.audit.get_entries("bob", 10).await.is_empty());
+}
+
+#[tokio::test]
+async fn file_sink_writes_jsonl_and_reads_it_back() {
+    let dir = std::env::temp_dir().join(format!("reoserv-audit-{}", std::process::id()));
+    let audit = AuditHandle::new(None, Some(dir.clone()));
+
+    let alice = test_character("alice", 1, 2, 2);
+    audit.record(&alice, AuditEvent::BankDeposit { amount: 5 });
+    audit.record(&test_character("bob", 1, 2, 2), AuditEvent::Death);
+    audit.record(&alice, AuditEvent::BankWithdraw { amount: 2 });
+
+    let entries = audit.get_entries("alice", 1).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].event, AuditEvent::BankWithdraw { amount: 2 });
+
+    let files: Vec<_> = fs::read_dir(&dir).unwrap().flatten().collect();
+    assert_eq!(files.len(), 1);
+    let contents = fs::read_to_string(files[0].path()).unwrap();
+    assert_eq!(contents.lines().count(), 3);
+    assert!(contents.contains(r#""event":"bank_deposit","amount":5"#));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
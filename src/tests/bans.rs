@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::db::{find_ban, Ban, BanTarget};
+
+use super::harness::{saved_test_character, test_db};
+
+fn ban(ip: Option<&str>, hdid: Option<&str>, account_id: Option<i32>) -> Ban {
+    Ban {
+        id: 1,
+        account_id,
+        account_name: None,
+        ip: ip.map(|ip| ip.to_string()),
+        hdid: hdid.map(|hdid| hdid.to_string()),
+        duration: 0,
+        created_by: None,
+        created_at: Utc::now().naive_utc(),
+    }
+}
+
+fn ip(ip: &str) -> BanTarget<'_> {
+    BanTarget {
+        ip: Some(ip),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn ip_bans_match_addresses_and_ranges() {
+    let exact = ban(Some("10.0.0.5"), None, None);
+    assert!(exact.matches(&ip("10.0.0.5")));
+    assert!(!exact.matches(&ip("10.0.0.6")));
+
+    let range = ban(Some("10.0.0.0/24"), None, None);
+    assert!(range.matches(&ip("10.0.0.200")));
+    assert!(range.matches(&ip("::ffff:10.0.0.7")));
+    assert!(!range.matches(&ip("10.0.1.1")));
+
+    let v6 = ban(Some("2001:db8::/32"), None, None);
+    assert!(v6.matches(&ip("2001:db8:1::1")));
+    assert!(!v6.matches(&ip("2001:db9::1")));
+    assert!(!v6.matches(&ip("10.0.0.5")));
+}
+
+#[test]
+fn bans_match_accounts_and_hardware_ids() {
+    let hdid = ban(None, Some("123456789"), None);
+    let target = BanTarget {
+        account_id: Some(4),
+        ip: Some("127.0.0.1"),
+        hdid: Some("123456789"),
+    };
+    assert!(hdid.matches(&target));
+    assert!(!hdid.matches(&BanTarget::default()));
+
+    assert!(ban(None, None, Some(4)).matches(&target));
+    assert!(!ban(None, None, Some(5)).matches(&target));
+}
+
+#[test]
+fn temporary_bans_run_out() {
+    let mut temporary = ban(Some("10.0.0.5"), None, None);
+    temporary.duration = 60;
+    assert!(temporary.minutes_remaining().unwrap() > 0);
+
+    temporary.created_at -= Duration::from_secs(2 * 60 * 60);
+    assert_eq!(temporary.minutes_remaining(), None);
+
+    assert_eq!(ban(None, None, Some(1)).minutes_remaining(), Some(0));
+}
+
+#[tokio::test]
+async fn lifted_bans_are_not_listed() {
+    let db = test_db().await;
+    let admin = saved_test_character(&db, "admin", 1, 2, 2).await;
+
+    db.create_ban(None, Some("10.0.0.0/8"), None, None, &admin.name)
+        .await
+        .unwrap();
+    db.create_ban(None, None, Some("42"), Some(30), "nobody")
+        .await
+        .unwrap();
+
+    let bans = db.get_bans().await.unwrap();
+    assert_eq!(bans.len(), 2);
+    assert_eq!(bans[0].hdid.as_deref(), Some("42"));
+    assert_eq!(bans[0].duration, 30);
+    assert_eq!(bans[0].created_by, None);
+    assert_eq!(bans[1].created_by.as_deref(), Some("admin"));
+
+    db.lift_ban(bans[0].id).await.unwrap();
+    let bans = db.get_bans().await.unwrap();
+    assert_eq!(bans.len(), 1);
+    assert_eq!(bans[0].ip.as_deref(), Some("10.0.0.0/8"));
+}
+
+#[tokio::test]
+async fn only_active_matching_bans_are_fetched() {
+    let db = test_db().await;
+    let now = Utc::now().naive_utc();
+
+    db.create_ban(None, Some("10.0.0.5"), None, None, "admin")
+        .await
+        .unwrap();
+    db.create_ban(None, Some("192.168.0.0/16"), None, Some(60), "admin")
+        .await
+        .unwrap();
+    db.create_ban(None, None, Some("42"), Some(30), "admin")
+        .await
+        .unwrap();
+    db.create_ban(None, Some("10.0.0.6"), None, None, "admin")
+        .await
+        .unwrap();
+
+    let bans = db
+        .get_active_bans(None, Some("10.0.0.5"), None, now)
+        .await
+        .unwrap();
+    let ips: Vec<_> = bans.iter().filter_map(|ban| ban.ip.as_deref()).collect();
+    assert_eq!(ips, vec!["192.168.0.0/16", "10.0.0.5"]);
+
+    let bans = db
+        .get_active_bans(None, None, Some("42"), now)
+        .await
+        .unwrap();
+    assert_eq!(bans.len(), 1);
+    assert!(db
+        .get_active_bans(None, None, Some("42"), now + Duration::from_secs(31 * 60))
+        .await
+        .unwrap()
+        .is_empty());
+
+    db.lift_ban(bans[0].id).await.unwrap();
+    assert!(db
+        .get_active_bans(None, None, Some("42"), now)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn find_ban_checks_ranges() {
+    let db = test_db().await;
+    db.create_ban(None, Some("192.168.0.0/16"), None, None, "admin")
+        .await
+        .unwrap();
+
+    assert!(find_ban(&db, &ip("192.168.4.20")).await.unwrap().is_some());
+    assert!(find_ban(&db, &ip("::ffff:192.168.4.20"))
+        .await
+        .unwrap()
+        .is_some());
+    assert!(find_ban(&db, &ip("10.0.0.5")).await.unwrap().is_none());
+}
@@ -0,0 +1,75 @@
+use chrono::{Duration, Utc};
+
+use crate::{
+    character::PlayedNotes,
+    settings::{Bard, BardSong},
+};
+
+const LULLABY: i32 = 20;
+const ANTHEM: i32 = 21;
+
+fn bard() -> Bard {
+    Bard {
+        instrument_items: vec![49, 50],
+        max_note_id: 36,
+        note_timeout: 3,
+        song_range: 6,
+        songs: vec![
+            BardSong {
+                spell_id: LULLABY,
+                notes: vec![1, 5, 8],
+            },
+            BardSong {
+                spell_id: ANTHEM,
+                notes: vec![12, 12, 7, 3],
+            },
+        ],
+    }
+}
+
+fn play(notes: &mut PlayedNotes, settings: &Bard, note_ids: &[i32]) -> Vec<Option<i32>> {
+    let now = Utc::now();
+    note_ids
+        .iter()
+        .map(|note_id| notes.play(*note_id, now, settings, |_| true))
+        .collect()
+}
+
+#[test]
+fn melodies_perform_their_spell() {
+    let settings = bard();
+    let mut notes = PlayedNotes::default();
+
+    assert_eq!(
+        play(&mut notes, &settings, &[1, 5, 8]),
+        vec![None, None, Some(LULLABY)]
+    );
+
+    // Wrong notes before the melody don't matter
+    assert_eq!(
+        play(&mut notes, &settings, &[30, 2, 12, 12, 7, 3]).last(),
+        Some(&Some(ANTHEM))
+    );
+
+    // A performed melody has to be played again from the start
+    assert_eq!(play(&mut notes, &settings, &[5, 8]), vec![None, None]);
+}
+
+#[test]
+fn melodies_need_a_known_spell_and_steady_playing() {
+    let settings = bard();
+    let mut notes = PlayedNotes::default();
+    let now = Utc::now();
+
+    for note_id in [1, 5, 8] {
+        assert_eq!(
+            notes.play(note_id, now, &settings, |spell_id| spell_id == ANTHEM),
+            None
+        );
+    }
+
+    notes.play(1, now, &settings, |_| true);
+    notes.play(5, now, &settings, |_| true);
+    let later = now + Duration::seconds(settings.note_timeout);
+    assert_eq!(notes.play(8, later, &settings, |_| true), None);
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use eolib::protocol::{
+    map::{Emf, MapNpc},
+    Coords,
+};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    audit::AuditHandle,
+    email::OutboxHandle,
+    map::{Map, Npc, NpcOpponent},
+    settings::{BossLootRule, Bosses},
+    world::WorldHandle,
+};
+
+use super::harness::{test_db, TestTransport};
+
+const BOSS: i32 = 3;
+const CHILD: i32 = 4;
+
+fn boss_with_opponents(damage: &[(i32, i32)]) -> Npc {
+    Npc {
+        id: BOSS,
+        boss: true,
+        alive: true,
+        hp: 0,
+        max_hp: 200,
+        opponents: damage
+            .iter()
+            .map(|(player_id, damage_dealt)| NpcOpponent {
+                player_id: *player_id,
+                damage_dealt: *damage_dealt,
+                bored_ticks: 0,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn rules(loot_rule: BossLootRule) -> Bosses {
+    Bosses {
+        rage_timer: 0,
+        rage_damage: 1.0,
+        loot_rule,
+        loot_players: 2,
+        loot_min_damage: 10,
+    }
+}
+
+#[test]
+fn loot_goes_to_top_damage_dealers() {
+    // 10% of 200 HP is needed to get anything
+    let boss = boss_with_opponents(&[(1, 30), (2, 90), (3, 19), (4, 61)]);
+
+    assert_eq!(boss.get_looters(3, &rules(BossLootRule::Split)), vec![2, 4]);
+    assert_eq!(
+        boss.get_looters(3, &rules(BossLootRule::TopDamage)),
+        vec![2]
+    );
+    assert_eq!(boss.get_looters(3, &rules(BossLootRule::Killer)), vec![3]);
+
+    let nobody_qualifies = boss_with_opponents(&[(1, 5), (2, 19)]);
+    assert_eq!(
+        nobody_qualifies.get_looters(2, &rules(BossLootRule::Split)),
+        vec![2]
+    );
+
+    let not_a_boss = Npc {
+        boss: false,
+        ..boss
+    };
+    assert_eq!(
+        not_a_boss.get_looters(1, &rules(BossLootRule::Split)),
+        vec![1]
+    );
+}
+
+#[test]
+fn bosses_enrage_after_the_rage_timer() {
+    let rage = Bosses {
+        rage_timer: 100,
+        rage_damage: 1.5,
+        ..rules(BossLootRule::Killer)
+    };
+    let mut boss = boss_with_opponents(&[(1, 10)]);
+    assert!(!boss.is_enraged(&rage));
+    assert_eq!(boss.get_rage_damage(10, &rage), 10);
+
+    boss.rage_ticks = 100;
+    assert!(boss.is_enraged(&rage));
+    assert_eq!(boss.get_rage_damage(10, &rage), 15);
+
+    // A rage timer of 0 turns enraging off
+    assert!(!boss.is_enraged(&Bosses {
+        rage_timer: 0,
+        ..rules(BossLootRule::Killer)
+    }));
+
+    let child = Npc {
+        boss: false,
+        ..boss
+    };
+    assert!(!child.is_enraged(&rage));
+}
+
+fn spawn(id: i32, x: i32, y: i32, amount: i32) -> MapNpc {
+    MapNpc {
+        coords: Coords { x, y },
+        id,
+        spawn_type: 7,
+        spawn_time: 0,
+        amount,
+    }
+}
+
+async fn alive_npcs(map: &Map) -> usize {
+    let (tx, rx) = oneshot::channel();
+    map.get_stats(tx);
+    rx.await.unwrap().npcs
+}
+
+#[tokio::test]
+async fn children_follow_the_nearest_boss() {
+    let file = Emf {
+        width: 9,
+        height: 9,
+        npcs: vec![
+            spawn(BOSS, 1, 1, 1),
+            spawn(CHILD, 2, 1, 2),
+            spawn(BOSS, 7, 7, 1),
+            spawn(CHILD, 7, 6, 1),
+        ],
+        ..Default::default()
+    };
+
+    let db = test_db().await;
+    let world = WorldHandle::new(
+        db.clone(),
+        AuditHandle::new(None, None),
+        OutboxHandle::new(db.clone(), Arc::new(TestTransport::default())),
+    );
+    let (_, rx) = mpsc::unbounded_channel();
+    let mut map = Map::new(1, 0, file, db, world, rx);
+
+    // Children wait for their boss so it can take a second pass
+    map.spawn_npcs();
+    map.spawn_npcs();
+    assert_eq!(alive_npcs(&map).await, 5);
+
+    map.despawn_boss_children(0);
+    assert_eq!(alive_npcs(&map).await, 3);
+
+    map.despawn_boss_children(3);
+    assert_eq!(alive_npcs(&map).await, 2);
+}
@@ -0,0 +1,167 @@
+use chrono::{Duration, Utc};
+use eolib::protocol::{
+    net::{
+        client::TalkReportClientPacket, server::TalkServerServerPacket, PacketAction, PacketFamily,
+    },
+    Coords,
+};
+
+use crate::{
+    player::{BotDetector, BotSignal},
+    settings::{BotDetection, BotPenalty},
+    LANG, SETTINGS,
+};
+
+use super::harness::{test_character, TestServer};
+
+fn bot_detection() -> BotDetection {
+    BotDetection {
+        enabled: true,
+        attack_samples: 5,
+        min_attack_deviation: 2.0,
+        stationary_kills: 3,
+        stationary_range: 2,
+        unattended_minutes: 60,
+        attack_timing_score: 2,
+        stationary_score: 2,
+        unattended_score: 2,
+        challenge_score: 3,
+        score_reset: 30,
+        challenge_timeout: 60,
+        challenge_reward: 0,
+        penalties: vec![BotPenalty::Freeze, BotPenalty::Jail, BotPenalty::Kick],
+    }
+}
+
+#[test]
+fn evenly_spaced_attacks_look_scripted() {
+    let settings = bot_detection();
+    let mut detector = BotDetector::default();
+
+    let signals = (0..6)
+        .map(|i| detector.attack(i * 60, &settings))
+        .collect::<Vec<_>>();
+    assert_eq!(signals.last(), Some(&Some(BotSignal::AttackTiming)));
+
+    let mut detector = BotDetector::default();
+    let mut timestamp = 0;
+    for interval in [60, 75, 52, 90, 64, 58, 81] {
+        timestamp += interval;
+        assert_eq!(detector.attack(timestamp, &settings), None);
+    }
+}
+
+#[test]
+fn killing_without_moving_looks_scripted() {
+    let settings = bot_detection();
+    let mut detector = BotDetector::default();
+
+    assert_eq!(detector.kill(Coords { x: 5, y: 5 }, &settings), None);
+    assert_eq!(detector.kill(Coords { x: 6, y: 5 }, &settings), None);
+
+    // Moving away starts the count over
+    assert_eq!(detector.kill(Coords { x: 12, y: 5 }, &settings), None);
+    assert_eq!(detector.kill(Coords { x: 12, y: 6 }, &settings), None);
+    assert_eq!(
+        detector.kill(Coords { x: 11, y: 5 }, &settings),
+        Some(BotSignal::StationaryKills)
+    );
+}
+
+#[test]
+fn killing_for_hours_without_talking_looks_unattended() {
+    let settings = bot_detection();
+    let mut detector = BotDetector::default();
+    let start = Utc::now();
+    let later = start + Duration::minutes(settings.unattended_minutes);
+
+    assert_eq!(detector.check_unattended(start, &settings), None);
+
+    // Idling without killing anything is fine
+    assert_eq!(detector.check_unattended(later, &settings), None);
+
+    detector.attend(start);
+    detector.kill(Coords { x: 1, y: 1 }, &settings);
+    assert_eq!(
+        detector.check_unattended(later, &settings),
+        Some(BotSignal::Unattended)
+    );
+}
+
+#[test]
+fn signals_add_up_to_a_challenge_and_failures_escalate() {
+    let settings = bot_detection();
+    let mut detector = BotDetector::default();
+    let start = Utc::now();
+
+    assert!(!detector.add_signal(BotSignal::AttackTiming, start, &settings));
+
+    // The same signal again doesn't add up
+    assert!(!detector.add_signal(BotSignal::AttackTiming, start, &settings));
+
+    // Scores are forgotten after a while
+    let later = start + Duration::minutes(settings.score_reset);
+    assert!(!detector.add_signal(BotSignal::StationaryKills, later, &settings));
+    assert!(detector.add_signal(BotSignal::AttackTiming, later, &settings));
+
+    assert_eq!(detector.fail(&settings), Some(BotPenalty::Freeze));
+    assert_eq!(detector.fail(&settings), Some(BotPenalty::Jail));
+    assert_eq!(detector.fail(&settings), Some(BotPenalty::Kick));
+    assert_eq!(detector.fail(&settings), Some(BotPenalty::Kick));
+
+    detector.pass(later);
+    assert_eq!(detector.fail(&settings), Some(BotPenalty::Freeze));
+}
+
+#[test]
+fn holding_the_attack_key_alone_is_not_challenged() {
+    // The vanilla client repeats held attacks as evenly as a macro would
+    for settings in [&bot_detection(), &SETTINGS.bot_detection] {
+        let mut detector = BotDetector::default();
+        let start = Utc::now();
+        let mut signals = 0;
+
+        for i in 0..1000 {
+            let Some(signal) = detector.attack(i * 50 + i % 2, settings) else {
+                continue;
+            };
+
+            signals += 1;
+            let now = start + Duration::seconds(i as i64 / 2);
+            assert!(!detector.add_signal(signal, now, settings));
+        }
+
+        assert!(signals > 1);
+    }
+}
+
+#[tokio::test]
+async fn vanilla_clients_answer_challenges_in_chat() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let player = server.world.get_player(alice.player_id).await.unwrap();
+    player.challenge_bot();
+
+    let (prefix, _) = LANG.bot_challenge.split_once("{code}").unwrap();
+    let code = loop {
+        let server: TalkServerServerPacket =
+            alice.expect(PacketAction::Server, PacketFamily::Talk).await;
+        if let Some(message) = server.message.strip_prefix(prefix) {
+            break message[..5].to_string();
+        }
+    };
+
+    alice
+        .send(
+            PacketAction::Report,
+            PacketFamily::Talk,
+            &TalkReportClientPacket {
+                message: code.to_lowercase(),
+            },
+        )
+        .await;
+
+    let passed: TalkServerServerPacket =
+        alice.expect(PacketAction::Server, PacketFamily::Talk).await;
+    assert_eq!(passed.message, LANG.bot_challenge_passed);
+}
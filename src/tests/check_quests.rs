@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use crate::validation::{check_quest_sources, check_quests, Report, Severity};
+
+fn check(source: &str) -> Report {
+    let mut report = Report::default();
+    check_quest_sources(
+        &mut report,
+        &[(PathBuf::from("quests/00010.eqf"), source.to_owned())],
+    );
+    report
+}
+
+fn messages(report: &Report) -> Vec<String> {
+    report
+        .issues
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect()
+}
+
+#[test]
+fn fixture_quests_are_clean() {
+    let report = check_quests();
+    assert!(report.issues.is_empty(), "{:?}", messages(&report));
+}
+
+#[test]
+fn reports_bad_references_with_lines() {
+    let report = check(
+        r#"Main
+{
+    questname "Broken"
+    version 1
+}
+
+state Begin
+{
+    desc "Start"
+    action GiveItem(999, 1);
+    action giveexp(10);
+    rule TalkedToNpc("one") goto Begin
+    rule EnterCoord(1, 500, 2) goto Middle
+    rule KilledNpcs(1) goto Nowhere
+}
+
+state Middle
+{
+    desc "rule of thumb"
+    action SetStat("luck", 1);
+    action StartQuest(404);
+}
+
+state Orphan
+{
+    action End();
+}
+"#,
+    );
+
+    assert_eq!(
+        messages(&report),
+        vec![
+            "quests/00010.eqf:10: error: Item 999 does not exist",
+            "quests/00010.eqf:11: error: Unknown action `giveexp` (did you mean `GiveExp`?)",
+            "quests/00010.eqf:12: error: Argument 1 of `TalkedToNpc` must be an integer",
+            "quests/00010.eqf:13: error: 500, 2 is outside of map 1 (19x19)",
+            "quests/00010.eqf:14: error: `KilledNpcs` takes 2 argument(s) but 1 were given",
+            "quests/00010.eqf:14: error: Rule `KilledNpcs` goes to unknown state `Nowhere`",
+            "quests/00010.eqf:20: error: Unknown stat `luck`",
+            "quests/00010.eqf:21: error: Quest 404 does not exist",
+            "quests/00010.eqf:24: warning: State `Orphan` can never be reached",
+        ]
+    );
+    assert_eq!(report.error_count(), 8);
+    assert_eq!(report.issues.last().unwrap().severity, Severity::Warning);
+}
+
+#[test]
+fn reports_nameless_states() {
+    let report = check("state {");
+    assert_eq!(
+        messages(&report),
+        vec![
+            "quests/00010.eqf: warning: Quest has no name",
+            "quests/00010.eqf: error: State 1 has no name",
+        ]
+    );
+}
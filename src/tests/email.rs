@@ -0,0 +1,184 @@
+use std::{fs, sync::Arc};
+
+use chrono::{Duration, Utc};
+use eolib::protocol::net::{PacketAction, PacketFamily};
+
+use crate::{
+    deep::{
+        AccountRecoverPinReply, AccountRecoverReply, LoginAcceptClientPacket,
+        LoginAcceptServerPacket, LoginCreateClientPacket, LoginCreateServerPacket,
+    },
+    email::{get_retry_at, DirectoryTransport, EmailTransport, OutboxHandle, OutgoingEmail},
+    emails::EmailTemplate,
+    settings::{Email, EmailTransportKind},
+    SETTINGS,
+};
+
+use super::harness::{test_account, test_db, TestServer, TestTransport};
+
+fn test_email(to_address: &str) -> OutgoingEmail {
+    OutgoingEmail {
+        to_address: to_address.to_string(),
+        to_name: "alice".to_string(),
+        subject: "Hello".to_string(),
+        text_body: "Plain hello".to_string(),
+        html_body: Some("<p>HTML hello</p>".to_string()),
+    }
+}
+
+#[test]
+fn templates_fill_placeholders_and_escape_html() {
+    let template = EmailTemplate {
+        subject: "{server_name} code".to_string(),
+        body: "Hi {name}, your code is {code}".to_string(),
+        html: "<p>Hi {name}, your code is {code}</p>".to_string(),
+    };
+
+    let email = template.render(
+        "alice@example.com",
+        "alice",
+        &[("name", "<alice>"), ("code", "1234")],
+    );
+
+    assert_eq!(email.subject, format!("{} code", SETTINGS.sln.server_name));
+    assert_eq!(email.text_body, "Hi <alice>, your code is 1234");
+    assert_eq!(
+        email.html_body.as_deref(),
+        Some("<p>Hi &lt;alice&gt;, your code is 1234</p>")
+    );
+
+    let template = EmailTemplate {
+        html: String::new(),
+        ..template
+    };
+    assert_eq!(template.render("a@b.c", "a", &[]).html_body, None);
+}
+
+#[test]
+fn retries_back_off_until_giving_up() {
+    let settings = Email {
+        transport: EmailTransportKind::Smtp,
+        directory: String::new(),
+        poll_rate: 5,
+        retry_delay: 30,
+        max_retry_delay: 100,
+        max_attempts: 4,
+        send_timeout: 30,
+    };
+    let now = Utc::now().naive_utc();
+
+    let delays: Vec<Option<i64>> = (1..=4)
+        .map(|attempts| get_retry_at(attempts, now, &settings).map(|at| (at - now).num_seconds()))
+        .collect();
+
+    assert_eq!(delays, vec![Some(30), Some(60), Some(100), None]);
+}
+
+#[tokio::test]
+async fn directory_transport_writes_multipart_eml_files() {
+    let dir = std::env::temp_dir().join(format!("reoserv-email-{}", std::process::id()));
+    let transport = DirectoryTransport::new(&dir);
+
+    transport
+        .send(&test_email("alice@example.com"))
+        .await
+        .unwrap();
+
+    let files: Vec<_> = fs::read_dir(&dir).unwrap().flatten().collect();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path().extension().unwrap(), "eml");
+
+    let contents = fs::read_to_string(files[0].path()).unwrap();
+    assert!(contents.contains("Subject: Hello"));
+    assert!(contents.contains("multipart/alternative"));
+    assert!(contents.contains("Plain hello"));
+    assert!(contents.contains("<p>HTML hello</p>"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn failed_sends_stay_in_the_outbox_for_a_retry() {
+    let db = test_db().await;
+    let transport = Arc::new(TestTransport::failing(1));
+    let outbox = OutboxHandle::new(db.clone(), transport.clone());
+
+    outbox.queue(test_email("alice@example.com"));
+    outbox.flush().await;
+    assert!(transport.sent().is_empty());
+
+    let now = Utc::now().naive_utc();
+    assert!(db.get_due_emails(now, 10).await.unwrap().is_empty());
+
+    let retry_at = now + Duration::seconds(SETTINGS.email.retry_delay + 1);
+    let due = db.get_due_emails(retry_at, 10).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].attempts, 1);
+    assert_eq!(due[0].email, test_email("alice@example.com"));
+
+    // Bring the retry forward rather than waiting for it
+    db.mark_email_failed(due[0].id, 1, Some(now), "connection refused")
+        .await
+        .unwrap();
+    outbox.flush().await;
+
+    assert_eq!(transport.sent(), vec![test_email("alice@example.com")]);
+    assert!(db.get_due_emails(retry_at, 10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn recovery_code_is_emailed_and_accepted() {
+    let mut server = TestServer::start().await;
+    server
+        .db
+        .create_account(&test_account("alice"))
+        .await
+        .unwrap();
+
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    client
+        .send(
+            PacketAction::Create,
+            PacketFamily::Login,
+            &LoginCreateClientPacket {
+                account_name: "alice".to_string(),
+            },
+        )
+        .await;
+    let reply: LoginCreateServerPacket = client
+        .expect(PacketAction::Create, PacketFamily::Login)
+        .await;
+    assert_ne!(reply.reply_code, AccountRecoverReply::AccountNotFound);
+
+    server.world.outbox.flush().await;
+    let sent = server.emails.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to_address, "test@example.com");
+    assert!(sent[0].html_body.is_some());
+
+    let code = sent[0]
+        .text_body
+        .split_whitespace()
+        .find(|word| word.len() == 7 && word.chars().all(|c| c.is_ascii_digit()))
+        .unwrap()
+        .to_string();
+
+    for (pin, expected) in [
+        ("0000000".to_string(), AccountRecoverPinReply::WrongPin),
+        (code, AccountRecoverPinReply::OK),
+    ] {
+        client
+            .send(
+                PacketAction::Accept,
+                PacketFamily::Login,
+                &LoginAcceptClientPacket { pin },
+            )
+            .await;
+        let reply: LoginAcceptServerPacket = client
+            .expect(PacketAction::Accept, PacketFamily::Login)
+            .await;
+        assert_eq!(reply.reply_code, expected);
+    }
+}
@@ -0,0 +1,44 @@
+use eolib::protocol::net::{server::InitReply, Version};
+
+use super::harness::{test_character, TestServer};
+
+#[tokio::test]
+async fn init_accepts_supported_version() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+
+    client.handshake().await;
+
+    assert!(client.player_id > 0);
+}
+
+#[tokio::test]
+async fn init_rejects_client_that_is_too_new() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+
+    let reply = client
+        .init(Version {
+            major: 9,
+            minor: 9,
+            patch: 9,
+        })
+        .await;
+
+    assert_eq!(reply.reply_code, InitReply::OutOfDate);
+}
+
+#[tokio::test]
+async fn character_enters_fixture_map() {
+    let mut server = TestServer::start().await;
+    let client = server.enter_game(test_character("alice", 1, 2, 2)).await;
+
+    let character = server
+        .map(1)
+        .await
+        .get_character(client.player_id)
+        .await
+        .expect("character should be on the map");
+
+    assert_eq!(character.name, "alice");
+}
@@ -0,0 +1,8 @@
+mod test_character;
+pub use test_character::{saved_test_character, test_account, test_character};
+mod test_client;
+pub use test_client::TestClient;
+mod test_server;
+pub use test_server::{test_db, TestServer};
+mod test_transport;
+pub use test_transport::TestTransport;
@@ -0,0 +1,45 @@
+use eolib::protocol::{net::Item, Coords};
+
+use crate::{
+    character::Character,
+    db::{Db, NewAccount},
+};
+
+pub fn test_character(name: &str, map_id: i32, x: i32, y: i32) -> Character {
+    Character {
+        name: name.to_string(),
+        home: "Test".to_string(),
+        class: 1,
+        level: 1,
+        hp: 10,
+        tp: 10,
+        map_id,
+        coords: Coords { x, y },
+        items: vec![Item { id: 1, amount: 100 }],
+        ..Default::default()
+    }
+}
+
+pub fn test_account(name: &str) -> NewAccount {
+    NewAccount {
+        name: name.to_string(),
+        password_hash: "hash".to_string(),
+        real_name: "Test".to_string(),
+        location: "Test".to_string(),
+        email: "test@example.com".to_string(),
+        computer: "Test".to_string(),
+        hdid: "123456789".to_string(),
+        register_ip: "127.0.0.1".to_string(),
+    }
+}
+
+/// A test character stored in `db` under an account of the same name
+pub async fn saved_test_character(db: &Db, name: &str, map_id: i32, x: i32, y: i32) -> Character {
+    db.create_account(&test_account(name)).await.unwrap();
+    let account = db.get_account_credentials(name).await.unwrap().unwrap();
+
+    let mut character = test_character(name, map_id, x, y);
+    character.account_id = account.id;
+    character.save(db).await.unwrap();
+    character
+}
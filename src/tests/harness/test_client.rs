@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use eolib::{
+    data::{decode_number, encode_number, EoReader, EoSerialize, EoWriter},
+    encrypt::{decrypt_packet, encrypt_packet},
+    packet::{get_init_sequence_start, Sequencer},
+    protocol::net::{
+        client::{ConnectionAcceptClientPacket, InitInitClientPacket},
+        server::{
+            InitInitServerPacket, InitInitServerPacketReplyCodeData, InitReply, WelcomeCode,
+            WelcomeReplyServerPacket,
+        },
+        PacketAction, PacketFamily, Version,
+    },
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time::timeout,
+};
+
+const RECV_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A scripted game client speaking the real wire protocol
+pub struct TestClient {
+    socket: TcpStream,
+    sequencer: Option<Sequencer>,
+    server_encryption_multiple: u8,
+    client_encryption_multiple: u8,
+    timestamp: i32,
+    pub player_id: i32,
+}
+
+impl TestClient {
+    pub fn new(socket: TcpStream) -> Self {
+        Self {
+            socket,
+            sequencer: None,
+            server_encryption_multiple: 0,
+            client_encryption_multiple: 0,
+            timestamp: 0,
+            player_id: 0,
+        }
+    }
+
+    /// Returns a client timestamp far enough ahead of the last one to pass
+    /// the server's walk and attack speed checks
+    pub fn next_timestamp(&mut self) -> i32 {
+        self.timestamp += 100;
+        self.timestamp
+    }
+
+    pub async fn send<T: EoSerialize>(
+        &mut self,
+        action: PacketAction,
+        family: PacketFamily,
+        packet: &T,
+    ) {
+        let mut writer = EoWriter::new();
+        writer.add_byte(u8::from(action));
+        writer.add_byte(u8::from(family));
+        if let Some(sequencer) = self.sequencer.as_mut() {
+            writer.add_char(sequencer.next_sequence()).unwrap();
+        }
+        packet.serialize(&mut writer).unwrap();
+
+        let mut data = BytesMut::from(&writer.to_byte_array()[..]);
+        if self.client_encryption_multiple != 0 {
+            encrypt_packet(&mut data, self.client_encryption_multiple);
+        }
+
+        let length = encode_number(data.len() as i32).unwrap();
+        let mut buf = BytesMut::with_capacity(data.len() + 2);
+        buf.put_slice(&length[0..2]);
+        buf.put(data);
+
+        self.socket.write_all(&buf).await.unwrap();
+    }
+
+    /// Reads the next packet, returning `None` if nothing arrives in time
+    /// or the server closed the connection
+    pub async fn recv(&mut self) -> Option<(PacketAction, PacketFamily, Bytes)> {
+        let mut length = [0; 2];
+        match timeout(RECV_TIMEOUT, self.socket.read_exact(&mut length)).await {
+            Ok(Ok(_)) => {}
+            _ => return None,
+        }
+
+        let mut data = vec![0; decode_number(&length) as usize];
+        if timeout(RECV_TIMEOUT, self.socket.read_exact(&mut data))
+            .await
+            .ok()?
+            .is_err()
+        {
+            return None;
+        }
+
+        if self.server_encryption_multiple != 0 {
+            decrypt_packet(&mut data, self.server_encryption_multiple);
+        }
+
+        let data = Bytes::from(data);
+        Some((
+            PacketAction::from(data[0]),
+            PacketFamily::from(data[1]),
+            data.slice(2..),
+        ))
+    }
+
+    /// Skips packets until one with the given action and family arrives
+    pub async fn expect<T: EoSerialize>(
+        &mut self,
+        action: PacketAction,
+        family: PacketFamily,
+    ) -> T {
+        loop {
+            match self.recv().await {
+                Some((a, f, data)) if a == action && f == family => {
+                    return T::deserialize(&EoReader::new(data)).unwrap();
+                }
+                Some(_) => continue,
+                None => panic!("Timed out waiting for {:?}_{:?}", family, action),
+            }
+        }
+    }
+
+    /// Asserts no packet with the given action and family arrives before
+    /// the connection goes quiet
+    pub async fn expect_none(&mut self, action: PacketAction, family: PacketFamily) {
+        while let Some((a, f, _)) = self.recv().await {
+            assert!(
+                a != action || f != family,
+                "Unexpected {:?}_{:?}",
+                family,
+                action
+            );
+        }
+    }
+
+    pub async fn init(&mut self, version: Version) -> InitInitServerPacket {
+        self.send(
+            PacketAction::Init,
+            PacketFamily::Init,
+            &InitInitClientPacket {
+                challenge: 12345,
+                version,
+                hdid: "123456789".to_string(),
+            },
+        )
+        .await;
+
+        self.expect(PacketAction::Init, PacketFamily::Init).await
+    }
+
+    pub async fn handshake(&mut self) {
+        let reply = self
+            .init(Version {
+                major: 0,
+                minor: 0,
+                patch: 28,
+            })
+            .await;
+
+        assert_eq!(reply.reply_code, InitReply::OK);
+        let ok = match reply.reply_code_data {
+            Some(InitInitServerPacketReplyCodeData::OK(ok)) => ok,
+            _ => panic!("Init reply is missing OK data"),
+        };
+
+        self.player_id = ok.player_id;
+        self.server_encryption_multiple = ok.server_encryption_multiple;
+        self.client_encryption_multiple = ok.client_encryption_multiple;
+        self.sequencer = Some(Sequencer::new(get_init_sequence_start(
+            ok.seq1 as i32,
+            ok.seq2 as i32,
+        )));
+
+        self.send(
+            PacketAction::Accept,
+            PacketFamily::Connection,
+            &ConnectionAcceptClientPacket {
+                client_encryption_multiple: ok.client_encryption_multiple as i32,
+                server_encryption_multiple: ok.server_encryption_multiple as i32,
+                player_id: ok.player_id,
+            },
+        )
+        .await;
+    }
+
+    pub async fn expect_welcome(&mut self) {
+        let reply: WelcomeReplyServerPacket = self
+            .expect(PacketAction::Reply, PacketFamily::Welcome)
+            .await;
+        assert_eq!(reply.welcome_code, WelcomeCode::EnterGame);
+    }
+}
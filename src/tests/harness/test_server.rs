@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::{
+    audit::AuditHandle,
+    character::Character,
+    db::{self, Db, SqliteDb},
+    email::OutboxHandle,
+    map::MapHandle,
+    player::PlayerHandle,
+    world::WorldHandle,
+};
+
+use super::{TestClient, TestTransport};
+
+/// An in-process server loaded with the fixture data under `tests/fixtures/data`
+/// and backed by a fresh in-memory SQLite database
+pub struct TestServer {
+    pub world: WorldHandle,
+    pub db: Db,
+    /// Emails the server has sent
+    pub emails: Arc<TestTransport>,
+    listener: TcpListener,
+}
+
+impl TestServer {
+    pub async fn start() -> Self {
+        let db = test_db().await;
+        let emails = Arc::new(TestTransport::default());
+        let world = WorldHandle::new(
+            db.clone(),
+            AuditHandle::new(Some(db.clone()), None),
+            OutboxHandle::new(db.clone(), emails.clone()),
+        );
+        world.load_maps().await;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        Self {
+            world,
+            db,
+            emails,
+            listener,
+        }
+    }
+
+    /// Opens a new connection the same way the accept loop in main does
+    pub async fn connect(&mut self) -> (TestClient, PlayerHandle) {
+        let addr = self.listener.local_addr().unwrap();
+        let (stream, accepted) = tokio::join!(TcpStream::connect(addr), self.listener.accept());
+        let (socket, addr) = accepted.unwrap();
+        self.world.add_connection(&addr.ip().to_string()).await;
+
+        let player_id = self.world.get_next_player_id().await.unwrap();
+        let player = PlayerHandle::new(
+            player_id,
+            socket,
+            Utc::now(),
+            self.world.clone(),
+            self.db.clone(),
+        );
+
+        self.world
+            .add_player(player_id, player.clone())
+            .await
+            .unwrap();
+
+        (TestClient::new(stream.unwrap()), player)
+    }
+
+    /// Connects, completes the handshake and places `character` in the world
+    pub async fn enter_game(&mut self, character: Character) -> TestClient {
+        let (mut client, player) = self.connect().await;
+        client.handshake().await;
+        assert!(player.enter_game(character).await, "failed to enter game");
+        client.expect_welcome().await;
+        client
+    }
+
+    pub async fn map(&self, map_id: i32) -> MapHandle {
+        self.world.get_map(map_id).await.unwrap()
+    }
+}
+
+pub async fn test_db() -> Db {
+    let db: Db = Arc::new(SqliteDb::open(":memory:").unwrap());
+    db::migrate(&db).await.unwrap();
+    db
+}
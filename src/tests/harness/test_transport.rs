@@ -0,0 +1,44 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use async_trait::async_trait;
+
+use crate::email::{EmailTransport, OutgoingEmail};
+
+/// Keeps sent emails in memory, failing the first `failures` sends
+#[derive(Debug, Default)]
+pub struct TestTransport {
+    sent: Mutex<Vec<OutgoingEmail>>,
+    failures: AtomicUsize,
+}
+
+impl TestTransport {
+    pub fn failing(failures: usize) -> Self {
+        Self {
+            failures: AtomicUsize::new(failures),
+            ..Default::default()
+        }
+    }
+
+    pub fn sent(&self) -> Vec<OutgoingEmail> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EmailTransport for TestTransport {
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        if self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            anyhow::bail!("connection refused");
+        }
+
+        self.sent.lock().unwrap().push(email.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use eolib::protocol::net::{
+    client::{AccountCreateClientPacket, AccountRequestClientPacket, LoginRequestClientPacket},
+    server::{
+        AccountReply, AccountReplyServerPacket, InitBanType, InitInitServerPacketReplyCodeData,
+        InitReply, LoginReply, LoginReplyServerPacket,
+    },
+    PacketAction, PacketFamily, Version,
+};
+
+use crate::db::LoginLockKind;
+
+use super::harness::{TestClient, TestServer};
+
+async fn create_account(client: &mut TestClient, username: &str, password: &str) -> AccountReply {
+    client
+        .send(
+            PacketAction::Request,
+            PacketFamily::Account,
+            &AccountRequestClientPacket {
+                username: username.to_string(),
+            },
+        )
+        .await;
+
+    let reply: AccountReplyServerPacket = client
+        .expect(PacketAction::Reply, PacketFamily::Account)
+        .await;
+
+    let session_id = match reply.reply_code {
+        AccountReply::Unrecognized(session_id) => session_id,
+        reply_code => return reply_code,
+    };
+
+    client
+        .send(
+            PacketAction::Create,
+            PacketFamily::Account,
+            &AccountCreateClientPacket {
+                session_id,
+                username: username.to_string(),
+                password: password.to_string(),
+                full_name: "Test".to_string(),
+                location: "Test".to_string(),
+                email: "test@example.com".to_string(),
+                computer: "Test".to_string(),
+                hdid: "123456789".to_string(),
+            },
+        )
+        .await;
+
+    let reply: AccountReplyServerPacket = client
+        .expect(PacketAction::Reply, PacketFamily::Account)
+        .await;
+
+    reply.reply_code
+}
+
+async fn login(client: &mut TestClient, username: &str, password: &str) -> LoginReply {
+    client
+        .send(
+            PacketAction::Request,
+            PacketFamily::Login,
+            &LoginRequestClientPacket {
+                username: username.to_string(),
+                password: password.to_string(),
+            },
+        )
+        .await;
+
+    let reply: LoginReplyServerPacket = client
+        .expect(PacketAction::Reply, PacketFamily::Login)
+        .await;
+
+    reply.reply_code
+}
+
+#[tokio::test]
+async fn created_account_can_log_in() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    assert_eq!(
+        create_account(&mut client, "alice", "hunter22").await,
+        AccountReply::Created
+    );
+    assert!(server.db.account_exists("alice").await.unwrap());
+
+    assert_eq!(
+        login(&mut client, "alice", "hunter22").await,
+        LoginReply::OK
+    );
+}
+
+#[tokio::test]
+async fn login_rejects_wrong_password() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    create_account(&mut client, "alice", "hunter22").await;
+
+    assert_eq!(
+        login(&mut client, "alice", "wrong").await,
+        LoginReply::WrongUserPassword
+    );
+}
+
+#[tokio::test]
+async fn unknown_usernames_look_like_wrong_passwords() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    assert_eq!(
+        login(&mut client, "nobody", "hunter22").await,
+        LoginReply::WrongUserPassword
+    );
+}
+
+#[tokio::test]
+async fn failed_logins_lock_accounts_across_reconnects() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+    create_account(&mut client, "alice", "hunter22").await;
+
+    // Five failures spread over connections that each close after three
+    for attempts in [2, 2, 1] {
+        let (mut client, _) = server.connect().await;
+        client.handshake().await;
+        for _ in 0..attempts {
+            assert_eq!(
+                login(&mut client, "Alice", "wrong").await,
+                LoginReply::WrongUserPassword
+            );
+        }
+    }
+
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+    assert_eq!(
+        login(&mut client, "alice", "hunter22").await,
+        LoginReply::Busy
+    );
+
+    assert_eq!(server.db.delete_login_locks("alice").await.unwrap(), 1);
+
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+    assert_eq!(
+        login(&mut client, "alice", "hunter22").await,
+        LoginReply::OK
+    );
+
+    // Logging in clears the address's failures as well as the account's
+    assert!(server
+        .db
+        .get_login_lock(LoginLockKind::Ip, "127.0.0.1")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn account_names_are_unique() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    create_account(&mut client, "alice", "hunter22").await;
+
+    assert_eq!(
+        create_account(&mut client, "alice", "hunter22").await,
+        AccountReply::Exists
+    );
+}
+
+#[tokio::test]
+async fn banned_hardware_ids_can_not_connect() {
+    let mut server = TestServer::start().await;
+    server
+        .db
+        .create_ban(None, None, Some("123456789"), None, "admin")
+        .await
+        .unwrap();
+
+    let (mut client, _) = server.connect().await;
+    let reply = client
+        .init(Version {
+            major: 0,
+            minor: 0,
+            patch: 28,
+        })
+        .await;
+
+    assert_eq!(reply.reply_code, InitReply::Banned);
+}
+
+#[tokio::test]
+async fn banned_addresses_are_told_the_ban_duration() {
+    let mut server = TestServer::start().await;
+    server
+        .db
+        .create_ban(None, Some("127.0.0.1"), None, Some(30), "admin")
+        .await
+        .unwrap();
+
+    let (mut client, _) = server.connect().await;
+    let reply = client
+        .init(Version {
+            major: 0,
+            minor: 0,
+            patch: 28,
+        })
+        .await;
+
+    assert_eq!(reply.reply_code, InitReply::Banned);
+    match reply.reply_code_data {
+        Some(InitInitServerPacketReplyCodeData::Banned(banned)) => {
+            assert_eq!(banned.ban_type, InitBanType::Temporary)
+        }
+        data => panic!("expected ban data, got {:?}", data),
+    }
+}
+
+#[tokio::test]
+async fn banned_accounts_can_not_log_in() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+    client.handshake().await;
+
+    create_account(&mut client, "alice", "hunter22").await;
+    let account = server
+        .db
+        .get_account_credentials("alice")
+        .await
+        .unwrap()
+        .unwrap();
+    server
+        .db
+        .create_ban(Some(account.id), None, None, Some(60), "admin")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        login(&mut client, "alice", "hunter22").await,
+        LoginReply::Banned
+    );
+}
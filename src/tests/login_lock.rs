@@ -0,0 +1,112 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::{
+    db::{add_login_failure, Db, LoginLockKind},
+    settings::LoginLockout,
+};
+
+use super::harness::test_db;
+
+fn settings() -> LoginLockout {
+    LoginLockout {
+        enabled: true,
+        max_account_failures: 3,
+        max_ip_failures: 10,
+        lockout_duration: 60,
+        max_lockout_duration: 200,
+        reset_after: 3600,
+        notify_email: false,
+    }
+}
+
+async fn fail(db: &Db, kind: LoginLockKind, now: NaiveDateTime, times: i32) -> Option<i64> {
+    let settings = settings();
+    let mut locked_for = None;
+    for _ in 0..times {
+        locked_for = add_login_failure(db, kind, "alice", now, 3, &settings)
+            .await
+            .unwrap();
+    }
+    locked_for
+}
+
+async fn seconds_remaining(db: &Db, kind: LoginLockKind, now: NaiveDateTime) -> Option<i64> {
+    db.get_login_lock(kind, "alice")
+        .await
+        .unwrap()
+        .and_then(|lock| lock.seconds_remaining(now))
+}
+
+#[tokio::test]
+async fn lockouts_double_up_to_the_limit() {
+    let db = test_db().await;
+    let kind = LoginLockKind::Account;
+    let now = Utc::now().naive_utc();
+
+    assert_eq!(fail(&db, kind, now, 2).await, None);
+    assert_eq!(seconds_remaining(&db, kind, now).await, None);
+
+    assert_eq!(fail(&db, kind, now, 1).await, Some(60));
+    assert_eq!(seconds_remaining(&db, kind, now).await, Some(60));
+    assert_eq!(
+        seconds_remaining(&db, kind, now + Duration::seconds(60)).await,
+        None
+    );
+
+    assert_eq!(fail(&db, kind, now, 3).await, Some(120));
+    assert_eq!(fail(&db, kind, now, 3).await, Some(200));
+    assert_eq!(fail(&db, kind, now, 3).await, Some(200));
+}
+
+#[tokio::test]
+async fn failures_are_forgotten_after_a_while() {
+    let db = test_db().await;
+    let kind = LoginLockKind::Ip;
+    let now = Utc::now().naive_utc();
+
+    assert_eq!(fail(&db, kind, now, 3).await, Some(60));
+    assert_eq!(fail(&db, kind, now, 2).await, None);
+
+    let later = now + Duration::seconds(3600);
+    assert_eq!(fail(&db, kind, later, 2).await, None);
+    assert_eq!(fail(&db, kind, later, 1).await, Some(60));
+}
+
+#[tokio::test]
+async fn parallel_failures_are_all_counted() {
+    let db = test_db().await;
+    let now = Utc::now().naive_utc();
+    let reset_before = now - Duration::seconds(3600);
+
+    let tasks: Vec<_> = (0..20)
+        .map(|_| {
+            let db = db.clone();
+            tokio::spawn(async move {
+                db.increment_login_failures(LoginLockKind::Account, "alice", now, reset_before)
+                    .await
+                    .unwrap()
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    let lock = db
+        .get_login_lock(LoginLockKind::Account, "alice")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(lock.failures, 20);
+
+    // Only one of the connections that reach the limit starts the lockout
+    let locked_until = now + Duration::seconds(60);
+    assert!(db
+        .start_login_lockout(LoginLockKind::Account, "alice", 3, locked_until)
+        .await
+        .unwrap());
+    assert!(!db
+        .start_login_lockout(LoginLockKind::Account, "alice", 3, locked_until)
+        .await
+        .unwrap());
+}
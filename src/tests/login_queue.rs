@@ -0,0 +1,55 @@
+use crate::world::{LoginQueue, QueuedLogin};
+
+fn login(player_id: i32, priority: bool) -> QueuedLogin {
+    QueuedLogin {
+        player_id,
+        account_id: player_id * 10,
+        priority,
+    }
+}
+
+#[test]
+fn logins_are_admitted_in_order() {
+    let mut queue = LoginQueue::default();
+
+    assert_eq!(queue.push(login(1, false)), 1);
+    assert_eq!(queue.push(login(2, false)), 2);
+    assert_eq!(queue.push(login(3, false)), 3);
+
+    assert_eq!(queue.pop(), Some(login(1, false)));
+    assert_eq!(queue.pop(), Some(login(2, false)));
+    assert_eq!(queue.pop(), Some(login(3, false)));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn priority_logins_skip_ahead_of_regular_ones() {
+    let mut queue = LoginQueue::default();
+
+    queue.push(login(1, false));
+    queue.push(login(2, false));
+    assert_eq!(queue.push(login(3, true)), 1);
+    assert_eq!(queue.push(login(4, true)), 2);
+
+    let order = queue
+        .iter()
+        .map(|login| login.player_id)
+        .collect::<Vec<_>>();
+    assert_eq!(order, vec![3, 4, 1, 2]);
+}
+
+#[test]
+fn dropped_players_leave_the_queue() {
+    let mut queue = LoginQueue::default();
+
+    queue.push(login(1, false));
+    queue.push(login(2, false));
+    queue.push(login(3, false));
+
+    assert_eq!(queue.remove(2), Some(login(2, false)));
+    assert_eq!(queue.remove(2), None);
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.pop().map(|login| login.player_id), Some(1));
+    assert_eq!(queue.pop().map(|login| login.player_id), Some(3));
+    assert!(queue.is_empty());
+}
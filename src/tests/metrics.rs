@@ -0,0 +1,52 @@
+use prometheus::IntGauge;
+
+use crate::metrics;
+
+use super::harness::{test_db, TestServer};
+
+#[tokio::test]
+async fn counts_packets_by_family_and_action() {
+    let mut server = TestServer::start().await;
+    let (mut client, _) = server.connect().await;
+
+    client.handshake().await;
+
+    let output = metrics::render();
+    assert!(output.contains(r#"reoserv_packets_received_total{action="Init",family="Init"}"#));
+    assert!(output.contains(r#"reoserv_packets_sent_total{action="Init",family="Init"}"#));
+}
+
+#[tokio::test]
+async fn tracks_map_mailboxes() {
+    let server = TestServer::start().await;
+    server.map(1).await.get_stats().await;
+
+    let output = metrics::render();
+    assert!(output.contains(r#"reoserv_map_mailbox_depth{map="1"}"#));
+    assert!(output.contains(r#"reoserv_map_command_seconds_count{map="1"}"#));
+}
+
+#[tokio::test]
+async fn mailbox_depth_counts_unhandled_commands() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let depth = IntGauge::new("test_mailbox_depth", "test").unwrap();
+    let sender = metrics::QueueSender::new(tx, depth.clone());
+
+    sender.send(1).unwrap();
+    sender.send(2).unwrap();
+    assert_eq!(depth.get(), 2);
+
+    // Commands the actor will never get to aren't counted
+    rx.close();
+    assert!(sender.send(3).is_err());
+    assert_eq!(depth.get(), 2);
+}
+
+#[tokio::test]
+async fn times_storage_calls() {
+    let db = test_db().await;
+    db.get_bans().await.unwrap();
+
+    let output = metrics::render();
+    assert!(output.contains(r#"reoserv_db_query_seconds_count{storage="ban"}"#));
+}
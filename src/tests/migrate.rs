@@ -0,0 +1,30 @@
+use crate::db::{self, Migration};
+
+use super::harness::test_db;
+
+#[tokio::test]
+async fn migrations_are_recorded_and_not_reapplied() {
+    let db = test_db().await;
+    let latest = db.migrations().last().unwrap().version;
+
+    assert_eq!(db.get_schema_version().await.unwrap(), latest);
+
+    db::migrate(&db).await.unwrap();
+
+    assert_eq!(db.get_schema_version().await.unwrap(), latest);
+}
+
+#[tokio::test]
+async fn refuses_database_newer_than_build() {
+    let db = test_db().await;
+
+    db.apply_migration(&Migration {
+        version: 9999,
+        name: "from_the_future",
+        sql: "CREATE TABLE `FromTheFuture` (`id` INTEGER)",
+    })
+    .await
+    .unwrap();
+
+    assert!(db::migrate(&db).await.is_err());
+}
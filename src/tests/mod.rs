@@ -0,0 +1,28 @@
+mod harness;
+
+mod admin_api;
+mod attack;
+mod audit;
+mod bans;
+mod bard;
+mod boss;
+mod bot_detection;
+mod check_quests;
+mod email;
+mod handshake;
+mod login;
+mod login_lock;
+mod login_queue;
+mod metrics;
+mod migrate;
+mod npc_abilities;
+mod pathfinding;
+mod pub_files;
+mod quest;
+mod reload;
+mod status_effects;
+mod storage;
+mod talk;
+mod trade;
+mod validate;
+mod walk;
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::{
+    npc_abilities::{NpcAbilities, NpcAbility, NpcAbilitySet, NpcAbilityType, NpcPhase},
+    NPC_ABILITIES,
+};
+
+use super::harness::{test_character, TestServer};
+
+const CROW: i32 = 2;
+
+fn summon(npc_id: i32, amount: i32, max: i32) -> NpcAbility {
+    NpcAbility {
+        r#type: NpcAbilityType::Summon,
+        chance: 100,
+        spell_id: 0,
+        range: 0,
+        npc_id,
+        amount,
+        max,
+    }
+}
+
+fn phase(hp_percentage: i32, melee: bool, abilities: Vec<NpcAbility>) -> NpcPhase {
+    NpcPhase {
+        hp_percentage,
+        melee,
+        abilities,
+    }
+}
+
+#[test]
+fn lowest_phase_reached_is_used() {
+    let abilities = NpcAbilities {
+        npcs: vec![NpcAbilitySet {
+            npc_id: 5,
+            phases: vec![
+                phase(100, true, Vec::new()),
+                phase(25, false, Vec::new()),
+                phase(50, true, vec![summon(6, 1, 1)]),
+            ],
+        }],
+    };
+
+    let hp_percentage = |hp| abilities.get_phase(5, hp).unwrap().hp_percentage;
+
+    assert_eq!(hp_percentage(100), 100);
+    assert_eq!(hp_percentage(51), 100);
+    assert_eq!(hp_percentage(50), 50);
+    assert_eq!(hp_percentage(26), 50);
+    assert_eq!(hp_percentage(10), 25);
+    assert!(abilities.get_phase(6, 100).is_none());
+}
+
+#[tokio::test]
+async fn summoners_stop_at_their_limit() {
+    NPC_ABILITIES.store(Arc::new(NpcAbilities {
+        npcs: vec![NpcAbilitySet {
+            npc_id: CROW,
+            phases: vec![phase(100, false, vec![summon(CROW, 2, 3)])],
+        }],
+    }));
+
+    let mut server = TestServer::start().await;
+    let alice = server.enter_game(test_character("alice", 1, 5, 5)).await;
+
+    let map = server.map(1).await;
+    map.spawn_npc(alice.player_id, CROW, 1, 0);
+    let before = map.get_stats().await.npcs;
+
+    // The summoner counts towards the limit so there's only room for two more
+    for _ in 0..5 {
+        map.act_npcs();
+    }
+
+    assert_eq!(map.get_stats().await.npcs, before + 2);
+
+    NPC_ABILITIES.store(Arc::new(NpcAbilities::default()));
+}
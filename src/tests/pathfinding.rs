@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use eolib::protocol::{
+    map::{Emf, MapTileSpec, MapTileSpecRow, MapTileSpecRowTile},
+    Coords,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    audit::AuditHandle, email::OutboxHandle, map::Map, utils::get_distance, world::WorldHandle,
+};
+
+use super::harness::{test_db, TestTransport};
+
+// 10x10 map with a wall down x = 5 that can only be passed at y = 9
+async fn walled_map() -> Map {
+    let file = Emf {
+        width: 9,
+        height: 9,
+        tile_spec_rows: (0..9)
+            .map(|y| MapTileSpecRow {
+                y,
+                tiles: vec![MapTileSpecRowTile {
+                    x: 5,
+                    tile_spec: MapTileSpec::Wall,
+                }],
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let db = test_db().await;
+    let world = WorldHandle::new(
+        db.clone(),
+        AuditHandle::new(None, None),
+        OutboxHandle::new(db.clone(), Arc::new(TestTransport::default())),
+    );
+    let (_, rx) = mpsc::unbounded_channel();
+    Map::new(1, 0, file, db, world, rx)
+}
+
+#[tokio::test]
+async fn path_goes_around_walls() {
+    let map = walled_map().await;
+    let from = Coords { x: 2, y: 2 };
+    let to = Coords { x: 8, y: 2 };
+
+    let steps = map.find_npc_path(from, to, 20, false).unwrap();
+
+    // 7 down, 6 across and 7 back up
+    assert_eq!(steps.len(), 20);
+    assert_eq!(steps.back(), Some(&to));
+    assert!(steps.contains(&Coords { x: 5, y: 9 }));
+
+    let mut previous = from;
+    for step in &steps {
+        assert_eq!(get_distance(&previous, step), 1);
+        assert!(map.is_tile_walkable_npc(step));
+        previous = *step;
+    }
+}
+
+#[tokio::test]
+async fn path_is_bounded_by_distance_from_target() {
+    let map = walled_map().await;
+
+    assert!(map
+        .find_npc_path(Coords { x: 2, y: 2 }, Coords { x: 8, y: 2 }, 10, false)
+        .is_none());
+    assert_eq!(
+        map.find_npc_path(Coords { x: 2, y: 2 }, Coords { x: 4, y: 2 }, 10, false)
+            .unwrap()
+            .len(),
+        2
+    );
+}
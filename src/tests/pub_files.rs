@@ -0,0 +1,110 @@
+use bytes::Bytes;
+use crc::{Crc, CRC_32_CKSUM};
+use eolib::{
+    data::{encode_number, EoReader, EoSerialize, EoWriter},
+    protocol::{
+        net::client::FileType,
+        r#pub::{Eif, EifRecord},
+    },
+};
+
+use crate::{
+    utils::{get_pub_file_reply, split_pub_file, ClientPubFiles, MAX_PUB_FILE_SIZE},
+    ITEM_DB,
+};
+
+fn large_item_file() -> Eif {
+    let items = (0..3000)
+        .map(|i| EifRecord {
+            name: format!("Item of considerable length number {}", i),
+            graphic_id: i,
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    Eif {
+        total_items_count: items.len() as i32,
+        items,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn large_files_are_split_into_packet_sized_pieces() {
+    let eif = large_item_file();
+    let split = split_pub_file(&eif);
+
+    assert!(split.files.len() > 1);
+    assert_eq!(split.length, 3000);
+
+    let mut items = Vec::new();
+    for (i, file) in split.files.iter().enumerate() {
+        assert!(file.len() <= MAX_PUB_FILE_SIZE);
+
+        let piece = Eif::deserialize(&EoReader::new(Bytes::from(file.clone()))).unwrap();
+        assert_eq!(piece.total_items_count, 3000);
+
+        // Each piece's rid is a checksum of the piece itself
+        let checksum = Crc::<u32>::new(&CRC_32_CKSUM).checksum(&file[7..]) % i32::MAX as u32;
+        let encoded = encode_number(checksum as i32).unwrap();
+        assert_eq!(&file[3..7], &encoded[..]);
+
+        if i == 0 {
+            assert_eq!(piece.rid, split.rid);
+        }
+
+        items.extend(piece.items);
+    }
+
+    assert_eq!(items, eif.items);
+    assert!(split.get(0).is_none());
+    assert!(split.get(split.files.len() as i32 + 1).is_none());
+}
+
+#[test]
+fn small_files_are_served_whole() {
+    let file_id = |file_id| get_pub_file_reply(FileType::Eif, file_id);
+
+    let reply = file_id(1).unwrap();
+    assert!(file_id(2).is_none());
+    assert!(get_pub_file_reply(FileType::Emf, 1).is_none());
+
+    let eif = ITEM_DB.load();
+    let split = split_pub_file(&**eif);
+    assert_eq!(split.files.len(), 1);
+    assert_eq!(split.length, eif.items.len() as i32);
+
+    // A file that isn't split keeps its own rid so cached copies still match
+    let mut writer = EoWriter::new();
+    eif.serialize(&mut writer).unwrap();
+    assert_eq!(split.rid, eif.rid);
+    assert_eq!(&split.files[0][..], &writer.to_byte_array()[..]);
+
+    let mut writer = EoWriter::new();
+    reply.serialize(&mut writer).unwrap();
+    let bytes = writer.to_byte_array();
+    assert!(bytes.ends_with(&split.files[0]));
+}
+
+#[test]
+fn edited_records_count_as_changed() {
+    let eif = large_item_file();
+    let mut edited = large_item_file();
+    edited.items[10].name = "Renamed".to_string();
+
+    let previous = ClientPubFiles {
+        eif: split_pub_file(&eif),
+        ..Default::default()
+    };
+    let same = ClientPubFiles {
+        eif: split_pub_file(&eif),
+        ..Default::default()
+    };
+    let renamed = ClientPubFiles {
+        eif: split_pub_file(&edited),
+        ..Default::default()
+    };
+
+    assert!(same.changed_since(&previous).is_empty());
+    assert_eq!(renamed.changed_since(&previous), vec![FileType::Eif]);
+}
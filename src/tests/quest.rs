@@ -0,0 +1,109 @@
+use eolib::protocol::net::{server::RecoverListServerPacket, PacketAction, PacketFamily};
+
+use crate::character::{Character, QuestProgress};
+
+use super::harness::{saved_test_character, test_character, TestServer};
+
+// States of tests/fixtures/data/quests/00002.eqf
+const BEGIN: i32 = 0;
+const SPELL: i32 = 3;
+const DONE: i32 = 4;
+// "Gather" in tests/fixtures/data/quests/00001.eqf
+const GATHER: i32 = 1;
+
+#[test]
+fn condition_rules_chain_through_actions() {
+    let mut character = test_character("alice", 1, 2, 2);
+    character.quests = vec![QuestProgress {
+        id: 2,
+        ..Default::default()
+    }];
+
+    character.check_quest_rules();
+    assert_eq!(character.quests[0].state, BEGIN);
+
+    character.level = 5;
+    character.check_quest_rules();
+
+    // StatGreater -> SetTitle/SetHome/Roll -> Rolled -> CitizenOf
+    assert_eq!(character.quests[0].state, SPELL);
+    assert_eq!(character.title.as_deref(), Some("Veteran"));
+    assert_eq!(character.home, "Aeven");
+    assert_eq!(character.quests[0].rolled, 1);
+}
+
+#[test]
+fn learned_spell_ends_quest_and_starts_another() {
+    let mut character = test_character("alice", 1, 2, 2);
+    character.quests = vec![QuestProgress {
+        id: 2,
+        state: SPELL,
+        ..Default::default()
+    }];
+
+    character.add_spell(1);
+    character.check_quest_rules();
+
+    let rules = character.quests.iter().find(|q| q.id == 2).unwrap();
+    assert_eq!(rules.state, DONE);
+    assert!(rules.done_at.is_some());
+
+    let started = character.quests.iter().find(|q| q.id == 1).unwrap();
+    assert_eq!(started.state, GATHER);
+}
+
+#[test]
+fn quests_that_loop_back_stop() {
+    let mut character = test_character("alice", 1, 2, 2);
+    character.quests = vec![QuestProgress {
+        id: 3,
+        ..Default::default()
+    }];
+
+    // 00003 jumps back into its own state and pings 00004, which sets it
+    // straight back
+    character.check_quest_rules();
+
+    assert_eq!(character.get_quest_progress(3).state, 1);
+    assert_eq!(character.get_quest_progress(4).state, 1);
+}
+
+#[tokio::test]
+async fn quest_titles_and_homes_are_saved() {
+    let server = TestServer::start().await;
+    let mut character = saved_test_character(&server.db, "alice", 1, 2, 2).await;
+    character.quests = vec![QuestProgress {
+        id: 2,
+        ..Default::default()
+    }];
+    character.level = 5;
+    character.check_quest_rules();
+    character.save(&server.db).await.unwrap();
+
+    let saved = Character::load(&server.db, "alice").await.unwrap().unwrap();
+    assert_eq!(saved.title.as_deref(), Some("Veteran"));
+    assert_eq!(saved.home, "Aeven");
+}
+
+#[tokio::test]
+async fn set_and_give_stat_update_character() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+
+    let map = server.map(1).await;
+    map.set_stat(alice.player_id, "str".to_owned(), 5);
+    map.give_stat(alice.player_id, "STR".to_owned(), 2);
+    map.give_stat(alice.player_id, "str".to_owned(), -10);
+    map.set_stat(alice.player_id, "bogus".to_owned(), 1);
+
+    for _ in 0..3 {
+        alice
+            .expect::<RecoverListServerPacket>(PacketAction::List, PacketFamily::Recover)
+            .await;
+    }
+
+    let character = map.get_character(alice.player_id).await.unwrap();
+    assert_eq!(character.base_strength, 0);
+    assert_eq!(character.get_quest_stat("str"), Some(0));
+    assert_eq!(character.get_quest_stat("bogus"), None);
+}
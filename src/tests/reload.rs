@@ -0,0 +1,73 @@
+use std::{collections::HashMap, sync::Arc};
+
+use eolib::protocol::net::{PacketAction, PacketFamily};
+use eoplus::{parse_quest, Quest};
+
+use crate::{character::QuestProgress, world::ReloadKind, QUEST_DB};
+
+use super::harness::{test_character, TestServer};
+
+fn quest(states: &[&str]) -> Quest {
+    let mut source = "Main { questname \"Fixture Quest\" version 1 }\n".to_string();
+    for state in states {
+        source.push_str(&format!("state {} {{ desc \"{}\" }}\n", state, state));
+    }
+    parse_quest(&source).unwrap()
+}
+
+#[tokio::test]
+async fn quest_progress_follows_state_names() {
+    let mut server = TestServer::start().await;
+
+    let mut character = test_character("alice", 1, 2, 2);
+    character.quests = vec![
+        QuestProgress {
+            id: 1,
+            state: 2,
+            npc_kills: vec![(1, 3)],
+            ..Default::default()
+        },
+        QuestProgress {
+            id: 99,
+            state: 1,
+            ..Default::default()
+        },
+    ];
+    let alice = server.enter_game(character).await;
+
+    let previous = Arc::new(HashMap::from([
+        (1, quest(&["Begin", "Removed", "Gather", "Finish"])),
+        (99, quest(&["Begin", "Removed"])),
+    ]));
+
+    assert!(QUEST_DB.load().contains_key(&1));
+    let map = server.map(1).await;
+    map.reload_quests(previous);
+
+    let character = map.get_character(alice.player_id).await.unwrap();
+    // "Gather" moved from index 2 to 1 in the fixture quest; kills carry over
+    // only when the state is unchanged
+    assert_eq!(character.quests[0].state, 1);
+    assert!(character.quests[0].npc_kills.is_empty());
+    // Quest 99 isn't in the reloaded set so it's left alone
+    assert_eq!(character.quests[1].state, 1);
+}
+
+#[tokio::test]
+async fn reloading_unchanged_pub_files_sends_nothing() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+
+    server.world.reload(ReloadKind::All);
+
+    alice
+        .expect_none(PacketAction::Init, PacketFamily::Init)
+        .await;
+}
+
+#[test]
+fn parses_reload_types() {
+    assert_eq!(ReloadKind::parse("PUB"), Some(ReloadKind::Pub));
+    assert_eq!(ReloadKind::parse(""), Some(ReloadKind::All));
+    assert_eq!(ReloadKind::parse("maps"), None);
+}
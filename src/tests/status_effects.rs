@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use eolib::protocol::{
+    net::{
+        client::{ItemUseClientPacket, WalkAction, WalkPlayerClientPacket},
+        server::{
+            RecoverListServerPacket, RefreshReplyServerPacket, WalkCloseServerPacket,
+            WalkOpenServerPacket, WalkReplyServerPacket,
+        },
+        Item, PacketAction, PacketFamily,
+    },
+    Coords, Direction,
+};
+
+use crate::{
+    settings::StatusEffectClearPolicy,
+    status_effects::{
+        ActiveStatusEffects, StatModifiers, StatusEffect, StatusEffectKind, StatusEffects,
+    },
+    STATUS_EFFECTS,
+};
+
+use super::harness::{saved_test_character, test_character, test_db, TestServer};
+
+const BREAD: i32 = 3;
+
+const BLESSING: i32 = 1;
+const POISON: i32 = 2;
+const FROZEN: i32 = 3;
+const HASTE: i32 = 4;
+const SLOW: i32 = 5;
+
+fn effect(id: i32, kind: StatusEffectKind, duration: i32) -> StatusEffect {
+    StatusEffect {
+        id,
+        name: format!("Effect {}", id),
+        kind,
+        duration,
+        interval: 1,
+        amount: 0,
+        tp: 0,
+        modifiers: StatModifiers::default(),
+        graphic_id: 0,
+        spells: Vec::new(),
+        items: Vec::new(),
+    }
+}
+
+fn status_effects() -> StatusEffects {
+    StatusEffects {
+        effects: vec![
+            StatusEffect {
+                modifiers: StatModifiers {
+                    armor: 10,
+                    ..Default::default()
+                },
+                items: vec![BREAD],
+                ..effect(BLESSING, StatusEffectKind::Modifier, 300)
+            },
+            StatusEffect {
+                interval: 2,
+                amount: 5,
+                ..effect(POISON, StatusEffectKind::DamageOverTime, 4)
+            },
+            StatusEffect {
+                items: vec![BREAD],
+                ..effect(FROZEN, StatusEffectKind::Stun, 2)
+            },
+            StatusEffect {
+                amount: 50,
+                ..effect(HASTE, StatusEffectKind::Haste, 10)
+            },
+            StatusEffect {
+                amount: 20,
+                ..effect(SLOW, StatusEffectKind::Slow, 10)
+            },
+        ],
+    }
+}
+
+fn apply(active: &mut ActiveStatusEffects, effects: &StatusEffects, id: i32) {
+    active.apply(effects.get(id).unwrap(), None);
+}
+
+#[test]
+fn modifiers_and_speed_stack() {
+    let effects = status_effects();
+    let mut active = ActiveStatusEffects::default();
+
+    apply(&mut active, &effects, BLESSING);
+    apply(&mut active, &effects, HASTE);
+    apply(&mut active, &effects, SLOW);
+
+    assert_eq!(active.get_modifiers(&effects).armor, 10);
+    assert_eq!(active.get_speed(&effects), 30);
+    assert!(!active.is_stunned(&effects));
+
+    // Applying an active effect again starts it over instead of stacking
+    apply(&mut active, &effects, BLESSING);
+    assert_eq!(active.get_modifiers(&effects).armor, 10);
+}
+
+#[test]
+fn effects_tick_on_their_interval_and_wear_off() {
+    let effects = status_effects();
+    let mut active = ActiveStatusEffects::default();
+
+    apply(&mut active, &effects, POISON);
+    active.0[0].caster_id = Some(7);
+    apply(&mut active, &effects, FROZEN);
+
+    let first = active.tick(&effects);
+    assert_eq!(first.damage, 0);
+    assert!(!first.stun_ended);
+
+    let second = active.tick(&effects);
+    assert_eq!(second.damage, 5);
+    assert_eq!(second.caster_id, Some(7));
+    assert!(second.stun_ended);
+    assert!(!active.is_stunned(&effects));
+
+    active.tick(&effects);
+    assert_eq!(active.tick(&effects).damage, 5);
+    assert!(active.is_empty());
+}
+
+#[test]
+fn clear_policies() {
+    let effects = status_effects();
+    let mut active = ActiveStatusEffects::default();
+    for id in [BLESSING, POISON, FROZEN, HASTE] {
+        apply(&mut active, &effects, id);
+    }
+
+    let cleared = active.clear(&effects, StatusEffectClearPolicy::Keep);
+    assert!(!cleared.stats_changed && !cleared.stun_ended);
+    assert_eq!(active.0.len(), 4);
+
+    let cleared = active.clear(&effects, StatusEffectClearPolicy::Harmful);
+    assert!(!cleared.stats_changed);
+    assert!(cleared.stun_ended);
+    let remaining = active.0.iter().map(|a| a.effect_id).collect::<Vec<_>>();
+    assert_eq!(remaining, vec![BLESSING, HASTE]);
+
+    let cleared = active.clear(&effects, StatusEffectClearPolicy::All);
+    assert!(cleared.stats_changed);
+    assert!(active.is_empty());
+}
+
+#[tokio::test]
+async fn items_apply_status_effects_and_stuns_stop_walking() {
+    STATUS_EFFECTS.store(Arc::new(status_effects()));
+
+    let mut server = TestServer::start().await;
+    let mut character = test_character("alice", 1, 2, 2);
+    character.hp = 1;
+    character.items.push(Item {
+        id: BREAD,
+        amount: 1,
+    });
+    let mut alice = server.enter_game(character).await;
+
+    let map = server.map(1).await;
+    let armor = map.get_character(alice.player_id).await.unwrap().armor;
+
+    alice
+        .send(
+            PacketAction::Use,
+            PacketFamily::Item,
+            &ItemUseClientPacket { item_id: BREAD },
+        )
+        .await;
+
+    let _: RecoverListServerPacket = alice
+        .expect(PacketAction::List, PacketFamily::Recover)
+        .await;
+    let _: WalkCloseServerPacket = alice.expect(PacketAction::Close, PacketFamily::Walk).await;
+
+    let character = map.get_character(alice.player_id).await.unwrap();
+    assert_eq!(character.armor, armor + 10);
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Player,
+            PacketFamily::Walk,
+            &WalkPlayerClientPacket {
+                walk_action: WalkAction {
+                    direction: Direction::Down,
+                    timestamp,
+                    coords: Coords { x: 2, y: 3 },
+                },
+            },
+        )
+        .await;
+
+    let _: RefreshReplyServerPacket = alice
+        .expect(PacketAction::Reply, PacketFamily::Refresh)
+        .await;
+    alice
+        .expect_none(PacketAction::Reply, PacketFamily::Walk)
+        .await;
+
+    map.timed_status_effects();
+    map.timed_status_effects();
+
+    let _: WalkOpenServerPacket = alice.expect(PacketAction::Open, PacketFamily::Walk).await;
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Player,
+            PacketFamily::Walk,
+            &WalkPlayerClientPacket {
+                walk_action: WalkAction {
+                    direction: Direction::Down,
+                    timestamp,
+                    coords: Coords { x: 2, y: 3 },
+                },
+            },
+        )
+        .await;
+
+    let _: WalkReplyServerPacket = alice.expect(PacketAction::Reply, PacketFamily::Walk).await;
+}
+
+#[tokio::test]
+async fn status_effects_survive_relog() {
+    STATUS_EFFECTS.store(Arc::new(status_effects()));
+
+    let db = test_db().await;
+    let mut character = saved_test_character(&db, "alice", 1, 2, 2).await;
+
+    let effects = status_effects();
+    apply(&mut character.status_effects, &effects, BLESSING);
+    apply(&mut character.status_effects, &effects, POISON);
+    character.status_effects.0[0].remaining = 120;
+    character.save(&db).await.unwrap();
+
+    let loaded = db.load_character(character.id).await.unwrap();
+    let mut remaining = loaded
+        .status_effects
+        .0
+        .iter()
+        .map(|active| (active.effect_id, active.remaining))
+        .collect::<Vec<_>>();
+    remaining.sort();
+    assert_eq!(remaining, vec![(BLESSING, 120), (POISON, 4)]);
+
+    character.status_effects = ActiveStatusEffects::default();
+    character.save(&db).await.unwrap();
+    let loaded = db.load_character(character.id).await.unwrap();
+    assert!(loaded.status_effects.is_empty());
+}
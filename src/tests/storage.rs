@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use eolib::protocol::net::Item;
+
+use crate::{
+    character::{Character, QuestProgress},
+    db::{Sanction, SanctionKind},
+};
+
+use super::harness::{saved_test_character, test_account, test_character, test_db};
+
+#[tokio::test]
+async fn character_round_trips_through_storage() {
+    let db = test_db().await;
+    db.create_account(&test_account("alice")).await.unwrap();
+    let account = db.get_account_credentials("alice").await.unwrap().unwrap();
+
+    let mut character = test_character("alice", 1, 2, 2);
+    character.account_id = account.id;
+    character.save(&db).await.unwrap();
+    assert!(character.id > 0);
+
+    character.items = vec![Item { id: 1, amount: 42 }, Item { id: 3, amount: 2 }];
+    character.bank = vec![Item { id: 2, amount: 1 }];
+    character.quests = vec![QuestProgress {
+        id: 1,
+        state: 2,
+        npc_kills: vec![(1, 3)],
+        ..Default::default()
+    }];
+    character.map_id = 2;
+    character.coords.x = 4;
+    character.coords.y = 5;
+    character.save(&db).await.unwrap();
+
+    let loaded = db.load_character(character.id).await.unwrap();
+    assert_eq!(loaded.name, "alice");
+    assert_eq!(loaded.account_id, account.id);
+    assert_eq!(loaded.items, character.items);
+    assert_eq!(loaded.bank, character.bank);
+    assert_eq!(loaded.quests[0].npc_kills, vec![(1, 3)]);
+    assert_eq!((loaded.map_id, loaded.coords.x, loaded.coords.y), (2, 4, 5));
+
+    let list = db.get_character_list(account.id).await.unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(db.get_num_of_characters(account.id).await.unwrap(), 1);
+
+    db.delete_character(character.id).await.unwrap();
+    assert!(db.load_character(character.id).await.is_err());
+    assert!(!db.character_exists("alice").await.unwrap());
+}
+
+#[tokio::test]
+async fn guild_round_trips_through_storage() {
+    let db = test_db().await;
+
+    db.create_guild("TST", "Testers", "A guild").await.unwrap();
+    assert!(db.guild_exists("TST", "Other").await.unwrap());
+
+    db.set_guild_rank("TST", 2, "Veteran").await.unwrap();
+    db.set_guild_bank("TST", 5000).await.unwrap();
+
+    let details = db.get_guild_details("Testers").await.unwrap().unwrap();
+    assert_eq!(details.tag, "TST");
+    assert_eq!(details.bank, 5000);
+    assert_eq!(details.ranks.len(), 9);
+    assert_eq!(details.ranks[2], "Veteran");
+
+    db.delete_guild("TST").await.unwrap();
+    assert!(db.get_guild_details("TST").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn offline_guild_members_can_be_ranked_and_removed() {
+    let db = test_db().await;
+    db.create_guild("TST", "Testers", "A guild").await.unwrap();
+
+    let mut character = saved_test_character(&db, "alice", 1, 2, 2).await;
+    character.guild_tag = Some("TST".to_string());
+    character.guild_rank = Some(9);
+    character.guild_rank_string = Some("Recruit".to_string());
+    character.save(&db).await.unwrap();
+
+    assert!(Character::load(&db, "nobody").await.unwrap().is_none());
+
+    db.set_guild_member_rank("alice", 3, "Officer")
+        .await
+        .unwrap();
+    let loaded = Character::load(&db, "alice").await.unwrap().unwrap();
+    assert_eq!(loaded.guild_tag.as_deref(), Some("TST"));
+    assert_eq!(loaded.guild_rank, Some(3));
+    assert_eq!(loaded.guild_rank_string.as_deref(), Some("Officer"));
+
+    db.remove_guild_member("alice").await.unwrap();
+    let loaded = Character::load(&db, "alice").await.unwrap().unwrap();
+    assert_eq!(loaded.guild_tag, None);
+    assert_eq!(loaded.guild_rank, None);
+    assert!(db.get_guild_members("TST").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn sanctions_are_kept_and_mutes_can_be_lifted() {
+    let db = test_db().await;
+    let character = saved_test_character(&db, "alice", 1, 2, 2).await;
+
+    let mut expired = Sanction::new(SanctionKind::GlobalMute, 30, "", "admin");
+    expired.created_at -= Duration::from_secs(60 * 60);
+    db.create_sanction(character.id, &expired).await.unwrap();
+    db.create_sanction(
+        character.id,
+        &Sanction::new(SanctionKind::Warning, 0, "spamming", "admin"),
+    )
+    .await
+    .unwrap();
+    db.create_sanction(
+        character.id,
+        &Sanction::new(SanctionKind::Mute, 0, "", "admin"),
+    )
+    .await
+    .unwrap();
+
+    let sanctions = db.get_sanctions(character.id).await.unwrap();
+    let kinds = sanctions.iter().map(|s| s.kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            SanctionKind::Mute,
+            SanctionKind::Warning,
+            SanctionKind::GlobalMute
+        ]
+    );
+    assert_eq!(sanctions[1].reason, "spamming");
+    assert!(sanctions[0].is_active_mute());
+    assert!(!sanctions[1].is_active_mute());
+    assert!(!sanctions[2].is_active_mute());
+
+    // Only the permanent mute is still in place
+    assert_eq!(db.lift_mutes(character.id).await.unwrap(), 1);
+    assert_eq!(db.lift_mutes(character.id).await.unwrap(), 0);
+
+    let sanctions = db.get_sanctions(character.id).await.unwrap();
+    assert!(sanctions[0].lifted_at.is_some());
+    assert!(sanctions.iter().all(|sanction| !sanction.is_active_mute()));
+    assert!(sanctions[0].to_string().ends_with("(lifted)"));
+    assert!(sanctions[2].to_string().ends_with("(expired)"));
+}
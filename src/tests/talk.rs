@@ -0,0 +1,161 @@
+use eolib::protocol::net::{
+    client::{TalkMsgClientPacket, TalkReportClientPacket},
+    server::{TalkPlayerServerPacket, TalkServerServerPacket},
+    PacketAction, PacketFamily,
+};
+
+use crate::LANG;
+
+use super::harness::{saved_test_character, test_character, TestClient, TestServer};
+
+#[tokio::test]
+async fn local_chat_reaches_nearby_players() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 3, 3)).await;
+
+    alice
+        .send(
+            PacketAction::Report,
+            PacketFamily::Talk,
+            &TalkReportClientPacket {
+                message: "hello bob".to_string(),
+            },
+        )
+        .await;
+
+    let talk: TalkPlayerServerPacket = bob.expect(PacketAction::Player, PacketFamily::Talk).await;
+    assert_eq!(talk.player_id, alice.player_id);
+    assert_eq!(talk.message, "hello bob");
+}
+
+async fn expect_server_message(client: &mut TestClient, message: &str) {
+    loop {
+        let server: TalkServerServerPacket = client
+            .expect(PacketAction::Server, PacketFamily::Talk)
+            .await;
+        if server.message == message {
+            return;
+        }
+    }
+}
+
+#[tokio::test]
+async fn muted_players_can_not_talk() {
+    let mut server = TestServer::start().await;
+    let character = saved_test_character(&server.db, "alice", 1, 2, 2).await;
+    let mut alice = server.enter_game(character).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 3, 3)).await;
+
+    server.world.mute_player(
+        "alice".to_string(),
+        "admin".to_string(),
+        "30m".to_string(),
+        false,
+    );
+    expect_server_message(
+        &mut alice,
+        &get_lang_string!(&LANG.announce_mute, victim = "alice", name = "admin"),
+    )
+    .await;
+
+    alice
+        .send(
+            PacketAction::Report,
+            PacketFamily::Talk,
+            &TalkReportClientPacket {
+                message: "hello bob".to_string(),
+            },
+        )
+        .await;
+
+    expect_server_message(&mut alice, &LANG.muted).await;
+    bob.expect_none(PacketAction::Player, PacketFamily::Talk)
+        .await;
+
+    server
+        .world
+        .unmute_player("alice".to_string(), "admin".to_string());
+    expect_server_message(
+        &mut alice,
+        &get_lang_string!(&LANG.announce_unmute, victim = "alice", name = "admin"),
+    )
+    .await;
+
+    alice
+        .send(
+            PacketAction::Report,
+            PacketFamily::Talk,
+            &TalkReportClientPacket {
+                message: "hello again".to_string(),
+            },
+        )
+        .await;
+
+    let talk: TalkPlayerServerPacket = bob.expect(PacketAction::Player, PacketFamily::Talk).await;
+    assert_eq!(talk.message, "hello again");
+}
+
+#[tokio::test]
+async fn global_mutes_only_block_global_chat() {
+    let mut server = TestServer::start().await;
+    let character = saved_test_character(&server.db, "alice", 1, 2, 2).await;
+    let mut alice = server.enter_game(character).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 3, 3)).await;
+
+    server.world.mute_player(
+        "alice".to_string(),
+        "admin".to_string(),
+        "".to_string(),
+        true,
+    );
+    expect_server_message(
+        &mut alice,
+        &get_lang_string!(&LANG.announce_global_mute, victim = "alice", name = "admin"),
+    )
+    .await;
+
+    alice
+        .send(
+            PacketAction::Msg,
+            PacketFamily::Talk,
+            &TalkMsgClientPacket {
+                message: "hello world".to_string(),
+            },
+        )
+        .await;
+    expect_server_message(&mut alice, &LANG.muted).await;
+
+    alice
+        .send(
+            PacketAction::Report,
+            PacketFamily::Talk,
+            &TalkReportClientPacket {
+                message: "hello bob".to_string(),
+            },
+        )
+        .await;
+
+    let talk: TalkPlayerServerPacket = bob.expect(PacketAction::Player, PacketFamily::Talk).await;
+    assert_eq!(talk.message, "hello bob");
+    bob.expect_none(PacketAction::Msg, PacketFamily::Talk).await;
+}
+
+#[tokio::test]
+async fn mutes_with_invalid_durations_are_rejected() {
+    let mut server = TestServer::start().await;
+    let character = saved_test_character(&server.db, "alice", 1, 2, 2).await;
+    let alice_id = character.id;
+    let _alice = server.enter_game(character).await;
+    let mut admin = server.enter_game(test_character("admin", 1, 3, 3)).await;
+
+    server.world.mute_player(
+        "alice".to_string(),
+        "admin".to_string(),
+        "30x".to_string(),
+        false,
+    );
+    expect_server_message(&mut admin, "Invalid duration \"30x\".").await;
+
+    assert!(server.db.get_sanctions(alice_id).await.unwrap().is_empty());
+}
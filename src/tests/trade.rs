@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use eolib::protocol::net::{
+    client::{
+        TradeAcceptClientPacket, TradeAddClientPacket, TradeAgreeClientPacket,
+        TradeRequestClientPacket,
+    },
+    server::{TradeOpenServerPacket, TradeRequestServerPacket, TradeUseServerPacket},
+    Item, PacketAction, PacketFamily,
+};
+use tokio::time::sleep;
+
+use crate::audit::{AuditEvent, AuditItem};
+
+use super::harness::{saved_test_character, test_character, TestClient, TestServer};
+
+/// Opens a trade between two players next to each other, has both offer an
+/// item and agree, and waits for it to go through
+async fn trade(
+    server: &TestServer,
+    alice: &mut TestClient,
+    alice_offer: Item,
+    bob: &mut TestClient,
+    bob_offer: Item,
+) {
+    let map = server.map(1).await;
+
+    alice
+        .send(
+            PacketAction::Request,
+            PacketFamily::Trade,
+            &TradeRequestClientPacket {
+                player_id: bob.player_id,
+            },
+        )
+        .await;
+
+    let request: TradeRequestServerPacket =
+        bob.expect(PacketAction::Request, PacketFamily::Trade).await;
+    assert_eq!(request.partner_player_id, alice.player_id);
+
+    bob.send(
+        PacketAction::Accept,
+        PacketFamily::Trade,
+        &TradeAcceptClientPacket {
+            player_id: alice.player_id,
+        },
+    )
+    .await;
+
+    let _: TradeOpenServerPacket = alice.expect(PacketAction::Open, PacketFamily::Trade).await;
+    let _: TradeOpenServerPacket = bob.expect(PacketAction::Open, PacketFamily::Trade).await;
+
+    alice
+        .send(
+            PacketAction::Add,
+            PacketFamily::Trade,
+            &TradeAddClientPacket {
+                add_item: alice_offer,
+            },
+        )
+        .await;
+    bob.send(
+        PacketAction::Add,
+        PacketFamily::Trade,
+        &TradeAddClientPacket {
+            add_item: bob_offer,
+        },
+    )
+    .await;
+
+    // Both offers have to land before either side agrees
+    for player_id in [alice.player_id, bob.player_id] {
+        for _ in 0..100 {
+            let character = map.get_character(player_id).await.unwrap();
+            if !character.trade_items.is_empty() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    for client in [&mut *alice, &mut *bob] {
+        client
+            .send(
+                PacketAction::Agree,
+                PacketFamily::Trade,
+                &TradeAgreeClientPacket { agree: true },
+            )
+            .await;
+    }
+
+    let _: TradeUseServerPacket = alice.expect(PacketAction::Use, PacketFamily::Trade).await;
+}
+
+#[tokio::test]
+async fn completed_trade_swaps_items() {
+    let mut server = TestServer::start().await;
+
+    let mut alice_character = test_character("alice", 1, 2, 2);
+    alice_character.items.push(Item { id: 3, amount: 5 });
+    let mut alice = server.enter_game(alice_character).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 3, 2)).await;
+
+    trade(
+        &server,
+        &mut alice,
+        Item { id: 3, amount: 5 },
+        &mut bob,
+        Item { id: 1, amount: 40 },
+    )
+    .await;
+
+    let map = server.map(1).await;
+    let alice_character = map.get_character(alice.player_id).await.unwrap();
+    let bob_character = map.get_character(bob.player_id).await.unwrap();
+
+    assert_eq!(alice_character.get_item_amount(1), 140);
+    assert_eq!(alice_character.get_item_amount(3), 0);
+    assert_eq!(bob_character.get_item_amount(1), 60);
+    assert_eq!(bob_character.get_item_amount(3), 5);
+
+    let entries = server.world.audit.get_entries("bob", 10).await;
+    assert_eq!(
+        entries[0].event,
+        AuditEvent::Trade {
+            partner_id: alice_character.id,
+            partner_name: "alice".to_owned(),
+            gave: vec![AuditItem { id: 1, amount: 40 }],
+            received: vec![AuditItem { id: 3, amount: 5 }],
+        }
+    );
+}
+
+#[tokio::test]
+async fn completed_trade_is_saved_right_away() {
+    let mut server = TestServer::start().await;
+
+    let mut alice_character = saved_test_character(&server.db, "alice", 1, 2, 2).await;
+    alice_character.items.push(Item { id: 3, amount: 5 });
+    let alice_id = alice_character.id;
+    let mut alice = server.enter_game(alice_character).await;
+
+    let bob_character = saved_test_character(&server.db, "bob", 1, 3, 2).await;
+    let bob_id = bob_character.id;
+    let mut bob = server.enter_game(bob_character).await;
+
+    trade(
+        &server,
+        &mut alice,
+        Item { id: 3, amount: 5 },
+        &mut bob,
+        Item { id: 1, amount: 40 },
+    )
+    .await;
+
+    // Nobody has logged out or been saved by the timer
+    let alice_saved = server.db.load_character(alice_id).await.unwrap();
+    let bob_saved = server.db.load_character(bob_id).await.unwrap();
+
+    assert_eq!(alice_saved.get_item_amount(1), 140);
+    assert_eq!(alice_saved.get_item_amount(3), 0);
+    assert_eq!(bob_saved.get_item_amount(1), 60);
+    assert_eq!(bob_saved.get_item_amount(3), 5);
+}
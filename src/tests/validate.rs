@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use eolib::protocol::{
+    map::{MapItem, MapNpc, MapWarp, MapWarpRow, MapWarpRowTile},
+    r#pub::server::{DropNpcRecord, DropRecord, TalkRecord},
+    Coords,
+};
+
+use crate::{
+    arenas::{Arena, ArenaCoords, ArenaSpawn, Arenas},
+    npc_abilities::{NpcAbilities, NpcAbility, NpcAbilitySet, NpcAbilityType, NpcPhase},
+    settings::{BardSong, Settings},
+    status_effects::{StatusEffect, StatusEffectKind, StatusEffects},
+    utils::{load_class_file, load_item_file, load_npc_file, load_spell_file, load_talk_file},
+    validation::{check_data, load_map, map_path, Data, Report},
+};
+
+fn messages(report: &Report, file: &str) -> Vec<String> {
+    report
+        .issues
+        .iter()
+        .filter(|issue| issue.file == file)
+        .map(|issue| issue.message.clone())
+        .collect()
+}
+
+fn fixture_data(settings: &Settings) -> Data<'_> {
+    let mut maps = HashMap::new();
+    maps.insert(1, load_map(1).unwrap());
+    maps.insert(2, load_map(2).unwrap());
+
+    Data {
+        settings,
+        classes: load_class_file().unwrap(),
+        items: load_item_file().unwrap(),
+        npcs: load_npc_file().unwrap(),
+        spells: load_spell_file().unwrap(),
+        drops: Default::default(),
+        shops: Default::default(),
+        inns: Default::default(),
+        skill_masters: Default::default(),
+        talk: load_talk_file().unwrap(),
+        arenas: None,
+        npc_abilities: None,
+        status_effects: None,
+        maps,
+    }
+}
+
+fn warp(x: i32, destination_map: i32, destination_x: i32, destination_y: i32) -> MapWarpRowTile {
+    MapWarpRowTile {
+        x,
+        warp: MapWarp {
+            destination_map,
+            destination_coords: Coords {
+                x: destination_x,
+                y: destination_y,
+            },
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn fixture_maps_and_pub_files_are_clean() {
+    let settings = Settings::new().unwrap();
+    let data = fixture_data(&settings);
+
+    let mut report = Report::default();
+    check_data(&mut report, &data);
+
+    assert!(messages(&report, &map_path(1)).is_empty());
+    assert!(messages(&report, &map_path(2)).is_empty());
+}
+
+#[test]
+fn reports_dangling_map_references() {
+    let settings = Settings::new().unwrap();
+    let mut data = fixture_data(&settings);
+
+    let map = data.maps.get_mut(&1).unwrap();
+    map.warp_rows.push(MapWarpRow {
+        y: 3,
+        tiles: vec![warp(4, 99, 1, 1), warp(5, 2, 500, 1), warp(6, 2, 1, 1)],
+    });
+    map.npcs.push(MapNpc {
+        coords: Coords { x: 30, y: 2 },
+        id: 999,
+        ..Default::default()
+    });
+    map.items.push(MapItem {
+        coords: Coords { x: 1, y: 1 },
+        item_id: 999,
+        ..Default::default()
+    });
+
+    let mut report = Report::default();
+    check_data(&mut report, &data);
+
+    assert_eq!(
+        messages(&report, &map_path(1)),
+        vec![
+            "Warp at 4, 3: map 99 does not exist",
+            "Warp at 5, 3: 500, 1 is outside of map 2 (9x9)",
+            "NPC spawn at 30, 2 uses unknown NPC 999",
+            "NPC spawn at 30, 2 is out of bounds",
+            "Chest at 1, 1 spawns unknown item 999",
+        ]
+    );
+}
+
+#[test]
+fn reports_dangling_pub_and_config_references() {
+    let mut settings = Settings::new().unwrap();
+    settings.bard.songs = vec![
+        BardSong {
+            spell_id: 1,
+            notes: vec![1, 2],
+        },
+        BardSong {
+            spell_id: 99,
+            notes: vec![0],
+        },
+    ];
+    let mut data = fixture_data(&settings);
+
+    data.drops.npcs.push(DropNpcRecord {
+        npc_id: 1,
+        drops: vec![DropRecord {
+            item_id: 999,
+            ..Default::default()
+        }],
+    });
+    data.talk.npcs.push(TalkRecord {
+        npc_id: 404,
+        ..Default::default()
+    });
+    data.arenas = Some(Arenas {
+        arenas: vec![
+            Arena {
+                map: 3,
+                rate: 0,
+                block: 0,
+                spawns: Vec::new(),
+            },
+            Arena {
+                map: 1,
+                rate: 0,
+                block: 0,
+                spawns: vec![ArenaSpawn {
+                    from: ArenaCoords { x: 1, y: 1 },
+                    to: ArenaCoords { x: 40, y: 1 },
+                }],
+            },
+        ],
+    });
+
+    let ability = |r#type, spell_id, npc_id| NpcAbility {
+        r#type,
+        chance: 100,
+        spell_id,
+        range: 0,
+        npc_id,
+        amount: 1,
+        max: 1,
+    };
+    data.npc_abilities = Some(NpcAbilities {
+        npcs: vec![NpcAbilitySet {
+            npc_id: 2,
+            phases: vec![NpcPhase {
+                hp_percentage: 100,
+                melee: true,
+                abilities: vec![
+                    ability(NpcAbilityType::Spell, 1, 0),
+                    ability(NpcAbilityType::Heal, 99, 0),
+                    ability(NpcAbilityType::Summon, 0, 404),
+                ],
+            }],
+        }],
+    });
+
+    data.status_effects = Some(StatusEffects {
+        effects: vec![StatusEffect {
+            id: 1,
+            name: "Haste".to_string(),
+            kind: StatusEffectKind::Haste,
+            duration: 10,
+            interval: 1,
+            amount: 50,
+            tp: 0,
+            modifiers: Default::default(),
+            graphic_id: 0,
+            spells: vec![1],
+            items: vec![1],
+        }],
+    });
+
+    let mut report = Report::default();
+    check_data(&mut report, &data);
+
+    let config = messages(&report, "config/Config.toml");
+    assert!(config.contains(&format!(
+        "new_character spawn: map {} does not exist",
+        settings.new_character.spawn_map
+    )));
+    assert!(config.contains(&"bard.songs: spell 1 is not a bard spell".to_string()));
+    assert!(config.contains(&"bard.songs: unknown spell 99".to_string()));
+    assert!(config.contains(&format!(
+        "bard.songs: spell 99 needs notes between 1 and {}",
+        settings.bard.max_note_id
+    )));
+    assert_eq!(
+        messages(&report, "config/Arenas.ron"),
+        vec![
+            "Arena 1: map 3 does not exist",
+            "Arena 2 spawn to: 40, 1 is outside of map 1 (19x19)",
+        ]
+    );
+    assert_eq!(
+        messages(&report, "config/NpcAbilities.ron"),
+        vec![
+            "NPC 2 Spell: spell 1 is Heal, not Attack",
+            "NPC 2 Heal: unknown spell 99",
+            "NPC 2 Summon: unknown NPC 404",
+        ]
+    );
+    assert_eq!(
+        messages(&report, "config/StatusEffects.ron"),
+        vec![
+            "Status effect 1 (Haste) only affects NPCs, spell 1 only targets players",
+            "Status effect 1 (Haste) only affects NPCs, item 1 is used by players",
+        ]
+    );
+    assert_eq!(
+        messages(&report, "tests/fixtures/data/pub/dtd001.edf"),
+        vec!["NPC 1 drops unknown item 999"]
+    );
+    assert_eq!(
+        messages(&report, "tests/fixtures/data/pub/ttd001.etf"),
+        vec!["Talk for unknown NPC 404"]
+    );
+}
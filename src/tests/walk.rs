@@ -0,0 +1,172 @@
+use chrono::{Duration, Utc};
+use eolib::protocol::{
+    net::{
+        client::{WalkAction, WalkPlayerClientPacket},
+        server::{RefreshReplyServerPacket, WalkPlayerServerPacket, WalkReplyServerPacket},
+        PacketAction, PacketFamily,
+    },
+    Coords, Direction,
+};
+
+use crate::{
+    player::{MovementViolation, WalkValidator},
+    settings::{Movement, MovementViolationResponse},
+};
+
+use super::harness::{test_character, TestServer};
+
+#[tokio::test]
+async fn walk_is_broadcast_to_nearby_players() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 2, 2)).await;
+    let mut bob = server.enter_game(test_character("bob", 1, 4, 2)).await;
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Player,
+            PacketFamily::Walk,
+            &WalkPlayerClientPacket {
+                walk_action: WalkAction {
+                    direction: Direction::Down,
+                    timestamp,
+                    coords: Coords { x: 2, y: 3 },
+                },
+            },
+        )
+        .await;
+
+    let _: WalkReplyServerPacket = alice.expect(PacketAction::Reply, PacketFamily::Walk).await;
+
+    let walk: WalkPlayerServerPacket = bob.expect(PacketAction::Player, PacketFamily::Walk).await;
+    assert_eq!(walk.player_id, alice.player_id);
+    assert_eq!(walk.coords, Coords { x: 2, y: 3 });
+}
+
+#[tokio::test]
+async fn walk_into_wall_is_ignored() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 9, 5)).await;
+
+    let timestamp = alice.next_timestamp();
+    alice
+        .send(
+            PacketAction::Player,
+            PacketFamily::Walk,
+            &WalkPlayerClientPacket {
+                walk_action: WalkAction {
+                    direction: Direction::Right,
+                    timestamp,
+                    coords: Coords { x: 10, y: 5 },
+                },
+            },
+        )
+        .await;
+
+    // The player is put back where the server has them
+    let _: RefreshReplyServerPacket = alice
+        .expect(PacketAction::Reply, PacketFamily::Refresh)
+        .await;
+
+    alice
+        .expect_none(PacketAction::Reply, PacketFamily::Walk)
+        .await;
+
+    let character = server
+        .map(1)
+        .await
+        .get_character(alice.player_id)
+        .await
+        .unwrap();
+    assert_eq!(character.coords, Coords { x: 9, y: 5 });
+}
+
+#[tokio::test]
+async fn blocked_walks_are_refreshed_at_most_once_a_second() {
+    let mut server = TestServer::start().await;
+    let mut alice = server.enter_game(test_character("alice", 1, 9, 5)).await;
+    let map = server.map(1).await;
+
+    // The client stayed put so there's nothing to correct
+    map.walk(alice.player_id, Direction::Right, Coords { x: 9, y: 5 });
+    alice
+        .expect_none(PacketAction::Reply, PacketFamily::Refresh)
+        .await;
+
+    map.walk(alice.player_id, Direction::Right, Coords { x: 10, y: 5 });
+    map.walk(alice.player_id, Direction::Right, Coords { x: 10, y: 5 });
+    let _: RefreshReplyServerPacket = alice
+        .expect(PacketAction::Reply, PacketFamily::Refresh)
+        .await;
+    alice
+        .expect_none(PacketAction::Reply, PacketFamily::Refresh)
+        .await;
+}
+
+fn movement() -> Movement {
+    Movement {
+        min_walk_interval: 36,
+        max_walk_drift: 1000,
+        max_violations: 3,
+        violation_reset: 60,
+        violation_response: MovementViolationResponse::Log,
+        min_refresh_interval: 1000,
+    }
+}
+
+#[test]
+fn walks_closer_than_the_walk_interval_are_too_fast() {
+    let settings = movement();
+    let mut validator = WalkValidator::default();
+    let now = Utc::now();
+
+    assert_eq!(
+        validator.check_walk(20, now, &settings),
+        Some(MovementViolation::TooFast)
+    );
+    assert_eq!(validator.check_walk(36, now, &settings), None);
+}
+
+#[test]
+fn walks_arriving_too_quickly_build_up_drift() {
+    let settings = movement();
+    let mut validator = WalkValidator::default();
+    let start = Utc::now();
+
+    // Lag bunching a few walks together is allowed
+    for i in 0..3 {
+        let now = start + Duration::milliseconds(i * 10);
+        assert_eq!(validator.check_walk(40, now, &settings), None);
+    }
+
+    // Walking at a normal pace pays the drift back
+    let mut now = start + Duration::milliseconds(20);
+    for _ in 0..10 {
+        now += Duration::milliseconds(400);
+        assert_eq!(validator.check_walk(40, now, &settings), None);
+    }
+
+    // Keeping it up does not
+    let violation = (0..10).find_map(|_| {
+        now += Duration::milliseconds(100);
+        validator.check_walk(40, now, &settings)
+    });
+    assert_eq!(violation, Some(MovementViolation::Speeding));
+}
+
+#[test]
+fn violations_are_counted_until_they_reset() {
+    let settings = movement();
+    let mut validator = WalkValidator::default();
+    let start = Utc::now();
+
+    assert!(!validator.add_violation(start, &settings));
+    assert!(!validator.add_violation(start, &settings));
+
+    // The count starts over once the player behaves for long enough
+    let later = start + Duration::seconds(settings.violation_reset);
+    assert!(!validator.add_violation(later, &settings));
+    assert!(!validator.add_violation(later, &settings));
+    assert!(validator.add_violation(later, &settings));
+    assert!(!validator.add_violation(later, &settings));
+}
@@ -0,0 +1,53 @@
+use eolib::protocol::net::{
+    client::FileType,
+    server::{
+        InitInitServerPacket, InitInitServerPacketReplyCodeData,
+        InitInitServerPacketReplyCodeDataFileEcf, InitInitServerPacketReplyCodeDataFileEif,
+        InitInitServerPacketReplyCodeDataFileEnf, InitInitServerPacketReplyCodeDataFileEsf,
+        InitReply, PubFile,
+    },
+};
+
+use crate::CLIENT_PUB_FILES;
+
+/// Builds the reply carrying one piece of the current copy of a client pub
+/// file. File ids start at 1
+pub fn get_pub_file_reply(file_type: FileType, file_id: i32) -> Option<InitInitServerPacket> {
+    let client_pub_files = CLIENT_PUB_FILES.load();
+    let content = client_pub_files.get(file_type)?.get(file_id)?.clone();
+
+    let pub_file = PubFile { file_id, content };
+
+    let (reply_code, reply_code_data) = match file_type {
+        FileType::Eif => (
+            InitReply::FileEif,
+            InitInitServerPacketReplyCodeData::FileEif(InitInitServerPacketReplyCodeDataFileEif {
+                pub_file,
+            }),
+        ),
+        FileType::Enf => (
+            InitReply::FileEnf,
+            InitInitServerPacketReplyCodeData::FileEnf(InitInitServerPacketReplyCodeDataFileEnf {
+                pub_file,
+            }),
+        ),
+        FileType::Esf => (
+            InitReply::FileEsf,
+            InitInitServerPacketReplyCodeData::FileEsf(InitInitServerPacketReplyCodeDataFileEsf {
+                pub_file,
+            }),
+        ),
+        FileType::Ecf => (
+            InitReply::FileEcf,
+            InitInitServerPacketReplyCodeData::FileEcf(InitInitServerPacketReplyCodeDataFileEcf {
+                pub_file,
+            }),
+        ),
+        _ => return None,
+    };
+
+    Some(InitInitServerPacket {
+        reply_code,
+        reply_code_data: Some(reply_code_data),
+    })
+}
@@ -9,13 +9,24 @@ use std::{fs::File, io::Read};
 
 use bytes::Bytes;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
 
 pub fn load_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let ecf_file = load_json()?;
+        save_pub_file(&ecf_file, &format!("{}/pub/dat001.ecf", DATA_DIR))?;
+        Ok(ecf_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_class_file`] but never writes the generated pub file
+pub fn read_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -26,7 +37,7 @@ pub fn load_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Ecf, Box<dyn std::error::Error>> {
     let mut ecf_file = Ecf::default();
 
-    for entry in glob("data/pub/classes/*.json")? {
+    for entry in glob(&format!("{}/pub/classes/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -70,13 +81,11 @@ fn load_json() -> Result<Ecf, Box<dyn std::error::Error>> {
         decode_number(&encoded[2..=3]) as i32,
     ];
 
-    save_pub_file(&ecf_file, "data/pub/dat001.ecf")?;
-
     Ok(ecf_file)
 }
 
 fn load_pub() -> Result<Ecf, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/dat001.ecf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dat001.ecf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
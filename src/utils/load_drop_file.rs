@@ -8,11 +8,22 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub fn load_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let drop_file = load_json()?;
+        save_pub_file(&drop_file, &format!("{}/pub/dtd001.edf", DATA_DIR))?;
+        Ok(drop_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_drop_file`] but never writes the generated pub file
+pub fn read_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,8 +34,7 @@ pub fn load_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<DropFile, Box<dyn std::error::Error>> {
     let mut drop_file = DropFile::default();
 
-    let mut npc_id = 1;
-    for entry in glob("data/pub/npcs/*.json")? {
+    for (npc_id, entry) in (1..).zip(glob(&format!("{}/pub/npcs/*.json", DATA_DIR))?) {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -47,16 +57,13 @@ fn load_json() -> Result<DropFile, Box<dyn std::error::Error>> {
                     .collect(),
             });
         }
-        npc_id += 1;
     }
 
-    save_pub_file(&drop_file, "data/pub/dtd001.edf")?;
-
     Ok(drop_file)
 }
 
 fn load_pub() -> Result<DropFile, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/serv_drops.epf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/serv_drops.epf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -106,7 +113,7 @@ fn load_pub() -> Result<DropFile, Box<dyn std::error::Error>> {
         return Ok(edf);
     }
 
-    if let Ok(mut file) = File::open("data/pub/dtd001.edf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dtd001.edf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -8,11 +8,22 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub fn load_inn_file() -> Result<InnFile, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let inn_file = load_json()?;
+        save_pub_file(&inn_file, &format!("{}/pub/din001.eid", DATA_DIR))?;
+        Ok(inn_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_inn_file`] but never writes the generated pub file
+pub fn read_inn_file() -> Result<InnFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,7 +34,7 @@ pub fn load_inn_file() -> Result<InnFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<InnFile, Box<dyn std::error::Error>> {
     let mut inn_file = InnFile::default();
 
-    for entry in glob("data/pub/inns/*.json")? {
+    for entry in glob(&format!("{}/pub/inns/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -75,13 +86,11 @@ fn load_json() -> Result<InnFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&inn_file, "data/pub/din001.eid")?;
-
     Ok(inn_file)
 }
 
 fn load_pub() -> Result<InnFile, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/serv_inns.epf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/serv_inns.epf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -139,7 +148,7 @@ fn load_pub() -> Result<InnFile, Box<dyn std::error::Error>> {
         return Ok(eif);
     }
 
-    if let Ok(mut file) = File::open("data/pub/din001.eid") {
+    if let Ok(mut file) = File::open(format!("{}/pub/din001.eid", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
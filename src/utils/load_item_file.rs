@@ -10,13 +10,24 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
 
 pub fn load_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let eif_file = load_json()?;
+        save_pub_file(&eif_file, &format!("{}/pub/dat001.eif", DATA_DIR))?;
+        Ok(eif_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_item_file`] but never writes the generated pub file
+pub fn read_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -27,7 +38,7 @@ pub fn load_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Eif, Box<dyn std::error::Error>> {
     let mut eif_file = Eif::default();
 
-    for entry in glob("data/pub/items/*.json")? {
+    for entry in glob(&format!("{}/pub/items/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -102,13 +113,11 @@ fn load_json() -> Result<Eif, Box<dyn std::error::Error>> {
         decode_number(&encoded[2..=3]) as i32,
     ];
 
-    save_pub_file(&eif_file, "data/pub/dat001.eif")?;
-
     Ok(eif_file)
 }
 
 fn load_pub() -> Result<Eif, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/dat001.eif") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dat001.eif", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -9,13 +9,24 @@ use std::{fs::File, io::Read};
 use bytes::Bytes;
 use crc::{Crc, CRC_32_CKSUM};
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
 
 pub fn load_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let enf_file = load_json()?;
+        save_pub_file(&enf_file, &format!("{}/pub/dtn001.enf", DATA_DIR))?;
+        Ok(enf_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_npc_file`] but never writes the generated pub file
+pub fn read_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -26,7 +37,7 @@ pub fn load_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Enf, Box<dyn std::error::Error>> {
     let mut enf_file = Enf::default();
 
-    for entry in glob("data/pub/npcs/*.json")? {
+    for entry in glob(&format!("{}/pub/npcs/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -82,13 +93,11 @@ fn load_json() -> Result<Enf, Box<dyn std::error::Error>> {
         decode_number(&encoded[2..=3]) as i32,
     ];
 
-    save_pub_file(&enf_file, "data/pub/dtn001.enf")?;
-
     Ok(enf_file)
 }
 
 fn load_pub() -> Result<Enf, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/dtn001.enf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dtn001.enf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -3,10 +3,12 @@ use std::{collections::HashMap, fs::File, io::Read, path::PathBuf};
 use eoplus::{parse_quest, Quest};
 use glob::glob;
 
+use crate::DATA_DIR;
+
 pub fn load_quests() -> HashMap<i32, Quest> {
     let mut quests = HashMap::new();
 
-    for entry in glob("data/quests/*.eqf").unwrap() {
+    for entry in glob(&format!("{}/quests/*.eqf", DATA_DIR)).unwrap() {
         match entry {
             Ok(path) => match load_quest(path) {
                 Some((id, quest)) => {
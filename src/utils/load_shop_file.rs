@@ -10,11 +10,22 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub fn load_shop_file() -> Result<ShopFile, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let shop_file = load_json()?;
+        save_pub_file(&shop_file, &format!("{}/pub/dts001.esf", DATA_DIR))?;
+        Ok(shop_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_shop_file`] but never writes the generated pub file
+pub fn read_shop_file() -> Result<ShopFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -25,7 +36,7 @@ pub fn load_shop_file() -> Result<ShopFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<ShopFile, Box<dyn std::error::Error>> {
     let mut shop_file = ShopFile::default();
 
-    for entry in glob("data/pub/shops/*.json")? {
+    for entry in glob(&format!("{}/pub/shops/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -79,13 +90,11 @@ fn load_json() -> Result<ShopFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&shop_file, "data/pub/dts001.esf")?;
-
     Ok(shop_file)
 }
 
 fn load_pub() -> Result<ShopFile, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/serv_shops.epf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/serv_shops.epf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -164,7 +173,7 @@ fn load_pub() -> Result<ShopFile, Box<dyn std::error::Error>> {
         return Ok(esf);
     }
 
-    if let Ok(mut file) = File::open("data/pub/dts001.esf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dts001.esf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -8,11 +8,22 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub fn load_skill_master_file() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let skill_master_file = load_json()?;
+        save_pub_file(&skill_master_file, &format!("{}/pub/dsm001.emf", DATA_DIR))?;
+        Ok(skill_master_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_skill_master_file`] but never writes the generated pub file
+pub fn read_skill_master_file() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,7 +34,7 @@ pub fn load_skill_master_file() -> Result<SkillMasterFile, Box<dyn std::error::E
 fn load_json() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
     let mut skill_master_file = SkillMasterFile::default();
 
-    for entry in glob("data/pub/skill_masters/*.json")? {
+    for entry in glob(&format!("{}/pub/skill_masters/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -71,13 +82,11 @@ fn load_json() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&skill_master_file, "data/pub/dsm001.emf")?;
-
     Ok(skill_master_file)
 }
 
 fn load_pub() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/serv_trainers.epf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/serv_trainers.epf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -142,7 +151,7 @@ fn load_pub() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
         return Ok(emf);
     }
 
-    if let Ok(mut file) = File::open("data/pub/dsm001.emf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dsm001.emf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
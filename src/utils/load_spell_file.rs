@@ -12,13 +12,24 @@ use std::{fs::File, io::Read};
 
 use bytes::Bytes;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
 
 pub fn load_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let esf_file = load_json()?;
+        save_pub_file(&esf_file, &format!("{}/pub/dsl001.esf", DATA_DIR))?;
+        Ok(esf_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_spell_file`] but never writes the generated pub file
+pub fn read_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -29,7 +40,7 @@ pub fn load_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Esf, Box<dyn std::error::Error>> {
     let mut esf_file = Esf::default();
 
-    for entry in glob("data/pub/spells/*.json")? {
+    for entry in glob(&format!("{}/pub/spells/*.json", DATA_DIR))? {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -96,13 +107,11 @@ fn load_json() -> Result<Esf, Box<dyn std::error::Error>> {
         decode_number(&encoded[2..=3]) as i32,
     ];
 
-    save_pub_file(&esf_file, "data/pub/dsl001.esf")?;
-
     Ok(esf_file)
 }
 
 fn load_pub() -> Result<Esf, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/dsl001.esf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/dsl001.esf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -8,11 +8,22 @@ use eolib::{
 use glob::glob;
 use serde_json::Value;
 
-use crate::SETTINGS;
+use crate::{DATA_DIR, SETTINGS};
 
 use super::save_pub_file;
 
 pub fn load_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
+    if SETTINGS.server.generate_pub {
+        let talk_file = load_json()?;
+        save_pub_file(&talk_file, &format!("{}/pub/ttd001.etf", DATA_DIR))?;
+        Ok(talk_file)
+    } else {
+        load_pub()
+    }
+}
+
+/// Same as [`load_talk_file`] but never writes the generated pub file
+pub fn read_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,8 +34,7 @@ pub fn load_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<TalkFile, Box<dyn std::error::Error>> {
     let mut talk_file = TalkFile::default();
 
-    let mut npc_id = 1;
-    for entry in glob("data/pub/npcs/*.json")? {
+    for (npc_id, entry) in (1..).zip(glob(&format!("{}/pub/npcs/*.json", DATA_DIR))?) {
         let path = entry?;
         let mut file = File::open(path)?;
         let mut json = String::new();
@@ -45,17 +55,13 @@ fn load_json() -> Result<TalkFile, Box<dyn std::error::Error>> {
                     .collect(),
             });
         }
-
-        npc_id += 1;
     }
 
-    save_pub_file(&talk_file, "data/pub/ttd001.etf")?;
-
     Ok(talk_file)
 }
 
 fn load_pub() -> Result<TalkFile, Box<dyn std::error::Error>> {
-    if let Ok(mut file) = File::open("data/pub/serv_chats.epf") {
+    if let Ok(mut file) = File::open(format!("{}/pub/serv_chats.epf", DATA_DIR)) {
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
 
@@ -101,7 +107,7 @@ fn load_pub() -> Result<TalkFile, Box<dyn std::error::Error>> {
         return Ok(etf);
     }
 
-    let mut file = File::open("data/pub/ttd001.etf")?;
+    let mut file = File::open(format!("{}/pub/ttd001.etf", DATA_DIR))?;
     let mut buf = Vec::new();
     file.read_to_end(&mut buf)?;
 
@@ -12,28 +12,30 @@ mod format_duration;
 pub use format_duration::format_duration;
 mod get_board_tile_spec;
 pub use get_board_tile_spec::get_board_tile_spec;
+mod get_pub_file_reply;
+pub use get_pub_file_reply::get_pub_file_reply;
 mod get_next_coords;
 pub use get_next_coords::get_next_coords;
 mod is_deep;
 pub use is_deep::is_deep;
 mod load_class_file;
-pub use load_class_file::load_class_file;
+pub use load_class_file::{load_class_file, read_class_file};
 mod load_drop_file;
-pub use load_drop_file::load_drop_file;
+pub use load_drop_file::{load_drop_file, read_drop_file};
 mod load_inn_file;
-pub use load_inn_file::load_inn_file;
+pub use load_inn_file::{load_inn_file, read_inn_file};
 mod load_item_file;
-pub use load_item_file::load_item_file;
+pub use load_item_file::{load_item_file, read_item_file};
 mod load_npc_file;
-pub use load_npc_file::load_npc_file;
+pub use load_npc_file::{load_npc_file, read_npc_file};
 mod load_shop_file;
-pub use load_shop_file::load_shop_file;
+pub use load_shop_file::{load_shop_file, read_shop_file};
 mod load_skill_master_file;
-pub use load_skill_master_file::load_skill_master_file;
+pub use load_skill_master_file::{load_skill_master_file, read_skill_master_file};
 mod load_spell_file;
-pub use load_spell_file::load_spell_file;
+pub use load_spell_file::{load_spell_file, read_spell_file};
 mod load_talk_file;
-pub use load_talk_file::load_talk_file;
+pub use load_talk_file::{load_talk_file, read_talk_file};
 mod save_pub_file;
 pub use save_pub_file::save_pub_file;
 mod load_quests;
@@ -42,8 +44,10 @@ mod pad_string;
 pub use pad_string::pad_string;
 mod validate_character_name;
 pub use validate_character_name::validate_character_name;
-mod send_email;
-pub use send_email::send_email;
+mod split_pub_file;
+pub use split_pub_file::ClientPubFiles;
+#[cfg(test)]
+pub use split_pub_file::{split_pub_file, MAX_PUB_FILE_SIZE};
 mod mask_email;
 pub use mask_email::mask_email;
 mod timestamp_diff;
@@ -0,0 +1,217 @@
+use crc::{Crc, CRC_32_CKSUM};
+use eolib::{
+    data::{decode_number, encode_number, EoSerialize, EoWriter},
+    protocol::{
+        net::client::FileType,
+        r#pub::{Ecf, EcfRecord, Eif, EifRecord, Enf, EnfRecord, Esf, EsfRecord},
+    },
+};
+
+use crate::{CLASS_DB, ITEM_DB, NPC_DB, SPELL_DB};
+
+const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+
+/// Largest a split file can be and still fit in a single packet along with
+/// the rest of the file reply
+pub const MAX_PUB_FILE_SIZE: usize = 64000;
+
+/// A client pub file cut into pieces small enough to send one per packet.
+/// Every piece is a complete pub file with the total record count of the
+/// whole file. A file that fits in one piece keeps its rid, otherwise every
+/// piece gets its own
+#[derive(Debug, Default, Clone)]
+pub struct SplitPubFile {
+    /// rid of the first piece which clients compare against their first file
+    pub rid: [i32; 2],
+    /// Records across every piece
+    pub length: i32,
+    pub files: Vec<Vec<u8>>,
+}
+
+impl SplitPubFile {
+    /// The piece a client asked for. File ids start at 1
+    pub fn get(&self, file_id: i32) -> Option<&Vec<u8>> {
+        if file_id < 1 {
+            return None;
+        }
+
+        self.files.get(file_id as usize - 1)
+    }
+}
+
+/// The split copies of every pub file clients download
+#[derive(Debug, Default)]
+pub struct ClientPubFiles {
+    pub eif: SplitPubFile,
+    pub enf: SplitPubFile,
+    pub esf: SplitPubFile,
+    pub ecf: SplitPubFile,
+}
+
+impl ClientPubFiles {
+    /// Splits the currently loaded pub files
+    pub fn load() -> Self {
+        Self {
+            eif: split_pub_file(&**ITEM_DB.load()),
+            enf: split_pub_file(&**NPC_DB.load()),
+            esf: split_pub_file(&**SPELL_DB.load()),
+            ecf: split_pub_file(&**CLASS_DB.load()),
+        }
+    }
+
+    /// Files whose bytes differ from `previous`, so edits that keep the
+    /// record count are caught too
+    pub fn changed_since(&self, previous: &Self) -> Vec<FileType> {
+        [FileType::Ecf, FileType::Eif, FileType::Enf, FileType::Esf]
+            .into_iter()
+            .filter(|file_type| {
+                self.get(*file_type).map(|file| &file.files)
+                    != previous.get(*file_type).map(|file| &file.files)
+            })
+            .collect()
+    }
+
+    pub fn get(&self, file_type: FileType) -> Option<&SplitPubFile> {
+        match file_type {
+            FileType::Eif => Some(&self.eif),
+            FileType::Enf => Some(&self.enf),
+            FileType::Esf => Some(&self.esf),
+            FileType::Ecf => Some(&self.ecf),
+            _ => None,
+        }
+    }
+}
+
+pub trait PubRecords: EoSerialize {
+    type Record: EoSerialize + Clone;
+
+    fn records(&self) -> &[Self::Record];
+
+    /// A copy of the file's header holding `records` and `total` as the
+    /// record count
+    fn with_records(&self, records: Vec<Self::Record>, total: i32) -> Self;
+}
+
+pub fn split_pub_file<T: PubRecords>(file: &T) -> SplitPubFile {
+    let records = file.records();
+    let total = records.len() as i32;
+    let header_size = serialize(&file.with_records(Vec::new(), total)).len();
+
+    let mut pieces: Vec<Vec<T::Record>> = Vec::new();
+    let mut piece = Vec::new();
+    let mut piece_size = header_size;
+
+    for record in records {
+        let record_size = serialize(record).len();
+        if !piece.is_empty() && piece_size + record_size > MAX_PUB_FILE_SIZE {
+            pieces.push(piece);
+            piece = Vec::new();
+            piece_size = header_size;
+        }
+
+        piece.push(record.clone());
+        piece_size += record_size;
+    }
+
+    pieces.push(piece);
+
+    let split = pieces.len() > 1;
+    let files = pieces
+        .into_iter()
+        .map(|records| {
+            let mut buf = serialize(&file.with_records(records, total));
+            // The rid is a checksum of everything after it, kept small enough
+            // to encode as a positive number
+            if split {
+                let checksum = CRC32.checksum(&buf[7..]) % i32::MAX as u32;
+                buf[3..7].copy_from_slice(&encode_number(checksum as i32).unwrap());
+            }
+            buf
+        })
+        .collect::<Vec<_>>();
+
+    let rid = [
+        decode_number(&files[0][3..5]) as i32,
+        decode_number(&files[0][5..7]) as i32,
+    ];
+
+    SplitPubFile {
+        rid,
+        length: total,
+        files,
+    }
+}
+
+fn serialize<T: EoSerialize>(value: &T) -> Vec<u8> {
+    let mut writer = EoWriter::new();
+    value.serialize(&mut writer).unwrap();
+    writer.to_byte_array().to_vec()
+}
+
+impl PubRecords for Eif {
+    type Record = EifRecord;
+
+    fn records(&self) -> &[EifRecord] {
+        &self.items
+    }
+
+    fn with_records(&self, items: Vec<EifRecord>, total: i32) -> Self {
+        Self {
+            rid: self.rid,
+            total_items_count: total,
+            version: self.version,
+            items,
+        }
+    }
+}
+
+impl PubRecords for Enf {
+    type Record = EnfRecord;
+
+    fn records(&self) -> &[EnfRecord] {
+        &self.npcs
+    }
+
+    fn with_records(&self, npcs: Vec<EnfRecord>, total: i32) -> Self {
+        Self {
+            rid: self.rid,
+            total_npcs_count: total,
+            version: self.version,
+            npcs,
+        }
+    }
+}
+
+impl PubRecords for Esf {
+    type Record = EsfRecord;
+
+    fn records(&self) -> &[EsfRecord] {
+        &self.skills
+    }
+
+    fn with_records(&self, skills: Vec<EsfRecord>, total: i32) -> Self {
+        Self {
+            rid: self.rid,
+            total_skills_count: total,
+            version: self.version,
+            skills,
+        }
+    }
+}
+
+impl PubRecords for Ecf {
+    type Record = EcfRecord;
+
+    fn records(&self) -> &[EcfRecord] {
+        &self.classes
+    }
+
+    fn with_records(&self, classes: Vec<EcfRecord>, total: i32) -> Self {
+        Self {
+            rid: self.rid,
+            total_classes_count: total,
+            version: self.version,
+            classes,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{fmt, fs};
+
+use bytes::Bytes;
+use eolib::{
+    data::{EoReader, EoSerialize},
+    protocol::map::Emf,
+};
+
+use crate::DATA_DIR;
+
+mod data;
+pub use data::validate;
+#[cfg(test)]
+pub use data::{check_data, Data};
+mod quests;
+#[cfg(test)]
+pub use quests::check_quest_sources;
+pub use quests::check_quests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Issue {
+    pub severity: Severity,
+    pub file: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}: {}", self.file, line, severity, self.message),
+            None => write!(f, "{}: {}: {}", self.file, severity, self.message),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    pub fn error(&mut self, file: &str, line: Option<usize>, message: String) {
+        self.issues.push(Issue {
+            severity: Severity::Error,
+            file: file.to_owned(),
+            line,
+            message,
+        });
+    }
+
+    pub fn warning(&mut self, file: &str, line: Option<usize>, message: String) {
+        self.issues.push(Issue {
+            severity: Severity::Warning,
+            file: file.to_owned(),
+            line,
+            message,
+        });
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.issues.len() - self.error_count()
+    }
+
+    /// Prints the report and returns the process exit code
+    pub fn print(&self, json: bool) -> i32 {
+        if json {
+            match serde_json::to_string_pretty(self) {
+                Ok(json) => println!("{}", json),
+                Err(e) => error!("Failed to serialize report: {}", e),
+            }
+        } else {
+            for issue in &self.issues {
+                println!("{}", issue);
+            }
+
+            println!(
+                "{} error(s), {} warning(s)",
+                self.error_count(),
+                self.warning_count()
+            );
+        }
+
+        if self.error_count() > 0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+pub fn map_path(id: i32) -> String {
+    format!("{}/maps/{:0>5}.emf", DATA_DIR, id)
+}
+
+pub fn load_map(id: i32) -> Option<Emf> {
+    let buf = fs::read(map_path(id)).ok()?;
+    let reader = EoReader::new(Bytes::from(buf));
+    Emf::deserialize(&reader).ok()
+}
@@ -0,0 +1,748 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use bytes::Bytes;
+use eolib::{
+    data::{EoReader, EoSerialize},
+    protocol::{
+        map::Emf,
+        r#pub::{
+            server::{DropFile, InnFile, ShopFile, SkillMasterFile, TalkFile},
+            Ecf, Eif, Enf, Esf, ItemType, NpcType, SkillTargetRestrict, SkillTargetType, SkillType,
+        },
+        Coords,
+    },
+};
+use glob::glob;
+
+use crate::{
+    arenas::Arenas,
+    map::is_tile_spec_walkable,
+    npc_abilities::{NpcAbilities, NpcAbilityType},
+    settings::Settings,
+    status_effects::StatusEffects,
+    utils::{
+        read_class_file, read_drop_file, read_inn_file, read_item_file, read_npc_file,
+        read_shop_file, read_skill_master_file, read_spell_file, read_talk_file,
+    },
+    DATA_DIR,
+};
+
+use super::{check_quests, map_path, Report};
+
+const CONFIG: &str = "config/Config.toml";
+const ARENAS: &str = "config/Arenas.ron";
+const NPC_ABILITIES: &str = "config/NpcAbilities.ron";
+const STATUS_EFFECTS: &str = "config/StatusEffects.ron";
+
+pub struct Data<'a> {
+    pub settings: &'a Settings,
+    pub classes: Ecf,
+    pub items: Eif,
+    pub npcs: Enf,
+    pub spells: Esf,
+    pub drops: DropFile,
+    pub shops: ShopFile,
+    pub inns: InnFile,
+    pub skill_masters: SkillMasterFile,
+    pub talk: TalkFile,
+    pub arenas: Option<Arenas>,
+    pub npc_abilities: Option<NpcAbilities>,
+    pub status_effects: Option<StatusEffects>,
+    pub maps: HashMap<i32, Emf>,
+}
+
+/// Loads every map, pub file and config file and reports anything that
+/// references content that doesn't exist
+pub fn validate() -> Report {
+    let mut report = Report::default();
+
+    // Everything below reads SETTINGS so bail out early if it can't load
+    let settings = match Settings::new() {
+        Ok(settings) => settings,
+        Err(e) => {
+            report.error(CONFIG, None, format!("Failed to load: {}", e));
+            return report;
+        }
+    };
+
+    let arenas = match Arenas::new() {
+        Ok(arenas) => Some(arenas),
+        Err(e) => {
+            report.error(ARENAS, None, format!("Failed to load: {}", e));
+            None
+        }
+    };
+
+    let npc_abilities = match NpcAbilities::new() {
+        Ok(npc_abilities) => Some(npc_abilities),
+        Err(e) => {
+            report.error(NPC_ABILITIES, None, format!("Failed to load: {}", e));
+            None
+        }
+    };
+
+    let status_effects = match StatusEffects::new() {
+        Ok(status_effects) => Some(status_effects),
+        Err(e) => {
+            report.error(STATUS_EFFECTS, None, format!("Failed to load: {}", e));
+            None
+        }
+    };
+
+    let data = Data {
+        settings: &settings,
+        classes: load(&mut report, "dat001.ecf", read_class_file),
+        items: load(&mut report, "dat001.eif", read_item_file),
+        npcs: load(&mut report, "dtn001.enf", read_npc_file),
+        spells: load(&mut report, "dsl001.esf", read_spell_file),
+        drops: load(&mut report, "dtd001.edf", read_drop_file),
+        shops: load(&mut report, "dts001.esf", read_shop_file),
+        inns: load(&mut report, "din001.eid", read_inn_file),
+        skill_masters: load(&mut report, "dsm001.emf", read_skill_master_file),
+        talk: load(&mut report, "ttd001.etf", read_talk_file),
+        arenas,
+        npc_abilities,
+        status_effects,
+        maps: load_maps(&mut report),
+    };
+
+    check_data(&mut report, &data);
+
+    report.issues.extend(check_quests().issues);
+
+    report
+}
+
+fn load<T: Default>(
+    report: &mut Report,
+    file_name: &str,
+    loader: fn() -> Result<T, Box<dyn std::error::Error>>,
+) -> T {
+    match loader() {
+        Ok(file) => file,
+        Err(e) => {
+            report.error(
+                &format!("{}/pub/{}", DATA_DIR, file_name),
+                None,
+                format!("Failed to load: {}", e),
+            );
+            T::default()
+        }
+    }
+}
+
+fn load_maps(report: &mut Report) -> HashMap<i32, Emf> {
+    let mut maps = HashMap::new();
+
+    let entries = match glob(&format!("{}/maps/*.emf", DATA_DIR)) {
+        Ok(entries) => entries,
+        Err(e) => {
+            report.error(DATA_DIR, None, format!("Invalid map path: {}", e));
+            return maps;
+        }
+    };
+
+    for path in entries.flatten() {
+        let display = path.display().to_string();
+
+        let id = match Path::new(&path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<i32>().ok())
+        {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let buf = match fs::read(&path) {
+            Ok(buf) => buf,
+            Err(e) => {
+                report.error(&display, None, format!("Failed to read file: {}", e));
+                continue;
+            }
+        };
+
+        let reader = EoReader::new(Bytes::from(buf));
+        match Emf::deserialize(&reader) {
+            Ok(map) => {
+                maps.insert(id, map);
+            }
+            Err(e) => report.error(&display, None, format!("Failed to parse map: {}", e)),
+        }
+    }
+
+    maps
+}
+
+pub fn check_data(report: &mut Report, data: &Data) {
+    let mut map_ids: Vec<&i32> = data.maps.keys().collect();
+    map_ids.sort();
+    for id in map_ids {
+        check_map(report, data, *id);
+    }
+
+    check_settings(report, data);
+    check_arenas(report, data);
+    check_npc_abilities(report, data);
+    check_status_effects(report, data);
+    check_drops(report, data);
+    check_shops(report, data);
+    check_inns(report, data);
+    check_skill_masters(report, data);
+    check_talk(report, data);
+}
+
+fn check_map(report: &mut Report, data: &Data, id: i32) {
+    let map = &data.maps[&id];
+    let file = map_path(id);
+
+    for row in &map.warp_rows {
+        for tile in &row.tiles {
+            let warp = &tile.warp;
+            if let Some(error) =
+                check_spawn(data, warp.destination_map, warp.destination_coords, false)
+            {
+                report.error(
+                    &file,
+                    None,
+                    format!("Warp at {}, {}: {}", tile.x, row.y, error),
+                );
+            }
+        }
+    }
+
+    for spawn in &map.npcs {
+        if !exists(&data.npcs.npcs, spawn.id) {
+            report.error(
+                &file,
+                None,
+                format!(
+                    "NPC spawn at {}, {} uses unknown NPC {}",
+                    spawn.coords.x, spawn.coords.y, spawn.id
+                ),
+            );
+        }
+
+        if !in_bounds(map, spawn.coords) {
+            report.error(
+                &file,
+                None,
+                format!(
+                    "NPC spawn at {}, {} is out of bounds",
+                    spawn.coords.x, spawn.coords.y
+                ),
+            );
+        }
+    }
+
+    let has_boss = map
+        .npcs
+        .iter()
+        .any(|spawn| get(&data.npcs.npcs, spawn.id).is_some_and(|npc| npc.boss));
+
+    if !has_boss {
+        for spawn in &map.npcs {
+            if get(&data.npcs.npcs, spawn.id).is_some_and(|npc| npc.child) {
+                report.warning(
+                    &file,
+                    None,
+                    format!(
+                        "Child NPC spawn at {}, {} has no boss on the map",
+                        spawn.coords.x, spawn.coords.y
+                    ),
+                );
+            }
+        }
+    }
+
+    for item in &map.items {
+        if !exists(&data.items.items, item.item_id) {
+            report.error(
+                &file,
+                None,
+                format!(
+                    "Chest at {}, {} spawns unknown item {}",
+                    item.coords.x, item.coords.y, item.item_id
+                ),
+            );
+        }
+    }
+}
+
+fn check_settings(report: &mut Report, data: &Data) {
+    let settings = data.settings;
+
+    let spawns = [
+        (
+            "new_character spawn",
+            settings.new_character.spawn_map,
+            settings.new_character.spawn_x,
+            settings.new_character.spawn_y,
+        ),
+        ("jail", settings.jail.map, settings.jail.x, settings.jail.y),
+        (
+            "jail free spawn",
+            settings.jail.free_map,
+            settings.jail.free_x,
+            settings.jail.free_y,
+        ),
+        (
+            "rescue",
+            settings.rescue.map,
+            settings.rescue.x,
+            settings.rescue.y,
+        ),
+    ];
+
+    for (name, map, x, y) in spawns {
+        if let Some(error) = check_spawn(data, map, Coords { x, y }, true) {
+            report.error(CONFIG, None, format!("{}: {}", name, error));
+        }
+    }
+
+    for (name, item_id) in [
+        (
+            "marriage.female_armor_id",
+            settings.marriage.female_armor_id,
+        ),
+        ("marriage.male_armor_id", settings.marriage.male_armor_id),
+    ] {
+        match get(&data.items.items, item_id) {
+            Some(item) if item.r#type == ItemType::Armor => {}
+            Some(_) => report.error(
+                CONFIG,
+                None,
+                format!("{}: item {} is not armor", name, item_id),
+            ),
+            None => report.error(CONFIG, None, format!("{}: unknown item {}", name, item_id)),
+        }
+    }
+
+    if !exists(&data.items.items, settings.marriage.ring_item_id) {
+        report.error(
+            CONFIG,
+            None,
+            format!(
+                "marriage.ring_item_id: unknown item {}",
+                settings.marriage.ring_item_id
+            ),
+        );
+    }
+
+    for graphic in &settings.bard.instrument_items {
+        if !data
+            .items
+            .items
+            .iter()
+            .any(|item| item.r#type == ItemType::Weapon && item.spec1 == *graphic)
+        {
+            report.error(
+                CONFIG,
+                None,
+                format!(
+                    "bard.instrument_items: no weapon uses instrument graphic {}",
+                    graphic
+                ),
+            );
+        }
+    }
+
+    for song in &settings.bard.songs {
+        match get(&data.spells.skills, song.spell_id) {
+            Some(spell) if spell.r#type == SkillType::Bard => {}
+            Some(_) => report.error(
+                CONFIG,
+                None,
+                format!("bard.songs: spell {} is not a bard spell", song.spell_id),
+            ),
+            None => report.error(
+                CONFIG,
+                None,
+                format!("bard.songs: unknown spell {}", song.spell_id),
+            ),
+        }
+
+        if song.notes.is_empty()
+            || song
+                .notes
+                .iter()
+                .any(|note| *note < 1 || *note > settings.bard.max_note_id)
+        {
+            report.error(
+                CONFIG,
+                None,
+                format!(
+                    "bard.songs: spell {} needs notes between 1 and {}",
+                    song.spell_id, settings.bard.max_note_id
+                ),
+            );
+        }
+    }
+}
+
+fn check_arenas(report: &mut Report, data: &Data) {
+    let arenas = match data.arenas {
+        Some(ref arenas) => arenas,
+        None => return,
+    };
+
+    for (index, arena) in arenas.arenas.iter().enumerate() {
+        if !data.maps.contains_key(&arena.map) {
+            report.error(
+                ARENAS,
+                None,
+                format!("Arena {}: map {} does not exist", index + 1, arena.map),
+            );
+            continue;
+        }
+
+        for spawn in &arena.spawns {
+            for (name, coords) in [("from", &spawn.from), ("to", &spawn.to)] {
+                if let Some(error) = check_spawn(
+                    data,
+                    arena.map,
+                    Coords {
+                        x: coords.x,
+                        y: coords.y,
+                    },
+                    true,
+                ) {
+                    report.error(
+                        ARENAS,
+                        None,
+                        format!("Arena {} spawn {}: {}", index + 1, name, error),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_npc_abilities(report: &mut Report, data: &Data) {
+    let npc_abilities = match data.npc_abilities {
+        Some(ref npc_abilities) => npc_abilities,
+        None => return,
+    };
+
+    for set in &npc_abilities.npcs {
+        if !exists(&data.npcs.npcs, set.npc_id) {
+            report.error(
+                NPC_ABILITIES,
+                None,
+                format!("Abilities for unknown NPC {}", set.npc_id),
+            );
+        }
+
+        for ability in set.phases.iter().flat_map(|phase| &phase.abilities) {
+            let error = match ability.r#type {
+                NpcAbilityType::Spell | NpcAbilityType::Heal => {
+                    let skill_type = if ability.r#type == NpcAbilityType::Spell {
+                        SkillType::Attack
+                    } else {
+                        SkillType::Heal
+                    };
+
+                    match get(&data.spells.skills, ability.spell_id) {
+                        None => Some(format!("unknown spell {}", ability.spell_id)),
+                        Some(spell) if spell.r#type != skill_type => Some(format!(
+                            "spell {} is {:?}, not {:?}",
+                            ability.spell_id, spell.r#type, skill_type
+                        )),
+                        Some(_) => None,
+                    }
+                }
+                NpcAbilityType::Summon if !exists(&data.npcs.npcs, ability.npc_id) => {
+                    Some(format!("unknown NPC {}", ability.npc_id))
+                }
+                NpcAbilityType::Summon => None,
+            };
+
+            if let Some(error) = error {
+                report.error(
+                    NPC_ABILITIES,
+                    None,
+                    format!("NPC {} {:?}: {}", set.npc_id, ability.r#type, error),
+                );
+            }
+        }
+    }
+}
+
+fn check_status_effects(report: &mut Report, data: &Data) {
+    let status_effects = match data.status_effects {
+        Some(ref status_effects) => status_effects,
+        None => return,
+    };
+
+    let mut ids = Vec::new();
+    for effect in &status_effects.effects {
+        if ids.contains(&effect.id) {
+            report.error(
+                STATUS_EFFECTS,
+                None,
+                format!("Duplicate status effect {}", effect.id),
+            );
+        }
+        ids.push(effect.id);
+
+        if effect.duration < 1 {
+            report.error(
+                STATUS_EFFECTS,
+                None,
+                format!(
+                    "Status effect {} ({}) has no duration",
+                    effect.id, effect.name
+                ),
+            );
+        }
+
+        for spell_id in &effect.spells {
+            match get(&data.spells.skills, *spell_id) {
+                Some(spell)
+                    if effect.kind.is_npc_only()
+                        && (spell.r#type != SkillType::Attack
+                            || spell.target_restrict == SkillTargetRestrict::Friendly
+                            || spell.target_type != SkillTargetType::Normal) =>
+                {
+                    report.error(
+                        STATUS_EFFECTS,
+                        None,
+                        format!(
+                            "Status effect {} ({}) only affects NPCs, spell {} only targets players",
+                            effect.id, effect.name, spell_id
+                        ),
+                    );
+                }
+                Some(_) => {}
+                None => report.error(
+                    STATUS_EFFECTS,
+                    None,
+                    format!(
+                        "Status effect {} ({}) unknown spell {}",
+                        effect.id, effect.name, spell_id
+                    ),
+                ),
+            }
+        }
+
+        for item_id in &effect.items {
+            if !exists(&data.items.items, *item_id) {
+                report.error(
+                    STATUS_EFFECTS,
+                    None,
+                    format!(
+                        "Status effect {} ({}) unknown item {}",
+                        effect.id, effect.name, item_id
+                    ),
+                );
+            } else if effect.kind.is_npc_only() {
+                report.error(
+                    STATUS_EFFECTS,
+                    None,
+                    format!(
+                        "Status effect {} ({}) only affects NPCs, item {} is used by players",
+                        effect.id, effect.name, item_id
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn check_drops(report: &mut Report, data: &Data) {
+    let file = format!("{}/pub/dtd001.edf", DATA_DIR);
+    for npc in &data.drops.npcs {
+        if !exists(&data.npcs.npcs, npc.npc_id) {
+            report.error(&file, None, format!("Drops for unknown NPC {}", npc.npc_id));
+        }
+
+        for drop in &npc.drops {
+            if !exists(&data.items.items, drop.item_id) {
+                report.error(
+                    &file,
+                    None,
+                    format!("NPC {} drops unknown item {}", npc.npc_id, drop.item_id),
+                );
+            }
+        }
+    }
+}
+
+fn check_shops(report: &mut Report, data: &Data) {
+    let file = format!("{}/pub/dts001.esf", DATA_DIR);
+    for shop in &data.shops.shops {
+        if !has_behavior(data, NpcType::Shop, shop.behavior_id) {
+            report.warning(
+                &file,
+                None,
+                format!(
+                    "Shop \"{}\": no shop NPC has behavior id {}",
+                    shop.name, shop.behavior_id
+                ),
+            );
+        }
+
+        if shop.class_requirement > 0 && !exists(&data.classes.classes, shop.class_requirement) {
+            report.error(
+                &file,
+                None,
+                format!(
+                    "Shop \"{}\" requires unknown class {}",
+                    shop.name, shop.class_requirement
+                ),
+            );
+        }
+
+        for trade in &shop.trades {
+            if !exists(&data.items.items, trade.item_id) {
+                report.error(
+                    &file,
+                    None,
+                    format!(
+                        "Shop \"{}\" trades unknown item {}",
+                        shop.name, trade.item_id
+                    ),
+                );
+            }
+        }
+
+        for craft in &shop.crafts {
+            let ingredients = craft
+                .ingredients
+                .iter()
+                .filter(|ingredient| ingredient.item_id > 0)
+                .map(|ingredient| ingredient.item_id);
+
+            for item_id in std::iter::once(craft.item_id).chain(ingredients) {
+                if !exists(&data.items.items, item_id) {
+                    report.error(
+                        &file,
+                        None,
+                        format!(
+                            "Shop \"{}\" crafts with unknown item {}",
+                            shop.name, item_id
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_inns(report: &mut Report, data: &Data) {
+    let file = format!("{}/pub/din001.eid", DATA_DIR);
+    for inn in &data.inns.inns {
+        let mut spawns = vec![
+            ("spawn", inn.spawn_map, inn.spawn_x, inn.spawn_y),
+            ("sleep", inn.sleep_map, inn.sleep_x, inn.sleep_y),
+        ];
+
+        if inn.alternate_spawn_enabled {
+            spawns.push((
+                "alternate spawn",
+                inn.alternate_spawn_map,
+                inn.alternate_spawn_x,
+                inn.alternate_spawn_y,
+            ));
+        }
+
+        for (name, map, x, y) in spawns {
+            if let Some(error) = check_spawn(data, map, Coords { x, y }, true) {
+                report.error(
+                    &file,
+                    None,
+                    format!("Inn \"{}\" {}: {}", inn.name, name, error),
+                );
+            }
+        }
+    }
+}
+
+fn check_skill_masters(report: &mut Report, data: &Data) {
+    let file = format!("{}/pub/dsm001.emf", DATA_DIR);
+    for skill_master in &data.skill_masters.skill_masters {
+        for skill in &skill_master.skills {
+            let requirements = skill
+                .skill_requirements
+                .iter()
+                .filter(|skill_id| **skill_id > 0)
+                .copied();
+
+            for skill_id in std::iter::once(skill.skill_id).chain(requirements) {
+                if !exists(&data.spells.skills, skill_id) {
+                    report.error(
+                        &file,
+                        None,
+                        format!(
+                            "Skill master \"{}\" references unknown spell {}",
+                            skill_master.name, skill_id
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_talk(report: &mut Report, data: &Data) {
+    let file = format!("{}/pub/ttd001.etf", DATA_DIR);
+    for npc in &data.talk.npcs {
+        if !exists(&data.npcs.npcs, npc.npc_id) {
+            report.error(&file, None, format!("Talk for unknown NPC {}", npc.npc_id));
+        }
+    }
+}
+
+fn check_spawn(data: &Data, map_id: i32, coords: Coords, must_be_walkable: bool) -> Option<String> {
+    let map = match data.maps.get(&map_id) {
+        Some(map) => map,
+        None => return Some(format!("map {} does not exist", map_id)),
+    };
+
+    if !in_bounds(map, coords) {
+        return Some(format!(
+            "{}, {} is outside of map {} ({}x{})",
+            coords.x, coords.y, map_id, map.width, map.height
+        ));
+    }
+
+    if must_be_walkable && !is_walkable(map, coords) {
+        return Some(format!(
+            "{}, {} on map {} is blocked",
+            coords.x, coords.y, map_id
+        ));
+    }
+
+    None
+}
+
+fn in_bounds(map: &Emf, coords: Coords) -> bool {
+    coords.x >= 0 && coords.y >= 0 && coords.x <= map.width && coords.y <= map.height
+}
+
+fn is_walkable(map: &Emf, coords: Coords) -> bool {
+    map.tile_spec_rows
+        .iter()
+        .find(|row| row.y == coords.y)
+        .and_then(|row| row.tiles.iter().find(|tile| tile.x == coords.x))
+        .is_none_or(|tile| is_tile_spec_walkable(tile.tile_spec))
+}
+
+fn has_behavior(data: &Data, npc_type: NpcType, behavior_id: i32) -> bool {
+    data.npcs
+        .npcs
+        .iter()
+        .any(|npc| npc.r#type == npc_type && npc.behavior_id == behavior_id)
+}
+
+fn get<T>(records: &[T], id: i32) -> Option<&T> {
+    if id < 1 {
+        return None;
+    }
+
+    records.get(id as usize - 1)
+}
+
+fn exists<T>(records: &[T], id: i32) -> bool {
+    get(records, id).is_some()
+}
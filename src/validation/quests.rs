@@ -0,0 +1,592 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use eolib::protocol::map::Emf;
+use eoplus::{parse_quest, Arg, Quest};
+use glob::glob;
+
+use crate::{character::Character, CLASS_DB, DATA_DIR, ITEM_DB, NPC_DB, SPELL_DB};
+
+use super::{load_map, Report};
+
+// Argument signatures: `i` is an integer, `s` a string and anything after
+// `?` is optional
+const ACTIONS: &[(&str, &str)] = &[
+    ("AddNpcText", "is"),
+    ("AddNpcChat", "is"),
+    ("AddNpcInput", "iis"),
+    ("End", ""),
+    ("Reset", ""),
+    ("ResetDaily", ""),
+    ("SetState", "s"),
+    ("SetQuestState", "is"),
+    ("StartQuest", "i?s"),
+    ("ResetQuest", "i"),
+    ("Roll", "i"),
+    ("SetTitle", "s"),
+    ("SetFiance", "s"),
+    ("SetPartner", "s"),
+    ("SetHome", "s"),
+    ("SetMap", "iii"),
+    ("SetCoord", "ii"),
+    ("GiveItem", "i?i"),
+    ("RemoveItem", "i?i"),
+    ("SetClass", "i"),
+    ("SetRace", "i"),
+    ("SetGender", "i"),
+    ("GiveSpell", "i"),
+    ("RemoveSpell", "i"),
+    ("GiveStat", "si"),
+    ("RemoveStat", "si"),
+    ("SetStat", "si"),
+    ("PlayMusic", "i"),
+    ("PlaySound", "i"),
+    ("ShowHint", "s"),
+    ("GiveExp", "i"),
+    ("GiveKarma", "i"),
+    ("RemoveKarma", "i"),
+    ("Quake", "i"),
+    ("QuakeWorld", "?i"),
+    ("EffectOnPlayer", "i"),
+    ("EffectOnCoord", "iii"),
+];
+
+const RULES: &[(&str, &str)] = &[
+    ("Always", ""),
+    ("TalkedToNpc", "i"),
+    ("InputNpc", "i"),
+    ("KilledNpcs", "ii"),
+    ("KilledPlayers", "i"),
+    ("ArenaKills", "i"),
+    ("GotItems", "ii"),
+    ("LostItems", "ii"),
+    ("EnterMap", "i"),
+    ("LeaveMap", "i"),
+    ("EnterCoord", "iii"),
+    ("LeaveCoord", "iii"),
+    ("DoneDaily", "i"),
+    ("GotSpell", "i?i"),
+    ("LostSpell", "i"),
+    ("IsGender", "i"),
+    ("IsClass", "i"),
+    ("IsRace", "i"),
+    ("IsWearing", "i"),
+    ("IsLeader", ""),
+    ("CitizenOf", "s"),
+    ("StatIs", "si"),
+    ("StatNot", "si"),
+    ("StatGreater", "si"),
+    ("StatLess", "si"),
+    ("StatBetween", "sii"),
+    ("Rolled", "i"),
+    ("IsQuestState", "is"),
+];
+
+struct QuestFile {
+    id: i32,
+    path: String,
+    quest: Quest,
+    lines: HashMap<String, StateLines>,
+}
+
+#[derive(Default)]
+struct StateLines {
+    line: usize,
+    actions: Vec<usize>,
+    rules: Vec<usize>,
+}
+
+struct Checker<'a> {
+    report: &'a mut Report,
+    quests: &'a HashMap<i32, Vec<String>>,
+    maps: HashMap<i32, Option<Emf>>,
+}
+
+pub fn check_quests() -> Report {
+    let mut report = Report::default();
+    let mut sources = Vec::new();
+
+    let entries = match glob(&format!("{}/quests/*.eqf", DATA_DIR)) {
+        Ok(entries) => entries,
+        Err(e) => {
+            report.error(DATA_DIR, None, format!("Invalid quest path: {}", e));
+            return report;
+        }
+    };
+
+    for path in entries.flatten() {
+        match fs::read(&path) {
+            Ok(buf) => sources.push((path, String::from_utf8_lossy(&buf).to_string())),
+            Err(e) => report.error(
+                &path.display().to_string(),
+                None,
+                format!("Failed to read file: {}", e),
+            ),
+        }
+    }
+
+    check_quest_sources(&mut report, &sources);
+    report
+}
+
+pub fn check_quest_sources(report: &mut Report, sources: &[(PathBuf, String)]) {
+    let mut files = Vec::new();
+
+    for (path, source) in sources {
+        let display = path.display().to_string();
+
+        let id = match quest_id(path) {
+            Some(id) => id,
+            None => {
+                report.error(
+                    &display,
+                    None,
+                    "File name must be the numeric quest id".to_owned(),
+                );
+                continue;
+            }
+        };
+
+        match parse_quest(source) {
+            Ok(quest) => files.push(QuestFile {
+                id,
+                path: display,
+                quest,
+                lines: index_lines(source),
+            }),
+            Err(e) => report.error(&display, None, format!("Failed to parse quest: {}", e)),
+        }
+    }
+
+    let quests: HashMap<i32, Vec<String>> = files
+        .iter()
+        .map(|file| {
+            (
+                file.id,
+                file.quest
+                    .states
+                    .iter()
+                    .map(|state| state.name.to_owned())
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let mut checker = Checker {
+        report,
+        quests: &quests,
+        maps: HashMap::new(),
+    };
+
+    for file in &files {
+        checker.check_quest(file);
+    }
+}
+
+fn quest_id(path: &Path) -> Option<i32> {
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+impl Checker<'_> {
+    fn check_quest(&mut self, file: &QuestFile) {
+        let quest = &file.quest;
+        let path = file.path.as_str();
+
+        if quest.states.is_empty() {
+            self.report
+                .error(path, None, "Quest has no states".to_owned());
+            return;
+        }
+
+        if quest.name.is_empty() {
+            self.report
+                .warning(path, None, "Quest has no name".to_owned());
+        }
+
+        let mut seen = HashSet::new();
+        for (index, state) in quest.states.iter().enumerate() {
+            if state.name.is_empty() {
+                self.report
+                    .error(path, None, format!("State {} has no name", index + 1));
+                continue;
+            }
+
+            if !seen.insert(state.name.to_lowercase()) {
+                self.report.warning(
+                    path,
+                    state_line(file, &state.name),
+                    format!("Duplicate state `{}`", state.name),
+                );
+            }
+        }
+
+        for state in &quest.states {
+            let lines = file.lines.get(&state.name.to_lowercase());
+
+            for (index, action) in state.actions.iter().enumerate() {
+                let line = lines.and_then(|lines| lines.actions.get(index).copied());
+                if self.check_signature(path, line, "action", ACTIONS, &action.name, &action.args) {
+                    self.check_references(file, line, &action.name, &action.args);
+                }
+            }
+
+            for (index, rule) in state.rules.iter().enumerate() {
+                let line = lines.and_then(|lines| lines.rules.get(index).copied());
+                if self.check_signature(path, line, "rule", RULES, &rule.name, &rule.args) {
+                    self.check_references(file, line, &rule.name, &rule.args);
+                }
+
+                if !quest.states.iter().any(|state| state.name == rule.goto) {
+                    self.report.error(
+                        path,
+                        line,
+                        format!("Rule `{}` goes to unknown state `{}`", rule.name, rule.goto),
+                    );
+                }
+            }
+        }
+
+        for state in unreachable_states(quest) {
+            self.report.warning(
+                path,
+                state_line(file, state),
+                format!("State `{}` can never be reached", state),
+            );
+        }
+    }
+
+    fn check_signature(
+        &mut self,
+        path: &str,
+        line: Option<usize>,
+        kind: &str,
+        known: &[(&str, &str)],
+        name: &str,
+        args: &[Arg],
+    ) -> bool {
+        let signature = match known.iter().find(|(known, _)| *known == name) {
+            Some((_, signature)) => *signature,
+            None => {
+                let message = match known
+                    .iter()
+                    .find(|(known, _)| known.eq_ignore_ascii_case(name))
+                {
+                    Some((known, _)) => {
+                        format!("Unknown {} `{}` (did you mean `{}`?)", kind, name, known)
+                    }
+                    None => format!("Unknown {} `{}`", kind, name),
+                };
+                self.report.error(path, line, message);
+                return false;
+            }
+        };
+
+        let (required, optional) = match signature.split_once('?') {
+            Some((required, optional)) => (required, optional),
+            None => (signature, ""),
+        };
+
+        if args.len() < required.len() || args.len() > required.len() + optional.len() {
+            self.report.error(
+                path,
+                line,
+                format!(
+                    "`{}` takes {} argument(s) but {} were given",
+                    name,
+                    if optional.is_empty() {
+                        required.len().to_string()
+                    } else {
+                        format!("{}-{}", required.len(), required.len() + optional.len())
+                    },
+                    args.len()
+                ),
+            );
+            return false;
+        }
+
+        let mut valid = true;
+        for (index, (arg, expected)) in args
+            .iter()
+            .zip(required.chars().chain(optional.chars()))
+            .enumerate()
+        {
+            let matches = matches!((arg, expected), (Arg::Int(_), 'i') | (Arg::Str(_), 's'));
+
+            if !matches {
+                self.report.error(
+                    path,
+                    line,
+                    format!(
+                        "Argument {} of `{}` must be {}",
+                        index + 1,
+                        name,
+                        if expected == 'i' {
+                            "an integer"
+                        } else {
+                            "a string"
+                        }
+                    ),
+                );
+                valid = false;
+            }
+        }
+
+        valid
+    }
+
+    fn check_references(
+        &mut self,
+        file: &QuestFile,
+        line: Option<usize>,
+        name: &str,
+        args: &[Arg],
+    ) {
+        let path = file.path.as_str();
+        let int = |index: usize| match args.get(index) {
+            Some(Arg::Int(value)) => Some(*value),
+            _ => None,
+        };
+        let string = |index: usize| match args.get(index) {
+            Some(Arg::Str(value)) => Some(value.as_str()),
+            _ => None,
+        };
+
+        let error = match name {
+            "GiveItem" | "RemoveItem" | "GotItems" | "LostItems" | "IsWearing" => {
+                let item_id = int(0).unwrap_or_default();
+                let item_db = ITEM_DB.load();
+                if item_id < 1 || item_db.items.get(item_id as usize - 1).is_none() {
+                    Some(format!("Item {} does not exist", item_id))
+                } else {
+                    None
+                }
+            }
+            "AddNpcText" | "AddNpcChat" | "AddNpcInput" | "TalkedToNpc" => {
+                let behavior_id = int(0).unwrap_or_default();
+                if NPC_DB
+                    .load()
+                    .npcs
+                    .iter()
+                    .any(|npc| npc.behavior_id == behavior_id)
+                {
+                    None
+                } else {
+                    Some(format!("No NPC has behavior id {}", behavior_id))
+                }
+            }
+            "KilledNpcs" => {
+                let npc_id = int(0).unwrap_or_default();
+                let npc_db = NPC_DB.load();
+                if npc_id < 1 || npc_db.npcs.get(npc_id as usize - 1).is_none() {
+                    Some(format!("NPC {} does not exist", npc_id))
+                } else {
+                    None
+                }
+            }
+            "GiveSpell" | "RemoveSpell" | "GotSpell" | "LostSpell" => {
+                let spell_id = int(0).unwrap_or_default();
+                let spell_db = SPELL_DB.load();
+                if spell_id < 1 || spell_db.skills.get(spell_id as usize - 1).is_none() {
+                    Some(format!("Spell {} does not exist", spell_id))
+                } else {
+                    None
+                }
+            }
+            "SetClass" | "IsClass" => {
+                let class_id = int(0).unwrap_or_default();
+                let class_db = CLASS_DB.load();
+                if class_id < 1 || class_db.classes.get(class_id as usize - 1).is_none() {
+                    Some(format!("Class {} does not exist", class_id))
+                } else {
+                    None
+                }
+            }
+            "SetMap" | "EnterCoord" | "LeaveCoord" => {
+                let map_id = int(0).unwrap_or_default();
+                let (x, y) = (int(1).unwrap_or_default(), int(2).unwrap_or_default());
+                match self.map(map_id) {
+                    Some(map) => {
+                        if x < 0 || y < 0 || x > map.width || y > map.height {
+                            Some(format!(
+                                "{}, {} is outside of map {} ({}x{})",
+                                x, y, map_id, map.width, map.height
+                            ))
+                        } else {
+                            None
+                        }
+                    }
+                    None => Some(format!("Map {} does not exist", map_id)),
+                }
+            }
+            "EnterMap" | "LeaveMap" => {
+                let map_id = int(0).unwrap_or_default();
+                if self.map(map_id).is_none() {
+                    Some(format!("Map {} does not exist", map_id))
+                } else {
+                    None
+                }
+            }
+            "GiveStat" | "RemoveStat" | "SetStat" | "StatIs" | "StatNot" | "StatGreater"
+            | "StatLess" | "StatBetween" => {
+                let stat = string(0).unwrap_or_default();
+                if Character::default().get_quest_stat(stat).is_none() {
+                    Some(format!("Unknown stat `{}`", stat))
+                } else {
+                    None
+                }
+            }
+            "SetState" => {
+                let state = string(0).unwrap_or_default();
+                if file
+                    .quest
+                    .states
+                    .iter()
+                    .any(|s| s.name.eq_ignore_ascii_case(state))
+                {
+                    None
+                } else {
+                    Some(format!("Unknown state `{}`", state))
+                }
+            }
+            "SetQuestState" | "StartQuest" | "ResetQuest" | "IsQuestState" => {
+                let quest_id = int(0).unwrap_or_default();
+                match self.quests.get(&quest_id) {
+                    Some(states) => match string(1) {
+                        Some(state) if !states.iter().any(|s| s.eq_ignore_ascii_case(state)) => {
+                            Some(format!("Quest {} has no state `{}`", quest_id, state))
+                        }
+                        _ => None,
+                    },
+                    None => Some(format!("Quest {} does not exist", quest_id)),
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(error) = error {
+            self.report.error(path, line, error);
+        }
+    }
+
+    fn map(&mut self, id: i32) -> Option<&Emf> {
+        self.maps.entry(id).or_insert_with(|| load_map(id)).as_ref()
+    }
+}
+
+fn state_line(file: &QuestFile, state: &str) -> Option<usize> {
+    file.lines
+        .get(&state.to_lowercase())
+        .map(|lines| lines.line)
+}
+
+fn unreachable_states(quest: &Quest) -> Vec<&str> {
+    let mut reached = vec![false; quest.states.len()];
+    let mut queue = VecDeque::from([0]);
+    reached[0] = true;
+
+    while let Some(index) = queue.pop_front() {
+        let state = &quest.states[index];
+
+        let mut targets: Vec<&str> = state.rules.iter().map(|rule| rule.goto.as_str()).collect();
+        for action in &state.actions {
+            match (action.name.as_str(), action.args.first()) {
+                ("SetState", Some(Arg::Str(name))) => targets.push(name),
+                ("Reset" | "ResetDaily", _) => targets.push(&quest.states[0].name),
+                _ => {}
+            }
+        }
+
+        for target in targets {
+            if let Some(next) = quest
+                .states
+                .iter()
+                .position(|state| state.name.eq_ignore_ascii_case(target))
+            {
+                if !reached[next] {
+                    reached[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    // Other quests can jump straight into a state with SetQuestState so we
+    // can't be certain these are dead, hence only a warning
+    quest
+        .states
+        .iter()
+        .zip(reached)
+        .filter(|(_, reached)| !reached)
+        .map(|(state, _)| state.name.as_str())
+        .collect()
+}
+
+/// Finds the line of every state, action and rule since the parsed quest
+/// doesn't keep track of where things came from
+fn index_lines(source: &str) -> HashMap<String, StateLines> {
+    let mut states: HashMap<String, StateLines> = HashMap::new();
+    let mut current: Option<String> = None;
+    let mut expect_state_name = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let code = strip_strings_and_comments(line);
+
+        for word in code
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+        {
+            if expect_state_name {
+                let name = word.to_lowercase();
+                states.entry(name.clone()).or_insert(StateLines {
+                    line: line_number,
+                    ..Default::default()
+                });
+                current = Some(name);
+                expect_state_name = false;
+                continue;
+            }
+
+            let lines = current.as_ref().and_then(|name| states.get_mut(name));
+            match word.to_lowercase().as_str() {
+                "state" => expect_state_name = true,
+                "action" => {
+                    if let Some(lines) = lines {
+                        lines.actions.push(line_number);
+                    }
+                }
+                "rule" => {
+                    if let Some(lines) = lines {
+                        lines.rules.push(line_number);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    states
+}
+
+fn strip_strings_and_comments(line: &str) -> String {
+    let mut code = String::with_capacity(line.len());
+    let mut in_string = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_string = !in_string,
+            '\\' if in_string => {
+                chars.next();
+            }
+            '/' if !in_string && chars.peek() == Some(&'/') => break,
+            _ if in_string => {}
+            _ => code.push(c),
+        }
+    }
+
+    code
+}
@@ -1,10 +1,12 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::PartyExpShare, PartyRequestType};
 use tokio::sync::oneshot;
 
 use crate::{character::Character, map::MapHandle, player::PlayerHandle};
 
-use super::{Party, WorldHandle};
+use super::{LoginQueueResult, Party, ReloadKind, WorldHandle};
 
 #[derive(Debug)]
 pub enum Command {
@@ -26,9 +28,6 @@ pub enum Command {
         ip: String,
         respond_to: oneshot::Sender<()>,
     },
-    AddLoggedInAccount {
-        account_id: i32,
-    },
     AddPendingLogin {
         account_id: i32,
     },
@@ -40,11 +39,22 @@ pub enum Command {
         player_id: i32,
         player: PlayerHandle,
     },
+    BanHdid {
+        hdid: String,
+        admin_name: String,
+        duration: String,
+    },
+    BanIp {
+        ip: String,
+        admin_name: String,
+        duration: String,
+    },
     BanPlayer {
         victim_name: String,
         admin_name: String,
         duration: String,
         silent: bool,
+        ban_ip_and_hdid: bool,
     },
     BroadcastAdminMessage {
         name: String,
@@ -111,9 +121,15 @@ pub enum Command {
         map_id: i32,
         respond_to: oneshot::Sender<Result<MapHandle, Box<dyn std::error::Error + Send + Sync>>>,
     },
+    GetMaps {
+        respond_to: oneshot::Sender<HashMap<i32, MapHandle>>,
+    },
     GetNextPlayerId {
         respond_to: oneshot::Sender<i32>,
     },
+    GetOnlinePlayers {
+        respond_to: oneshot::Sender<Vec<PlayerHandle>>,
+    },
     GetPlayer {
         player_id: i32,
         respond_to: oneshot::Sender<Option<PlayerHandle>>,
@@ -145,6 +161,8 @@ pub enum Command {
     MutePlayer {
         victim_name: String,
         admin_name: String,
+        duration: String,
+        global_only: bool,
     },
     Quake {
         magnitude: i32,
@@ -154,6 +172,20 @@ pub enum Command {
         reportee_name: String,
         message: String,
     },
+    RequestBanInfo {
+        player_id: i32,
+        victim_name: String,
+    },
+    RequestBans {
+        player_id: i32,
+        limit: usize,
+    },
+    RequestLogin {
+        player_id: i32,
+        account_id: i32,
+        priority: bool,
+        respond_to: oneshot::Sender<LoginQueueResult>,
+    },
     RequestPartyList {
         player_id: i32,
     },
@@ -164,6 +196,10 @@ pub enum Command {
     RequestPlayerList {
         player_id: i32,
     },
+    RequestSanctions {
+        player_id: i32,
+        victim_name: String,
+    },
     RequestPlayerNameList {
         player_id: i32,
     },
@@ -179,6 +215,9 @@ pub enum Command {
         player_id: i32,
         target_player_id: i32,
     },
+    Reload {
+        kind: ReloadKind,
+    },
     ReloadMap {
         map_id: i32,
     },
@@ -203,10 +242,22 @@ pub enum Command {
     ToggleGlobal {
         admin_name: String,
     },
+    Unban {
+        target: String,
+        admin_name: String,
+    },
+    UnlockLogin {
+        target: String,
+        admin_name: String,
+    },
     UnfreezePlayer {
         victim_name: String,
         admin_name: String,
     },
+    UnmutePlayer {
+        victim_name: String,
+        admin_name: String,
+    },
     UpdatePartyHP {
         player_id: i32,
         hp_percentage: i32,
@@ -215,4 +266,9 @@ pub enum Command {
         player_id: i32,
         exp_gains: Vec<PartyExpShare>,
     },
+    WarnPlayer {
+        victim_name: String,
+        admin_name: String,
+        reason: String,
+    },
 }
@@ -14,17 +14,16 @@ use eolib::{
 };
 use futures::{stream, StreamExt};
 use glob::glob;
-use mysql_async::Pool;
 
-use crate::map::MapHandle;
+use crate::{db::Db, map::MapHandle, DATA_DIR};
 
 use super::WorldHandle;
 
 pub async fn load_maps(
-    pool: Pool,
+    db: Db,
     world: WorldHandle,
 ) -> Result<HashMap<i32, MapHandle>, Box<dyn std::error::Error + Send + Sync>> {
-    let entries = glob("data/maps/*.emf")?;
+    let entries = glob(&format!("{}/maps/*.emf", DATA_DIR))?;
     let mut map_files: HashMap<i32, MapHandle> = HashMap::new();
     let mut load_handles = vec![];
     for path in entries {
@@ -38,7 +37,7 @@ pub async fn load_maps(
 
         load_handles.push(load_map(
             path.to_path_buf(),
-            pool.to_owned(),
+            db.to_owned(),
             world.to_owned(),
         ));
     }
@@ -54,13 +53,13 @@ pub async fn load_maps(
 
     map_files.insert(
         0,
-        MapHandle::new(0, 0, pool.to_owned(), Emf::default(), world.to_owned()),
+        MapHandle::new(0, 0, db.to_owned(), Emf::default(), world.to_owned()),
     );
 
     Ok(map_files)
 }
 
-async fn load_map(path: PathBuf, pool: Pool, world: WorldHandle) -> Option<(i32, MapHandle)> {
+async fn load_map(path: PathBuf, db: Db, world: WorldHandle) -> Option<(i32, MapHandle)> {
     let file_name = match path.file_name() {
         Some(file_name) => match file_name.to_str() {
             Some(file_name) => file_name,
@@ -116,5 +115,5 @@ async fn load_map(path: PathBuf, pool: Pool, world: WorldHandle) -> Option<(i32,
         }
     };
 
-    Some((id, MapHandle::new(id, file_size as i32, pool, file, world)))
+    Some((id, MapHandle::new(id, file_size as i32, db, file, world)))
 }
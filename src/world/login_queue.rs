@@ -0,0 +1,67 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuedLogin {
+    pub player_id: i32,
+    pub account_id: i32,
+    pub priority: bool,
+}
+
+/// Authenticated players waiting for a free slot, first come first served
+/// with priority players ahead of everyone else
+#[derive(Debug, Default)]
+pub struct LoginQueue {
+    entries: Vec<QueuedLogin>,
+}
+
+/// Outcome of asking the world to log an account in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginQueueResult {
+    Admitted,
+    Queued { position: usize },
+    Full,
+}
+
+impl LoginQueue {
+    /// Adds a login behind everyone with the same or higher priority and
+    /// returns its position, starting at 1
+    pub fn push(&mut self, login: QueuedLogin) -> usize {
+        let index = if login.priority {
+            self.entries
+                .iter()
+                .position(|entry| !entry.priority)
+                .unwrap_or(self.entries.len())
+        } else {
+            self.entries.len()
+        };
+
+        self.entries.insert(index, login);
+        index + 1
+    }
+
+    pub fn pop(&mut self) -> Option<QueuedLogin> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.entries.remove(0))
+        }
+    }
+
+    pub fn remove(&mut self, player_id: i32) -> Option<QueuedLogin> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.player_id == player_id)?;
+        Some(self.entries.remove(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedLogin> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -1,6 +1,10 @@
 mod command;
 pub use command::Command;
 mod load_maps;
+mod login_queue;
+pub use login_queue::{LoginQueue, LoginQueueResult, QueuedLogin};
+mod reload_kind;
+pub use reload_kind::ReloadKind;
 #[allow(clippy::module_inception)]
 mod world;
 mod world_handle;
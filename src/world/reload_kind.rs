@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReloadKind {
+    /// Pub files under `data/pub`
+    Pub,
+    /// Quest files under `data/quests`
+    Quests,
+    /// Formulas, commands, arenas and rate limits under `config`
+    Config,
+    All,
+}
+
+impl ReloadKind {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "pub" => Some(Self::Pub),
+            "quests" | "quest" => Some(Self::Quests),
+            "config" => Some(Self::Config),
+            "all" | "" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    connection_log::ConnectionLog, errors::DataNotFoundError, map::MapHandle, player::PlayerHandle,
+    connection_log::ConnectionLog, db::Db, errors::DataNotFoundError, map::MapHandle, metrics,
+    player::PlayerHandle,
 };
 
-use super::{load_maps::load_maps, Command, Party};
-use mysql_async::Pool;
+use super::{load_maps::load_maps, Command, LoginQueue, Party};
 use std::collections::HashMap;
 use tokio::sync::mpsc::UnboundedReceiver;
 
@@ -13,9 +13,10 @@ pub struct World {
     players: HashMap<i32, PlayerHandle>,
     accounts: Vec<i32>,
     pending_logins: Vec<i32>,
+    login_queue: LoginQueue,
     characters: HashMap<String, i32>,
     guilds: HashMap<String, Vec<i32>>,
-    pool: Pool,
+    db: Db,
     maps: Option<HashMap<i32, MapHandle>>,
     parties: Vec<Party>,
     npc_act_ticks: i32,
@@ -26,6 +27,7 @@ pub struct World {
     spike_ticks: i32,
     drain_ticks: i32,
     second_ticks: i32,
+    login_queue_ticks: i32,
     auto_pickup_ticks: i32,
     global_locked: bool,
     connection_log: ConnectionLog,
@@ -39,7 +41,9 @@ mod drop_player;
 mod find_player;
 mod get_character_by_name;
 mod get_next_player_id;
+mod login_queue;
 mod party;
+mod reload_data;
 mod reload_map;
 mod request_player_list;
 mod request_player_name_list;
@@ -48,13 +52,14 @@ mod shutdown;
 mod tick;
 
 impl World {
-    pub fn new(rx: UnboundedReceiver<Command>, pool: Pool) -> Self {
+    pub fn new(rx: UnboundedReceiver<Command>, db: Db) -> Self {
         Self {
             rx,
-            pool,
+            db,
             players: HashMap::new(),
             accounts: Vec::new(),
             pending_logins: Vec::new(),
+            login_queue: LoginQueue::default(),
             characters: HashMap::new(),
             guilds: HashMap::new(),
             maps: None,
@@ -67,6 +72,7 @@ impl World {
             spike_ticks: 0,
             drain_ticks: 0,
             second_ticks: 0,
+            login_queue_ticks: 0,
             auto_pickup_ticks: 0,
             global_locked: false,
             connection_log: ConnectionLog::new(),
@@ -86,14 +92,10 @@ impl World {
 
             Command::AddConnection { ip, respond_to } => {
                 self.connection_log.add_connection(&ip);
+                metrics::CONNECTIONS.set(self.connection_log.len() as i64);
                 let _ = respond_to.send(());
             }
 
-            Command::AddLoggedInAccount { account_id } => {
-                self.pending_logins.retain(|id| *id != account_id);
-                self.accounts.push(account_id);
-            }
-
             Command::AddPendingLogin { account_id } => {
                 self.pending_logins.push(account_id);
             }
@@ -218,10 +220,24 @@ impl World {
                 }
             }
 
+            Command::GetMaps { respond_to } => {
+                let _ = respond_to.send(self.maps.clone().unwrap_or_default());
+            }
+
             Command::GetNextPlayerId { respond_to } => {
                 let _ = respond_to.send(self.get_next_player_id(300));
             }
 
+            Command::GetOnlinePlayers { respond_to } => {
+                let _ = respond_to.send(
+                    self.characters
+                        .values()
+                        .filter_map(|player_id| self.players.get(player_id))
+                        .cloned()
+                        .collect(),
+                );
+            }
+
             Command::GetPlayer {
                 player_id,
                 respond_to,
@@ -255,7 +271,7 @@ impl World {
             }
 
             Command::LoadMapFiles { world, respond_to } => {
-                match load_maps(self.pool.to_owned(), world).await {
+                match load_maps(self.db.to_owned(), world).await {
                     Ok(maps) => {
                         self.maps = Some(maps);
                         let _ = respond_to.send(());
@@ -295,8 +311,19 @@ impl World {
                 message,
             } => self.report_player(player_id, reportee_name, message).await,
 
+            Command::RequestLogin {
+                player_id,
+                account_id,
+                priority,
+                respond_to,
+            } => {
+                let _ = respond_to.send(self.request_login(player_id, account_id, priority));
+            }
+
             Command::RequestPartyList { player_id } => self.refresh_party(player_id).await,
 
+            Command::Reload { kind } => self.reload_data(kind),
+
             Command::ReloadMap { map_id } => self.reload_map(map_id).await,
 
             Command::Save => self.save().await,
@@ -319,7 +346,9 @@ impl World {
             Command::Shutdown { respond_to } => self.shutdown(respond_to).await,
 
             Command::Tick => {
+                let timer = metrics::WORLD_TICK_SECONDS.start_timer();
                 self.tick().await;
+                timer.observe_duration();
             }
 
             Command::UpdatePartyHP {
@@ -337,11 +366,37 @@ impl World {
                 admin_name,
                 duration,
                 silent,
+                ban_ip_and_hdid,
             } => {
-                self.ban_player(victim_name, admin_name, duration, silent)
+                self.ban_player(victim_name, admin_name, duration, silent, ban_ip_and_hdid)
                     .await
             }
 
+            Command::BanIp {
+                ip,
+                admin_name,
+                duration,
+            } => self.ban_ip(ip, admin_name, duration).await,
+
+            Command::BanHdid {
+                hdid,
+                admin_name,
+                duration,
+            } => self.ban_hdid(hdid, admin_name, duration).await,
+
+            Command::Unban { target, admin_name } => self.unban(target, admin_name).await,
+
+            Command::UnlockLogin { target, admin_name } => {
+                self.unlock_login(target, admin_name).await
+            }
+
+            Command::RequestBanInfo {
+                player_id,
+                victim_name,
+            } => self.request_ban_info(player_id, victim_name).await,
+
+            Command::RequestBans { player_id, limit } => self.request_bans(player_id, limit).await,
+
             Command::FindPlayer { player_id, name } => self.find_player(player_id, name),
 
             Command::FreePlayer { victim_name } => self.free_player(victim_name),
@@ -365,7 +420,12 @@ impl World {
             Command::MutePlayer {
                 victim_name,
                 admin_name,
-            } => self.mute_player(victim_name, admin_name),
+                duration,
+                global_only,
+            } => {
+                self.mute_player(victim_name, admin_name, duration, global_only)
+                    .await
+            }
 
             Command::Quake { magnitude } => self.quake(magnitude),
 
@@ -385,6 +445,22 @@ impl World {
                 victim_name,
                 admin_name,
             } => self.unfreeze_player(victim_name, admin_name),
+
+            Command::UnmutePlayer {
+                victim_name,
+                admin_name,
+            } => self.unmute_player(victim_name, admin_name).await,
+
+            Command::WarnPlayer {
+                victim_name,
+                admin_name,
+                reason,
+            } => self.warn_player(victim_name, admin_name, reason).await,
+
+            Command::RequestSanctions {
+                player_id,
+                victim_name,
+            } => self.request_sanctions(player_id, victim_name).await,
             Command::RequestPlayerList { player_id } => self.request_player_list(player_id),
             Command::RequestPlayerNameList { player_id } => {
                 self.request_player_name_list(player_id)
@@ -0,0 +1,36 @@
+use super::super::World;
+
+impl World {
+    /// Bans an address or CIDR range. `ip` has already been validated
+    pub async fn ban_ip(&mut self, ip: String, admin_name: String, duration: String) {
+        self.ban_address(Some(ip.as_str()), None, &admin_name, &duration)
+            .await;
+        self.send_admin_reply(&admin_name, &format!("{} has been banned.", ip));
+    }
+
+    pub async fn ban_hdid(&mut self, hdid: String, admin_name: String, duration: String) {
+        self.ban_address(None, Some(hdid.as_str()), &admin_name, &duration)
+            .await;
+        self.send_admin_reply(&admin_name, &format!("{} has been banned.", hdid));
+    }
+
+    async fn ban_address(
+        &self,
+        ip: Option<&str>,
+        hdid: Option<&str>,
+        admin_name: &str,
+        duration: &str,
+    ) {
+        let duration = duration_str::parse(duration)
+            .ok()
+            .map(|duration| (duration.as_secs() / 60) as i32);
+
+        if let Err(err) = self
+            .db
+            .create_ban(None, ip, hdid, duration, admin_name)
+            .await
+        {
+            error!("Failed to ban address: {}", err);
+        }
+    }
+}
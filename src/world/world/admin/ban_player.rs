@@ -1,6 +1,3 @@
-use mysql_async::{params, prelude::Queryable, Row};
-use mysql_common::Value;
-
 use super::super::World;
 use crate::LANG;
 
@@ -11,6 +8,7 @@ impl World {
         admin_name: String,
         duration: String,
         silent: bool,
+        ban_ip_and_hdid: bool,
     ) {
         if let Some(player_id) = self.characters.get(&victim_name) {
             if let Some(player) = self.players.get(player_id) {
@@ -27,26 +25,10 @@ impl World {
             ));
         }
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(err) => {
-                    error!("Failed to get connection from pool: {}", err);
-                    return;
-                }
-            };
-
-            let row: Row = match conn
-                .exec_first(
-                    include_str!("../../../sql/get_character_account.sql"),
-                    params! {
-                        "character_name" => &victim_name,
-                    },
-                )
-                .await
-            {
-                Ok(Some(row)) => row,
+            let account = match db.get_character_account(&victim_name).await {
+                Ok(Some(account)) => account,
                 Err(err) => {
                     error!("Failed to get account from database: {}", err);
                     return;
@@ -54,37 +36,26 @@ impl World {
                 _ => return,
             };
 
-            let account_id: u32 = match row.get("id") {
-                Some(account_id) => account_id,
-                None => return,
-            };
+            let duration = duration_str::parse(&duration)
+                .ok()
+                .map(|duration| (duration.as_secs() / 60) as i32);
 
-            let ip: String = match row.get("last_login_ip") {
-                Some(ip) => ip,
-                None => return,
+            // Only ban the address and hardware id when asked to, other
+            // players may be sharing them
+            let (ip, hdid) = if ban_ip_and_hdid {
+                (
+                    account.last_login_ip.as_deref(),
+                    Some(account.hdid.as_str()),
+                )
+            } else {
+                (None, None)
             };
 
-            let duration = duration_str::parse(&duration);
-
-            match conn
-                .exec_drop(
-                    include_str!("../../../sql/create_ban.sql"),
-                    params! {
-                        "account_id" => account_id,
-                        "admin_name" => &admin_name,
-                        "ip" => &ip,
-                        "duration" => &match duration {
-                            Ok(duration) => Value::from(format!("{}", duration.as_secs() / 60)),
-                            Err(_) => Value::NULL,
-                        },
-                    },
-                )
+            if let Err(err) = db
+                .create_ban(Some(account.account_id), ip, hdid, duration, &admin_name)
                 .await
             {
-                Ok(_) => {}
-                Err(err) => {
-                    error!("Failed to ban player: {}", err);
-                }
+                error!("Failed to ban player: {}", err);
             }
         });
     }
@@ -1,6 +1,4 @@
 use eolib::protocol::{net::server::WarpEffect, Coords};
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
 use crate::SETTINGS;
 
@@ -24,26 +22,15 @@ impl World {
             }
         }
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         if !player_online {
             tokio::spawn(async move {
-                let mut conn = match pool.get_conn().await {
-                    Ok(conn) => conn,
-                    Err(err) => {
-                        error!("Failed to get connection from pool: {}", err);
-                        return;
-                    }
-                };
-
-                if let Err(e) = conn
-                    .exec_drop(
-                        include_str!("../../../sql/offline_jail.sql"),
-                        params! {
-                            "map" => SETTINGS.jail.free_map,
-                            "x" => SETTINGS.jail.free_x,
-                            "y" => SETTINGS.jail.free_y,
-                            "name" => &victim_name,
-                        },
+                if let Err(e) = db
+                    .set_character_position(
+                        &victim_name,
+                        SETTINGS.jail.free_map,
+                        SETTINGS.jail.free_x,
+                        SETTINGS.jail.free_y,
                     )
                     .await
                 {
@@ -1,3 +1,4 @@
+mod ban_address;
 mod ban_player;
 mod free_player;
 mod freeze_player;
@@ -6,9 +7,14 @@ mod kick_player;
 mod mute_player;
 mod quake;
 mod report_player;
+mod request_bans;
 mod request_player_info;
 mod request_player_inventory;
+mod request_sanctions;
 mod send_admin_message;
 mod show_captcha;
 mod toggle_global;
+mod unban;
 mod unfreeze_player;
+mod unlock_login;
+mod warn_player;
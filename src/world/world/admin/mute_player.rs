@@ -1,35 +1,146 @@
 use eolib::protocol::net::{server::TalkSpecServerPacket, PacketAction, PacketFamily};
 
-use crate::LANG;
+use crate::{
+    db::{Sanction, SanctionKind},
+    LANG,
+};
 
 use super::super::World;
 
 impl World {
-    // TODO: Server side mute?
-    // https://github.com/sorokya/reoserv/issues/187
-    pub fn mute_player(&mut self, victim_name: String, admin_name: String) {
-        let player_id = match self.characters.get(&victim_name) {
-            Some(player_id) => player_id,
-            None => return,
+    pub async fn mute_player(
+        &mut self,
+        victim_name: String,
+        admin_name: String,
+        duration: String,
+        global_only: bool,
+    ) {
+        // Only an omitted duration means permanent, a typo shouldn't
+        let duration = if duration.is_empty() {
+            0
+        } else {
+            match duration_str::parse(&duration) {
+                Ok(duration) => ((duration.as_secs() / 60) as i32).max(1),
+                Err(_) => {
+                    self.send_admin_reply(
+                        &admin_name,
+                        &format!("Invalid duration \"{}\".", duration),
+                    );
+                    return;
+                }
+            }
         };
 
-        let player = match self.players.get(player_id) {
-            Some(player) => player,
+        let character_id = match self.get_sanction_target(&victim_name, &admin_name).await {
+            Some(character_id) => character_id,
             None => return,
         };
 
-        player.send(
-            PacketAction::Spec,
-            PacketFamily::Talk,
-            &TalkSpecServerPacket {
-                admin_name: admin_name.to_owned(),
+        let kind = if global_only {
+            SanctionKind::GlobalMute
+        } else {
+            SanctionKind::Mute
+        };
+
+        let sanction = Sanction::new(kind, duration, "", &admin_name);
+        if let Err(e) = self.db.create_sanction(character_id, &sanction).await {
+            error!("Failed to mute player: {}", e);
+            return;
+        }
+
+        if let Some(player) = self
+            .characters
+            .get(&victim_name)
+            .and_then(|player_id| self.players.get(player_id))
+        {
+            player.add_mute(sanction);
+
+            if !global_only {
+                player.send(
+                    PacketAction::Spec,
+                    PacketFamily::Talk,
+                    &TalkSpecServerPacket {
+                        admin_name: admin_name.to_owned(),
+                    },
+                );
+            }
+        }
+
+        self.broadcast_server_message(&get_lang_string!(
+            if global_only {
+                &LANG.announce_global_mute
+            } else {
+                &LANG.announce_mute
             },
-        );
+            victim = victim_name,
+            name = admin_name
+        ));
+    }
+
+    pub async fn unmute_player(&mut self, victim_name: String, admin_name: String) {
+        let character_id = match self.get_sanction_target(&victim_name, &admin_name).await {
+            Some(character_id) => character_id,
+            None => return,
+        };
+
+        let lifted = match self.db.lift_mutes(character_id).await {
+            Ok(lifted) => lifted,
+            Err(e) => {
+                error!("Failed to unmute player: {}", e);
+                return;
+            }
+        };
+
+        if let Some(player) = self
+            .characters
+            .get(&victim_name)
+            .and_then(|player_id| self.players.get(player_id))
+        {
+            player.clear_mutes();
+        }
+
+        if lifted == 0 {
+            self.send_admin_reply(&admin_name, &format!("{} is not muted.", victim_name));
+            return;
+        }
 
         self.broadcast_server_message(&get_lang_string!(
-            &LANG.announce_mute,
+            &LANG.announce_unmute,
             victim = victim_name,
             name = admin_name
         ));
     }
+
+    /// Id of the character an admin is sanctioning, letting the admin know if
+    /// there's no such character
+    pub(super) async fn get_sanction_target(
+        &self,
+        victim_name: &str,
+        admin_name: &str,
+    ) -> Option<i32> {
+        match self.db.get_character_id(victim_name).await {
+            Ok(Some(character_id)) => Some(character_id),
+            Ok(None) => {
+                self.send_admin_reply(
+                    admin_name,
+                    &format!("Character \"{}\" does not exist.", victim_name),
+                );
+                None
+            }
+            Err(e) => {
+                error!("Failed to get character id: {}", e);
+                None
+            }
+        }
+    }
+
+    pub(super) fn send_admin_reply(&self, admin_name: &str, message: &str) {
+        if let Some(admin) = self
+            .characters
+            .get(admin_name)
+            .and_then(|player_id| self.players.get(player_id))
+        {
+            admin.send_server_message(message);
+        }
+    }
 }
@@ -8,8 +8,6 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
 use super::super::World;
 use crate::{utils::capitalize, SETTINGS};
@@ -86,26 +84,17 @@ impl World {
         message: String,
         reportee_name: String,
     ) {
-        let pool = self.pool.clone();
+        let db = self.db.clone();
         tokio::spawn(async move {
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Failed to get connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            if let Err(e) = conn.exec_drop(
-                include_str!("../../../sql/create_board_post.sql"),
-                params! {
-                    "board_id" => SETTINGS.board.admin_board,
-                    "character_id" => character_id,
-                    "subject" => format!("[Report] {} reports {}", capitalize(&player_name), capitalize(&reportee_name)),
-                    "body" => message,
-                },
-            )
-            .await {
+            let subject = format!(
+                "[Report] {} reports {}",
+                capitalize(&player_name),
+                capitalize(&reportee_name)
+            );
+            if let Err(e) = db
+                .create_board_post(SETTINGS.board.admin_board, character_id, &subject, &message)
+                .await
+            {
                 error!("Failed to add report to admin board: {}", e);
             }
         });
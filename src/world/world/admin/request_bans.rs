@@ -0,0 +1,96 @@
+use crate::db::{Ban, BanTarget};
+
+use super::super::World;
+
+impl World {
+    /// Sends an admin the account, address and hardware id of a player
+    /// along with any bans that stop them from logging in
+    pub async fn request_ban_info(&mut self, player_id: i32, victim_name: String) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let account = match self.db.get_character_account(&victim_name).await {
+            Ok(Some(account)) => account,
+            Ok(None) => {
+                player
+                    .send_server_message(&format!("Character \"{}\" does not exist.", victim_name));
+                return;
+            }
+            Err(e) => {
+                error!("Failed to get account: {}", e);
+                return;
+            }
+        };
+
+        let bans = match self.get_active_bans().await {
+            Some(bans) => bans,
+            None => return,
+        };
+
+        player.send_server_message(&format!(
+            "{} account {} ip {} hdid {}",
+            victim_name,
+            account.account_id,
+            account.last_login_ip.as_deref().unwrap_or("none"),
+            account.hdid
+        ));
+
+        let target = BanTarget {
+            account_id: Some(account.account_id),
+            ip: account.last_login_ip.as_deref(),
+            hdid: Some(&account.hdid),
+        };
+
+        let bans = bans
+            .iter()
+            .filter(|ban| ban.matches(&target))
+            .collect::<Vec<_>>();
+
+        if bans.is_empty() {
+            player.send_server_message(&format!("No active bans for \"{}\".", victim_name));
+            return;
+        }
+
+        for ban in bans.iter().rev() {
+            player.send_server_message(&ban.to_string());
+        }
+    }
+
+    pub async fn request_bans(&mut self, player_id: i32, limit: usize) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let bans = match self.get_active_bans().await {
+            Some(bans) => bans,
+            None => return,
+        };
+
+        if bans.is_empty() {
+            player.send_server_message("No active bans.");
+            return;
+        }
+
+        // Oldest first so the newest ends up at the bottom of the chat
+        for ban in bans.iter().take(limit).rev() {
+            player.send_server_message(&ban.to_string());
+        }
+    }
+
+    async fn get_active_bans(&self) -> Option<Vec<Ban>> {
+        match self.db.get_bans().await {
+            Ok(bans) => Some(
+                bans.into_iter()
+                    .filter(|ban| ban.minutes_remaining().is_some())
+                    .collect(),
+            ),
+            Err(e) => {
+                error!("Failed to get bans: {}", e);
+                None
+            }
+        }
+    }
+}
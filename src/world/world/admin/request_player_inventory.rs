@@ -2,28 +2,37 @@ use eolib::protocol::net::{
     server::AdminInteractListServerPacket, PacketAction, PacketFamily, ThreeItem,
 };
 
+use crate::character::Character;
+
 use super::super::World;
 
 impl World {
-    // TODO: Work when player offline
     pub fn request_player_inventory(&mut self, player_id: i32, name: String) {
         let player = match self.players.get(&player_id) {
             Some(player) => player.to_owned(),
             None => return,
         };
 
-        let target_player_id = match self.characters.get(&name) {
-            Some(player_id) => player_id,
-            None => return,
-        };
+        let target = self
+            .characters
+            .get(&name)
+            .and_then(|player_id| self.players.get(player_id))
+            .cloned();
 
-        let target = match self.players.get(target_player_id) {
-            Some(player) => player.to_owned(),
-            None => return,
-        };
+        let db = self.db.clone();
 
         tokio::spawn(async move {
-            let character = match target.get_character().await {
+            // Offline characters are looked up in the database instead
+            let character = match target {
+                Some(target) => target.get_character().await.map_err(|e| e.to_string()),
+                None => match Character::load(&db, &name).await {
+                    Ok(Some(character)) => Ok(Box::new(character)),
+                    Ok(None) => return,
+                    Err(e) => Err(e.to_string()),
+                },
+            };
+
+            let character = match character {
                 Ok(character) => character,
                 Err(e) => {
                     error!("Failed to get character: {}", e);
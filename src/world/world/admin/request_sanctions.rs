@@ -0,0 +1,41 @@
+use super::super::World;
+
+impl World {
+    pub async fn request_sanctions(&mut self, player_id: i32, victim_name: String) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let character_id = match self.db.get_character_id(&victim_name).await {
+            Ok(Some(character_id)) => character_id,
+            Ok(None) => {
+                player
+                    .send_server_message(&format!("Character \"{}\" does not exist.", victim_name));
+                return;
+            }
+            Err(e) => {
+                error!("Failed to get character id: {}", e);
+                return;
+            }
+        };
+
+        let sanctions = match self.db.get_sanctions(character_id).await {
+            Ok(sanctions) => sanctions,
+            Err(e) => {
+                error!("Failed to get sanctions: {}", e);
+                return;
+            }
+        };
+
+        if sanctions.is_empty() {
+            player.send_server_message(&format!("No sanctions for \"{}\".", victim_name));
+            return;
+        }
+
+        // Oldest first so the newest ends up at the bottom of the chat
+        for sanction in sanctions.iter().rev() {
+            player.send_server_message(&sanction.to_string());
+        }
+    }
+}
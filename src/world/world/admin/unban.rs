@@ -0,0 +1,62 @@
+use crate::db::{parse_ip_range, Ban};
+
+use super::super::World;
+
+impl World {
+    /// Lifts the bans on a player's account, an address or range, or a
+    /// hardware id
+    pub async fn unban(&mut self, target: String, admin_name: String) {
+        let bans = match self.db.get_bans().await {
+            Ok(bans) => bans,
+            Err(e) => {
+                error!("Failed to get bans: {}", e);
+                return;
+            }
+        };
+
+        let bans: Vec<Ban> = match parse_ip_range(&target) {
+            Some(range) => bans
+                .into_iter()
+                .filter(|ban| {
+                    ban.ip
+                        .as_deref()
+                        .and_then(parse_ip_range)
+                        .is_some_and(|ip| ip.trunc() == range.trunc())
+                })
+                .collect(),
+            None => {
+                let account_id = match self.db.get_character_account(&target).await {
+                    Ok(account) => account.map(|account| account.account_id),
+                    Err(e) => {
+                        error!("Failed to get account: {}", e);
+                        return;
+                    }
+                };
+
+                bans.into_iter()
+                    .filter(|ban| {
+                        (account_id.is_some() && ban.account_id == account_id)
+                            || ban.hdid.as_deref() == Some(target.as_str())
+                    })
+                    .collect()
+            }
+        };
+
+        if bans.is_empty() {
+            self.send_admin_reply(&admin_name, &format!("No bans found for {}.", target));
+            return;
+        }
+
+        for ban in &bans {
+            if let Err(e) = self.db.lift_ban(ban.id).await {
+                error!("Failed to lift ban {}: {}", ban.id, e);
+                return;
+            }
+        }
+
+        self.send_admin_reply(
+            &admin_name,
+            &format!("Lifted {} ban(s) on {}.", bans.len(), target),
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use super::super::World;
+
+impl World {
+    /// Clears the failed login lockouts on an account name or address
+    pub async fn unlock_login(&mut self, target: String, admin_name: String) {
+        let cleared = match self.db.delete_login_locks(&target.to_lowercase()).await {
+            Ok(cleared) => cleared,
+            Err(e) => {
+                error!("Failed to clear login locks: {}", e);
+                return;
+            }
+        };
+
+        if cleared == 0 {
+            self.send_admin_reply(
+                &admin_name,
+                &format!("No login locks found for {}.", target),
+            );
+            return;
+        }
+
+        self.send_admin_reply(
+            &admin_name,
+            &format!("Cleared {} login lock(s) on {}.", cleared, target),
+        );
+    }
+}
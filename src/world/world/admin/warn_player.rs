@@ -0,0 +1,35 @@
+use crate::{
+    db::{Sanction, SanctionKind},
+    LANG,
+};
+
+use super::super::World;
+
+impl World {
+    pub async fn warn_player(&mut self, victim_name: String, admin_name: String, reason: String) {
+        let character_id = match self.get_sanction_target(&victim_name, &admin_name).await {
+            Some(character_id) => character_id,
+            None => return,
+        };
+
+        let sanction = Sanction::new(SanctionKind::Warning, 0, &reason, &admin_name);
+        if let Err(e) = self.db.create_sanction(character_id, &sanction).await {
+            error!("Failed to warn player: {}", e);
+            return;
+        }
+
+        if let Some(player) = self
+            .characters
+            .get(&victim_name)
+            .and_then(|player_id| self.players.get(player_id))
+        {
+            player.send_server_message(&get_lang_string!(
+                &LANG.warning,
+                reason = reason,
+                name = admin_name
+            ));
+        }
+
+        self.send_admin_reply(&admin_name, &format!("{} has been warned.", victim_name));
+    }
+}
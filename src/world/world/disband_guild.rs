@@ -1,6 +1,3 @@
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
-
 use super::World;
 
 impl World {
@@ -16,7 +13,7 @@ impl World {
             .map(|player| player.to_owned())
             .collect::<Vec<_>>();
 
-        let pool = self.pool.clone();
+        let db = self.db.clone();
 
         tokio::spawn(async move {
             for (index, player) in online_players.iter().enumerate() {
@@ -32,30 +29,7 @@ impl World {
                 map.kick_from_guild(player_id);
             }
 
-            let mut conn = match pool.get_conn().await {
-                Ok(conn) => conn,
-                Err(e) => {
-                    error!("Error getting connection from pool: {}", e);
-                    return;
-                }
-            };
-
-            if let Err(e) = conn
-                .exec_drop(
-                    include_str!("../../sql/delete_guild.sql"),
-                    params! {
-                        "tag" => &guild_tag,
-                    },
-                )
-                .await
-            {
-                error!("Error deleting guild: {}", e);
-            }
-
-            if let Err(e) = conn
-                .query_drop(include_str!("../../sql/cleanup_guildless_characters.sql"))
-                .await
-            {
+            if let Err(e) = db.delete_guild(&guild_tag).await {
                 error!("Error deleting guild: {}", e);
             }
         });
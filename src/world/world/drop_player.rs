@@ -1,5 +1,7 @@
 use tokio::sync::oneshot;
 
+use crate::metrics;
+
 use super::World;
 
 impl World {
@@ -13,6 +15,7 @@ impl World {
         respond_to: oneshot::Sender<()>,
     ) {
         self.connection_log.remove_connection(&ip);
+        metrics::CONNECTIONS.set(self.connection_log.len() as i64);
 
         if !self.players.contains_key(&player_id) {
             let _ = respond_to.send(());
@@ -21,6 +24,10 @@ impl World {
 
         self.players.remove(&player_id);
 
+        if self.login_queue.remove(player_id).is_some() {
+            self.pending_logins.retain(|id| *id != account_id);
+        }
+
         if account_id > 0 {
             self.accounts.retain(|id| *id != account_id);
         }
@@ -43,6 +50,8 @@ impl World {
             }
         }
 
+        self.admit_queued_logins();
+
         let _ = respond_to.send(());
     }
 }
@@ -0,0 +1,21 @@
+use super::super::World;
+
+impl World {
+    pub fn admit_queued_logins(&mut self) {
+        while !self.is_full() {
+            let login = match self.login_queue.pop() {
+                Some(login) => login,
+                None => return,
+            };
+
+            let player = match self.players.get(&login.player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            self.pending_logins.retain(|id| *id != login.account_id);
+            self.accounts.push(login.account_id);
+            player.admit_login();
+        }
+    }
+}
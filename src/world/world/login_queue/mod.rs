@@ -0,0 +1,3 @@
+mod admit_queued_logins;
+mod request_login;
+mod send_login_queue_positions;
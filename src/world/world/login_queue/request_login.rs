@@ -0,0 +1,50 @@
+use crate::{
+    world::{LoginQueueResult, QueuedLogin},
+    LANG, SETTINGS,
+};
+
+use super::super::World;
+
+impl World {
+    /// Logs the account in if there's room for it, otherwise puts it in the
+    /// login queue. Accounts waiting in the queue stay pending so they can't
+    /// log in twice
+    pub fn request_login(
+        &mut self,
+        player_id: i32,
+        account_id: i32,
+        priority: bool,
+    ) -> LoginQueueResult {
+        if self.login_queue.is_empty() && !self.is_full() {
+            self.pending_logins.retain(|id| *id != account_id);
+            self.accounts.push(account_id);
+            return LoginQueueResult::Admitted;
+        }
+
+        if !SETTINGS.login_queue.enabled {
+            return LoginQueueResult::Full;
+        }
+
+        let position = self.login_queue.push(QueuedLogin {
+            player_id,
+            account_id,
+            priority,
+        });
+
+        if let Some(player) = self.players.get(&player_id) {
+            player.send_server_message(&get_lang_string!(
+                &LANG.login_queued,
+                position = position,
+                total = self.login_queue.len()
+            ));
+        }
+
+        LoginQueueResult::Queued { position }
+    }
+
+    /// Logged in accounts count towards max_players so slots handed to
+    /// queued players are held for them while they pick a character
+    pub fn is_full(&self) -> bool {
+        self.accounts.len() as i32 >= SETTINGS.server.max_players
+    }
+}
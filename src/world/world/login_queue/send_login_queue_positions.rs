@@ -0,0 +1,18 @@
+use crate::LANG;
+
+use super::super::World;
+
+impl World {
+    pub fn send_login_queue_positions(&self) {
+        let total = self.login_queue.len();
+        for (index, login) in self.login_queue.iter().enumerate() {
+            if let Some(player) = self.players.get(&login.player_id) {
+                player.send_server_message(&get_lang_string!(
+                    &LANG.login_queued,
+                    position = index + 1,
+                    total = total
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use eolib::protocol::net::{client::FileType, PacketAction, PacketFamily};
+use tokio::task;
+
+use crate::{
+    arenas::Arenas,
+    commands::Commands,
+    formulas::Formulas,
+    npc_abilities::NpcAbilities,
+    packet_rate_limits::PacketRateLimits,
+    player::PlayerHandle,
+    player_commands::PlayerCommands,
+    status_effects::StatusEffects,
+    utils::{
+        get_pub_file_reply, load_class_file, load_drop_file, load_inn_file, load_item_file,
+        load_npc_file, load_quests, load_shop_file, load_skill_master_file, load_spell_file,
+        load_talk_file, ClientPubFiles,
+    },
+    world::ReloadKind,
+    ARENAS, CLASS_DB, CLIENT_PUB_FILES, COMMANDS, DROP_DB, FORMULAS, INN_DB, ITEM_DB,
+    NPC_ABILITIES, NPC_DB, PACKET_RATE_LIMITS, PLAYER_COMMANDS, QUEST_DB, SHOP_DB, SKILL_MASTER_DB,
+    SPELL_DB, STATUS_EFFECTS, TALK_DB,
+};
+
+use super::World;
+
+impl World {
+    pub fn reload_data(&mut self, kind: ReloadKind) {
+        if matches!(kind, ReloadKind::Pub | ReloadKind::All) {
+            // Reading (and with generate_pub, writing) every pub file is slow
+            // so it's done off the world actor
+            let players: Vec<PlayerHandle> = self
+                .characters
+                .values()
+                .filter_map(|player_id| self.players.get(player_id))
+                .cloned()
+                .collect();
+
+            tokio::spawn(async move {
+                let result =
+                    task::spawn_blocking(|| reload_pub_files().map_err(|e| e.to_string())).await;
+
+                match result {
+                    Ok(Ok(changed)) => {
+                        info!("Pub files reloaded");
+                        send_pub_files(&players, &changed);
+                    }
+                    Ok(Err(e)) => error!("Failed to reload pub files, keeping old ones: {}", e),
+                    Err(e) => error!("Failed to reload pub files: {}", e),
+                }
+            });
+        }
+
+        if matches!(kind, ReloadKind::Config | ReloadKind::All) {
+            match reload_config() {
+                Ok(()) => info!("Config reloaded"),
+                Err(e) => error!("Failed to reload config, keeping old one: {}", e),
+            }
+        }
+
+        if matches!(kind, ReloadKind::Quests | ReloadKind::All) {
+            let previous = QUEST_DB.swap(Arc::new(load_quests()));
+            info!("Quests reloaded: {}", QUEST_DB.load().len());
+
+            if let Some(maps) = self.maps.as_ref() {
+                for map in maps.values() {
+                    map.reload_quests(previous.clone());
+                }
+            }
+        }
+    }
+}
+
+fn send_pub_files(players: &[PlayerHandle], file_types: &[FileType]) {
+    let replies = {
+        let client_pub_files = CLIENT_PUB_FILES.load();
+        file_types
+            .iter()
+            .flat_map(|file_type| {
+                let pieces = client_pub_files
+                    .get(*file_type)
+                    .map_or(0, |file| file.files.len() as i32);
+                (1..=pieces).filter_map(|file_id| get_pub_file_reply(*file_type, file_id))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for player in players {
+        for reply in &replies {
+            player.send(PacketAction::Init, PacketFamily::Init, reply);
+        }
+    }
+}
+
+/// Loads every pub file before swapping any of them in so a bad file leaves
+/// the server on the previous set. Returns the client files that changed.
+fn reload_pub_files() -> Result<Vec<FileType>, Box<dyn std::error::Error>> {
+    let classes = load_class_file()?;
+    let drops = load_drop_file()?;
+    let inns = load_inn_file()?;
+    let items = load_item_file()?;
+    let npcs = load_npc_file()?;
+    let shops = load_shop_file()?;
+    let skill_masters = load_skill_master_file()?;
+    let spells = load_spell_file()?;
+    let talks = load_talk_file()?;
+
+    CLASS_DB.store(Arc::new(classes));
+    DROP_DB.store(Arc::new(drops));
+    INN_DB.store(Arc::new(inns));
+    ITEM_DB.store(Arc::new(items));
+    NPC_DB.store(Arc::new(npcs));
+    SHOP_DB.store(Arc::new(shops));
+    SKILL_MASTER_DB.store(Arc::new(skill_masters));
+    SPELL_DB.store(Arc::new(spells));
+    TALK_DB.store(Arc::new(talks));
+
+    let client_pub_files = ClientPubFiles::load();
+    let changed = client_pub_files.changed_since(&CLIENT_PUB_FILES.load());
+    CLIENT_PUB_FILES.store(Arc::new(client_pub_files));
+
+    Ok(changed)
+}
+
+fn reload_config() -> Result<(), config::ConfigError> {
+    let arenas = Arenas::new()?;
+    let commands = Commands::new()?;
+    let formulas = Formulas::new()?;
+    let npc_abilities = NpcAbilities::new()?;
+    let packet_rate_limits = PacketRateLimits::new()?;
+    let player_commands = PlayerCommands::new()?;
+    let status_effects = StatusEffects::new()?;
+
+    ARENAS.store(Arc::new(arenas));
+    COMMANDS.store(Arc::new(commands));
+    FORMULAS.store(Arc::new(formulas));
+    NPC_ABILITIES.store(Arc::new(npc_abilities));
+    PACKET_RATE_LIMITS.store(Arc::new(packet_rate_limits));
+    PLAYER_COMMANDS.store(Arc::new(player_commands));
+    STATUS_EFFECTS.store(Arc::new(status_effects));
+
+    Ok(())
+}
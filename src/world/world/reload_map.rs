@@ -10,6 +10,8 @@ use eolib::{
 };
 use tokio::fs::File;
 
+use crate::DATA_DIR;
+
 use super::World;
 
 impl World {
@@ -24,7 +26,7 @@ impl World {
             None => return,
         };
 
-        let raw_path = format!("data/maps/{:0>5}.emf", map_id);
+        let raw_path = format!("{}/maps/{:0>5}.emf", DATA_DIR, map_id);
         let path = Path::new(&raw_path);
 
         if !Path::exists(path) {
@@ -1,11 +1,17 @@
+use crate::metrics;
+
 use super::World;
 
 impl World {
     pub async fn save(&mut self) {
+        let timer = metrics::SAVE_SECONDS.start_timer();
+
         if let Some(maps) = self.maps.as_ref() {
             for map in maps.values() {
                 map.save().await;
             }
         }
+
+        timer.observe_duration();
     }
 }
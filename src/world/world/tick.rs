@@ -1,4 +1,4 @@
-use crate::SETTINGS;
+use crate::{metrics, SETTINGS};
 
 use super::World;
 
@@ -28,6 +28,8 @@ impl World {
             for player in self.players.values() {
                 player.tick();
             }
+
+            metrics::PLAYERS_ONLINE.set(self.characters.len() as i64);
         }
 
         for map in maps {
@@ -51,6 +53,7 @@ impl World {
                 }
                 map.timed_drop_protection();
                 map.timed_ghost();
+                map.timed_status_effects();
             }
 
             if self.item_spawn_ticks >= SETTINGS.world.chest_spawn_rate {
@@ -80,6 +83,15 @@ impl World {
 
         if self.second_ticks >= ONE_SECOND {
             self.second_ticks = 0;
+            self.admit_queued_logins();
+
+            self.login_queue_ticks += 1;
+            if self.login_queue_ticks >= SETTINGS.login_queue.position_message_rate {
+                self.login_queue_ticks = 0;
+                if SETTINGS.login_queue.position_message_rate > 0 {
+                    self.send_login_queue_positions();
+                }
+            }
         }
 
         if self.auto_pickup_ticks >= SETTINGS.auto_pickup.rate && SETTINGS.auto_pickup.enabled {
@@ -1,25 +1,41 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::PartyExpShare, PartyRequestType};
-use mysql_async::Pool;
 use tokio::sync::{mpsc, oneshot};
 
-use crate::{character::Character, map::MapHandle, player::PlayerHandle};
+use crate::{
+    audit::AuditHandle,
+    character::Character,
+    db::Db,
+    email::OutboxHandle,
+    map::MapHandle,
+    metrics::{self, QueueSender},
+    player::PlayerHandle,
+};
 
-use super::{world::World, Command, Party};
+use super::{world::World, Command, LoginQueueResult, Party, ReloadKind};
 
 #[derive(Debug, Clone)]
 pub struct WorldHandle {
-    tx: mpsc::UnboundedSender<Command>,
+    tx: QueueSender<Command>,
     pub is_alive: bool,
+    pub audit: AuditHandle,
+    pub outbox: OutboxHandle,
 }
 
 impl WorldHandle {
-    pub fn new(pool: Pool) -> Self {
+    pub fn new(db: Db, audit: AuditHandle, outbox: OutboxHandle) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        let world = World::new(rx, pool);
+        let world = World::new(rx, db);
         tokio::spawn(run_world(world));
 
-        Self { tx, is_alive: true }
+        Self {
+            tx: QueueSender::new(tx, metrics::WORLD_MAILBOX_DEPTH.clone()),
+            is_alive: true,
+            audit,
+            outbox,
+        }
     }
 
     pub fn accept_party_request(
@@ -35,10 +51,6 @@ impl WorldHandle {
         });
     }
 
-    pub fn add_logged_in_account(&self, account_id: i32) {
-        let _ = self.tx.send(Command::AddLoggedInAccount { account_id });
-    }
-
     pub fn add_pending_login(&self, account_id: i32) {
         let _ = self.tx.send(Command::AddPendingLogin { account_id });
     }
@@ -86,18 +98,36 @@ impl WorldHandle {
         Ok(())
     }
 
+    pub fn ban_hdid(&self, hdid: String, admin_name: String, duration: String) {
+        let _ = self.tx.send(Command::BanHdid {
+            hdid,
+            admin_name,
+            duration,
+        });
+    }
+
+    pub fn ban_ip(&self, ip: String, admin_name: String, duration: String) {
+        let _ = self.tx.send(Command::BanIp {
+            ip,
+            admin_name,
+            duration,
+        });
+    }
+
     pub fn ban_player(
         &self,
         victim_name: String,
         duration: String,
         admin_name: String,
         silent: bool,
+        ban_ip_and_hdid: bool,
     ) {
         let _ = self.tx.send(Command::BanPlayer {
             victim_name,
             duration,
             admin_name,
             silent,
+            ban_ip_and_hdid,
         });
     }
 
@@ -206,6 +236,12 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_maps(&self) -> HashMap<i32, MapHandle> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetMaps { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_next_player_id(
         &self,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
@@ -238,6 +274,12 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_online_players(&self) -> Vec<PlayerHandle> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetOnlinePlayers { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_player(&self, player_id: i32) -> Option<PlayerHandle> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetPlayer {
@@ -295,10 +337,18 @@ impl WorldHandle {
         rx.await.unwrap();
     }
 
-    pub fn mute_player(&self, victim_name: String, admin_name: String) {
+    pub fn mute_player(
+        &self,
+        victim_name: String,
+        admin_name: String,
+        duration: String,
+        global_only: bool,
+    ) {
         let _ = self.tx.send(Command::MutePlayer {
             victim_name,
             admin_name,
+            duration,
+            global_only,
         });
     }
 
@@ -314,6 +364,22 @@ impl WorldHandle {
         });
     }
 
+    pub async fn request_login(
+        &self,
+        player_id: i32,
+        account_id: i32,
+        priority: bool,
+    ) -> LoginQueueResult {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::RequestLogin {
+            player_id,
+            account_id,
+            priority,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
     pub fn request_party_list(&self, player_id: i32) {
         let _ = self.tx.send(Command::RequestPartyList { player_id });
     }
@@ -332,6 +398,17 @@ impl WorldHandle {
         });
     }
 
+    pub fn request_ban_info(&self, player_id: i32, victim_name: String) {
+        let _ = self.tx.send(Command::RequestBanInfo {
+            player_id,
+            victim_name,
+        });
+    }
+
+    pub fn request_bans(&self, player_id: i32, limit: usize) {
+        let _ = self.tx.send(Command::RequestBans { player_id, limit });
+    }
+
     pub fn request_player_info(&self, player_id: i32, victim_name: String) {
         let _ = self.tx.send(Command::RequestPlayerInfo {
             player_id,
@@ -354,6 +431,17 @@ impl WorldHandle {
         let _ = self.tx.send(Command::RequestPlayerList { player_id });
     }
 
+    pub fn request_sanctions(&self, player_id: i32, victim_name: String) {
+        let _ = self.tx.send(Command::RequestSanctions {
+            player_id,
+            victim_name,
+        });
+    }
+
+    pub fn reload(&self, kind: ReloadKind) {
+        let _ = self.tx.send(Command::Reload { kind });
+    }
+
     pub fn reload_map(&self, map_id: i32) {
         let _ = self.tx.send(Command::ReloadMap { map_id });
     }
@@ -397,6 +485,14 @@ impl WorldHandle {
         let _ = self.tx.send(Command::ToggleGlobal { admin_name });
     }
 
+    pub fn unban(&self, target: String, admin_name: String) {
+        let _ = self.tx.send(Command::Unban { target, admin_name });
+    }
+
+    pub fn unlock_login(&self, target: String, admin_name: String) {
+        let _ = self.tx.send(Command::UnlockLogin { target, admin_name });
+    }
+
     pub fn unfreeze_player(&self, victim_name: String, admin_name: String) {
         let _ = self.tx.send(Command::UnfreezePlayer {
             victim_name,
@@ -404,6 +500,13 @@ impl WorldHandle {
         });
     }
 
+    pub fn unmute_player(&self, victim_name: String, admin_name: String) {
+        let _ = self.tx.send(Command::UnmutePlayer {
+            victim_name,
+            admin_name,
+        });
+    }
+
     pub fn update_party_hp(&self, player_id: i32, hp_percentage: i32) {
         let _ = self.tx.send(Command::UpdatePartyHP {
             player_id,
@@ -417,11 +520,20 @@ impl WorldHandle {
             exp_gains,
         });
     }
+
+    pub fn warn_player(&self, victim_name: String, admin_name: String, reason: String) {
+        let _ = self.tx.send(Command::WarnPlayer {
+            victim_name,
+            admin_name,
+            reason,
+        });
+    }
 }
 
 async fn run_world(mut world: World) {
     loop {
         if let Some(command) = world.rx.recv().await {
+            metrics::WORLD_MAILBOX_DEPTH.dec();
             world.handle_command(command).await;
         }
     }